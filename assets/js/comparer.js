@@ -0,0 +1,304 @@
+import * as THREE from '../../../../three/three.webgpu.min.js';
+import { STLLoader } from '../../../../three/loaders/STLLoader.js';
+import { GLTFLoader } from '../../../../three/loaders/GLTFLoader.js';
+import { DRACOLoader } from '../../../../three/loaders/DRACOLoader.js';
+import { OrbitControls } from '../../../../three/OrbitControls.js';
+
+const COLORS = {
+    left: '#1872f0',
+    right: '#ed760e',
+    ghost: '#b5b5b5',
+    same: '#48c78e',
+    near: '#ffe08a',
+    far: '#f14668',
+};
+
+// Deviations are measured relative to the size of both models
+const TOLERANCE_RATIO = 0.001;
+const MAX_DEVIATION_RATIO = 0.05;
+
+// Only one comparison is shown at a time
+let activeComparer = null;
+
+// Shows two models in synchronised viewers (side by side) or one over another
+// with the second model coloured by its deviation from the first one
+export class ModelComparer {
+    constructor(config) {
+        ({
+            left: this.left,
+            right: this.right,
+            mode: this.mode,
+            labels: this.labels,
+        } = config);
+        this.views = [];
+        this.isInitialized = false;
+    }
+
+    async starter() {
+        activeComparer?.destroy();
+        activeComparer = this;
+        this.hull = document.querySelector('compare-hull');
+        if (!this.hull) return;
+        this.containers().forEach(container => container.textContent = '');
+
+        this.camera = new THREE.PerspectiveCamera(45, 1, 0.1, 5000);
+        try {
+            const [leftObject, rightObject] = await Promise.all([
+                this.loadModel(this.left),
+                this.loadModel(this.right),
+            ]);
+            // The comparison was replaced while the models were loading
+            if (activeComparer !== this) return;
+            const maxSize = this.placeModels(leftObject, rightObject);
+            if (this.mode === 'Overlay') {
+                paintObject(leftObject, COLORS.ghost, 0.35);
+                paintDeviation(rightObject, leftObject, maxSize);
+                await this.addView(this.hull.querySelector('o-container'), [leftObject, rightObject]);
+            } else {
+                paintObject(leftObject, COLORS.left, 1);
+                paintObject(rightObject, COLORS.right, 1);
+                await this.addView(this.hull.querySelector('l-container'), [leftObject]);
+                await this.addView(this.hull.querySelector('r-container'), [rightObject]);
+            }
+            this.isInitialized = true;
+            this.startAnimation();
+        } catch (error) {
+            console.error('Failed to compare models:', error);
+            this.containers().forEach(container => {
+                container.textContent = `${this.labels.failed_to_load_model}: ${error?.message || error}`;
+            });
+        }
+    }
+
+    containers() {
+        return Array.from(this.hull?.querySelectorAll('l-container, r-container, o-container') || []);
+    }
+
+    // Centers both models by their common bounding box so that their relative position is kept
+    placeModels(leftObject, rightObject) {
+        const box = new THREE.Box3().setFromObject(leftObject).union(new THREE.Box3().setFromObject(rightObject));
+        const center = box.getCenter(new THREE.Vector3());
+        const size = box.getSize(new THREE.Vector3());
+        const maxSize = Math.max(size.x, size.y, size.z) || 1;
+        leftObject.position.sub(center);
+        rightObject.position.sub(center);
+        leftObject.updateMatrixWorld(true);
+        rightObject.updateMatrixWorld(true);
+
+        this.camera.near = maxSize / 100;
+        this.camera.far = maxSize * 100;
+        this.camera.position.set(maxSize, maxSize, maxSize);
+        this.camera.lookAt(0, 0, 0);
+        this.camera.updateProjectionMatrix();
+        return maxSize;
+    }
+
+    async addView(container, objects) {
+        if (!container) return;
+        const scene = new THREE.Scene();
+        scene.background = new THREE.Color('#fff');
+        scene.add(new THREE.AmbientLight(0xffffff, 0.6));
+        const light = new THREE.DirectionalLight(0xffffff, 0.8);
+        scene.add(light);
+        objects.forEach(object => scene.add(object));
+
+        const renderer = new THREE.WebGPURenderer({ alpha: true, antialias: true });
+        await renderer.init();
+        renderer.setPixelRatio(Math.min(window.devicePixelRatio, 2));
+        renderer.setSize(container.clientWidth, container.clientHeight);
+        container.append(renderer.domElement);
+        this.camera.aspect = container.clientWidth / (container.clientHeight || 1);
+        this.camera.updateProjectionMatrix();
+
+        // All controls move the same camera, so the views stay synchronised
+        const controls = new OrbitControls(this.camera, renderer.domElement);
+
+        const resizeObserver = new ResizeObserver(entries => {
+            for (const entry of entries) {
+                const { width, height } = entry.contentRect;
+                if (width > 0 && height > 0) {
+                    renderer.setSize(width, height);
+                    this.camera.aspect = width / height;
+                    this.camera.updateProjectionMatrix();
+                }
+            }
+        });
+        resizeObserver.observe(container);
+        this.views.push({ container, scene, light, renderer, controls, resizeObserver });
+    }
+
+    startAnimation() {
+        const animate = () => {
+            if (!this.isInitialized) return;
+            this.animationId = requestAnimationFrame(animate);
+            if (this.views.some(view => view.container.clientHeight === 0)) {
+                this.destroy();
+                return;
+            }
+            this.views.forEach(view => {
+                view.light.position.copy(this.camera.position);
+                view.renderer.render(view.scene, this.camera);
+            });
+        };
+        animate();
+    }
+
+    loadModel(model) {
+        return new Promise((resolve, reject) => {
+            switch (model.model_format) {
+                case 'STL':
+                    new STLLoader().load(
+                        model.url,
+                        geometry => resolve(new THREE.Mesh(geometry)),
+                        undefined,
+                        reject
+                    );
+                    break;
+                case 'GLTF':
+                case 'GLB':
+                    const loader = new GLTFLoader();
+                    const dracoLoader = new DRACOLoader();
+                    dracoLoader.setDecoderPath('../../../../three/draco/');
+                    dracoLoader.setDecoderConfig({ type: 'wasm' });
+                    loader.setDRACOLoader(dracoLoader);
+                    loader.load(
+                        model.url,
+                        gltf => {
+                            dracoLoader.dispose();
+                            resolve(gltf.scene);
+                        },
+                        undefined,
+                        error => {
+                            dracoLoader.dispose();
+                            reject(error);
+                        }
+                    );
+                    break;
+                default:
+                    reject(new Error(`${this.labels.format_not_supported}: ${model.filename}`));
+            }
+        });
+    }
+
+    destroy() {
+        this.isInitialized = false;
+        if (this.animationId) cancelAnimationFrame(this.animationId);
+        this.views.forEach(view => {
+            view.controls.dispose();
+            view.resizeObserver.disconnect();
+            view.renderer.dispose();
+            view.container.textContent = '';
+        });
+        this.views = [];
+        if (activeComparer === this) activeComparer = null;
+    }
+}
+
+function paintObject(object, color, opacity) {
+    object.traverse(child => {
+        if (!child.isMesh) return;
+        child.material = new THREE.MeshStandardMaterial({
+            color: new THREE.Color(color),
+            metalness: 0.2,
+            roughness: 0.6,
+            transparent: opacity < 1,
+            opacity,
+            depthWrite: opacity >= 1,
+        });
+    });
+}
+
+// Colours every vertex of the object by the distance to the nearest vertex of the reference
+function paintDeviation(object, reference, maxSize) {
+    const tolerance = maxSize * TOLERANCE_RATIO;
+    const maxDeviation = maxSize * MAX_DEVIATION_RATIO;
+    const grid = new PointGrid(collectPoints(reference), maxDeviation);
+    const same = new THREE.Color(COLORS.same);
+    const near = new THREE.Color(COLORS.near);
+    const far = new THREE.Color(COLORS.far);
+    const color = new THREE.Color();
+    const vertex = new THREE.Vector3();
+
+    object.traverse(child => {
+        if (!child.isMesh) return;
+        child.geometry = child.geometry.clone();
+        const position = child.geometry.attributes.position;
+        const colors = new Float32Array(position.count * 3);
+        for (let i = 0; i < position.count; i++) {
+            vertex.fromBufferAttribute(position, i).applyMatrix4(child.matrixWorld);
+            const distance = grid.nearest(vertex);
+            if (distance <= tolerance) {
+                color.copy(same);
+            } else {
+                const t = Math.min((distance - tolerance) / (maxDeviation - tolerance), 1);
+                color.copy(near).lerp(far, t);
+            }
+            color.toArray(colors, i * 3);
+        }
+        child.geometry.setAttribute('color', new THREE.BufferAttribute(colors, 3));
+        child.material = new THREE.MeshStandardMaterial({
+            vertexColors: true,
+            metalness: 0.2,
+            roughness: 0.6,
+        });
+    });
+}
+
+function collectPoints(object) {
+    const points = [];
+    const vertex = new THREE.Vector3();
+    object.traverse(child => {
+        if (!child.isMesh) return;
+        const position = child.geometry.attributes.position;
+        for (let i = 0; i < position.count; i++) {
+            vertex.fromBufferAttribute(position, i).applyMatrix4(child.matrixWorld);
+            points.push(vertex.x, vertex.y, vertex.z);
+        }
+    });
+    return points;
+}
+
+// Uniform grid for searching the nearest point within one cell around the query
+class PointGrid {
+    constructor(points, cellSize) {
+        this.points = points;
+        this.cellSize = cellSize || 1;
+        this.cells = new Map();
+        for (let i = 0; i < points.length; i += 3) {
+            const key = this.key(
+                Math.floor(points[i] / this.cellSize),
+                Math.floor(points[i + 1] / this.cellSize),
+                Math.floor(points[i + 2] / this.cellSize)
+            );
+            const cell = this.cells.get(key);
+            cell ? cell.push(i) : this.cells.set(key, [i]);
+        }
+    }
+
+    key(x, y, z) {
+        return `${x},${y},${z}`;
+    }
+
+    // Returns the distance to the nearest point, or Infinity if it is farther than one cell
+    nearest(vertex) {
+        const cx = Math.floor(vertex.x / this.cellSize);
+        const cy = Math.floor(vertex.y / this.cellSize);
+        const cz = Math.floor(vertex.z / this.cellSize);
+        let best = Infinity;
+        for (let x = cx - 1; x <= cx + 1; x++) {
+            for (let y = cy - 1; y <= cy + 1; y++) {
+                for (let z = cz - 1; z <= cz + 1; z++) {
+                    const cell = this.cells.get(this.key(x, y, z));
+                    if (!cell) continue;
+                    for (const i of cell) {
+                        const dx = this.points[i] - vertex.x;
+                        const dy = this.points[i + 1] - vertex.y;
+                        const dz = this.points[i + 2] - vertex.z;
+                        best = Math.min(best, dx * dx + dy * dy + dz * dz);
+                    }
+                }
+            }
+        }
+        return Math.sqrt(best);
+    }
+}
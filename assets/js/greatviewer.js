@@ -29,6 +29,17 @@ const COLORS = {
     dark: 0x000000,         // #000000
 };
 
+// The last started viewer, its state is used for the view link
+let activeViewer = null;
+
+export function getViewerState() {
+    return activeViewer?.getState() ?? null;
+}
+
+export function setPinMode(enabled) {
+    activeViewer?.setPinMode(enabled);
+}
+
 export class GreatViewer {
     constructor(config) {
         ({
@@ -36,6 +47,7 @@ export class GreatViewer {
             model_format: this.modelFormat,
             resource_mapping: this.resourceMapping,
             size_flag: this.sizeFlag,
+            initial_state: this.initialState,
             labels: this.labels
         } = config);
 
@@ -118,6 +130,14 @@ export class GreatViewer {
         this.infoMessage = null;
         this.useCustomMaterial = false;
         this.isWireframe = false;
+        this.modelColor = null;
+        // Annotation pin
+        this.pin = null;
+        this.pinMarker = null;
+        this.pinMode = false;
+        this.pointerStart = null;
+        this.handlePointerDown = this.handlePointerDown.bind(this);
+        this.handlePointerUp = this.handlePointerUp.bind(this);
         // GCode
         this.parsedLayers = [];
         this.gcodeLayers = [];
@@ -178,6 +198,7 @@ export class GreatViewer {
     async starter() {
         if (this.initPromise) return this.initPromise;
         this.startTime = performance.now();
+        activeViewer = this;
         this.initPromise = this._starterInternal();
         return this.initPromise;
     }
@@ -247,6 +268,8 @@ export class GreatViewer {
 
         this.controls = new OrbitControls(this.camera, this.renderer.domElement);
         this.controls.enableDamping = true;
+        this.renderer.domElement.addEventListener('pointerdown', this.handlePointerDown);
+        this.renderer.domElement.addEventListener('pointerup', this.handlePointerUp);
 
         this.infoMessage = document.createElement('div');
         this.infoMessage.classList.add('text-center');
@@ -324,6 +347,10 @@ export class GreatViewer {
                 this.scene.rotation.x += 0.005;
                 this.scene.rotation.y += 0.01;
             }
+            // Highlight the pin by pulsing
+            if (this.pinMarker) {
+                this.pinMarker.scale.setScalar(1 + 0.25 * Math.sin(performance.now() / 200));
+            }
             this.renderer.render(this.scene, this.camera);
         };
 
@@ -367,17 +394,112 @@ export class GreatViewer {
         }
         if (!this.sizeFlag) {
             this.updateViewPreset(this.labels.view_isometric);
+            this.applyInitialState();
             return;
         }
+        if (this.modelFormat == 'GCode') {
+            this.updateViewPreset(this.labels.view_top);
+        }
+        // Restore the state before creating the GUI so the controls show the restored values
+        this.applyInitialState();
         this.setControlsGui();
         if (!this.stats) return;
         this.container.appendChild(this.stats.dom);
         if (this.modelFormat == 'GCode') {
-            this.updateViewPreset(this.labels.view_top);
             this.updateGCodeLayers();
         }
     }
 
+    applyInitialState() {
+        const state = this.initialState;
+        this.initialState = null;
+        if (!state || !this.mesh) return;
+        if (state.camera && state.target) {
+            this.camera.position.fromArray(state.camera);
+            this.controls.target.fromArray(state.target);
+            this.controls.enableRotate = true;
+            this.controls.update();
+        }
+        if (state.wireframe && this.modelFormat != 'GCode') {
+            this.isWireframe = true;
+            this.useCustomMaterial = true;
+            this.updateMaterial();
+        }
+        if (state.model_color) {
+            this.setMaterialColor('#' + state.model_color);
+        }
+        if (state.background) {
+            this.scene.background.set('#' + state.background);
+        }
+        if (state.layer != null && this.modelFormat == 'GCode') {
+            // Layers are split only in full screen mode, otherwise the layer is kept for the next start
+            this.currentGCodeLayer = this.sizeFlag ? Math.min(state.layer, this.gcodeLayers.length - 1) : state.layer;
+        }
+        if (state.pin) {
+            this.setPin(new THREE.Vector3().fromArray(state.pin));
+        }
+    }
+
+    setPinMode(enabled) {
+        this.pinMode = enabled;
+        if (this.renderer) {
+            this.renderer.domElement.style.cursor = enabled ? 'crosshair' : '';
+        }
+    }
+
+    handlePointerDown(e) {
+        this.pointerStart = [e.clientX, e.clientY];
+    }
+
+    handlePointerUp(e) {
+        if (!this.pinMode || !this.pointerStart || !this.mesh) return;
+        const [startX, startY] = this.pointerStart;
+        this.pointerStart = null;
+        // Skip camera rotation by dragging
+        if (Math.hypot(e.clientX - startX, e.clientY - startY) > 5) return;
+        const rect = this.renderer.domElement.getBoundingClientRect();
+        const pointer = new THREE.Vector2(
+            ((e.clientX - rect.left) / rect.width) * 2 - 1,
+            -((e.clientY - rect.top) / rect.height) * 2 + 1
+        );
+        const raycaster = new THREE.Raycaster();
+        raycaster.setFromCamera(pointer, this.camera);
+        const hit = raycaster.intersectObject(this.mesh, true)[0];
+        if (!hit) return;
+        // The pin is stored in the scene coordinates so it does not depend on the scale and rotation
+        this.setPin(this.scene.worldToLocal(hit.point.clone()));
+    }
+
+    setPin(point) {
+        this.pin = point;
+        if (!this.pinMarker) {
+            const box = new THREE.Box3().setFromObject(this.mesh);
+            const radius = box.getSize(new THREE.Vector3()).length() / this.scene.scale.x * 0.01;
+            this.pinMarker = new THREE.Mesh(
+                new THREE.SphereGeometry(radius, 16, 16),
+                new THREE.MeshBasicMaterial({ color: COLORS['red'], depthTest: false, transparent: true, opacity: 0.9 })
+            );
+            // Always visible through the model
+            this.pinMarker.renderOrder = 999;
+            this.scene.add(this.pinMarker);
+        }
+        this.pinMarker.position.copy(point);
+    }
+
+    getState() {
+        if (!this.isInitialized || !this.mesh) return null;
+        const background = this.scene.background.getHexString();
+        return {
+            camera: this.camera.position.toArray(),
+            target: this.controls.target.toArray(),
+            wireframe: this.isWireframe,
+            model_color: this.modelColor,
+            background: background == 'ffffff' ? null : background,
+            layer: this.modelFormat == 'GCode' && this.currentGCodeLayer > 0 ? this.currentGCodeLayer : null,
+            pin: this.pin?.toArray() ?? null
+        };
+    }
+
     loadModel() {
         console.log('=== STARTING LOAD ===');
         console.log('Model format:', this.modelFormat);
@@ -790,6 +912,7 @@ export class GreatViewer {
     }
 
     setMaterialColor(color) {
+        this.modelColor = new THREE.Color(color).getHexString();
         if (this.modelFormat == 'GCode') {
             this.gcodeLayers?.forEach(layer => {
                 layer.object?.children[0]?.material?.color.set(color);
@@ -981,6 +1104,8 @@ export class GreatViewer {
         // Clearing Three.js objects
         this.controls?.dispose();
         if (this.renderer) {
+            this.renderer.domElement?.removeEventListener('pointerdown', this.handlePointerDown);
+            this.renderer.domElement?.removeEventListener('pointerup', this.handlePointerUp);
             try {
                 this.renderer.forceContextLoss();
             } catch (e) {
@@ -996,5 +1121,6 @@ export class GreatViewer {
         // Unsubscribing from events
         document.removeEventListener('keydown', this.handleKeyDown);
         this.resizeObserver?.disconnect();
+        if (activeViewer === this) activeViewer = null;
     }
 }
\ No newline at end of file
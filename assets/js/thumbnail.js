@@ -0,0 +1,119 @@
+import * as THREE from '../../../../three/three.webgpu.min.js';
+import { STLLoader } from '../../../../three/loaders/STLLoader.js';
+import { GLTFLoader } from '../../../../three/loaders/GLTFLoader.js';
+import { DRACOLoader } from '../../../../three/loaders/DRACOLoader.js';
+
+// Camera directions for the supported views (same as presets of the viewer)
+const VIEW_DIRECTIONS = {
+    Isometric: [1, 1, 1],
+    Front: [0, 0, 1],
+    Back: [0, 0, -1],
+    Top: [0, 1, 0],
+    Bottom: [0, -1, 0],
+    Left: [-1, 0, 0],
+    Right: [1, 0, 0],
+};
+
+// Renders a model to an offscreen canvas and returns the PNG image as bytes
+export class ThumbnailRenderer {
+    constructor(config) {
+        ({
+            model: this.model,
+            model_format: this.modelFormat,
+            view: this.view,
+            width: this.width,
+            height: this.height,
+            background: this.background,
+            model_color: this.modelColor,
+        } = config);
+        this.material = new THREE.MeshStandardMaterial({
+            color: new THREE.Color(this.modelColor),
+            metalness: 0.3,
+            roughness: 0.5,
+        });
+    }
+
+    async render() {
+        const canvas = document.createElement('canvas');
+        canvas.width = this.width;
+        canvas.height = this.height;
+        const renderer = new THREE.WebGPURenderer({
+            canvas,
+            alpha: false,
+            antialias: true,
+            preserveDrawingBuffer: true,
+        });
+        await renderer.init();
+        renderer.setPixelRatio(1);
+        renderer.setSize(this.width, this.height, false);
+
+        const scene = new THREE.Scene();
+        scene.background = new THREE.Color(this.background);
+        scene.add(new THREE.AmbientLight(0xffffff, 0.6));
+
+        try {
+            const object = await this.loadModel();
+            scene.add(object);
+            // Center the model and place the camera in front of the selected view
+            const box = new THREE.Box3().setFromObject(object);
+            const center = box.getCenter(new THREE.Vector3());
+            object.position.sub(center);
+            const size = box.getSize(new THREE.Vector3());
+            const maxSize = Math.max(size.x, size.y, size.z) || 1;
+            const [x, y, z] = VIEW_DIRECTIONS[this.view] || VIEW_DIRECTIONS.Isometric;
+            const direction = new THREE.Vector3(x, y, z).normalize();
+            const camera = new THREE.PerspectiveCamera(45, this.width / this.height, maxSize / 100, maxSize * 100);
+            camera.position.copy(direction.multiplyScalar(maxSize * 2));
+            camera.lookAt(0, 0, 0);
+            const light = new THREE.DirectionalLight(0xffffff, 0.8);
+            light.position.copy(camera.position);
+            scene.add(light);
+
+            await renderer.renderAsync(scene, camera);
+            const blob = await new Promise((resolve, reject) => canvas.toBlob(
+                (result) => result ? resolve(result) : reject(new Error('Failed to encode PNG')),
+                'image/png'
+            ));
+            return new Uint8Array(await blob.arrayBuffer());
+        } finally {
+            renderer.dispose();
+        }
+    }
+
+    loadModel() {
+        return new Promise((resolve, reject) => {
+            switch (this.modelFormat) {
+                case 'STL':
+                    new STLLoader().load(
+                        this.model.url,
+                        (geometry) => resolve(new THREE.Mesh(geometry, this.material)),
+                        undefined,
+                        reject
+                    );
+                    break;
+                case 'GLTF':
+                case 'GLB':
+                    const loader = new GLTFLoader();
+                    const dracoLoader = new DRACOLoader();
+                    dracoLoader.setDecoderPath('../../../../three/draco/');
+                    dracoLoader.setDecoderConfig({ type: 'wasm' });
+                    loader.setDRACOLoader(dracoLoader);
+                    loader.load(
+                        this.model.url,
+                        (gltf) => {
+                            dracoLoader.dispose();
+                            resolve(gltf.scene);
+                        },
+                        undefined,
+                        (error) => {
+                            dracoLoader.dispose();
+                            reject(error);
+                        }
+                    );
+                    break;
+                default:
+                    reject(new Error(`Unsupported format: ${this.modelFormat}`));
+            }
+        });
+    }
+}
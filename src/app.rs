@@ -21,12 +21,13 @@ use crate::routes::{
     profile::Profile,
     register::Register,
     settings::Settings,
-    component::{ShowComponent, ComponentSettings, CreateComponent},
+    component::{ShowComponent, ComponentSettings, CreateComponent, CompareComponents},
     company::{ShowSupplierCompany, ShowCompany, CompanySettings, CreateCompany},
     standard::{ShowStandard, StandardSettings, CreateStandard},
+    bom::BomWorkspace,
     AppRoute,
 };
-use crate::services::{is_authenticated, get_current_user, get_value_field, title_changer};
+use crate::services::{is_authenticated, get_current_user, get_value_field, title_changer, rerun_saved_searches};
 use crate::types::SlimUser;
 
 /// The root app component
@@ -44,6 +45,8 @@ pub enum Msg {
     CurrentUserResponse(Result<SlimUser, Error>),
     Route(Route),
     Authenticated(SlimUser),
+    RerunSavedSearches,
+    SavedSearchesChecked,
     Logout,
 }
 
@@ -85,6 +88,7 @@ impl Component for App {
                 match res {
                     Ok(slim_user) => {
                         self.current_user = Some(slim_user);
+                        self.link.send_message(Msg::RerunSavedSearches);
                     },
                     Err(err) => {
                         debug!("Error with CurrentUserResponse: {:#?}", err);
@@ -98,7 +102,18 @@ impl Component for App {
             }
             Msg::Authenticated(slim_user) => {
                 self.current_user = Some(slim_user);
+                self.link.send_message(Msg::RerunSavedSearches);
             }
+            Msg::RerunSavedSearches => {
+                let link = self.link.clone();
+                spawn_local(async move {
+                    rerun_saved_searches().await;
+                    // the pages read the results of the saved searches when rendered again
+                    link.send_message(Msg::SavedSearchesChecked);
+                });
+                return false
+            }
+            Msg::SavedSearchesChecked => {}
             Msg::Logout => {
                 self.current_user = None;
             }
@@ -129,6 +144,8 @@ impl Component for App {
                             AppRoute::Register => html!{<Register />},
                             AppRoute::Home => html!{<Home />},
                             AppRoute::SearchPage => html!{<SearchPage />},
+                            AppRoute::Bom => html!{<BomWorkspace />},
+                            AppRoute::CompareComponents => html!{<CompareComponents />},
                             AppRoute::Notifications => html!{<Notifications />},
                             AppRoute::Settings => html!{<Settings />},
                             AppRoute::Profile(_username) =>
@@ -0,0 +1,661 @@
+use yew::{html, Callback, ChangeData, MouseEvent, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{
+    get_value_field, keyword_ids, parse_keywords, resp_parsing, BulkAction, BulkActionKind, BulkTarget,
+};
+use crate::types::{Keyword, ShowStandardShort, SpecPathInfo, TypeAccessInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::relate::{GetTypesAccess, get_types_access, SearchSpecs, search_specs};
+use crate::gqls::component::{
+    ChangeComponentAccess, change_component_access,
+    AddComponentKeywordsByNames, add_component_keywords_by_names,
+    GetComponentKeywords, get_component_keywords,
+    DeleteComponentKeywords, delete_component_keywords,
+    AddComponentSpecs, add_component_specs,
+    AddStandardToComponent, add_standard_to_component,
+    AddComponentFav, add_component_fav,
+    DeleteComponentFav, delete_component_fav,
+    DeleteComponent, delete_component,
+};
+use crate::gqls::standard::{
+    GetStandardsShortList, get_standards_short_list,
+    ChangeStandardAccess, change_standard_access,
+    AddStandardKeywordsByNames, add_standard_keywords_by_names,
+    GetStandardKeywords, get_standard_keywords,
+    DeleteStandardKeywords, delete_standard_keywords,
+    AddStandardSpecs, add_standard_specs,
+    AddStandardFav, add_standard_fav,
+    DeleteStandardFav, delete_standard_fav,
+    DeleteStandard, delete_standard,
+};
+use crate::gqls::company::{
+    ChangeCompanyAccess, change_company_access,
+    AddCompanySpecs, add_company_specs,
+    AddCompanyFav, add_company_fav,
+    DeleteCompanyFav, delete_company_fav,
+    DeleteCompany, delete_company,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub target: BulkTarget,
+    /// Selected objects: UUID and name for the report
+    pub selected: Vec<(UUID, String)>,
+    /// Called after the action is applied to all selected objects
+    pub callback_finish: Callback<()>,
+}
+
+/// Bar with the action for the objects selected in the catalog. The action is applied to the objects
+/// one by one by the mutations for a single object, the errors are reported for each object.
+pub struct BulkActionBar {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    kind: Option<BulkActionKind>,
+    types_access: Vec<TypeAccessInfo>,
+    type_access_id: usize,
+    keywords: String,
+    spec_search: String,
+    found_specs: Vec<SpecPathInfo>,
+    spec_id: usize,
+    standards: Vec<ShowStandardShort>,
+    standard_uuid: UUID,
+    confirm_delete: bool,
+    /// Objects of the running action, the action is applied to them in order
+    queue: Vec<(UUID, String)>,
+    action: Option<BulkAction>,
+    processed: usize,
+    failures: Vec<(String, Error)>,
+}
+
+pub enum Msg {
+    SelectKind(String),
+    GetTypesAccessResult(String),
+    UpdateTypeAccess(String),
+    UpdateKeywords(String),
+    UpdateSpecSearch(String),
+    RequestSearchSpecs,
+    GetSearchSpecsResult(String),
+    UpdateSpec(String),
+    GetStandardsResult(String),
+    UpdateStandard(String),
+    RequestApply,
+    ConfirmDelete,
+    RunNext,
+    GetItemResult(String, Result<(), Error>),
+    ClearError,
+}
+
+impl Component for BulkActionBar {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            kind: None,
+            types_access: Vec::new(),
+            type_access_id: 0,
+            keywords: String::new(),
+            spec_search: String::new(),
+            found_specs: Vec::new(),
+            spec_id: 0,
+            standards: Vec::new(),
+            standard_uuid: String::new(),
+            confirm_delete: false,
+            queue: Vec::new(),
+            action: None,
+            processed: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::SelectKind(value) => {
+                self.kind = BulkActionKind::from_value(&value);
+                match self.kind {
+                    Some(BulkActionKind::ChangeAccess) if self.types_access.is_empty() => spawn_local(async move {
+                        let res = make_query(GetTypesAccess::build_query(
+                            get_types_access::Variables { type_access_ids: None }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetTypesAccessResult(res));
+                    }),
+                    Some(BulkActionKind::AttachStandard) if self.standards.is_empty() => spawn_local(async move {
+                        let res = make_query(GetStandardsShortList::build_query(
+                            get_standards_short_list::Variables { ipt_standards_arg: None }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetStandardsResult(res));
+                    }),
+                    _ => (),
+                }
+            },
+            Msg::GetTypesAccessResult(res) => {
+                match resp_parsing::<Vec<TypeAccessInfo>>(res, "typesAccess") {
+                    Ok(types_access) => {
+                        self.type_access_id = types_access.first().map(|t| t.type_access_id).unwrap_or_default();
+                        self.types_access = types_access;
+                    },
+                    Err(err) => self.error = Some(err),
+                }
+            },
+            Msg::UpdateTypeAccess(value) => self.type_access_id = value.parse().unwrap_or_default(),
+            Msg::UpdateKeywords(value) => self.keywords = value,
+            Msg::UpdateSpecSearch(value) => self.spec_search = value,
+            Msg::RequestSearchSpecs => {
+                let ipt_search_spec_arg = search_specs::IptSearchSpecArg {
+                    text: self.spec_search.trim().to_string(),
+                    splitChar: None,
+                    depthLevel: None,
+                };
+                spawn_local(async move {
+                    let res = make_query(SearchSpecs::build_query(search_specs::Variables {
+                        ipt_search_spec_arg
+                    })).await.unwrap();
+                    link.send_message(Msg::GetSearchSpecsResult(res));
+                })
+            },
+            Msg::GetSearchSpecsResult(res) => {
+                match resp_parsing::<Vec<SpecPathInfo>>(res, "searchSpecs") {
+                    Ok(found_specs) => {
+                        self.spec_id = found_specs.first().map(|s| s.spec_id).unwrap_or_default();
+                        self.found_specs = found_specs;
+                    },
+                    Err(err) => self.error = Some(err),
+                }
+            },
+            Msg::UpdateSpec(value) => self.spec_id = value.parse().unwrap_or_default(),
+            Msg::GetStandardsResult(res) => {
+                match resp_parsing::<Vec<ShowStandardShort>>(res, "standards") {
+                    Ok(standards) => {
+                        self.standard_uuid = standards.first().map(|s| s.uuid.clone()).unwrap_or_default();
+                        self.standards = standards;
+                    },
+                    Err(err) => self.error = Some(err),
+                }
+            },
+            Msg::UpdateStandard(value) => self.standard_uuid = value,
+            Msg::RequestApply => {
+                match self.kind {
+                    Some(BulkActionKind::Delete) => self.confirm_delete = !self.confirm_delete,
+                    _ => self.start(),
+                }
+            },
+            Msg::ConfirmDelete => {
+                self.confirm_delete = false;
+                self.start();
+            },
+            Msg::RunNext => {
+                if let (Some(action), Some((uuid, name))) = (self.action.clone(), self.queue.get(self.processed).cloned()) {
+                    let target = self.props.target;
+                    spawn_local(async move {
+                        let result = apply_action(target, action, uuid).await;
+                        link.send_message(Msg::GetItemResult(name, result));
+                    });
+                    return true
+                }
+                debug!("Bulk action {:?} finished, errors: {}", self.action.take(), self.failures.len());
+                self.queue.clear();
+                self.props.callback_finish.emit(());
+            },
+            Msg::GetItemResult(name, result) => {
+                if let Err(err) = result {
+                    self.failures.push((name, err));
+                }
+                self.processed += 1;
+                link.send_message(Msg::RunNext);
+            },
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onchange_kind = self.link.callback(|ev: ChangeData| Msg::SelectKind(match ev {
+            ChangeData::Select(el) => el.value(),
+            _ => String::new(),
+        }));
+        let onclick_apply = self.link.callback(|_| Msg::RequestApply);
+        let running = self.action.is_some();
+        html!{
+            <div id="bulk-action-bar" class="box p-3 mb-3">
+                <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                <div class="is-flex is-flex-wrap-wrap is-align-items-center">
+                    <span class="mr-3 has-text-weight-bold">
+                        {format!("{} {}", self.props.selected.len(), get_value_field(&663))} // selected
+                    </span>
+                    <div class="select is-small mr-2">
+                        <select id="bulk-action-kind" onchange={onchange_kind} disabled={running}>
+                            <option value="" selected={self.kind.is_none()}>{get_value_field(&678)}</option> // Action for the selected items
+                            {for self.props.target.actions().into_iter().map(|kind| html!{
+                                <option value={kind.value()} selected={self.kind == Some(kind)}>{kind.label()}</option>
+                            })}
+                        </select>
+                    </div>
+                    {self.show_action_data(running)}
+                    <button
+                        id="bulk-action-apply"
+                        class={match (running, self.kind) {
+                            (true, _) => "button is-small is-info is-loading",
+                            (false, Some(BulkActionKind::Delete)) => "button is-small is-danger",
+                            (false, _) => "button is-small is-info",
+                        }}
+                        disabled={running || self.props.selected.is_empty() || self.build_action().is_none()}
+                        onclick={onclick_apply} >
+                        {get_value_field(&566)} // Apply
+                    </button>
+                </div>
+                {self.show_progress()}
+                {match self.confirm_delete {
+                    true => self.show_confirm_delete(),
+                    false => html!{},
+                }}
+            </div>
+        }
+    }
+}
+
+impl BulkActionBar {
+    /// The action with the entered data, `None` while the data is not entered
+    fn build_action(&self) -> Option<BulkAction> {
+        let action = match self.kind? {
+            BulkActionKind::ChangeAccess => BulkAction::ChangeAccess(self.type_access_id),
+            BulkActionKind::AddKeywords => BulkAction::AddKeywords(parse_keywords(&self.keywords)),
+            BulkActionKind::RemoveKeywords => BulkAction::RemoveKeywords(parse_keywords(&self.keywords)),
+            BulkActionKind::AddSpec => BulkAction::AddSpec(self.spec_id),
+            BulkActionKind::AttachStandard => BulkAction::AttachStandard(self.standard_uuid.clone()),
+            BulkActionKind::Follow => BulkAction::Follow,
+            BulkActionKind::Unfollow => BulkAction::Unfollow,
+            BulkActionKind::Delete => BulkAction::Delete,
+        };
+        let ready = match &action {
+            BulkAction::ChangeAccess(id) | BulkAction::AddSpec(id) => *id > 0,
+            BulkAction::AddKeywords(keywords) | BulkAction::RemoveKeywords(keywords) => !keywords.is_empty(),
+            BulkAction::AttachStandard(uuid) => !uuid.is_empty(),
+            _ => true,
+        };
+        ready.then_some(action)
+    }
+
+    fn start(&mut self) {
+        self.action = self.build_action();
+        self.queue = self.props.selected.clone();
+        self.processed = 0;
+        self.failures.clear();
+        self.link.send_message(Msg::RunNext);
+    }
+
+    fn show_action_data(&self, running: bool) -> Html {
+        match self.kind {
+            Some(BulkActionKind::ChangeAccess) => {
+                let onchange = self.link.callback(|ev: ChangeData| Msg::UpdateTypeAccess(match ev {
+                    ChangeData::Select(el) => el.value(),
+                    _ => String::new(),
+                }));
+                html!{
+                    <div class="select is-small mr-2">
+                        <select id="bulk-type-access" onchange={onchange} disabled={running}>
+                            {for self.types_access.iter().map(|t| html!{
+                                <option value={t.type_access_id.to_string()} selected={t.type_access_id == self.type_access_id}>
+                                    {&t.name}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                }
+            },
+            Some(BulkActionKind::AddKeywords) | Some(BulkActionKind::RemoveKeywords) => {
+                let oninput = self.link.callback(|ev: InputData| Msg::UpdateKeywords(ev.value));
+                html!{
+                    <input
+                        id="bulk-keywords"
+                        class="input is-small mr-2"
+                        style="width: 20rem;"
+                        type="text"
+                        placeholder={get_value_field(&671)}
+                        value={self.keywords.clone()}
+                        disabled={running}
+                        oninput={oninput} />
+                }
+            },
+            Some(BulkActionKind::AddSpec) => {
+                let oninput = self.link.callback(|ev: InputData| Msg::UpdateSpecSearch(ev.value));
+                let onclick_search = self.link.callback(|_| Msg::RequestSearchSpecs);
+                let onchange = self.link.callback(|ev: ChangeData| Msg::UpdateSpec(match ev {
+                    ChangeData::Select(el) => el.value(),
+                    _ => String::new(),
+                }));
+                html!{<>
+                    <div class="field has-addons mb-0 mr-2">
+                        <div class="control">
+                            <input
+                                id="bulk-spec-search"
+                                class="input is-small"
+                                type="text"
+                                placeholder={get_value_field(&672)}
+                                value={self.spec_search.clone()}
+                                disabled={running}
+                                oninput={oninput} />
+                        </div>
+                        <div class="control">
+                            <button class="button is-small" onclick={onclick_search} disabled={running || self.spec_search.trim().is_empty()}>
+                                <span class="icon"><i class="fas fa-search" aria-hidden="true"></i></span>
+                            </button>
+                        </div>
+                    </div>
+                    {match self.found_specs.is_empty() {
+                        true => html!{},
+                        false => html!{
+                            <div class="select is-small mr-2">
+                                <select id="bulk-spec" onchange={onchange} disabled={running}>
+                                    {for self.found_specs.iter().map(|s| html!{
+                                        <option value={s.spec_id.to_string()} selected={s.spec_id == self.spec_id}>{&s.path}</option>
+                                    })}
+                                </select>
+                            </div>
+                        },
+                    }}
+                </>}
+            },
+            Some(BulkActionKind::AttachStandard) => {
+                let onchange = self.link.callback(|ev: ChangeData| Msg::UpdateStandard(match ev {
+                    ChangeData::Select(el) => el.value(),
+                    _ => String::new(),
+                }));
+                html!{
+                    <div class="select is-small mr-2">
+                        <select id="bulk-standard" onchange={onchange} disabled={running} title={get_value_field(&673)}>
+                            {for self.standards.iter().map(|s| html!{
+                                <option value={s.uuid.clone()} selected={s.uuid == self.standard_uuid}>{&s.name}</option>
+                            })}
+                        </select>
+                    </div>
+                }
+            },
+            _ => html!{},
+        }
+    }
+
+    fn show_progress(&self) -> Html {
+        if self.queue.is_empty() && self.processed == 0 {
+            return html!{}
+        }
+        let total = match self.queue.is_empty() {
+            true => self.processed,
+            false => self.queue.len(),
+        };
+        html!{
+            <div class="mt-3">
+                <p class="help">{format!("{} / {} {}", self.processed, total, get_value_field(&674))}</p> // processed
+                <progress
+                    class={match (self.queue.is_empty(), self.failures.is_empty()) {
+                        (_, false) => "progress is-small is-danger",
+                        (false, true) => "progress is-small is-info",
+                        (true, true) => "progress is-small is-success",
+                    }}
+                    value={self.processed.to_string()}
+                    max={total.max(1).to_string()}>
+                </progress>
+                {match (self.queue.is_empty(), self.failures.is_empty()) {
+                    (true, true) => html!{<p class="help is-success">{get_value_field(&677)}</p>}, // Done without errors
+                    (_, false) => html!{
+                        <div id="bulk-action-failures" class="notification is-danger is-light p-3">
+                            <p class="has-text-weight-bold">{get_value_field(&675)}</p> // Errors
+                            <ul>
+                                {for self.failures.iter().map(|(name, err)| html!{
+                                    <li>{format!("{}: {}", name, err)}</li>
+                                })}
+                            </ul>
+                        </div>
+                    },
+                    _ => html!{},
+                }}
+            </div>
+        }
+    }
+
+    fn show_confirm_delete(&self) -> Html {
+        let onclick_cancel = self.link.callback(|_| Msg::RequestApply);
+        let onclick_confirm = self.link.callback(|_| Msg::ConfirmDelete);
+        html!{
+            <div class="modal is-active">
+                <div class="modal-background" onclick={onclick_cancel.clone()} />
+                <div class="modal-content">
+                    <div class="box">
+                        <p class="mb-3">
+                            {get_value_field(&676)} // The selected items will be deleted...
+                            {format!(" {}", self.props.selected.len())}
+                        </p>
+                        <ul class="mb-3">
+                            {for self.props.selected.iter().map(|(_, name)| html!{<li>{name}</li>})}
+                        </ul>
+                        <div class="buttons">
+                            <button id="bulk-delete-confirm" class="button is-danger" onclick={onclick_confirm}>
+                                {get_value_field(&135)} // Delete
+                            </button>
+                            <button class="button" onclick={onclick_cancel}>{get_value_field(&221)}</button> // Cancel
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+/// Card of the catalog item with the checkbox of the selection
+pub(crate) fn select_item(checked: bool, item: Html, onclick: Callback<MouseEvent>) -> Html {
+    html!{
+        <div class="is-flex is-align-items-flex-start">
+            <label class="checkbox p-2">
+                <input type="checkbox" checked={checked} onclick={onclick} />
+            </label>
+            <div class="is-flex-grow-1">{item}</div>
+        </div>
+    }
+}
+
+fn mutation_result(res: String, key: &str) -> Result<(), Error> {
+    resp_parsing::<Value>(res, key).map(|_| ())
+}
+
+/// Applies the action to one object by the mutations for a single object
+async fn apply_action(target: BulkTarget, action: BulkAction, uuid: UUID) -> Result<(), Error> {
+    match (target, action) {
+        (BulkTarget::Component, BulkAction::ChangeAccess(type_access_id)) => {
+            let change_type_access_component = change_component_access::ChangeTypeAccessComponent {
+                componentUuid: uuid,
+                newTypeAccessId: type_access_id as i64,
+            };
+            let res = make_query(ChangeComponentAccess::build_query(
+                change_component_access::Variables { change_type_access_component }
+            )).await.unwrap();
+            mutation_result(res, "changeComponentAccess")
+        },
+        (BulkTarget::Component, BulkAction::AddKeywords(keywords)) => {
+            let ipt_component_keywords_names = add_component_keywords_by_names::IptComponentKeywordsNames {
+                componentUuid: uuid,
+                keywords,
+            };
+            let res = make_query(AddComponentKeywordsByNames::build_query(
+                add_component_keywords_by_names::Variables { ipt_component_keywords_names }
+            )).await.unwrap();
+            mutation_result(res, "addComponentKeywordsByNames")
+        },
+        (BulkTarget::Component, BulkAction::RemoveKeywords(keywords)) => {
+            let res = make_query(GetComponentKeywords::build_query(
+                get_component_keywords::Variables { component_uuid: uuid.clone() }
+            )).await.unwrap();
+            let existing = resp_parsing::<Vec<Keyword>>(res, "componentKeywords")?;
+            let keyword_ids: Vec<i64> = keyword_ids(&existing, &keywords).into_iter().map(|id| id as i64).collect();
+            if keyword_ids.is_empty() {
+                return Ok(())
+            }
+            let ipt_component_keywords_data = delete_component_keywords::IptComponentKeywordsData {
+                componentUuid: uuid,
+                keywordIds: keyword_ids,
+            };
+            let res = make_query(DeleteComponentKeywords::build_query(
+                delete_component_keywords::Variables { ipt_component_keywords_data }
+            )).await.unwrap();
+            mutation_result(res, "deleteComponentKeywords")
+        },
+        (BulkTarget::Component, BulkAction::AddSpec(spec_id)) => {
+            let ipt_component_specs_data = add_component_specs::IptComponentSpecsData {
+                componentUuid: uuid,
+                specIds: vec![spec_id as i64],
+            };
+            let res = make_query(AddComponentSpecs::build_query(
+                add_component_specs::Variables { ipt_component_specs_data }
+            )).await.unwrap();
+            mutation_result(res, "addComponentSpecs")
+        },
+        (BulkTarget::Component, BulkAction::AttachStandard(standard_uuid)) => {
+            let ipt_standard_to_component_data = add_standard_to_component::IptStandardToComponentData {
+                standardUuid: standard_uuid,
+                componentUuid: uuid,
+            };
+            let res = make_query(AddStandardToComponent::build_query(
+                add_standard_to_component::Variables { ipt_standard_to_component_data }
+            )).await.unwrap();
+            mutation_result(res, "addStandardToComponent")
+        },
+        (BulkTarget::Component, BulkAction::Follow) => {
+            let res = make_query(AddComponentFav::build_query(
+                add_component_fav::Variables { component_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "addComponentFav")
+        },
+        (BulkTarget::Component, BulkAction::Unfollow) => {
+            let res = make_query(DeleteComponentFav::build_query(
+                delete_component_fav::Variables { component_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteComponentFav")
+        },
+        (BulkTarget::Component, BulkAction::Delete) => {
+            let res = make_query(DeleteComponent::build_query(
+                delete_component::Variables { component_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteComponent")
+        },
+        (BulkTarget::Standard, BulkAction::ChangeAccess(type_access_id)) => {
+            let change_type_access_standard = change_standard_access::ChangeTypeAccessStandard {
+                standardUuid: uuid,
+                newTypeAccessId: type_access_id as i64,
+            };
+            let res = make_query(ChangeStandardAccess::build_query(
+                change_standard_access::Variables { change_type_access_standard }
+            )).await.unwrap();
+            mutation_result(res, "changeStandardAccess")
+        },
+        (BulkTarget::Standard, BulkAction::AddKeywords(keywords)) => {
+            let ipt_standard_keywords_names = add_standard_keywords_by_names::IptStandardKeywordsNames {
+                standardUuid: uuid,
+                keywords,
+            };
+            let res = make_query(AddStandardKeywordsByNames::build_query(
+                add_standard_keywords_by_names::Variables { ipt_standard_keywords_names }
+            )).await.unwrap();
+            mutation_result(res, "addStandardKeywordsByNames")
+        },
+        (BulkTarget::Standard, BulkAction::RemoveKeywords(keywords)) => {
+            let res = make_query(GetStandardKeywords::build_query(
+                get_standard_keywords::Variables { standard_uuid: uuid.clone() }
+            )).await.unwrap();
+            let existing = resp_parsing::<Vec<Keyword>>(res, "standardKeywords")?;
+            let keyword_ids: Vec<i64> = keyword_ids(&existing, &keywords).into_iter().map(|id| id as i64).collect();
+            if keyword_ids.is_empty() {
+                return Ok(())
+            }
+            let ipt_standard_keywords_data = delete_standard_keywords::IptStandardKeywordsData {
+                standardUuid: uuid,
+                keywordIds: keyword_ids,
+            };
+            let res = make_query(DeleteStandardKeywords::build_query(
+                delete_standard_keywords::Variables { ipt_standard_keywords_data }
+            )).await.unwrap();
+            mutation_result(res, "deleteStandardKeywords")
+        },
+        (BulkTarget::Standard, BulkAction::AddSpec(spec_id)) => {
+            let ipt_standard_specs_data = add_standard_specs::IptStandardSpecsData {
+                standardUuid: uuid,
+                specIds: vec![spec_id as i64],
+            };
+            let res = make_query(AddStandardSpecs::build_query(
+                add_standard_specs::Variables { ipt_standard_specs_data }
+            )).await.unwrap();
+            mutation_result(res, "addStandardSpecs")
+        },
+        (BulkTarget::Standard, BulkAction::Follow) => {
+            let res = make_query(AddStandardFav::build_query(
+                add_standard_fav::Variables { standard_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "addStandardFav")
+        },
+        (BulkTarget::Standard, BulkAction::Unfollow) => {
+            let res = make_query(DeleteStandardFav::build_query(
+                delete_standard_fav::Variables { standard_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteStandardFav")
+        },
+        (BulkTarget::Standard, BulkAction::Delete) => {
+            let res = make_query(DeleteStandard::build_query(
+                delete_standard::Variables { standard_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteStandard")
+        },
+        (BulkTarget::Company, BulkAction::ChangeAccess(type_access_id)) => {
+            let change_type_access_company = change_company_access::ChangeTypeAccessCompany {
+                companyUuid: uuid,
+                newTypeAccessId: type_access_id as i64,
+            };
+            let res = make_query(ChangeCompanyAccess::build_query(
+                change_company_access::Variables { change_type_access_company }
+            )).await.unwrap();
+            mutation_result(res, "changeCompanyAccess")
+        },
+        (BulkTarget::Company, BulkAction::AddSpec(spec_id)) => {
+            let ipt_company_specs_data = add_company_specs::IptCompanySpecsData {
+                companyUuid: uuid,
+                specIds: vec![spec_id as i64],
+            };
+            let res = make_query(AddCompanySpecs::build_query(
+                add_company_specs::Variables { ipt_company_specs_data }
+            )).await.unwrap();
+            mutation_result(res, "addCompanySpecs")
+        },
+        (BulkTarget::Company, BulkAction::Follow) => {
+            let res = make_query(AddCompanyFav::build_query(
+                add_company_fav::Variables { company_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "addCompanyFav")
+        },
+        (BulkTarget::Company, BulkAction::Unfollow) => {
+            let res = make_query(DeleteCompanyFav::build_query(
+                delete_company_fav::Variables { company_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteCompanyFav")
+        },
+        (BulkTarget::Company, BulkAction::Delete) => {
+            let res = make_query(DeleteCompany::build_query(
+                delete_company::Variables { delete_company_uuid: uuid }
+            )).await.unwrap();
+            mutation_result(res, "deleteCompany")
+        },
+        (target, action) => Err(Error::BadRequest(format!("{:?} is not supported for {:?}", action, target))),
+    }
+}
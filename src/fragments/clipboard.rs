@@ -9,6 +9,8 @@ pub struct ShareLinkBtn {
     open_window: bool,
     link: ComponentLink<Self>,
     share_link: String,
+    /// The link of the page is taken again when the window is opened (the query of the page can be changed)
+    location_link: bool,
     copyed: bool,
     input_id: String
 }
@@ -31,11 +33,9 @@ impl Component for ShareLinkBtn {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let window = web_sys::window().expect("no global `window` exists");
-        let document = window.document().expect("should have a document on window");
-        let location = document.location().expect("document should have a location");
-        let share_link = if props.share_link == String::new() {
-          location.href().unwrap()
+        let location_link = props.share_link.is_empty();
+        let share_link = if location_link {
+          location_href()
         } else {
           props.share_link
         };
@@ -45,6 +45,7 @@ impl Component for ShareLinkBtn {
             open_window: false,
             input_id: rand::random::<char>().to_string(),
             share_link,
+            location_link,
             copyed: false,
         }
     }
@@ -56,6 +57,8 @@ impl Component for ShareLinkBtn {
               self.open_window = !self.open_window;
               if !self.open_window {
                 self.copyed = false
+              } else if self.location_link {
+                self.share_link = location_href();
               }
             },
             Msg::Copyed(value) => self.copyed = value,
@@ -115,3 +118,10 @@ impl ShareLinkBtn {
         }
     }
 }
+
+fn location_href() -> String {
+    let window = web_sys::window().expect("no global `window` exists");
+    let document = window.document().expect("should have a document on window");
+    let location = document.location().expect("document should have a location");
+    location.href().unwrap()
+}
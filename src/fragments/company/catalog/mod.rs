@@ -10,16 +10,21 @@ use log::debug;
 use crate::routes::AppRoute;
 use crate::error::Error;
 use crate::fragments::{list_errors::ListErrors, list_empty::ListEmpty};
-use crate::types::{ShowCompanyShort, CompaniesQueryArg};
-use crate::services::{get_value_field, resp_parsing};
+use crate::types::{UUID, ShowCompanyShort, CompaniesQueryArg};
+use crate::services::{get_logged_user, get_value_field, resp_parsing, BulkTarget};
 use crate::gqls::make_query;
 use crate::gqls::company::{GetCompaniesShortList, get_companies_short_list};
 use crate::fragments::ListState;
+use crate::fragments::bulk_actions::{BulkActionBar, select_item};
 
 pub enum Msg {
     SwitchShowType,
     UpdateList(String),
     GetList,
+    SwitchSelectMode,
+    SelectItem(UUID),
+    SelectAll,
+    FinishBulk,
     ResponseError(Error),
     ClearError,
 }
@@ -29,7 +34,10 @@ pub struct CatalogCompanies {
     link: ComponentLink<Self>,
     props: Props,
     show_type: ListState,
-    list: Vec<ShowCompanyShort>
+    list: Vec<ShowCompanyShort>,
+    /// The checkboxes of the items and the bar of the bulk actions are shown
+    select_mode: bool,
+    selected: Vec<UUID>,
 }
 
 #[derive(Properties, Clone)]
@@ -48,7 +56,9 @@ impl Component for CatalogCompanies {
             link,
             props,
             show_type: ListState::get_from_storage(),
-            list: Vec::new()
+            list: Vec::new(),
+            select_mode: false,
+            selected: Vec::new(),
         }
     }
 
@@ -94,6 +104,26 @@ impl Component for CatalogCompanies {
                   Err(err) => link.send_message(Msg::ResponseError(err)),
               }
             },
+            Msg::SwitchSelectMode => {
+                self.select_mode = !self.select_mode;
+                self.selected.clear();
+            },
+            Msg::SelectItem(uuid) => match self.selected.iter().position(|u| u == &uuid) {
+                Some(index) => {
+                    self.selected.remove(index);
+                },
+                None => self.selected.push(uuid),
+            },
+            Msg::SelectAll => {
+                self.selected = match self.selected.len() == self.list.len() {
+                    true => Vec::new(),
+                    false => self.list.iter().map(|x| x.uuid.clone()).collect(),
+                };
+            },
+            Msg::FinishBulk => {
+                self.selected.clear();
+                link.send_message(Msg::GetList);
+            },
             Msg::ResponseError(err) => self.error = Some(err),
             Msg::ClearError => self.error = None,
         }
@@ -132,6 +162,7 @@ impl Component for CatalogCompanies {
               <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
               <div class="level" >
                 <div class="level-left">
+                    {self.show_select_mode()}
                 </div>
                 <div class="level-right">
                     <div class="buttons">
@@ -151,6 +182,10 @@ impl Component for CatalogCompanies {
                     </div>
                 </div>
               </div>
+              {match self.select_mode {
+                true => self.show_bulk_bar(),
+                false => html!{},
+              }}
               {if self.list.is_empty() {
                 html!{<ListEmpty />}
               } else { html!{
@@ -164,12 +199,62 @@ impl Component for CatalogCompanies {
 }
 
 impl CatalogCompanies {
-    fn show_card(&self, show_company: &ShowCompanyShort) -> Html {
+    fn show_select_mode(&self) -> Html {
+        if get_logged_user().is_none() {
+            return html!{}
+        }
+        let onclick_select_mode = self.link.callback(|_| Msg::SwitchSelectMode);
+        let onclick_select_all = self.link.callback(|_| Msg::SelectAll);
+        html!{<>
+            <button
+                id="companies-select-btn"
+                class={match self.select_mode {
+                    true => "button is-info is-light",
+                    false => "button",
+                }}
+                onclick={onclick_select_mode} >
+                <span class="icon is-small"><i class="far fa-check-square" aria-hidden="true"></i></span>
+                <span>{get_value_field(&129)}</span> // Select
+            </button>
+            {match self.select_mode {
+                true => html!{
+                    <label class="checkbox ml-3">
+                        <input
+                            id="companies-select-all"
+                            type="checkbox"
+                            checked={!self.list.is_empty() && self.selected.len() == self.list.len()}
+                            onclick={onclick_select_all} />
+                        {" "}{get_value_field(&662)} // Select all
+                    </label>
+                },
+                false => html!{},
+            }}
+        </>}
+    }
+
+    fn show_bulk_bar(&self) -> Html {
+        let callback_finish = self.link.callback(|_| Msg::FinishBulk);
+        let selected: Vec<(UUID, String)> = self.list.iter()
+            .filter(|x| self.selected.contains(&x.uuid))
+            .map(|x| (x.uuid.clone(), x.shortname.clone()))
+            .collect();
         html!{
+            <BulkActionBar target={BulkTarget::Company} selected={selected} callback_finish={callback_finish} />
+        }
+    }
+
+    fn show_card(&self, show_company: &ShowCompanyShort) -> Html {
+        let item_uuid = show_company.uuid.clone();
+        let onclick_select = self.link.callback(move |_| Msg::SelectItem(item_uuid.clone()));
+        let item = html!{
             <ListItemCompany
                 data={show_company.clone()}
                 show_list={self.show_type == ListState::List}
                 />
+        };
+        match self.select_mode {
+            true => select_item(self.selected.contains(&show_company.uuid), item, onclick_select),
+            false => item,
         }
     }
 }
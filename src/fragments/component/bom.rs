@@ -0,0 +1,285 @@
+use yew::{html, Component, ComponentLink, Html, InputData, ChangeData, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{add_bom_line, get_value_field, load_bom, resp_parsing, save_bom, BomLine};
+use crate::types::{ComponentModificationInfo, ShowComponentShort, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentsShortList, get_components_short_list,
+    GetComponentModifications, get_component_modifications,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    /// Selected modification, if empty the modification is selected in the modal
+    #[prop_or_default]
+    pub modification_uuid: UUID,
+}
+
+/// Adds a modification of the component with the quantity and the note to the bill of materials
+pub struct AddToBom {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    hide_modal: bool,
+    component: Option<ShowComponentShort>,
+    modifications: Vec<ComponentModificationInfo>,
+    modification_uuid: UUID,
+    quantity: usize,
+    note: String,
+    added: bool,
+}
+
+pub enum Msg {
+    ShowModal,
+    RequestComponent,
+    GetComponentResult(String),
+    GetModificationsResult(String),
+    SelectModification(UUID),
+    UpdateQuantity(String),
+    UpdateNote(String),
+    AddLine,
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for AddToBom {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            modification_uuid: props.modification_uuid.clone(),
+            props,
+            link,
+            hide_modal: true,
+            component: None,
+            modifications: Vec::new(),
+            quantity: 1,
+            note: String::new(),
+            added: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::ShowModal => {
+                self.hide_modal = !self.hide_modal;
+                self.added = false;
+                if !self.hide_modal {
+                    self.modification_uuid = self.props.modification_uuid.clone();
+                    if self.component.as_ref().is_none_or(|c| c.uuid != self.props.component_uuid) {
+                        link.send_message(Msg::RequestComponent);
+                    }
+                }
+            },
+            Msg::RequestComponent => {
+                let component_uuid = self.props.component_uuid.clone();
+                let ipt_components_arg = Some(get_components_short_list::IptComponentsArg {
+                    componentsUuids: Some(vec![component_uuid.clone()]),
+                    companyUuid: None,
+                    standardUuid: None,
+                    serviceUuid: None,
+                    userUuid: None,
+                    specId: None,
+                    favorite: None,
+                });
+                let link_modifications = link.clone();
+                spawn_local(async move {
+                    let res = make_query(GetComponentsShortList::build_query(
+                        get_components_short_list::Variables { ipt_components_arg, ipt_sort: None, ipt_paginate: None }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetComponentResult(res));
+                });
+                spawn_local(async move {
+                    let res = make_query(GetComponentModifications::build_query(
+                        get_component_modifications::Variables { component_uuid, filter: None, ipt_sort: None, ipt_paginate: None }
+                    )).await.unwrap();
+                    link_modifications.send_message(Msg::GetModificationsResult(res));
+                });
+            },
+            Msg::GetComponentResult(res) => {
+                match resp_parsing::<Vec<ShowComponentShort>>(res, "components") {
+                    Ok(result) => self.component = result.into_iter().next(),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetModificationsResult(res) => {
+                match resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications") {
+                    Ok(result) => {
+                        debug!("Modifications for BOM: {}", result.len());
+                        if self.modification_uuid.is_empty() {
+                            if let Some(first) = result.first() {
+                                self.modification_uuid = first.uuid.clone();
+                            }
+                        }
+                        self.modifications = result;
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::SelectModification(modification_uuid) => {
+                self.modification_uuid = modification_uuid;
+                self.added = false;
+            },
+            Msg::UpdateQuantity(value) => {
+                self.quantity = value.trim().parse().unwrap_or_default();
+                self.added = false;
+            },
+            Msg::UpdateNote(value) => self.note = value,
+            Msg::AddLine => {
+                let (component, modification) = match (&self.component, self.selected_modification()) {
+                    (Some(component), Some(modification)) => (component, modification),
+                    _ => return false,
+                };
+                let mut lines = load_bom();
+                add_bom_line(&mut lines, BomLine {
+                    component_uuid: component.uuid.clone(),
+                    modification_uuid: modification.uuid.clone(),
+                    quantity: self.quantity,
+                    note: self.note.trim().to_string(),
+                    component_name: component.name.clone(),
+                    modification_name: modification.modification_name.clone(),
+                    suppliers: component.component_suppliers.iter().map(|s| s.supplier.shortname.clone()).collect(),
+                });
+                save_bom(&lines);
+                self.added = true;
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid && self.props.modification_uuid == props.modification_uuid {
+            return false
+        }
+        if self.props.component_uuid != props.component_uuid {
+            self.component = None;
+            self.modifications.clear();
+        }
+        self.props = props;
+        self.hide_modal = true;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{<>
+            <button
+                id="add-to-bom-btn"
+                class="button is-small is-white"
+                onclick={onclick_show_modal}
+                title={get_value_field(&601)} >
+                <span class="icon">
+                    <i class="fas fa-clipboard-list" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&600)}</span> // Add to BOM
+            </button>
+            {match self.hide_modal {
+                true => html!{},
+                false => self.show_modal(),
+            }}
+        </>}
+    }
+}
+
+impl AddToBom {
+    fn selected_modification(&self) -> Option<&ComponentModificationInfo> {
+        self.modifications.iter().find(|m| m.uuid == self.modification_uuid)
+    }
+
+    fn show_modal(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowModal);
+        let onchange_modification = self.link.callback(|ev: ChangeData| Msg::SelectModification(match ev {
+            ChangeData::Select(el) => el.value(),
+            _ => String::new(),
+        }));
+        let oninput_quantity = self.link.callback(|ev: InputData| Msg::UpdateQuantity(ev.value));
+        let oninput_note = self.link.callback(|ev: InputData| Msg::UpdateNote(ev.value));
+        let onclick_add = self.link.callback(|_| Msg::AddLine);
+        let loading = self.component.is_none() && self.error.is_none();
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&601)}</p> // Add the modification to the bill of materials
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                        <p class="has-text-weight-bold mb-2">
+                            {self.component.as_ref().map(|c| c.name.clone()).unwrap_or_default()}
+                        </p>
+                        <label class="label" for="bom-modification">{get_value_field(&463)}</label> // Modification
+                        <div class="select is-fullwidth mb-3">
+                            <select id="bom-modification" onchange={onchange_modification} >
+                                {for self.modifications.iter().map(|m| html!{
+                                    <option value={m.uuid.clone()} selected={m.uuid == self.modification_uuid}>
+                                        {m.modification_name.clone()}
+                                    </option>
+                                })}
+                            </select>
+                        </div>
+                        <div class="columns">
+                            <div class="column is-3">
+                                <label class="label" for="bom-quantity">{get_value_field(&603)}</label> // Quantity
+                                <input
+                                    id="bom-quantity"
+                                    class={match self.quantity {
+                                        0 => "input is-danger",
+                                        _ => "input",
+                                    }}
+                                    type="number"
+                                    min="1"
+                                    value={self.quantity.to_string()}
+                                    oninput={oninput_quantity} />
+                            </div>
+                            <div class="column">
+                                <label class="label" for="bom-note">{get_value_field(&604)}</label> // Note
+                                <input
+                                    id="bom-note"
+                                    class="input"
+                                    type="text"
+                                    value={self.note.clone()}
+                                    oninput={oninput_note} />
+                            </div>
+                        </div>
+                        {match self.added {
+                            true => html!{
+                                <div class="notification is-success is-light">
+                                    {get_value_field(&605)}{" "} // Added to the bill of materials
+                                    <a href="#/bom">{get_value_field(&606)}</a> // Open the bill of materials
+                                </div>
+                            },
+                            false => html!{},
+                        }}
+                        <button
+                            id="add-to-bom"
+                            class={match loading {
+                                true => "button is-fullwidth is-success is-loading",
+                                false => "button is-fullwidth is-success",
+                            }}
+                            disabled={self.quantity == 0 || self.selected_modification().is_none() || self.added}
+                            onclick={onclick_add} >
+                            <span class="icon"><i class="fas fa-clipboard-list" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&600)}</span> // Add to BOM
+                        </button>
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::fragments::component::{CopyRequests, request_all_modifications};
+use crate::services::{
+    get_file, get_value_field, resp_parsing, resp_parsing_two_level, revoke_object_url, save_as_file, write_bundle,
+    BundleComponent, BundleFile, BundleFileOf, BundleFileset, BundleLink, BundleManifest, BundleModification, BundleParam,
+};
+use crate::types::{ComponentInfo, ComponentModificationInfo, ComponentParam, DownloadFile, FilesetProgramInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentParams, get_component_params,
+    ComponentModificationFilesets, component_modification_filesets,
+    ComponentFiles, component_files,
+    ComponentModificationFiles, component_modification_files,
+    ComModFilesetFiles, com_mod_fileset_files,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component: ComponentInfo,
+}
+
+/// Saves the component with its parameters, modifications, filesets and files as a ZIP bundle
+/// with `manifest.json`, the bundle is imported on the page of the component creation
+pub struct ExportComponentBundle {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    manifest: BundleManifest,
+    /// Data of the downloaded files by the paths in the bundle
+    files_data: Vec<(String, Vec<u8>)>,
+    requests: CopyRequests<Self>,
+    file_url: Option<String>,
+}
+
+pub enum Msg {
+    RequestExport,
+    GetParamsResult(String),
+    GetModificationsResult(Result<Vec<ComponentModificationInfo>, Error>),
+    /// Response with the filesets of the modification
+    GetFilesetsResult(String, UUID),
+    /// Response with the files and their owner in the bundle
+    GetFilesResult(String, BundleFileOf),
+    GetDownloadResult(Result<Vec<(String, Vec<u8>)>, Error>, BundleFileOf),
+    ClearError,
+}
+
+impl Component for ExportComponentBundle {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            requests: CopyRequests::new(link.clone()),
+            link,
+            manifest: BundleManifest::default(),
+            files_data: Vec::new(),
+            file_url: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestExport => {
+                self.error = None;
+                self.requests.start();
+                self.files_data.clear();
+                self.manifest = BundleManifest::new(bundle_component(&self.props.component));
+                self.request_data();
+            },
+            Msg::GetParamsResult(res) => {
+                match resp_parsing_two_level::<ComponentParam>(res, "component", "componentParams") {
+                    Ok(params) => {
+                        self.manifest.component.params = params.into_iter()
+                            .map(|p| BundleParam { param: p.param, value: p.value })
+                            .collect();
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetModificationsResult(result) => {
+                match result {
+                    Ok(modifications) => {
+                        for modification in modifications {
+                            self.request_filesets(modification.uuid.clone());
+                            if modification.files_count > 0 {
+                                self.request_files(modification.uuid.clone(), BundleFileOf::Modification(modification.uuid.clone()));
+                            }
+                            self.manifest.component.modifications.push(BundleModification {
+                                uuid: modification.uuid,
+                                modification_name: modification.modification_name,
+                                description: modification.description,
+                                actual_status: Some(modification.actual_status),
+                                params: modification.modification_params.into_iter()
+                                    .map(|p| BundleParam { param: p.param, value: p.value })
+                                    .collect(),
+                                files: Vec::new(),
+                                filesets: Vec::new(),
+                            });
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetFilesetsResult(res, modification_uuid) => {
+                match resp_parsing::<Vec<FilesetProgramInfo>>(res, "componentModificationFilesets") {
+                    Ok(filesets) => {
+                        for fileset in filesets {
+                            if fileset.files_count > 0 {
+                                let file_of = BundleFileOf::Fileset(modification_uuid.clone(), fileset.program.id);
+                                self.request_files(fileset.uuid, file_of);
+                            }
+                            if let Some(modification) = self.modification_mut(&modification_uuid) {
+                                modification.filesets.push(BundleFileset { program: fileset.program, files: Vec::new() });
+                            }
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetFilesResult(res, file_of) => {
+                let key = match file_of {
+                    BundleFileOf::Component => "componentFiles",
+                    BundleFileOf::Modification(_) => "componentModificationFiles",
+                    BundleFileOf::Fileset(..) => "componentModificationFilesetFiles",
+                };
+                match resp_parsing::<Vec<DownloadFile>>(res, key) {
+                    Ok(files) => {
+                        self.requests.send(async move {
+                            let mut files_data = Vec::new();
+                            for file in files {
+                                match get_file(&file.download_url).await {
+                                    Ok(data) => files_data.push((file.filename, data)),
+                                    Err(err) => return Msg::GetDownloadResult(Err(err), file_of),
+                                }
+                            }
+                            Msg::GetDownloadResult(Ok(files_data), file_of)
+                        });
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetDownloadResult(result, file_of) => {
+                match result {
+                    Ok(files_data) => {
+                        let mut bundle_files = Vec::new();
+                        for (filename, data) in files_data {
+                            let file = BundleFile::new(&file_of, &filename);
+                            self.files_data.push((file.path.clone(), data));
+                            bundle_files.push(file);
+                        }
+                        if let Some(files) = self.files_mut(&file_of) {
+                            files.extend(bundle_files);
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component.uuid == props.component.uuid {
+            return false
+        }
+        self.props = props;
+        self.requests.completed = 0;
+        true
+    }
+
+    fn destroy(&mut self) {
+        if let Some(url) = self.file_url.take() {
+            revoke_object_url(&url);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_export = self.link.callback(|_| Msg::RequestExport);
+        let in_progress = self.requests.pending > 0;
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            <button
+                id="export-bundle-btn"
+                class={match in_progress {
+                    true => "button is-loading",
+                    false => "button",
+                }}
+                onclick={onclick_export}
+                disabled={in_progress}
+                title={match in_progress {
+                    true => format!("{}: {} / {}", get_value_field(&656), self.requests.completed, self.requests.completed + self.requests.pending),
+                    false => get_value_field(&642).to_string(),
+                }} >
+                <span class="icon is-small">
+                    <i class="fas fa-file-archive" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&641)}</span> // Export bundle
+            </button>
+        </>}
+    }
+}
+
+impl ExportComponentBundle {
+    fn request_data(&mut self) {
+        let component = self.props.component.clone();
+        if component.params_count > 0 {
+            let component_uuid = component.uuid.clone();
+            self.requests.send(async move {
+                let res = make_query(GetComponentParams::build_query(
+                    get_component_params::Variables { component_uuid, ipt_sort: None, ipt_paginate: None }
+                )).await.unwrap();
+                Msg::GetParamsResult(res)
+            });
+        }
+        if component.modifications_count > 0 {
+            let component_uuid = component.uuid.clone();
+            self.requests.send(async move {
+                Msg::GetModificationsResult(request_all_modifications(component_uuid).await)
+            });
+        }
+        if component.files_count > 0 {
+            self.request_files(component.uuid, BundleFileOf::Component);
+        }
+        if self.requests.pending == 0 {
+            self.save_bundle();
+        }
+    }
+
+    fn request_filesets(&mut self, modification_uuid: UUID) {
+        let ipt_fileset_program_arg = component_modification_filesets::IptFilesetProgramArg {
+            modificationUuid: modification_uuid.clone(),
+            programIds: None,
+        };
+        self.requests.send(async move {
+            let res = make_query(ComponentModificationFilesets::build_query(
+                component_modification_filesets::Variables { ipt_fileset_program_arg }
+            )).await.unwrap();
+            Msg::GetFilesetsResult(res, modification_uuid)
+        });
+    }
+
+    /// Requests the files of the component, the modification or the fileset with the UUID
+    fn request_files(&mut self, source_uuid: UUID, file_of: BundleFileOf) {
+        match file_of {
+            BundleFileOf::Component => self.requests.send(async move {
+                let ipt_component_files_arg = component_files::IptComponentFilesArg {
+                    componentUuid: source_uuid,
+                    filesUuids: None,
+                };
+                let res = make_query(ComponentFiles::build_query(
+                    component_files::Variables { ipt_component_files_arg }
+                )).await.unwrap();
+                Msg::GetFilesResult(res, file_of)
+            }),
+            BundleFileOf::Modification(_) => self.requests.send(async move {
+                let ipt_modification_files_arg = component_modification_files::IptModificationFilesArg {
+                    modificationUuid: source_uuid,
+                    filesUuids: None,
+                };
+                let res = make_query(ComponentModificationFiles::build_query(
+                    component_modification_files::Variables { ipt_modification_files_arg }
+                )).await.unwrap();
+                Msg::GetFilesResult(res, file_of)
+            }),
+            BundleFileOf::Fileset(..) => self.requests.send(async move {
+                let ipt_file_of_fileset_arg = com_mod_fileset_files::IptFileOfFilesetArg {
+                    filesetUuid: source_uuid,
+                    fileUuids: None,
+                };
+                let res = make_query(ComModFilesetFiles::build_query(
+                    com_mod_fileset_files::Variables { ipt_file_of_fileset_arg, ipt_paginate: None }
+                )).await.unwrap();
+                Msg::GetFilesResult(res, file_of)
+            }),
+        }
+    }
+
+    fn modification_mut(&mut self, modification_uuid: &str) -> Option<&mut BundleModification> {
+        self.manifest.component.modifications.iter_mut().find(|m| m.uuid == modification_uuid)
+    }
+
+    fn files_mut(&mut self, file_of: &BundleFileOf) -> Option<&mut Vec<BundleFile>> {
+        match file_of {
+            BundleFileOf::Component => Some(&mut self.manifest.component.files),
+            BundleFileOf::Modification(uuid) => self.modification_mut(uuid).map(|m| &mut m.files),
+            BundleFileOf::Fileset(uuid, program_id) => self.modification_mut(uuid)
+                .and_then(|m| m.filesets.iter_mut().find(|f| f.program.id == *program_id))
+                .map(|f| &mut f.files),
+        }
+    }
+
+    /// Counts the response and after the last one saves the bundle if all data is received
+    fn finish_request(&mut self, result: Result<(), Error>) {
+        if let Err(err) = self.requests.finish(result) {
+            debug!("Export bundle failed: {:?}", err);
+            self.error = Some(err);
+        }
+        if self.requests.pending == 0 && !self.requests.failed {
+            self.save_bundle();
+        }
+    }
+
+    fn save_bundle(&mut self) {
+        let result = write_bundle(&self.manifest, &self.files_data);
+        self.files_data.clear();
+        let data = match result {
+            Ok(data) => data,
+            Err(err) => {
+                self.error = Some(Error::BadRequest(err));
+                self.requests.failed = true;
+                return
+            },
+        };
+        if let Some(url) = self.file_url.take() {
+            revoke_object_url(&url);
+        }
+        let filename = format!("{}.zip", self.manifest.component.name.replace(|c: char| !c.is_alphanumeric(), "_"));
+        self.file_url = save_as_file(&data, "application/zip", &filename);
+    }
+}
+
+fn bundle_component(component: &ComponentInfo) -> BundleComponent {
+    BundleComponent {
+        uuid: component.uuid.clone(),
+        name: component.name.clone(),
+        description: component.description.clone(),
+        type_access_id: component.type_access.type_access_id,
+        component_type_id: component.component_type.component_type_id,
+        actual_status: Some(component.actual_status.clone()),
+        specs: component.component_specs.clone(),
+        keywords: component.component_keywords.iter().map(|k| k.keyword.clone()).collect(),
+        licenses: component.licenses.clone(),
+        standards: component.component_standards.iter()
+            .map(|s| BundleLink { uuid: s.uuid.clone(), name: s.name.clone() })
+            .collect(),
+        suppliers: component.component_suppliers.iter()
+            .map(|s| BundleLink { uuid: s.supplier.uuid.clone(), name: s.supplier.shortname.clone() })
+            .collect(),
+        params: Vec::new(),
+        files: Vec::new(),
+        modifications: Vec::new(),
+    }
+}
@@ -0,0 +1,756 @@
+use yew::{agent::Bridged, html, Bridge, ChangeData, Component, ComponentLink, Html, InputData, ShouldRender};
+use yew::services::reader::{File, FileData, ReaderService, ReaderTask};
+use yew_router::{agent::RouteRequest::ChangeRoute, prelude::RouteAgent};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::routes::AppRoute;
+use crate::fragments::list_errors::ListErrors;
+use crate::fragments::component::{CopyRequests, FilesOf, pair_uploads, pair_uuids, request_upload_urls};
+use crate::services::{
+    get_value_field, param_values, plan_import, read_bundle, read_bundle_files, resp_parsing,
+    BundleFile, BundleKnown, BundleManifest, BundlePlan,
+};
+use crate::types::{ActualStatus, LicenseInfo, Param, Program, Spec, UploadFile, UUID};
+use crate::gqls::make_query;
+use crate::gqls::relate::{
+    ConfirmUploadCompleted, confirm_upload_completed,
+    GetSpecs, get_specs,
+    GetParams, get_params,
+    GetLicenses, get_licenses,
+    GetPrograms, get_programs,
+    RegisterParamsBulk, register_params_bulk,
+};
+use crate::gqls::component::{
+    RegisterComponent, register_component,
+    DeleteComponent, delete_component,
+    ComponentActualStatuses, component_actual_statuses,
+    AddComponentSpecs, add_component_specs,
+    AddComponentKeywordsByNames, add_component_keywords_by_names,
+    AddComponentLicense, add_component_license,
+    PutComponentParams, put_component_params,
+    RegisterComponentModificationsBulk, register_component_modifications_bulk,
+    RegisterModificationFileset, register_modification_fileset,
+};
+
+/// Stage of the import, the stages go in the order of the declaration
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Stage {
+    Register,
+    Related,
+    Modifications,
+    Files,
+    Confirm,
+    Rollback,
+}
+
+impl Stage {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Register => get_value_field(&591),
+            Self::Related => get_value_field(&657),
+            Self::Modifications => get_value_field(&658),
+            Self::Files => get_value_field(&659),
+            Self::Confirm => get_value_field(&595),
+            Self::Rollback => get_value_field(&596),
+        }
+    }
+}
+
+/// Creates a component from the ZIP bundle saved by the export of a component.
+/// Before the import the bundle is checked with the data of the server and the conflicts are shown:
+/// unknown specs, licenses and programs are skipped, parameters are matched by the names.
+/// If something is not imported, the new component is deleted.
+pub struct ImportComponentBundle {
+    error: Option<Error>,
+    link: ComponentLink<Self>,
+    router_agent: Box<dyn Bridge<RouteAgent>>,
+    reader: Option<ReaderTask>,
+    hide_modal: bool,
+    bundle_data: Vec<u8>,
+    manifest: Option<BundleManifest>,
+    known: BundleKnown,
+    /// Number of the requests of the check without a response
+    pending_checks: usize,
+    plan: Option<BundlePlan>,
+    name: String,
+    new_component_uuid: UUID,
+    stage: Stage,
+    requests: CopyRequests<Self>,
+    /// Uploaded files which are confirmed after the last upload
+    uploaded_files: Vec<UUID>,
+    rolled_back: bool,
+}
+
+pub enum Msg {
+    ShowModal,
+    UploadFile(File),
+    GetFileData(FileData),
+    GetSpecsResult(String),
+    GetParamsResult(String),
+    GetLicensesResult(String),
+    GetStatusesResult(String),
+    GetProgramsResult(String),
+    UpdateName(String),
+    RequestImport,
+    GetRegisterResult(String),
+    GetRegisterParamsResult(String),
+    /// Response with the new modifications and the modifications of the bundle in the same order
+    GetRegisterModificationsResult(String, Vec<UUID>),
+    /// Response with the new fileset and the files of the fileset in the bundle
+    GetRegisterFilesetResult(String, Vec<BundleFile>),
+    GetUploadUrlsResult(String, FilesOf, Vec<(String, Vec<u8>)>),
+    GetUploadResult(Result<Option<String>, Error>),
+    /// Response of an import request with the key of the result
+    GetImportResult(String, &'static str),
+    GetRollbackResult(String),
+    OpenNewComponent,
+    ClearError,
+    Ignore,
+}
+
+impl Component for ImportComponentBundle {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            router_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            requests: CopyRequests::new(link.clone()),
+            link,
+            reader: None,
+            hide_modal: true,
+            bundle_data: Vec::new(),
+            manifest: None,
+            known: BundleKnown::default(),
+            pending_checks: 0,
+            plan: None,
+            name: String::new(),
+            new_component_uuid: String::new(),
+            stage: Stage::Register,
+            uploaded_files: Vec::new(),
+            rolled_back: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ShowModal => self.hide_modal = !self.hide_modal,
+            Msg::UploadFile(file) => {
+                let callback = self.link.callback(Msg::GetFileData);
+                self.reader = ReaderService::read_file(file, callback).ok();
+                return false
+            },
+            Msg::GetFileData(data) => {
+                self.reader = None;
+                self.error = None;
+                self.plan = None;
+                self.requests.completed = 0;
+                self.rolled_back = false;
+                self.new_component_uuid.clear();
+                match read_bundle(&data.content) {
+                    Ok(manifest) => {
+                        debug!("Bundle {:?}: {:?}", data.name, manifest.component.name);
+                        self.name = manifest.component.name.clone();
+                        self.bundle_data = data.content;
+                        self.request_check(&manifest);
+                        self.manifest = Some(manifest);
+                    },
+                    Err(err) => {
+                        self.manifest = None;
+                        self.error = Some(Error::BadRequest(err));
+                    },
+                }
+            },
+            Msg::GetSpecsResult(res) => {
+                let result = resp_parsing::<Vec<Spec>>(res, "specs").map(|specs| self.known.specs = specs);
+                self.finish_check(result);
+            },
+            Msg::GetParamsResult(res) => {
+                let result = resp_parsing::<Vec<Param>>(res, "params").map(|params| self.known.params = params);
+                self.finish_check(result);
+            },
+            Msg::GetLicensesResult(res) => {
+                let result = resp_parsing::<Vec<LicenseInfo>>(res, "licenses").map(|licenses| self.known.licenses = licenses);
+                self.finish_check(result);
+            },
+            Msg::GetStatusesResult(res) => {
+                let result = resp_parsing::<Vec<ActualStatus>>(res, "componentActualStatuses")
+                    .map(|statuses| self.known.statuses = statuses);
+                self.finish_check(result);
+            },
+            Msg::GetProgramsResult(res) => {
+                let result = resp_parsing::<Vec<Program>>(res, "programs").map(|programs| self.known.programs = programs);
+                self.finish_check(result);
+            },
+            Msg::UpdateName(value) => self.name = value,
+            Msg::RequestImport => {
+                let (manifest, plan) = match (&self.manifest, &self.plan) {
+                    (Some(manifest), Some(plan)) => (manifest, plan),
+                    _ => return false,
+                };
+                let component = &manifest.component;
+                let ipt_component_data = register_component::IptComponentData {
+                    parentComponentUuid: None,
+                    name: self.name.trim().to_string(),
+                    description: component.description.clone(),
+                    typeAccessId: component.type_access_id as i64,
+                    componentTypeId: component.component_type_id as i64,
+                    actualStatusId: plan.status_id(&component.actual_status) as i64,
+                    isBase: false,
+                };
+                self.error = None;
+                self.requests.start();
+                self.rolled_back = false;
+                self.stage = Stage::Register;
+                self.uploaded_files.clear();
+                self.requests.send(async move {
+                    let res = make_query(RegisterComponent::build_query(register_component::Variables {
+                        ipt_component_data
+                    })).await.unwrap();
+                    Msg::GetRegisterResult(res)
+                });
+            },
+            Msg::GetRegisterResult(res) => {
+                match resp_parsing::<UUID>(res, "registerComponent") {
+                    Ok(component_uuid) => {
+                        debug!("Imported component: {:?}", component_uuid);
+                        self.new_component_uuid = component_uuid;
+                        self.import_related_data();
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetRegisterParamsResult(res) => {
+                match resp_parsing::<Vec<Param>>(res, "registerParamsBulk") {
+                    Ok(registered) => {
+                        self.put_params(&registered);
+                        self.register_modifications(&registered);
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetRegisterModificationsResult(res, source_uuids) => {
+                let result = resp_parsing::<Vec<UUID>>(res, "registerComponentModificationsBulk")
+                    .and_then(|new_uuids| pair_uuids(source_uuids, new_uuids));
+                match result {
+                    Ok(pairs) => {
+                        for (source_uuid, target_uuid) in pairs {
+                            self.import_modification_files(&source_uuid, target_uuid);
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetRegisterFilesetResult(res, files) => {
+                match resp_parsing::<UUID>(res, "registerModificationFileset") {
+                    Ok(fileset_uuid) => {
+                        self.upload_files(FilesOf::Fileset, fileset_uuid, &files);
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetUploadUrlsResult(res, files_of, files_data) => {
+                let result = resp_parsing::<Vec<UploadFile>>(res, files_of.upload_key())
+                    .and_then(|uploads| pair_uploads(files_data, uploads));
+                match result {
+                    Ok(pairs) => {
+                        for (file_uuid, upload_data) in pairs {
+                            if self.requests.failed {
+                                break
+                            }
+                            self.uploaded_files.push(file_uuid);
+                            self.requests.upload(upload_data, Msg::GetUploadResult);
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetUploadResult(result) => self.finish_request(result.map(|_| ())),
+            Msg::GetImportResult(res, key) => {
+                let result = resp_parsing::<Value>(res, key).map(|_| ());
+                self.finish_request(result);
+            },
+            Msg::GetRollbackResult(res) => {
+                match resp_parsing::<UUID>(res, "deleteComponent") {
+                    Ok(component_uuid) => {
+                        debug!("Rollback of the import: {:?}", component_uuid);
+                        self.new_component_uuid.clear();
+                        self.rolled_back = true;
+                    },
+                    Err(err) => self.error = Some(err),
+                }
+                self.requests.pending = 0;
+            },
+            Msg::OpenNewComponent => {
+                self.hide_modal = true;
+                self.router_agent.send(ChangeRoute(
+                    AppRoute::ComponentSettings(std::mem::take(&mut self.new_component_uuid)).into()
+                ));
+            },
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{<>
+            <button
+                id="import-bundle-btn"
+                class="button"
+                onclick={onclick_show_modal}
+                title={get_value_field(&644)} >
+                <span class="icon is-small">
+                    <i class="fas fa-file-import" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&643)}</span> // Import bundle
+            </button>
+            {match self.hide_modal {
+                true => html!{},
+                false => self.show_modal(),
+            }}
+        </>}
+    }
+}
+
+impl ImportComponentBundle {
+    /// Requests the data of the server by the IDs used in the bundle
+    fn request_check(&mut self, manifest: &BundleManifest) {
+        let component = &manifest.component;
+        self.known = BundleKnown::default();
+        self.pending_checks = 0;
+        let spec_ids: Vec<i64> = component.specs.iter().map(|s| s.spec_id as i64).collect();
+        if !spec_ids.is_empty() {
+            let ipt_spec_arg = Some(get_specs::IptSpecArg { specIds: Some(spec_ids), specsLevels: None });
+            self.send_check(async move {
+                let res = make_query(GetSpecs::build_query(
+                    get_specs::Variables { ipt_spec_arg, ipt_paginate: None }
+                )).await.unwrap();
+                Msg::GetSpecsResult(res)
+            });
+        }
+        let param_ids: Vec<i64> = manifest.params().iter().map(|p| p.param_id as i64).collect();
+        if !param_ids.is_empty() {
+            self.send_check(async move {
+                let res = make_query(GetParams::build_query(
+                    get_params::Variables { param_ids: Some(param_ids), ipt_paginate: None }
+                )).await.unwrap();
+                Msg::GetParamsResult(res)
+            });
+        }
+        let license_ids: Vec<i64> = component.licenses.iter().map(|l| l.id as i64).collect();
+        if !license_ids.is_empty() {
+            self.send_check(async move {
+                let res = make_query(GetLicenses::build_query(
+                    get_licenses::Variables { license_ids: Some(license_ids) }
+                )).await.unwrap();
+                Msg::GetLicensesResult(res)
+            });
+        }
+        let program_ids: Vec<i64> = component.modifications.iter()
+            .flat_map(|m| m.filesets.iter().map(|f| f.program.id as i64))
+            .collect();
+        if !program_ids.is_empty() {
+            self.send_check(async move {
+                let res = make_query(GetPrograms::build_query(
+                    get_programs::Variables { program_ids: Some(program_ids) }
+                )).await.unwrap();
+                Msg::GetProgramsResult(res)
+            });
+        }
+        self.send_check(async move {
+            let res = make_query(ComponentActualStatuses::build_query(
+                component_actual_statuses::Variables { filter_int: None }
+            )).await.unwrap();
+            Msg::GetStatusesResult(res)
+        });
+    }
+
+    fn send_check(&mut self, request: impl std::future::Future<Output = Msg> + 'static) {
+        let link = self.link.clone();
+        self.pending_checks += 1;
+        spawn_local(async move {
+            link.send_message(request.await);
+        })
+    }
+
+    /// Counts the response of the check and after the last one compares the bundle with the received data
+    fn finish_check(&mut self, result: Result<(), Error>) {
+        self.pending_checks = self.pending_checks.saturating_sub(1);
+        if let Err(err) = result {
+            self.error = Some(err);
+        }
+        if self.pending_checks > 0 || self.error.is_some() {
+            return
+        }
+        if let Some(manifest) = &self.manifest {
+            let plan = plan_import(&self.bundle_data, manifest, &self.known);
+            debug!("Bundle conflicts: {:?}", plan.conflicts);
+            self.plan = Some(plan);
+        }
+    }
+
+    fn import_related_data(&mut self) {
+        let (manifest, plan) = match (self.manifest.clone(), self.plan.clone()) {
+            (Some(manifest), Some(plan)) => (manifest, plan),
+            _ => return,
+        };
+        self.stage = Stage::Related;
+        let component = &manifest.component;
+        let component_uuid = self.new_component_uuid.clone();
+        if !plan.spec_ids.is_empty() {
+            let ipt_component_specs_data = add_component_specs::IptComponentSpecsData {
+                componentUuid: component_uuid.clone(),
+                specIds: plan.spec_ids.iter().map(|id| *id as i64).collect(),
+            };
+            self.requests.send(async move {
+                let res = make_query(AddComponentSpecs::build_query(
+                    add_component_specs::Variables { ipt_component_specs_data }
+                )).await.unwrap();
+                Msg::GetImportResult(res, "addComponentSpecs")
+            });
+        }
+        if !component.keywords.is_empty() {
+            let ipt_component_keywords_names = add_component_keywords_by_names::IptComponentKeywordsNames {
+                componentUuid: component_uuid.clone(),
+                keywords: component.keywords.clone(),
+            };
+            self.requests.send(async move {
+                let res = make_query(AddComponentKeywordsByNames::build_query(
+                    add_component_keywords_by_names::Variables { ipt_component_keywords_names }
+                )).await.unwrap();
+                Msg::GetImportResult(res, "addComponentKeywordsByNames")
+            });
+        }
+        for license_id in &plan.license_ids {
+            let ipt_component_license_data = add_component_license::IptComponentLicenseData {
+                componentUuid: component_uuid.clone(),
+                licenseId: *license_id as i64,
+            };
+            self.requests.send(async move {
+                let res = make_query(AddComponentLicense::build_query(
+                    add_component_license::Variables { ipt_component_license_data }
+                )).await.unwrap();
+                Msg::GetImportResult(res, "addComponentLicense")
+            });
+        }
+        // the parameters are matched by the names, missing names are registered
+        let params = manifest.params();
+        match params.is_empty() {
+            true => self.register_modifications(&[]),
+            false => {
+                let ipt_params_translate_list_data = params.into_iter()
+                    .map(|p| register_params_bulk::IptParamTranslateListData {
+                        langId: p.lang_id as i64,
+                        paramname: p.paramname,
+                    })
+                    .collect();
+                self.requests.send(async move {
+                    let res = make_query(RegisterParamsBulk::build_query(
+                        register_params_bulk::Variables { ipt_params_translate_list_data }
+                    )).await.unwrap();
+                    Msg::GetRegisterParamsResult(res)
+                });
+            },
+        }
+        self.upload_files(FilesOf::Component, component_uuid, &component.files);
+    }
+
+    fn put_params(&mut self, registered: &[Param]) {
+        let params = match &self.manifest {
+            Some(manifest) => param_values(&manifest.component.params, registered),
+            None => return,
+        };
+        if params.is_empty() {
+            return
+        }
+        let ipt_component_params_data = put_component_params::IptComponentParamsData {
+            componentUuid: self.new_component_uuid.clone(),
+            params: params.into_iter().map(|(param_id, value)| put_component_params::IptParamData {
+                paramId: param_id as i64,
+                value,
+            }).collect(),
+        };
+        self.requests.send(async move {
+            let res = make_query(PutComponentParams::build_query(
+                put_component_params::Variables { ipt_component_params_data }
+            )).await.unwrap();
+            Msg::GetImportResult(res, "putComponentParams")
+        });
+    }
+
+    fn register_modifications(&mut self, registered: &[Param]) {
+        let (manifest, plan) = match (&self.manifest, &self.plan) {
+            (Some(manifest), Some(plan)) => (manifest, plan),
+            _ => return,
+        };
+        let modifications = &manifest.component.modifications;
+        if modifications.is_empty() {
+            return
+        }
+        let source_uuids: Vec<UUID> = modifications.iter().map(|m| m.uuid.clone()).collect();
+        let ipt_multiple_modifications_data = register_component_modifications_bulk::IptMultipleModificationsData {
+            componentUuid: self.new_component_uuid.clone(),
+            modificationsData: modifications.iter().map(|m| register_component_modifications_bulk::IptModificationsData {
+                modificationName: m.modification_name.clone(),
+                description: m.description.clone(),
+                actualStatusId: plan.status_id(&m.actual_status) as i64,
+                parameters: param_values(&m.params, registered).into_iter()
+                    .map(|(param_id, value)| register_component_modifications_bulk::IptParamData {
+                        paramId: param_id as i64,
+                        value,
+                    })
+                    .collect(),
+            }).collect(),
+        };
+        self.stage = self.stage.max(Stage::Modifications);
+        self.requests.send(async move {
+            let res = make_query(RegisterComponentModificationsBulk::build_query(
+                register_component_modifications_bulk::Variables { ipt_multiple_modifications_data }
+            )).await.unwrap();
+            Msg::GetRegisterModificationsResult(res, source_uuids)
+        });
+    }
+
+    /// Uploads the files of the modification from the bundle and registers its filesets with known programs
+    fn import_modification_files(&mut self, source_uuid: &str, modification_uuid: UUID) {
+        let (manifest, plan) = match (self.manifest.clone(), self.plan.clone()) {
+            (Some(manifest), Some(plan)) => (manifest, plan),
+            _ => return,
+        };
+        let modification = match manifest.component.modifications.iter().find(|m| m.uuid == source_uuid) {
+            Some(modification) => modification,
+            None => return,
+        };
+        self.upload_files(FilesOf::Modification, modification_uuid.clone(), &modification.files);
+        for fileset in modification.filesets.iter().filter(|f| plan.has_program(f.program.id)) {
+            let ipt_fileset_program_data = register_modification_fileset::IptFilesetProgramData {
+                modificationUuid: modification_uuid.clone(),
+                programId: fileset.program.id as i64,
+            };
+            let files = fileset.files.clone();
+            self.requests.send(async move {
+                let res = make_query(RegisterModificationFileset::build_query(
+                    register_modification_fileset::Variables { ipt_fileset_program_data }
+                )).await.unwrap();
+                Msg::GetRegisterFilesetResult(res, files)
+            });
+        }
+    }
+
+    /// Requests the upload urls for the files found in the bundle
+    fn upload_files(&mut self, files_of: FilesOf, target_uuid: UUID, files: &[BundleFile]) {
+        let files = match &self.plan {
+            Some(plan) => plan.present_files(files),
+            None => return,
+        };
+        if files.is_empty() {
+            return
+        }
+        self.stage = self.stage.max(Stage::Files);
+        let files_data = read_bundle_files(&self.bundle_data, &files);
+        let filenames: Vec<String> = files_data.iter().map(|(filename, _)| filename.clone()).collect();
+        self.requests.send(async move {
+            let res = request_upload_urls(files_of, target_uuid, filenames).await;
+            Msg::GetUploadUrlsResult(res, files_of, files_data)
+        });
+    }
+
+    /// Counts the response and after the last one confirms the uploaded files,
+    /// opens the new component or deletes it if some data was not imported
+    fn finish_request(&mut self, result: Result<(), Error>) {
+        if let Err(err) = self.requests.finish(result) {
+            debug!("Import bundle failed: {:?}", err);
+            self.error = Some(err);
+        }
+        if self.requests.pending > 0 {
+            return
+        }
+        match (self.requests.failed, self.uploaded_files.is_empty()) {
+            (true, _) => self.rollback(),
+            (false, false) => {
+                self.stage = Stage::Confirm;
+                let file_uuids = std::mem::take(&mut self.uploaded_files);
+                self.requests.send(async move {
+                    let res = make_query(ConfirmUploadCompleted::build_query(
+                        confirm_upload_completed::Variables { file_uuids }
+                    )).await.unwrap();
+                    Msg::GetImportResult(res, "uploadCompleted")
+                });
+            },
+            (false, true) => self.link.send_message(Msg::OpenNewComponent),
+        }
+    }
+
+    fn rollback(&mut self) {
+        if self.new_component_uuid.is_empty() {
+            return
+        }
+        self.stage = Stage::Rollback;
+        self.uploaded_files.clear();
+        // the rollback is sent after the failure, so it is counted here
+        self.requests.pending = 1;
+        let component_uuid = self.new_component_uuid.clone();
+        let link = self.link.clone();
+        spawn_local(async move {
+            let res = make_query(DeleteComponent::build_query(
+                delete_component::Variables { component_uuid }
+            )).await.unwrap();
+            link.send_message(Msg::GetRollbackResult(res));
+        })
+    }
+
+    fn show_modal(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowModal);
+        let onchange_file = self.link.callback(|value: ChangeData| match value {
+            ChangeData::Files(files) => match files.get(0) {
+                Some(file) => Msg::UploadFile(file),
+                None => Msg::Ignore,
+            },
+            _ => Msg::Ignore,
+        });
+        let in_progress = self.requests.pending > 0;
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&643)}</p> // Import bundle
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                        <p class="help mb-3">{get_value_field(&644)}</p>
+                        <div class="file is-small mb-3">
+                            <label class="file-label">
+                                <input
+                                    id="import-bundle-file"
+                                    class="file-input"
+                                    type="file"
+                                    accept=".zip"
+                                    disabled={in_progress}
+                                    onchange={onchange_file} />
+                                <span class="file-cta">
+                                    <span class="file-icon"><i class="fas fa-file-archive"></i></span>
+                                    <span class="file-label">{get_value_field(&655)}</span> // Bundle file (.zip)
+                                </span>
+                            </label>
+                        </div>
+                        {match (self.pending_checks > 0, &self.manifest, &self.plan) {
+                            (true, _, _) => html!{
+                                <p class="help">
+                                    <span class="icon"><i class="fas fa-spinner fa-pulse"></i></span>
+                                    {get_value_field(&661)} // Checking the bundle
+                                </p>
+                            },
+                            (false, Some(manifest), Some(plan)) => self.show_plan(manifest, plan, in_progress),
+                            _ => html!{},
+                        }}
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+
+    fn show_plan(&self, manifest: &BundleManifest, plan: &BundlePlan, in_progress: bool) -> Html {
+        let oninput_name = self.link.callback(|ev: InputData| Msg::UpdateName(ev.value));
+        let onclick_import = self.link.callback(|_| Msg::RequestImport);
+        let onclick_open = self.link.callback(|_| Msg::OpenNewComponent);
+        let started = in_progress || self.requests.completed > 0;
+        html!{<>
+            <label class="label" for="import-bundle-name">{get_value_field(&110)}</label> // Name
+            <input
+                id="import-bundle-name"
+                class="input"
+                type="text"
+                value={self.name.clone()}
+                disabled={in_progress}
+                oninput={oninput_name} />
+            <p class="help mb-3">
+                {format!("{}: {}, {}: {}, {}: {}",
+                    get_value_field(&626), manifest.params().len(), // Parameters
+                    get_value_field(&100), manifest.component.modifications.len(), // Modifications
+                    get_value_field(&627), manifest.files().len(), // Files
+                )}
+            </p>
+            {match plan.conflicts.is_empty() {
+                true => html!{<p class="help is-success mb-3">{get_value_field(&653)}</p>}, // No conflicts found
+                false => html!{
+                    <div id="import-bundle-conflicts" class="notification is-warning is-light p-3 mb-3">
+                        <p class="has-text-weight-bold">{get_value_field(&654)}</p> // Conflicts of the bundle with the data of the server
+                        <ul>
+                            {for plan.conflicts.iter().map(|conflict| html!{<li>{conflict.message()}</li>})}
+                        </ul>
+                    </div>
+                },
+            }}
+            {match started {
+                true => self.show_progress(in_progress),
+                false => html!{},
+            }}
+            {match self.requests.failed && !self.rolled_back && !self.new_component_uuid.is_empty() && !in_progress {
+                true => html!{
+                    <button id="open-imported-component" class="button is-fullwidth is-warning" onclick={onclick_open}>
+                        {get_value_field(&587)} // Open the new component
+                    </button>
+                },
+                false => html!{
+                    <button
+                        id="import-bundle"
+                        class={match in_progress {
+                            true => "button is-fullwidth is-success is-loading",
+                            false => "button is-fullwidth is-success",
+                        }}
+                        disabled={self.name.trim().is_empty() || in_progress || !self.new_component_uuid.is_empty()}
+                        onclick={onclick_import} >
+                        <span class="icon"><i class="fas fa-file-import" aria-hidden="true"></i></span>
+                        <span>{get_value_field(&643)}</span> // Import bundle
+                    </button>
+                },
+            }}
+        </>}
+    }
+
+    fn show_progress(&self, in_progress: bool) -> Html {
+        if self.rolled_back {
+            return html!{<p class="help is-danger mb-3">{get_value_field(&660)}</p>} // Importing failed, the created component has been deleted
+        }
+        let total = self.requests.completed + self.requests.pending;
+        html!{
+            <div class="mb-3">
+                <p class="help">
+                    {self.stage.label()}
+                    {format!(": {} / {} ", self.requests.completed, total)}
+                    {get_value_field(&598)} // requests completed
+                </p>
+                <progress
+                    class={match (in_progress, self.requests.failed) {
+                        (_, true) => "progress is-small is-danger",
+                        (true, false) => "progress is-small is-info",
+                        (false, false) => "progress is-small is-success",
+                    }}
+                    value={self.requests.completed.to_string()}
+                    max={total.max(1).to_string()}>
+                </progress>
+            </div>
+        }
+    }
+}
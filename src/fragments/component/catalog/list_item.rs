@@ -11,7 +11,8 @@ use crate::fragments::{
     buttons::ft_follow_btn,
     switch_icon::res_btn,
 };
-use crate::types::{Pathname, ShowComponentShort};
+use crate::fragments::component::{AddToBom, AddToCompare, parent_tag};
+use crate::types::{ComponentParentInfo, Pathname, ShowComponentShort};
 
 pub enum Msg {
     OpenComponent,
@@ -25,6 +26,9 @@ pub struct Props {
     pub show_list: bool,
     pub add_fav: Callback<String>,
     pub del_fav : Callback<String>,
+    /// Parent of the forked component, loaded by the catalog for all items of the list
+    #[prop_or_default]
+    pub parent: Option<ComponentParentInfo>,
 }
 
 pub struct ListItem {
@@ -70,6 +74,10 @@ impl Component for ListItem {
         if self.props.show_list != props.show_list || self.props.data.is_followed != props.data.is_followed || self.props.data.uuid != props.data.uuid {
             self.props.show_list = props.show_list;
             self.props.data = props.data;
+            self.props.parent = props.parent;
+            true
+        } else if self.props.parent != props.parent {
+            self.props.parent = props.parent;
             true
         } else {
             false
@@ -127,6 +135,9 @@ impl ListItem {
                   </div>
                 </div>
                 <div class="buttons flexBox p-0" >
+                    {self.props.parent.as_ref().map(parent_tag).unwrap_or_default()}
+                    <AddToBom component_uuid={self.props.data.uuid.clone()} />
+                    <AddToCompare component_uuid={self.props.data.uuid.clone()} />
                     {res_btn(
                       classes!("far", "fa-folder"),
                       onclick_open_component,
@@ -1,6 +1,7 @@
 mod list_item;
 pub use list_item::ListItem;
 
+use std::collections::BTreeMap;
 use yew::{html, Component, ComponentLink, Html, ShouldRender, Properties};
 use yew_router::prelude::RouterAnchor;
 use graphql_client::GraphQLQuery;
@@ -8,11 +9,11 @@ use log::debug;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::error::Error;
-use crate::fragments::{list_errors::ListErrors, list_empty::ListEmpty};
+use crate::fragments::{list_errors::ListErrors, list_empty::ListEmpty, paginate::Paginate};
 use crate::routes::component::CreateComponent;
 use crate::routes::AppRoute;
-use crate::types::{ComponentsQueryArg, ShowComponentShort, UUID};
-use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentParentInfo, ComponentsQueryArg, PaginateSet, ShowComponentShort, UUID};
+use crate::services::{get_logged_user, get_value_field, resp_parsing, BulkTarget};
 use crate::gqls::make_query;
 use crate::gqls::component::{
     GetComponentsShortList, get_components_short_list,
@@ -20,6 +21,8 @@ use crate::gqls::component::{
     DeleteComponentFav, delete_component_fav,
 };
 use crate::fragments::ListState;
+use crate::fragments::bulk_actions::{BulkActionBar, select_item};
+use crate::fragments::component::request_parents;
 
 pub enum Msg {
     SwitchShowType,
@@ -27,7 +30,14 @@ pub enum Msg {
     AddFav(UUID),
     DelFav(UUID),
     GetList,
+    RequestParents,
+    GetParentsResult(Vec<UUID>, BTreeMap<UUID, ComponentParentInfo>),
     ShowAddComponentCard,
+    SwitchSelectMode,
+    SelectItem(UUID),
+    SelectAll,
+    FinishBulk,
+    ChangePaginate(PaginateSet),
     ResponseError(Error),
     ClearError,
 }
@@ -38,8 +48,15 @@ pub struct CatalogComponents {
     props: Props,
     show_type: ListState,
     list: Vec<ShowComponentShort>,
+    /// Parents of the forked components of the list by the UUIDs of the components
+    parents: BTreeMap<UUID, ComponentParentInfo>,
     company_uuid: Option<UUID>,
     show_add_component: bool,
+    /// The checkboxes of the items and the bar of the bulk actions are shown
+    select_mode: bool,
+    /// Selected components with their names, the selection is kept when the page is changed
+    selected: Vec<(UUID, String)>,
+    page_set: PaginateSet,
 }
 
 #[derive(Properties, Clone)]
@@ -61,14 +78,21 @@ impl Component for CatalogComponents {
             props,
             show_type: ListState::get_from_storage(),
             list: Vec::new(),
+            parents: BTreeMap::new(),
             company_uuid,
             show_add_component: false,
+            select_mode: false,
+            selected: Vec::new(),
+            page_set: PaginateSet::set(Some(1), Some(30)),
         }
     }
 
     fn rendered(&mut self, first_render: bool) {
-        if first_render && self.props.component_list.is_none() {
-            self.link.send_message(Msg::GetList);
+        if first_render {
+            match self.props.component_list.is_none() {
+                true => self.link.send_message(Msg::GetList),
+                false => self.link.send_message(Msg::RequestParents),
+            }
         }
     }
 
@@ -99,10 +123,9 @@ impl Component for CatalogComponents {
                     byField: "createdAt".to_string(),
                     asDesc: true,
                 });
-                // todo!(make paginate)
                 let ipt_paginate = Some(get_components_short_list::IptPaginate {
-                    currentPage: 1,
-                    perPage: 25,
+                    currentPage: self.page_set.current_page,
+                    perPage: self.page_set.per_page,
                 });
                 spawn_local(async move {
                     let res = make_query(GetComponentsShortList::build_query(
@@ -120,10 +143,28 @@ impl Component for CatalogComponents {
             },
             Msg::UpdateList(res) => {
                 match resp_parsing(res, "components") {
-                    Ok(result) => self.list = result,
+                    Ok(result) => {
+                        self.list = result;
+                        link.send_message(Msg::RequestParents);
+                    },
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
+            Msg::RequestParents => {
+                let uuids = self.current_uuids();
+                spawn_local(async move {
+                    let parents = request_parents(uuids.clone()).await;
+                    link.send_message(Msg::GetParentsResult(uuids, parents));
+                });
+                return false
+            },
+            Msg::GetParentsResult(uuids, parents) => {
+                // the parents of the previous list are dropped
+                if uuids != self.current_uuids() {
+                    return false
+                }
+                self.parents = parents;
+            },
             Msg::AddFav(component_uuid) => {
                 spawn_local(async move {
                     make_query(AddComponentFav::build_query(add_component_fav::Variables {
@@ -147,6 +188,45 @@ impl Component for CatalogComponents {
                 });
             },
             Msg::ShowAddComponentCard => self.show_add_component = !self.show_add_component,
+            Msg::SwitchSelectMode => {
+                self.select_mode = !self.select_mode;
+                self.selected.clear();
+            },
+            Msg::SelectItem(uuid) => match self.selected.iter().position(|(u, _)| u == &uuid) {
+                Some(index) => {
+                    self.selected.remove(index);
+                },
+                None => {
+                    if let Some(component) = self.current_list().iter().find(|c| c.uuid == uuid) {
+                        self.selected.push((uuid, component.name.clone()));
+                    }
+                },
+            },
+            Msg::SelectAll => {
+                // the components of the shown page are selected or unselected, the others are kept
+                let page: Vec<(UUID, String)> = self.current_list().iter().map(|c| (c.uuid.clone(), c.name.clone())).collect();
+                match self.page_selected() {
+                    true => self.selected.retain(|(uuid, _)| !page.iter().any(|(u, _)| u == uuid)),
+                    false => for item in page {
+                        if !self.selected.iter().any(|(uuid, _)| uuid == &item.0) {
+                            self.selected.push(item);
+                        }
+                    },
+                }
+            },
+            Msg::FinishBulk => {
+                self.selected.clear();
+                if self.props.component_list.is_none() {
+                    link.send_message(Msg::GetList);
+                }
+            },
+            Msg::ChangePaginate(page_set) => {
+                if self.page_set.compare(&page_set) {
+                    return false
+                }
+                self.page_set = page_set;
+                link.send_message(Msg::GetList);
+            },
             Msg::ResponseError(err) => self.error = Some(err),
             Msg::ClearError => self.error = None,
         }
@@ -172,8 +252,11 @@ impl Component for CatalogComponents {
             false
         } else {
             self.props = props;
-            if self.props.component_list.is_none() {
-                self.link.send_message(Msg::GetList);
+            self.parents.clear();
+            self.page_set.to(1);
+            match self.props.component_list.is_none() {
+                true => self.link.send_message(Msg::GetList),
+                false => self.link.send_message(Msg::RequestParents),
             }
             true
         }
@@ -191,6 +274,7 @@ impl Component for CatalogComponents {
               <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
               <div class="level" >
                 <div class="level-left ">
+                    {self.show_select_mode()}
                 </div>
                 <div class="level-right">
                     <div class="buttons">
@@ -206,16 +290,96 @@ impl Component for CatalogComponents {
                     </div>
                 </div>
               </div>
-              {match self.props.component_list {
-                Some(ref list) => self.show_list(list),
-                None => self.show_list(&self.list),
+              {match self.select_mode {
+                true => self.show_bulk_bar(),
+                false => html!{},
               }}
+              {self.show_list(self.current_list())}
+              {self.show_paginate()}
             </div>
         }
     }
 }
 
 impl CatalogComponents {
+    fn current_list(&self) -> &[ShowComponentShort] {
+        match self.props.component_list {
+            Some(ref list) => list,
+            None => &self.list,
+        }
+    }
+
+    fn current_uuids(&self) -> Vec<UUID> {
+        self.current_list().iter().map(|c| c.uuid.clone()).collect()
+    }
+
+    fn is_selected(&self, uuid: &UUID) -> bool {
+        self.selected.iter().any(|(u, _)| u == uuid)
+    }
+
+    /// All components of the shown page are selected
+    fn page_selected(&self) -> bool {
+        let list = self.current_list();
+        !list.is_empty() && list.iter().all(|c| self.is_selected(&c.uuid))
+    }
+
+    /// The list given in the props is shown without the pages
+    fn show_paginate(&self) -> Html {
+        if self.props.component_list.is_some() {
+            return html!{}
+        }
+        let onclick_paginate = self.link.callback(Msg::ChangePaginate);
+        html!{
+            <Paginate
+                callback_change={onclick_paginate}
+                current_items={self.list.len() as i64}
+                current_page={Some(self.page_set.current_page)}
+                per_page={Some(self.page_set.per_page)}
+                total_items={None}
+                />
+        }
+    }
+
+    fn show_select_mode(&self) -> Html {
+        if get_logged_user().is_none() {
+            return html!{}
+        }
+        let onclick_select_mode = self.link.callback(|_| Msg::SwitchSelectMode);
+        let onclick_select_all = self.link.callback(|_| Msg::SelectAll);
+        html!{<>
+            <button
+                id="components-select-btn"
+                class={match self.select_mode {
+                    true => "button is-info is-light",
+                    false => "button",
+                }}
+                onclick={onclick_select_mode} >
+                <span class="icon is-small"><i class="far fa-check-square" aria-hidden="true"></i></span>
+                <span>{get_value_field(&129)}</span> // Select
+            </button>
+            {match self.select_mode {
+                true => html!{
+                    <label class="checkbox ml-3">
+                        <input
+                            id="components-select-all"
+                            type="checkbox"
+                            checked={self.page_selected()}
+                            onclick={onclick_select_all} />
+                        {" "}{get_value_field(&662)} // Select all
+                    </label>
+                },
+                false => html!{},
+            }}
+        </>}
+    }
+
+    fn show_bulk_bar(&self) -> Html {
+        let callback_finish = self.link.callback(|_| Msg::FinishBulk);
+        html!{
+            <BulkActionBar target={BulkTarget::Component} selected={self.selected.clone()} callback_finish={callback_finish} />
+        }
+    }
+
     fn show_list(&self, list: &[ShowComponentShort]) -> Html {
         let class_for_list = match self.show_type {
             ListState::Box => "flex-box",
@@ -274,13 +438,20 @@ impl CatalogComponents {
     fn show_card(&self, show_comp: &ShowComponentShort) -> Html {
         let onclick_add_fav = self.link.callback(|target_uuid| Msg::AddFav(target_uuid));
         let onclick_del_fav = self.link.callback(|target_uuid| Msg::DelFav(target_uuid));
-        html! {
+        let component_uuid = show_comp.uuid.clone();
+        let onclick_select = self.link.callback(move |_| Msg::SelectItem(component_uuid.clone()));
+        let item = html! {
             <ListItem
                 data={show_comp.clone()}
                 show_list={self.show_type == ListState::List}
                 add_fav={onclick_add_fav}
                 del_fav={onclick_del_fav}
+                parent={self.parents.get(&show_comp.uuid).cloned()}
                 />
+        };
+        match self.select_mode {
+            true => select_item(self.is_selected(&show_comp.uuid), item, onclick_select),
+            false => item,
         }
     }
 }
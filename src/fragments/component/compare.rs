@@ -0,0 +1,104 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+
+use crate::services::{get_value_field, load_compare, save_compare, toggle_compare, MAX_COMPARE};
+use crate::types::UUID;
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+}
+
+/// Adds the component to the comparison (saved in the local storage) or removes it from there,
+/// while the component is in the comparison a link to the comparison page is shown
+pub struct AddToCompare {
+    props: Props,
+    link: ComponentLink<Self>,
+    in_compare: bool,
+    /// The comparison is full and the component is not in it
+    is_full: bool,
+}
+
+pub enum Msg {
+    Toggle,
+}
+
+impl Component for AddToCompare {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut component = Self {
+            props,
+            link,
+            in_compare: false,
+            is_full: false,
+        };
+        component.read_selection();
+        component
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                // the selection could be changed by other buttons on the page
+                let mut uuids = load_compare();
+                toggle_compare(&mut uuids, &self.props.component_uuid);
+                save_compare(&uuids);
+                self.read_selection();
+            },
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            return false
+        }
+        self.props = props;
+        self.read_selection();
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_toggle = self.link.callback(|_| Msg::Toggle);
+        html!{<>
+            <button
+                id="add-to-compare-btn"
+                class={match self.in_compare {
+                    true => "button is-small is-info is-light",
+                    false => "button is-small is-white",
+                }}
+                onclick={onclick_toggle}
+                disabled={self.is_full}
+                title={match (self.in_compare, self.is_full) {
+                    (true, _) => get_value_field(&618).to_string(), // In comparison
+                    (false, true) => format!("{} {}", get_value_field(&631), MAX_COMPARE), // The maximum number of components in the comparison
+                    (false, false) => get_value_field(&617).to_string(), // Add to the comparison
+                }} >
+                <span class="icon">
+                    {match self.in_compare {
+                        true => html!{<i class="fas fa-check" aria-hidden="true"></i>},
+                        false => html!{<i class="fas fa-balance-scale" style="color: #1872f0;" aria-hidden="true"></i>},
+                    }}
+                </span>
+                <span>{get_value_field(&616)}</span> // Compare
+            </button>
+            {match self.in_compare {
+                true => html!{
+                    <a class="button is-small is-white" href="#/compare" title={get_value_field(&620)}> // Open the comparison
+                        <span class="icon"><i class="fas fa-columns" aria-hidden="true"></i></span>
+                    </a>
+                },
+                false => html!{},
+            }}
+        </>}
+    }
+}
+
+impl AddToCompare {
+    fn read_selection(&mut self) {
+        let uuids = load_compare();
+        self.in_compare = uuids.contains(&self.props.component_uuid);
+        self.is_full = !self.in_compare && uuids.len() >= MAX_COMPARE;
+    }
+}
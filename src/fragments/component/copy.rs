@@ -0,0 +1,238 @@
+use std::future::Future;
+use yew::{Callback, Component, ComponentLink};
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::services::{get_value_field, put_file, resp_parsing, resp_parsing_two_level, UploadData};
+use crate::types::{ComponentInfo, ComponentModificationInfo, ComponentParam, UUID};
+use crate::gqls::make_query;
+use crate::fragments::component::request_all_modifications;
+use crate::gqls::component::{
+    AddComponentSpecs, add_component_specs,
+    AddComponentKeywordsByNames, add_component_keywords_by_names,
+    AddComponentLicense, add_component_license,
+    GetComponentParams, get_component_params,
+    PutComponentParams, put_component_params,
+    RegisterComponentModificationsBulk, register_component_modifications_bulk,
+};
+
+/// Response of a request copying the data of the component to the new one
+pub enum CopyResponse {
+    /// Params of the source component
+    Params(String),
+    /// Modifications of the source component
+    Modifications(Result<Vec<ComponentModificationInfo>, Error>),
+    /// New modifications and the source modifications in the same order
+    ModificationsRegistered(String, Vec<UUID>),
+    /// Response of a copy request with the key of the result
+    Copied(String, &'static str),
+}
+
+/// Counts the requests of copying the data of the component, the requests are not sent after a failure.
+/// The description data, params and modifications of the component are copied to the new one
+/// by the fork and the duplicate, the responses are sent to the component as `CopyResponse`.
+/// The export and the import of the bundle use only the counting of their requests.
+pub(crate) struct CopyRequests<COMP: Component> {
+    link: ComponentLink<COMP>,
+    /// Number of the requests without a response
+    pub(crate) pending: usize,
+    pub(crate) completed: usize,
+    pub(crate) failed: bool,
+}
+
+impl<COMP: Component> CopyRequests<COMP> {
+    pub(crate) fn new(link: ComponentLink<COMP>) -> Self {
+        Self { link, pending: 0, completed: 0, failed: false }
+    }
+
+    /// Clears the counts and the failure before the new copying
+    pub(crate) fn start(&mut self) {
+        self.pending = 0;
+        self.completed = 0;
+        self.failed = false;
+    }
+
+    /// Requests are not sent after a failure
+    pub(crate) fn send<M: Into<COMP::Message>>(&mut self, request: impl Future<Output = M> + 'static) {
+        if self.failed {
+            return
+        }
+        let link = self.link.clone();
+        self.pending += 1;
+        spawn_local(async move {
+            link.send_message(request.await.into());
+        })
+    }
+
+    /// Uploads the file to the storage, the upload is counted as a request and is not started after a failure
+    pub(crate) fn upload<M, F>(&mut self, upload_data: UploadData, result: F)
+    where
+        M: Into<COMP::Message>,
+        F: Fn(Result<Option<String>, Error>) -> M + 'static,
+    {
+        if self.failed {
+            return
+        }
+        self.pending += 1;
+        put_file(upload_data, self.link.callback(result), Callback::noop());
+    }
+
+    /// Counts the response, the error is returned to be shown by the component
+    pub(crate) fn finish(&mut self, result: Result<(), Error>) -> Result<(), Error> {
+        self.pending = self.pending.saturating_sub(1);
+        self.completed += 1;
+        if result.is_err() {
+            self.failed = true;
+        }
+        result
+    }
+}
+
+impl<COMP: Component> CopyRequests<COMP>
+where
+    COMP::Message: From<CopyResponse>,
+{
+    /// Copies the specs, keywords, licenses, params and optionally modifications of the source component
+    pub(crate) fn copy_related_data(&mut self, source: &ComponentInfo, target_uuid: &UUID, copy_modifications: bool) {
+        if !source.component_specs.is_empty() {
+            let ipt_component_specs_data = add_component_specs::IptComponentSpecsData {
+                componentUuid: target_uuid.clone(),
+                specIds: source.component_specs.iter().map(|s| s.spec_id as i64).collect(),
+            };
+            self.send(async move {
+                let res = make_query(AddComponentSpecs::build_query(
+                    add_component_specs::Variables { ipt_component_specs_data }
+                )).await.unwrap();
+                CopyResponse::Copied(res, "addComponentSpecs")
+            });
+        }
+        if !source.component_keywords.is_empty() {
+            let ipt_component_keywords_names = add_component_keywords_by_names::IptComponentKeywordsNames {
+                componentUuid: target_uuid.clone(),
+                keywords: source.component_keywords.iter().map(|k| k.keyword.clone()).collect(),
+            };
+            self.send(async move {
+                let res = make_query(AddComponentKeywordsByNames::build_query(
+                    add_component_keywords_by_names::Variables { ipt_component_keywords_names }
+                )).await.unwrap();
+                CopyResponse::Copied(res, "addComponentKeywordsByNames")
+            });
+        }
+        for license in &source.licenses {
+            let ipt_component_license_data = add_component_license::IptComponentLicenseData {
+                componentUuid: target_uuid.clone(),
+                licenseId: license.id as i64,
+            };
+            self.send(async move {
+                let res = make_query(AddComponentLicense::build_query(
+                    add_component_license::Variables { ipt_component_license_data }
+                )).await.unwrap();
+                CopyResponse::Copied(res, "addComponentLicense")
+            });
+        }
+        if source.params_count > 0 {
+            let component_uuid = source.uuid.clone();
+            self.send(async move {
+                let res = make_query(GetComponentParams::build_query(
+                    get_component_params::Variables { component_uuid, ipt_sort: None, ipt_paginate: None }
+                )).await.unwrap();
+                CopyResponse::Params(res)
+            });
+        }
+        if copy_modifications && source.modifications_count > 0 {
+            let component_uuid = source.uuid.clone();
+            self.send(async move {
+                CopyResponse::Modifications(request_all_modifications(component_uuid).await)
+            });
+        }
+    }
+
+    /// Handles the response: the received params and modifications are put to the new component.
+    /// Returns the pairs of the source and new modifications after their registration.
+    pub(crate) fn handle(&mut self, response: CopyResponse, target_uuid: &UUID) -> Result<Vec<(UUID, UUID)>, Error> {
+        match response {
+            CopyResponse::Params(res) => {
+                let params = resp_parsing_two_level::<ComponentParam>(res, "component", "componentParams")?;
+                self.put_params(target_uuid, params);
+                Ok(Vec::new())
+            },
+            CopyResponse::Modifications(result) => {
+                let modifications = result?;
+                self.register_modifications(target_uuid, modifications);
+                Ok(Vec::new())
+            },
+            CopyResponse::ModificationsRegistered(res, source_uuids) => {
+                resp_parsing::<Vec<UUID>>(res, "registerComponentModificationsBulk")
+                    .and_then(|new_uuids| pair_uuids(source_uuids, new_uuids))
+            },
+            CopyResponse::Copied(res, key) => resp_parsing::<Value>(res, key).map(|_| Vec::new()),
+        }
+    }
+
+    fn put_params(&mut self, target_uuid: &UUID, params: Vec<ComponentParam>) {
+        if params.is_empty() {
+            return
+        }
+        let ipt_component_params_data = put_component_params::IptComponentParamsData {
+            componentUuid: target_uuid.clone(),
+            params: params.iter().map(|p| put_component_params::IptParamData {
+                paramId: p.param.param_id as i64,
+                value: p.value.clone(),
+            }).collect(),
+        };
+        self.send(async move {
+            let res = make_query(PutComponentParams::build_query(
+                put_component_params::Variables { ipt_component_params_data }
+            )).await.unwrap();
+            CopyResponse::Copied(res, "putComponentParams")
+        });
+    }
+
+    fn register_modifications(&mut self, target_uuid: &UUID, modifications: Vec<ComponentModificationInfo>) {
+        if modifications.is_empty() {
+            return
+        }
+        let source_uuids: Vec<UUID> = modifications.iter().map(|m| m.uuid.clone()).collect();
+        let ipt_multiple_modifications_data = register_component_modifications_bulk::IptMultipleModificationsData {
+            componentUuid: target_uuid.clone(),
+            modificationsData: modifications.iter().map(|m| register_component_modifications_bulk::IptModificationsData {
+                modificationName: m.modification_name.clone(),
+                description: m.description.clone(),
+                actualStatusId: m.actual_status.actual_status_id as i64,
+                parameters: m.modification_params.iter().map(|p| register_component_modifications_bulk::IptParamData {
+                    paramId: p.param.param_id as i64,
+                    value: p.value.clone(),
+                }).collect(),
+            }).collect(),
+        };
+        self.send(async move {
+            let res = make_query(RegisterComponentModificationsBulk::build_query(
+                register_component_modifications_bulk::Variables { ipt_multiple_modifications_data }
+            )).await.unwrap();
+            CopyResponse::ModificationsRegistered(res, source_uuids)
+        });
+    }
+}
+
+/// Pairs the source objects with the created ones which are returned in the same order
+pub(crate) fn pair_uuids(source_uuids: Vec<UUID>, new_uuids: Vec<UUID>) -> Result<Vec<(UUID, UUID)>, Error> {
+    if source_uuids.len() != new_uuids.len() {
+        return Err(Error::BadRequest(format!(
+            "{}: {} / {}", get_value_field(&708), new_uuids.len(), source_uuids.len()
+        )))
+    }
+    Ok(source_uuids.into_iter().zip(new_uuids).collect())
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn modifications_pairs() {
+        let pairs = pair_uuids(vec!["s1".into(), "s2".into()], vec!["n1".into(), "n2".into()]).unwrap();
+        assert_eq!(pairs[1], ("s2".to_string(), "n2".to_string()));
+    }
+}
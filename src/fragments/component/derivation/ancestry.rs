@@ -0,0 +1,143 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use super::{valid_parent, MAX_ANCESTORS};
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentParentInfo, Pathname, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{GetComponentParent, get_component_parent};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    pub parent_component_uuid: UUID,
+    pub name: String,
+}
+
+/// Breadcrumb of the components from which the component is derived, the parents are requested one by one
+pub struct ComponentAncestry {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    /// The nearest parent goes first
+    ancestors: Vec<ComponentParentInfo>,
+    /// The requested parent, the responses for the previous component are skipped
+    request_uuid: UUID,
+}
+
+pub enum Msg {
+    RequestParent(UUID),
+    GetParentResult(String),
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for ComponentAncestry {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            ancestors: Vec::new(),
+            request_uuid: String::new(),
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.request_first_parent();
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestParent(component_uuid) => {
+                self.request_uuid = component_uuid.clone();
+                spawn_local(async move {
+                    let res = make_query(GetComponentParent::build_query(
+                        get_component_parent::Variables { component_uuid }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetParentResult(res));
+                })
+            },
+            Msg::GetParentResult(res) => {
+                match resp_parsing::<ComponentParentInfo>(res, "component") {
+                    Ok(parent) => {
+                        debug!("Parent component: {:?}", parent);
+                        if parent.uuid != self.request_uuid {
+                            return false
+                        }
+                        // the chain stops on a cycle of the parents or on the limit
+                        let next = valid_parent(&parent.uuid, &parent.parent_component_uuid)
+                            .filter(|uuid| *uuid != self.props.component_uuid)
+                            .filter(|uuid| self.ancestors.iter().all(|a| a.uuid != *uuid))
+                            .map(|uuid| uuid.to_string());
+                        self.ancestors.push(parent);
+                        if let Some(uuid) = next.filter(|_| self.ancestors.len() < MAX_ANCESTORS) {
+                            link.send_message(Msg::RequestParent(uuid));
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid
+            && self.props.parent_component_uuid == props.parent_component_uuid {
+            return false
+        }
+        self.props = props;
+        self.ancestors.clear();
+        self.request_uuid.clear();
+        self.request_first_parent();
+        true
+    }
+
+    fn view(&self) -> Html {
+        if self.ancestors.is_empty() && self.error.is_none() {
+            return html!{}
+        }
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            <nav class="breadcrumb is-small mb-1" aria-label="breadcrumbs" title={get_value_field(&578)}>
+                <ul>
+                    <li>
+                        <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+                    </li>
+                    {for self.ancestors.iter().rev().map(|ancestor| html!{
+                        <li>
+                            <a href={Pathname::Component(ancestor.uuid.clone()).get_pathname()}>
+                                {ancestor.name.clone()}
+                            </a>
+                        </li>
+                    })}
+                    <li class="is-active">
+                        <a aria-current="page">{self.props.name.clone()}</a>
+                    </li>
+                </ul>
+            </nav>
+        </>}
+    }
+}
+
+impl ComponentAncestry {
+    fn request_first_parent(&self) {
+        if let Some(parent_uuid) = valid_parent(&self.props.component_uuid, &self.props.parent_component_uuid) {
+            self.link.send_message(Msg::RequestParent(parent_uuid.to_string()));
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeSet;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use super::valid_parent;
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_logged_user, get_value_field, resp_parsing};
+use crate::types::{ComponentParentInfo, Pathname, ShowComponentShort, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentParent, get_component_parent,
+    GetComponentsShortList, get_components_short_list,
+};
+
+/// Number of the components of each user which are checked for the parent
+const CANDIDATES_PER_USER: i64 = 100;
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    pub owner_uuid: UUID,
+}
+
+/// Components derived from the component. The API does not filter the components by the parent,
+/// so the parents of the components of the owner and the current user are checked on demand.
+pub struct DerivedComponents {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    /// Lists of the components requested and not received yet
+    pending_lists: usize,
+    candidates: BTreeSet<UUID>,
+    pending_parents: usize,
+    derived: Vec<ComponentParentInfo>,
+    searched: bool,
+}
+
+pub enum Msg {
+    Search,
+    GetComponentsResult(String),
+    GetParentResult(String),
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for DerivedComponents {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            pending_lists: 0,
+            candidates: BTreeSet::new(),
+            pending_parents: 0,
+            derived: Vec::new(),
+            searched: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::Search => {
+                self.searched = true;
+                self.candidates.clear();
+                self.derived.clear();
+                let mut user_uuids = vec![self.props.owner_uuid.clone()];
+                if let Some(user) = get_logged_user().filter(|user| user.uuid != self.props.owner_uuid) {
+                    user_uuids.push(user.uuid);
+                }
+                self.pending_lists = user_uuids.len();
+                for user_uuid in user_uuids {
+                    let link = link.clone();
+                    let ipt_components_arg = Some(get_components_short_list::IptComponentsArg {
+                        componentsUuids: None,
+                        companyUuid: None,
+                        standardUuid: None,
+                        serviceUuid: None,
+                        userUuid: Some(user_uuid),
+                        specId: None,
+                        favorite: None,
+                    });
+                    let ipt_paginate = Some(get_components_short_list::IptPaginate {
+                        currentPage: 1,
+                        perPage: CANDIDATES_PER_USER,
+                    });
+                    spawn_local(async move {
+                        let res = make_query(GetComponentsShortList::build_query(
+                            get_components_short_list::Variables { ipt_components_arg, ipt_sort: None, ipt_paginate }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetComponentsResult(res));
+                    })
+                }
+            },
+            Msg::GetComponentsResult(res) => {
+                self.pending_lists = self.pending_lists.saturating_sub(1);
+                match resp_parsing::<Vec<ShowComponentShort>>(res, "components") {
+                    Ok(result) => {
+                        let component_uuid = &self.props.component_uuid;
+                        self.candidates.extend(
+                            result.into_iter().map(|c| c.uuid).filter(|uuid| uuid != component_uuid)
+                        );
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+                if self.pending_lists == 0 {
+                    debug!("Check parents of {} components", self.candidates.len());
+                    self.pending_parents = self.candidates.len();
+                    for component_uuid in self.candidates.iter().cloned() {
+                        let link = link.clone();
+                        spawn_local(async move {
+                            let res = make_query(GetComponentParent::build_query(
+                                get_component_parent::Variables { component_uuid }
+                            )).await.unwrap();
+                            link.send_message(Msg::GetParentResult(res));
+                        })
+                    }
+                }
+            },
+            Msg::GetParentResult(res) => {
+                self.pending_parents = self.pending_parents.saturating_sub(1);
+                match resp_parsing::<ComponentParentInfo>(res, "component") {
+                    Ok(component) => {
+                        if valid_parent(&component.uuid, &component.parent_component_uuid) == Some(self.props.component_uuid.as_str()) {
+                            self.derived.push(component);
+                            self.derived.sort_by(|a, b| a.name.cmp(&b.name));
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            return false
+        }
+        self.props = props;
+        self.searched = false;
+        self.candidates.clear();
+        self.derived.clear();
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_search = self.link.callback(|_| Msg::Search);
+        let loading = self.pending_lists > 0 || self.pending_parents > 0;
+        html!{
+            <div class="card">
+                <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                <header class="card-header">
+                    <p class="card-header-title">{get_value_field(&579)}</p> // Derived components
+                    <div class="card-header-icon">
+                        <button
+                            id="search-derived-components"
+                            class={match loading {
+                                true => "button is-small is-loading",
+                                false => "button is-small",
+                            }}
+                            title={get_value_field(&580)}
+                            onclick={onclick_search} >
+                            <span class="icon is-small"><i class="fas fa-search" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&580)}</span> // Find among the components of the owner and yours
+                        </button>
+                    </div>
+                </header>
+                {match self.searched && !loading {
+                    true => self.show_derived(),
+                    false => html!{},
+                }}
+            </div>
+        }
+    }
+}
+
+impl DerivedComponents {
+    fn show_derived(&self) -> Html {
+        if self.derived.is_empty() {
+            return html!{
+                <div class="card-content">
+                    <p class="help">{get_value_field(&581)}</p> // No derived components found
+                </div>
+            }
+        }
+        html!{
+            <div class="card-content">
+                <ul>
+                    {for self.derived.iter().map(|component| html!{
+                        <li>
+                            <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+                            {" "}
+                            <a href={Pathname::Component(component.uuid.clone()).get_pathname()}>
+                                {component.name.clone()}
+                            </a>
+                        </li>
+                    })}
+                </ul>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use yew::{agent::Bridged, html, Bridge, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use yew_router::{agent::RouteRequest::ChangeRoute, prelude::RouteAgent};
+use log::debug;
+use graphql_client::GraphQLQuery;
+
+use crate::error::Error;
+use crate::routes::AppRoute;
+use crate::fragments::list_errors::ListErrors;
+use crate::fragments::component::{CopyRequests, CopyResponse};
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{RegisterComponent, register_component};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component: ComponentInfo,
+}
+
+/// Creates a component derived from this one with the copied description, specs, keywords,
+/// licenses, params and optionally modifications, then opens the settings of the new component
+pub struct ForkComponent {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    router_agent: Box<dyn Bridge<RouteAgent>>,
+    hide_modal: bool,
+    name: String,
+    copy_modifications: bool,
+    new_component_uuid: UUID,
+    requests: CopyRequests<Self>,
+}
+
+pub enum Msg {
+    ShowModal,
+    UpdateName(String),
+    SwitchCopyModifications,
+    RequestFork,
+    GetForkResult(String),
+    Copy(CopyResponse),
+    OpenNewComponent,
+    ClearError,
+    Ignore,
+}
+
+impl From<CopyResponse> for Msg {
+    fn from(response: CopyResponse) -> Self {
+        Msg::Copy(response)
+    }
+}
+
+impl Component for ForkComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            name: props.component.name.clone(),
+            props,
+            router_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            requests: CopyRequests::new(link.clone()),
+            link,
+            hide_modal: true,
+            copy_modifications: true,
+            new_component_uuid: String::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ShowModal => self.hide_modal = !self.hide_modal,
+            Msg::UpdateName(value) => self.name = value,
+            Msg::SwitchCopyModifications => self.copy_modifications = !self.copy_modifications,
+            Msg::RequestFork => {
+                let component = &self.props.component;
+                let ipt_component_data = register_component::IptComponentData {
+                    parentComponentUuid: Some(component.uuid.clone()),
+                    name: self.name.trim().to_string(),
+                    description: component.description.clone(),
+                    typeAccessId: component.type_access.type_access_id as i64,
+                    componentTypeId: component.component_type.component_type_id as i64,
+                    actualStatusId: component.actual_status.actual_status_id as i64,
+                    isBase: false,
+                };
+                self.requests.start();
+                self.requests.send(async move {
+                    let res = make_query(RegisterComponent::build_query(register_component::Variables {
+                        ipt_component_data
+                    })).await.unwrap();
+                    Msg::GetForkResult(res)
+                })
+            },
+            Msg::GetForkResult(res) => {
+                match resp_parsing::<UUID>(res, "registerComponent") {
+                    Ok(component_uuid) => {
+                        debug!("Fork component: {:?}", component_uuid);
+                        self.new_component_uuid = component_uuid;
+                        self.requests.copy_related_data(&self.props.component, &self.new_component_uuid, self.copy_modifications);
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::Copy(response) => {
+                let result = self.requests.handle(response, &self.new_component_uuid).map(|_| ());
+                self.finish_request(result);
+            },
+            Msg::OpenNewComponent => {
+                self.router_agent.send(ChangeRoute(AppRoute::ComponentSettings(self.new_component_uuid.clone()).into()));
+            },
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component.uuid == props.component.uuid {
+            return false
+        }
+        self.name = props.component.name.clone();
+        self.props = props;
+        self.hide_modal = true;
+        self.new_component_uuid.clear();
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{<>
+            <button
+                id="fork-component-btn"
+                class="button"
+                onclick={onclick_show_modal}
+                title={get_value_field(&576)} >
+                <span class="icon is-small">
+                    <i class="fas fa-code-branch" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&575)}</span> // Fork
+            </button>
+            {match self.hide_modal {
+                true => html!{},
+                false => self.show_modal(),
+            }}
+        </>}
+    }
+}
+
+impl ForkComponent {
+    /// Counts the response, the new component is opened after the last response if all data is copied
+    fn finish_request(&mut self, result: Result<(), Error>) {
+        if let Err(err) = self.requests.finish(result) {
+            self.error = Some(err);
+        }
+        if self.requests.pending == 0 && !self.requests.failed {
+            self.link.send_message(Msg::OpenNewComponent);
+        }
+    }
+
+    fn show_modal(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowModal);
+        let oninput_name = self.link.callback(|ev: InputData| Msg::UpdateName(ev.value));
+        let onclick_copy_modifications = self.link.callback(|_| Msg::SwitchCopyModifications);
+        let onclick_fork = self.link.callback(|_| Msg::RequestFork);
+        let onclick_open = self.link.callback(|_| Msg::OpenNewComponent);
+        let in_progress = self.requests.pending > 0;
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&576)}</p> // Fork this component
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                        <label class="label" for="fork-component-name">{get_value_field(&110)}</label> // Name
+                        <input
+                            id="fork-component-name"
+                            class="input"
+                            type="text"
+                            value={self.name.clone()}
+                            oninput={oninput_name} />
+                        <p class="help">{get_value_field(&586)}</p>
+                        <label class="checkbox mt-3">
+                            <input
+                                id="fork-component-modifications"
+                                type="checkbox"
+                                checked={self.copy_modifications}
+                                onclick={onclick_copy_modifications} />
+                            {" "}{get_value_field(&577)} // Copy modifications with their parameters
+                        </label>
+                        <br/><br/>
+                        {match self.requests.failed && !self.new_component_uuid.is_empty() && !in_progress {
+                            true => html!{
+                                <button id="open-fork-component" class="button is-fullwidth is-warning" onclick={onclick_open}>
+                                    {get_value_field(&587)} // Open the new component
+                                </button>
+                            },
+                            false => html!{
+                                <button
+                                    id="fork-component"
+                                    class={match in_progress {
+                                        true => "button is-fullwidth is-success is-loading",
+                                        false => "button is-fullwidth is-success",
+                                    }}
+                                    disabled={self.name.trim().is_empty() || in_progress || !self.new_component_uuid.is_empty()}
+                                    onclick={onclick_fork} >
+                                    <span class="icon"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+                                    <span>{get_value_field(&575)}</span> // Fork
+                                </button>
+                            },
+                        }}
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+}
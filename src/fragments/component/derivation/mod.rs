@@ -0,0 +1,55 @@
+mod ancestry;
+mod derived;
+mod fork;
+mod parent_tag;
+
+pub use ancestry::ComponentAncestry;
+pub use derived::DerivedComponents;
+pub use fork::ForkComponent;
+pub(crate) use parent_tag::{parent_tag, request_parents};
+
+use crate::types::UUID;
+
+/// Maximum number of the ancestors requested for the breadcrumb
+pub(crate) const MAX_ANCESTORS: usize = 10;
+
+/// UUID of the parent component if it is set: the components without a parent have
+/// the nil UUID or their own UUID in the parent field
+pub(crate) fn valid_parent<'a>(uuid: &str, parent_uuid: &'a str) -> Option<&'a str> {
+    let nil = parent_uuid.chars().all(|c| c == '0' || c == '-');
+    match parent_uuid.len() == 36 && !nil && parent_uuid != uuid {
+        true => Some(parent_uuid),
+        false => None,
+    }
+}
+
+/// Finds the UUID of a component in the text, e.g. in the link to the component page
+pub(crate) fn find_uuid(text: &str) -> Option<UUID> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    chars.windows(36).find(|window| {
+        window.iter().enumerate().all(|(number, c)| match number {
+            8 | 13 | 18 | 23 => *c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+    }).map(|window| window.iter().collect::<String>().to_lowercase())
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    const UUID_A: &str = "2b7f1c2e-1d3a-4c5b-9e8f-0a1b2c3d4e5f";
+
+    #[test]
+    fn parent_and_uuid_in_link() {
+        assert_eq!(Some(UUID_A), valid_parent("x", UUID_A));
+        assert_eq!(None, valid_parent(UUID_A, UUID_A));
+        assert_eq!(None, valid_parent("x", "00000000-0000-0000-0000-000000000000"));
+        assert_eq!(None, valid_parent("x", ""));
+
+        let link = format!("https://app.cadbase.rs/#/component/{}?modification=1", UUID_A.to_uppercase());
+        assert_eq!(Some(UUID_A), find_uuid(&link).as_deref());
+        assert_eq!(Some(UUID_A), find_uuid(UUID_A).as_deref());
+        assert_eq!(None, find_uuid("#/component/2b7f1c2e-1d3a"));
+    }
+}
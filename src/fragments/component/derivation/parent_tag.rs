@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use yew::{html, Html};
+use log::debug;
+use serde_json::Value;
+
+use super::{find_uuid, valid_parent};
+use crate::services::{get_value_field, get_value_response};
+use crate::types::{ComponentParentInfo, Pathname, UUID};
+use crate::gqls::make_raw_query;
+
+/// Tag with the link to the parent component, shown in the lists of the components
+pub(crate) fn parent_tag(parent: &ComponentParentInfo) -> Html {
+    html!{
+        <span class="tag is-light" title={get_value_field(&578)}>
+            <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+            <span>{get_value_field(&585)}{" "}</span> // Fork of
+            <a href={Pathname::Component(parent.uuid.clone()).get_pathname()}>{parent.name.clone()}</a>
+        </span>
+    }
+}
+
+/// Query of the components with an alias for each UUID (`c0`, `c1`, ...), the strings that are not UUIDs are skipped
+fn components_parent_query(uuids: &[UUID]) -> Option<String> {
+    let fields = uuids.iter()
+        .filter(|uuid| uuid.len() == 36 && find_uuid(uuid).is_some())
+        .enumerate()
+        .map(|(index, uuid)| format!(
+            "c{}: component(componentUuid: \"{}\") {{ uuid parentComponentUuid name }}",
+            index, uuid
+        ))
+        .collect::<Vec<String>>();
+    match fields.is_empty() {
+        true => None,
+        false => Some(format!("query GetComponentsParent {{ {} }}", fields.join(" "))),
+    }
+}
+
+/// Requests the components by one query, None if the query failed
+async fn request_components(uuids: &[UUID]) -> Option<Vec<ComponentParentInfo>> {
+    let query = match components_parent_query(uuids) {
+        Some(query) => query,
+        None => return Some(Vec::new()),
+    };
+    let res = make_raw_query(query).await.unwrap();
+    match get_value_response(res) {
+        Ok(Value::Object(data)) => Some(data.into_iter().filter_map(|(_, value)| serde_json::from_value(value).ok()).collect()),
+        Ok(_) => Some(Vec::new()),
+        Err(err) => {
+            debug!("Components for the parents: {:?}", err);
+            None
+        },
+    }
+}
+
+/// Parents of the components of the list by the UUIDs of the components.
+/// The short data of the components has no parent, so the components and then their parents
+/// are requested by one query each instead of the requests for every component of the list.
+pub(crate) async fn request_parents(uuids: Vec<UUID>) -> BTreeMap<UUID, ComponentParentInfo> {
+    let components = request_components(&uuids).await.unwrap_or_default();
+    let mut parent_uuids = components.iter()
+        .filter_map(|c| valid_parent(&c.uuid, &c.parent_component_uuid))
+        .map(str::to_string)
+        .collect::<Vec<UUID>>();
+    parent_uuids.sort();
+    parent_uuids.dedup();
+    if parent_uuids.is_empty() {
+        return BTreeMap::new()
+    }
+    let parents = match request_components(&parent_uuids).await {
+        Some(parents) => parents,
+        None => {
+            // a parent without access fails the whole query, then the parents are requested one by one
+            let mut parents = Vec::new();
+            for parent_uuid in parent_uuids {
+                parents.extend(request_components(&[parent_uuid]).await.unwrap_or_default());
+            }
+            parents
+        },
+    };
+    components.into_iter().filter_map(|component| {
+        let parent_uuid = valid_parent(&component.uuid, &component.parent_component_uuid)?;
+        let parent = parents.iter().find(|p| p.uuid == parent_uuid)?.clone();
+        Some((component.uuid, parent))
+    }).collect()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn parents_query_aliases() {
+        let uuids = vec![
+            String::from("2b7f1c2e-1d3a-4c5b-9e8f-0a1b2c3d4e5f"),
+            String::from("\") { uuid } x: component(componentUuid: \""),
+            String::from("3c8f1c2e-1d3a-4c5b-9e8f-0a1b2c3d4e5f"),
+        ];
+        assert_eq!(
+            Some("query GetComponentsParent { \
+                c0: component(componentUuid: \"2b7f1c2e-1d3a-4c5b-9e8f-0a1b2c3d4e5f\") { uuid parentComponentUuid name } \
+                c1: component(componentUuid: \"3c8f1c2e-1d3a-4c5b-9e8f-0a1b2c3d4e5f\") { uuid parentComponentUuid name } }"),
+            components_parent_query(&uuids).as_deref()
+        );
+        assert_eq!(None, components_parent_query(&uuids[1..2]));
+    }
+}
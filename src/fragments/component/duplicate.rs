@@ -0,0 +1,680 @@
+use yew::{agent::Bridged, html, Bridge, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use yew_router::{agent::RouteRequest::ChangeRoute, prelude::RouteAgent};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::routes::AppRoute;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_file, get_value_field, resp_parsing, UploadData};
+use crate::types::{ComponentInfo, DownloadFile, FilesetProgramInfo, UploadFile, UUID};
+use crate::gqls::make_query;
+use crate::gqls::relate::{ConfirmUploadCompleted, confirm_upload_completed};
+use crate::gqls::component::{
+    RegisterComponent, register_component,
+    DeleteComponent, delete_component,
+    AddStandardToComponent, add_standard_to_component,
+    AddComponentSupplier, add_component_supplier,
+    ComponentModificationFilesets, component_modification_filesets,
+    RegisterModificationFileset, register_modification_fileset,
+    ComponentFiles, component_files,
+    ComponentModificationFiles, component_modification_files,
+    ComModFilesetFiles, com_mod_fileset_files,
+    UploadComponentFiles, upload_component_files,
+    UploadModificationFiles, upload_modification_files,
+    UploadFilesToFileset, upload_files_to_fileset,
+};
+use super::copy::{CopyRequests, CopyResponse};
+
+/// Stage of the duplication, the stages go in the order of the declaration
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Stage {
+    Register,
+    Related,
+    Modifications,
+    Files,
+    Confirm,
+    Rollback,
+}
+
+impl Stage {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Register => get_value_field(&591),
+            Self::Related => get_value_field(&592),
+            Self::Modifications => get_value_field(&593),
+            Self::Files => get_value_field(&594),
+            Self::Confirm => get_value_field(&595),
+            Self::Rollback => get_value_field(&596),
+        }
+    }
+}
+
+/// Owner of the copied files, the files of the source object are uploaded to the target one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FilesOf {
+    Component,
+    Modification,
+    Fileset,
+}
+
+impl FilesOf {
+    /// Key of the response with the upload urls
+    pub(crate) fn upload_key(&self) -> &'static str {
+        match self {
+            FilesOf::Component => "uploadComponentFiles",
+            FilesOf::Modification => "uploadModificationFiles",
+            FilesOf::Fileset => "uploadFilesToFileset",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FilesCopy {
+    files_of: FilesOf,
+    source_uuid: UUID,
+    target_uuid: UUID,
+}
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component: ComponentInfo,
+}
+
+/// Creates a copy of the component with its related data, parameters, modifications, filesets
+/// and optionally files. If something is not copied, the new component is deleted.
+pub struct DuplicateComponent {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    router_agent: Box<dyn Bridge<RouteAgent>>,
+    hide_modal: bool,
+    name: String,
+    copy_modifications: bool,
+    copy_files: bool,
+    new_component_uuid: UUID,
+    stage: Stage,
+    requests: CopyRequests<Self>,
+    /// Uploaded files which are confirmed after the last upload
+    uploaded_files: Vec<UUID>,
+    rolled_back: bool,
+}
+
+pub enum Msg {
+    ShowModal,
+    UpdateName(String),
+    SwitchCopyModifications,
+    SwitchCopyFiles,
+    RequestDuplicate,
+    GetRegisterResult(String),
+    Copy(CopyResponse),
+    /// Response with the filesets of the source modification and the new modification
+    GetFilesetsResult(String, UUID),
+    /// Response with the new fileset and the source fileset
+    GetRegisterFilesetResult(String, UUID),
+    GetSourceFilesResult(String, FilesCopy),
+    GetDownloadResult(Result<Vec<(String, Vec<u8>)>, Error>, FilesCopy),
+    GetUploadUrlsResult(String, FilesOf, Vec<(String, Vec<u8>)>),
+    GetUploadResult(Result<Option<String>, Error>),
+    GetRollbackResult(String),
+    OpenNewComponent,
+    ClearError,
+    Ignore,
+}
+
+impl From<CopyResponse> for Msg {
+    fn from(response: CopyResponse) -> Self {
+        Msg::Copy(response)
+    }
+}
+
+impl Component for DuplicateComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            router_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            requests: CopyRequests::new(link.clone()),
+            link,
+            hide_modal: true,
+            name: String::new(),
+            copy_modifications: true,
+            copy_files: false,
+            new_component_uuid: String::new(),
+            stage: Stage::Register,
+            uploaded_files: Vec::new(),
+            rolled_back: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ShowModal => {
+                self.hide_modal = !self.hide_modal;
+                if !self.hide_modal && self.new_component_uuid.is_empty() {
+                    self.name = format!("{} ({})", self.props.component.name, get_value_field(&322));
+                }
+            },
+            Msg::UpdateName(value) => self.name = value,
+            Msg::SwitchCopyModifications => self.copy_modifications = !self.copy_modifications,
+            Msg::SwitchCopyFiles => self.copy_files = !self.copy_files,
+            Msg::RequestDuplicate => {
+                let component = &self.props.component;
+                let ipt_component_data = register_component::IptComponentData {
+                    parentComponentUuid: None,
+                    name: self.name.trim().to_string(),
+                    description: component.description.clone(),
+                    typeAccessId: component.type_access.type_access_id as i64,
+                    componentTypeId: component.component_type.component_type_id as i64,
+                    actualStatusId: component.actual_status.actual_status_id as i64,
+                    isBase: false,
+                };
+                self.error = None;
+                self.rolled_back = false;
+                self.stage = Stage::Register;
+                self.requests.start();
+                self.uploaded_files.clear();
+                self.requests.send(async move {
+                    let res = make_query(RegisterComponent::build_query(register_component::Variables {
+                        ipt_component_data
+                    })).await.unwrap();
+                    Msg::GetRegisterResult(res)
+                });
+            },
+            Msg::GetRegisterResult(res) => {
+                match resp_parsing::<UUID>(res, "registerComponent") {
+                    Ok(component_uuid) => {
+                        debug!("Duplicate component: {:?}", component_uuid);
+                        self.new_component_uuid = component_uuid;
+                        self.copy_related_data();
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::Copy(response) => {
+                if let CopyResponse::Modifications(_) = response {
+                    self.stage = self.stage.max(Stage::Modifications);
+                }
+                let new_component_uuid = self.new_component_uuid.clone();
+                match self.requests.handle(response, &new_component_uuid) {
+                    Ok(pairs) => {
+                        for (source_uuid, target_uuid) in pairs {
+                            self.request_filesets(source_uuid.clone(), target_uuid.clone());
+                            if self.copy_files {
+                                self.request_source_files(FilesCopy { files_of: FilesOf::Modification, source_uuid, target_uuid });
+                            }
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetFilesetsResult(res, modification_uuid) => {
+                match resp_parsing::<Vec<FilesetProgramInfo>>(res, "componentModificationFilesets") {
+                    Ok(filesets) => {
+                        for fileset in filesets {
+                            let ipt_fileset_program_data = register_modification_fileset::IptFilesetProgramData {
+                                modificationUuid: modification_uuid.clone(),
+                                programId: fileset.program.id as i64,
+                            };
+                            self.requests.send(async move {
+                                let res = make_query(RegisterModificationFileset::build_query(
+                                    register_modification_fileset::Variables { ipt_fileset_program_data }
+                                )).await.unwrap();
+                                Msg::GetRegisterFilesetResult(res, fileset.uuid)
+                            });
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetRegisterFilesetResult(res, source_uuid) => {
+                match resp_parsing::<UUID>(res, "registerModificationFileset") {
+                    Ok(target_uuid) => {
+                        if self.copy_files {
+                            self.request_source_files(FilesCopy { files_of: FilesOf::Fileset, source_uuid, target_uuid });
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetSourceFilesResult(res, files_copy) => {
+                let key = match files_copy.files_of {
+                    FilesOf::Component => "componentFiles",
+                    FilesOf::Modification => "componentModificationFiles",
+                    FilesOf::Fileset => "componentModificationFilesetFiles",
+                };
+                match resp_parsing::<Vec<DownloadFile>>(res, key) {
+                    Ok(files) => {
+                        if !files.is_empty() {
+                            self.requests.send(async move {
+                                let mut files_data = Vec::new();
+                                for file in files {
+                                    match get_file(&file.download_url).await {
+                                        Ok(data) => files_data.push((file.filename, data)),
+                                        Err(err) => return Msg::GetDownloadResult(Err(err), files_copy),
+                                    }
+                                }
+                                Msg::GetDownloadResult(Ok(files_data), files_copy)
+                            });
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetDownloadResult(result, files_copy) => {
+                match result {
+                    Ok(files_data) => {
+                        self.request_upload_urls(files_copy, files_data);
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetUploadUrlsResult(res, files_of, files_data) => {
+                let result = resp_parsing::<Vec<UploadFile>>(res, files_of.upload_key())
+                    .and_then(|uploads| pair_uploads(files_data, uploads));
+                match result {
+                    Ok(pairs) => {
+                        for (file_uuid, upload_data) in pairs {
+                            self.uploaded_files.push(file_uuid);
+                            self.requests.upload(upload_data, Msg::GetUploadResult);
+                        }
+                        self.finish_request(Ok(()));
+                    },
+                    Err(err) => self.finish_request(Err(err)),
+                }
+            },
+            Msg::GetUploadResult(result) => self.finish_request(result.map(|_| ())),
+            Msg::GetRollbackResult(res) => {
+                match resp_parsing::<UUID>(res, "deleteComponent") {
+                    Ok(component_uuid) => {
+                        debug!("Rollback of the duplicate: {:?}", component_uuid);
+                        self.new_component_uuid.clear();
+                        self.rolled_back = true;
+                    },
+                    Err(err) => self.error = Some(err),
+                }
+                self.requests.pending = 0;
+            },
+            Msg::OpenNewComponent => {
+                self.hide_modal = true;
+                self.router_agent.send(ChangeRoute(
+                    AppRoute::ComponentSettings(std::mem::take(&mut self.new_component_uuid)).into()
+                ));
+            },
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component.uuid == props.component.uuid {
+            return false
+        }
+        self.props = props;
+        self.hide_modal = true;
+        self.new_component_uuid.clear();
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{<>
+            <button
+                id="duplicate-component-btn"
+                class="button"
+                onclick={onclick_show_modal}
+                title={get_value_field(&589)} >
+                <span class="icon is-small">
+                    <i class="far fa-copy" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&588)}</span> // Duplicate
+            </button>
+            {match self.hide_modal {
+                true => html!{},
+                false => self.show_modal(),
+            }}
+        </>}
+    }
+}
+
+impl DuplicateComponent {
+    fn copy_related_data(&mut self) {
+        self.stage = Stage::Related;
+        let component = self.props.component.clone();
+        let component_uuid = self.new_component_uuid.clone();
+        self.requests.copy_related_data(&component, &component_uuid, self.copy_modifications);
+        for standard in &component.component_standards {
+            let ipt_standard_to_component_data = add_standard_to_component::IptStandardToComponentData {
+                standardUuid: standard.uuid.clone(),
+                componentUuid: component_uuid.clone(),
+            };
+            self.requests.send(async move {
+                let res = make_query(AddStandardToComponent::build_query(
+                    add_standard_to_component::Variables { ipt_standard_to_component_data }
+                )).await.unwrap();
+                CopyResponse::Copied(res, "addStandardToComponent")
+            });
+        }
+        for supplier in &component.component_suppliers {
+            let ipt_supplier_component_data = add_component_supplier::IptSupplierComponentData {
+                componentUuid: component_uuid.clone(),
+                companyUuid: supplier.supplier.uuid.clone(),
+                description: supplier.description.clone(),
+            };
+            self.requests.send(async move {
+                let res = make_query(AddComponentSupplier::build_query(
+                    add_component_supplier::Variables { ipt_supplier_component_data }
+                )).await.unwrap();
+                CopyResponse::Copied(res, "addComponentSupplier")
+            });
+        }
+        if self.copy_files && component.files_count > 0 {
+            self.request_source_files(FilesCopy {
+                files_of: FilesOf::Component,
+                source_uuid: component.uuid,
+                target_uuid: component_uuid,
+            });
+        }
+    }
+
+    fn request_filesets(&mut self, source_uuid: UUID, target_uuid: UUID) {
+        let ipt_fileset_program_arg = component_modification_filesets::IptFilesetProgramArg {
+            modificationUuid: source_uuid,
+            programIds: None,
+        };
+        self.requests.send(async move {
+            let res = make_query(ComponentModificationFilesets::build_query(
+                component_modification_filesets::Variables { ipt_fileset_program_arg }
+            )).await.unwrap();
+            Msg::GetFilesetsResult(res, target_uuid)
+        });
+    }
+
+    fn request_source_files(&mut self, files_copy: FilesCopy) {
+        self.stage = self.stage.max(Stage::Files);
+        let source_uuid = files_copy.source_uuid.clone();
+        match files_copy.files_of {
+            FilesOf::Component => self.requests.send(async move {
+                let ipt_component_files_arg = component_files::IptComponentFilesArg {
+                    componentUuid: source_uuid,
+                    filesUuids: None,
+                };
+                let res = make_query(ComponentFiles::build_query(
+                    component_files::Variables { ipt_component_files_arg }
+                )).await.unwrap();
+                Msg::GetSourceFilesResult(res, files_copy)
+            }),
+            FilesOf::Modification => self.requests.send(async move {
+                let ipt_modification_files_arg = component_modification_files::IptModificationFilesArg {
+                    modificationUuid: source_uuid,
+                    filesUuids: None,
+                };
+                let res = make_query(ComponentModificationFiles::build_query(
+                    component_modification_files::Variables { ipt_modification_files_arg }
+                )).await.unwrap();
+                Msg::GetSourceFilesResult(res, files_copy)
+            }),
+            FilesOf::Fileset => self.requests.send(async move {
+                let ipt_file_of_fileset_arg = com_mod_fileset_files::IptFileOfFilesetArg {
+                    filesetUuid: source_uuid,
+                    fileUuids: None,
+                };
+                let res = make_query(ComModFilesetFiles::build_query(
+                    com_mod_fileset_files::Variables { ipt_file_of_fileset_arg, ipt_paginate: None }
+                )).await.unwrap();
+                Msg::GetSourceFilesResult(res, files_copy)
+            }),
+        }
+    }
+
+    fn request_upload_urls(&mut self, files_copy: FilesCopy, files_data: Vec<(String, Vec<u8>)>) {
+        let filenames: Vec<String> = files_data.iter().map(|(filename, _)| filename.clone()).collect();
+        let files_of = files_copy.files_of;
+        self.requests.send(async move {
+            let res = request_upload_urls(files_of, files_copy.target_uuid, filenames).await;
+            Msg::GetUploadUrlsResult(res, files_of, files_data)
+        });
+    }
+
+    /// Counts the response and after the last one confirms the uploaded files,
+    /// opens the new component or deletes it if some data was not copied
+    fn finish_request(&mut self, result: Result<(), Error>) {
+        if let Err(err) = self.requests.finish(result) {
+            debug!("Duplicate component failed: {:?}", err);
+            self.error = Some(err);
+        }
+        if self.requests.pending > 0 {
+            return
+        }
+        match (self.requests.failed, self.uploaded_files.is_empty()) {
+            (true, _) => self.rollback(),
+            (false, false) => {
+                self.stage = Stage::Confirm;
+                let file_uuids = std::mem::take(&mut self.uploaded_files);
+                self.requests.send(async move {
+                    let res = make_query(ConfirmUploadCompleted::build_query(
+                        confirm_upload_completed::Variables { file_uuids }
+                    )).await.unwrap();
+                    CopyResponse::Copied(res, "uploadCompleted")
+                });
+            },
+            (false, true) => self.link.send_message(Msg::OpenNewComponent),
+        }
+    }
+
+    fn rollback(&mut self) {
+        if self.new_component_uuid.is_empty() {
+            return
+        }
+        self.stage = Stage::Rollback;
+        self.uploaded_files.clear();
+        self.requests.pending = 1;
+        let component_uuid = self.new_component_uuid.clone();
+        let link = self.link.clone();
+        spawn_local(async move {
+            let res = make_query(DeleteComponent::build_query(
+                delete_component::Variables { component_uuid }
+            )).await.unwrap();
+            link.send_message(Msg::GetRollbackResult(res));
+        })
+    }
+
+    fn show_modal(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowModal);
+        let oninput_name = self.link.callback(|ev: InputData| Msg::UpdateName(ev.value));
+        let onclick_copy_modifications = self.link.callback(|_| Msg::SwitchCopyModifications);
+        let onclick_copy_files = self.link.callback(|_| Msg::SwitchCopyFiles);
+        let onclick_duplicate = self.link.callback(|_| Msg::RequestDuplicate);
+        let onclick_open = self.link.callback(|_| Msg::OpenNewComponent);
+        let in_progress = self.requests.pending > 0;
+        let started = in_progress || self.requests.completed > 0;
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&589)}</p> // Duplicate component
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                        <label class="label" for="duplicate-component-name">{get_value_field(&110)}</label> // Name
+                        <input
+                            id="duplicate-component-name"
+                            class="input"
+                            type="text"
+                            value={self.name.clone()}
+                            disabled={in_progress}
+                            oninput={oninput_name} />
+                        <p class="help">{get_value_field(&599)}</p>
+                        <label class="checkbox mt-3">
+                            <input
+                                id="duplicate-component-modifications"
+                                type="checkbox"
+                                checked={self.copy_modifications}
+                                disabled={in_progress}
+                                onclick={onclick_copy_modifications} />
+                            {" "}{get_value_field(&577)} // Copy modifications with their parameters
+                        </label>
+                        <br/>
+                        <label class="checkbox">
+                            <input
+                                id="duplicate-component-files"
+                                type="checkbox"
+                                checked={self.copy_files}
+                                disabled={in_progress}
+                                onclick={onclick_copy_files} />
+                            {" "}{get_value_field(&590)} // Copy files
+                        </label>
+                        <br/><br/>
+                        {match started {
+                            true => self.show_progress(in_progress),
+                            false => html!{},
+                        }}
+                        {match self.requests.failed && !self.rolled_back && !self.new_component_uuid.is_empty() && !in_progress {
+                            true => html!{
+                                <button id="open-duplicate-component" class="button is-fullwidth is-warning" onclick={onclick_open}>
+                                    {get_value_field(&587)} // Open the new component
+                                </button>
+                            },
+                            false => html!{
+                                <button
+                                    id="duplicate-component"
+                                    class={match in_progress {
+                                        true => "button is-fullwidth is-success is-loading",
+                                        false => "button is-fullwidth is-success",
+                                    }}
+                                    disabled={self.name.trim().is_empty() || in_progress || !self.new_component_uuid.is_empty()}
+                                    onclick={onclick_duplicate} >
+                                    <span class="icon"><i class="far fa-copy" aria-hidden="true"></i></span>
+                                    <span>{get_value_field(&588)}</span> // Duplicate
+                                </button>
+                            },
+                        }}
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+
+    fn show_progress(&self, in_progress: bool) -> Html {
+        if self.rolled_back {
+            return html!{<p class="help is-danger mb-3">{get_value_field(&597)}</p>} // Copying failed, the created component has been deleted
+        }
+        let total = self.requests.completed + self.requests.pending;
+        html!{
+            <div class="mb-3">
+                <p class="help">
+                    {self.stage.label()}
+                    {format!(": {} / {} ", self.requests.completed, total)}
+                    {get_value_field(&598)} // requests completed
+                </p>
+                <progress
+                    class={match (in_progress, self.requests.failed) {
+                        (_, true) => "progress is-small is-danger",
+                        (true, false) => "progress is-small is-info",
+                        (false, false) => "progress is-small is-success",
+                    }}
+                    value={self.requests.completed.to_string()}
+                    max={total.max(1).to_string()}>
+                </progress>
+            </div>
+        }
+    }
+}
+
+/// Requests the upload urls of the files for the target object, the urls are in the response by `FilesOf::upload_key`
+pub(crate) async fn request_upload_urls(files_of: FilesOf, target_uuid: UUID, filenames: Vec<String>) -> String {
+    match files_of {
+        FilesOf::Component => {
+            let ipt_component_files_data = upload_component_files::IptComponentFilesData {
+                filenames,
+                componentUuid: target_uuid,
+                commitMsg: String::new(),
+            };
+            make_query(UploadComponentFiles::build_query(
+                upload_component_files::Variables { ipt_component_files_data }
+            )).await.unwrap()
+        },
+        FilesOf::Modification => {
+            let ipt_modification_files_data = upload_modification_files::IptModificationFilesData {
+                filenames,
+                modificationUuid: target_uuid,
+                commitMsg: String::new(),
+            };
+            make_query(UploadModificationFiles::build_query(
+                upload_modification_files::Variables { ipt_modification_files_data }
+            )).await.unwrap()
+        },
+        FilesOf::Fileset => {
+            let ipt_modification_file_from_fileset_data = upload_files_to_fileset::IptModificationFileFromFilesetData {
+                filesetUuid: target_uuid,
+                filenames,
+                commitMsg: String::new(),
+            };
+            make_query(UploadFilesToFileset::build_query(
+                upload_files_to_fileset::Variables { ipt_modification_file_from_fileset_data }
+            )).await.unwrap()
+        },
+    }
+}
+
+/// Matches the downloaded files with the upload urls by the filenames, each url is used once.
+/// A file without the url is an error, then the copy is not complete.
+pub(crate) fn pair_uploads(
+    files_data: Vec<(String, Vec<u8>)>,
+    mut uploads: Vec<UploadFile>,
+) -> Result<Vec<(UUID, UploadData)>, Error> {
+    let mut result = Vec::new();
+    for (filename, file_data) in files_data {
+        let index = match uploads.iter().position(|u| u.filename == filename) {
+            Some(index) => index,
+            None => return Err(Error::BadRequest(format!("{}: {}", get_value_field(&707), filename))),
+        };
+        let upload = uploads.remove(index);
+        result.push((upload.file_uuid, UploadData { filename, upload_url: upload.upload_url, file_data }));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn upload(file_uuid: &str, filename: &str) -> UploadFile {
+        UploadFile {
+            file_uuid: file_uuid.to_string(),
+            filename: filename.to_string(),
+            upload_url: format!("https://storage/{}", file_uuid),
+        }
+    }
+
+    #[test]
+    fn uploads_pairs() {
+        let files_data = vec![
+            ("a.step".to_string(), vec![1]),
+            ("b.stl".to_string(), vec![2]),
+            ("a.step".to_string(), vec![3]),
+        ];
+        let uploads = vec![upload("1", "b.stl"), upload("2", "a.step"), upload("3", "a.step")];
+        let result = pair_uploads(files_data, uploads).unwrap();
+        let uuids: Vec<&str> = result.iter().map(|(uuid, _)| uuid.as_str()).collect();
+        assert_eq!(uuids, vec!["2", "1", "3"]);
+        assert_eq!(result[2].1.upload_url, "https://storage/3");
+        assert_eq!(result[2].1.file_data, vec![3]);
+    }
+}
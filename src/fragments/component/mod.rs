@@ -1,15 +1,31 @@
+mod bom;
+mod bundle;
 mod catalog;
+mod compare;
+mod copy;
+mod derivation;
+mod duplicate;
 mod file;
 mod keyword;
 mod license;
 mod modification;
 mod param;
 mod spec;
+mod status;
 mod supplier;
 mod standard;
+mod thumbnail;
 mod update_favicon;
 
+pub use bom::AddToBom;
+pub use bundle::{ExportComponentBundle, ImportComponentBundle};
 pub use catalog::{CatalogComponents, ListItem};
+pub use compare::AddToCompare;
+pub(crate) use copy::{CopyRequests, CopyResponse, pair_uuids};
+pub use derivation::{ComponentAncestry, DerivedComponents, ForkComponent};
+pub(crate) use derivation::{find_uuid, parent_tag, request_parents};
+pub use duplicate::DuplicateComponent;
+pub(crate) use duplicate::{FilesOf, pair_uploads, request_upload_urls};
 pub use file::{ComponentFileItem, ComponentFilesBlock, ManageComponentFilesCard};
 pub use keyword::{KeywordsTags, KeywordTagItem, AddKeywordsTags};
 pub use license::{ComponentLicenseTag, ComponentLicensesTags};
@@ -18,8 +34,12 @@ pub use modification::{
     ModificationTableItem, ModificationTableHeads, ModificationFilesTableCard,
     ManageModificationFilesCard, ModificationFilesetsCard, FilesOfFilesetCard,
 };
-pub use param::{ComponentParamTag, ComponentParamsTags, RegisterParamnameBlock};
+pub(crate) use modification::request_all_modifications;
+pub use param::{ComponentParamTag, ComponentParamsTags, ParamTemplates, RegisterParamnameBlock};
 pub use spec::{SpecsTags, SpecTagItem, SearchSpecsTags};
+pub use status::StatusTimeline;
+pub(crate) use status::{status_options, status_note_field, send_status_change};
 pub use supplier::{ComponentSuppliersCard, ComponentSupplierItem};
 pub use standard::{ComponentStandardsCard, ComponentStandardItem};
+pub use thumbnail::GenerateThumbnailCard;
 pub use update_favicon::UpdateComponentFaviconCard;
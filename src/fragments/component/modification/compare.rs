@@ -0,0 +1,137 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use crate::fragments::three_compare::ThreeCompare;
+use crate::services::get_value_field;
+use crate::types::{UUID, ComponentModificationInfo, DownloadFile};
+use super::{ModificationModelSelect, SelectedModel};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub modifications: Vec<ComponentModificationInfo>,
+    pub select_modification_uuid: UUID,
+}
+
+/// Comparison of the models of two modifications
+pub struct CompareModificationModels {
+    props: Props,
+    link: ComponentLink<Self>,
+    initial_right_uuid: UUID,
+    left_modification_uuid: UUID,
+    right_modification_uuid: UUID,
+    left_model: Option<DownloadFile>,
+    right_model: Option<DownloadFile>,
+}
+
+pub enum Msg {
+    SelectLeftModel(SelectedModel),
+    SelectRightModel(SelectedModel),
+}
+
+impl Component for CompareModificationModels {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let left_modification_uuid = props.select_modification_uuid.clone();
+        let right_modification_uuid = next_modification_uuid(&props.modifications, &left_modification_uuid);
+        Self {
+            props,
+            link,
+            initial_right_uuid: right_modification_uuid.clone(),
+            left_modification_uuid,
+            right_modification_uuid,
+            left_model: None,
+            right_model: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SelectLeftModel((modification_uuid, model)) => {
+                debug!("Left model for compare: {:?}", model);
+                self.left_modification_uuid = modification_uuid;
+                self.left_model = model;
+            },
+            Msg::SelectRightModel((modification_uuid, model)) => {
+                debug!("Right model for compare: {:?}", model);
+                self.right_modification_uuid = modification_uuid;
+                self.right_model = model;
+            },
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.select_modification_uuid == props.select_modification_uuid &&
+            self.props.modifications.iter().map(|m| &m.uuid).eq(props.modifications.iter().map(|m| &m.uuid)) {
+            return false
+        }
+        if self.props.select_modification_uuid != props.select_modification_uuid {
+            self.left_modification_uuid = props.select_modification_uuid.clone();
+            self.initial_right_uuid = next_modification_uuid(&props.modifications, &self.left_modification_uuid);
+        }
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let callback_select_left = self.link.callback(|value: SelectedModel| Msg::SelectLeftModel(value));
+        let callback_select_right = self.link.callback(|value: SelectedModel| Msg::SelectRightModel(value));
+        html!{<>
+            <h1 class="title is-5">{get_value_field(&464)}</h1> // Compare models
+            <div class="columns">
+                <div class="column">
+                    <ModificationModelSelect
+                        modifications={self.props.modifications.clone()}
+                        select_modification_uuid={self.props.select_modification_uuid.clone()}
+                        callback_select_model={callback_select_left}
+                        />
+                </div>
+                <div class="column">
+                    <ModificationModelSelect
+                        modifications={self.props.modifications.clone()}
+                        select_modification_uuid={self.initial_right_uuid.clone()}
+                        callback_select_model={callback_select_right}
+                        />
+                </div>
+            </div>
+            {self.show_compare()}
+        </>}
+    }
+}
+
+impl CompareModificationModels {
+    fn show_compare(&self) -> Html {
+        match (&self.left_model, &self.right_model) {
+            (Some(left_file), Some(right_file)) => html!{
+                <ThreeCompare
+                    left_file={left_file.clone()}
+                    left_title={self.modification_title(&self.left_modification_uuid, left_file)}
+                    right_file={right_file.clone()}
+                    right_title={self.modification_title(&self.right_modification_uuid, right_file)}
+                    />
+            },
+            _ => html!{<p class="help">{get_value_field(&461)}</p>},
+        }
+    }
+
+    fn modification_title(&self, modification_uuid: &UUID, file: &DownloadFile) -> String {
+        let modification_name = self.props.modifications
+            .iter()
+            .find(|m| &m.uuid == modification_uuid)
+            .map(|m| m.modification_name.as_str())
+            .unwrap_or_default();
+        format!("{} ({})", modification_name, file.filename)
+    }
+}
+
+/// By default, the selected modification is compared with the next one
+fn next_modification_uuid(modifications: &[ComponentModificationInfo], select_uuid: &UUID) -> UUID {
+    modifications
+        .iter()
+        .skip_while(|m| &m.uuid != select_uuid)
+        .nth(1)
+        .or_else(|| modifications.iter().find(|m| &m.uuid != select_uuid))
+        .map(|m| m.uuid.clone())
+        .unwrap_or_default()
+}
@@ -0,0 +1,290 @@
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use log::debug;
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentModificationInfo, FilesetProgramInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    RegisterComponentModification, register_component_modification,
+    PutModificationParams, put_modification_params,
+    ComponentModificationFilesets, component_modification_filesets,
+    RegisterModificationFileset, register_modification_fileset,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub parent: ComponentModificationInfo,
+    /// Returns the UUID of the created modification
+    pub callback_finish_derive: Callback<UUID>,
+}
+
+/// Creates a modification derived from the parent one with its parameters and, if selected, its filesets
+pub struct ModificationDerive {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    hide_card: bool,
+    modification_name: String,
+    copy_params: bool,
+    copy_filesets: bool,
+    new_modification_uuid: UUID,
+    /// Number of requests for copying without a response
+    pending_requests: usize,
+}
+
+pub enum Msg {
+    RequestRegisterModification,
+    GetRegisterModificationResult(String),
+    GetPutParamsResult(String),
+    GetParentFilesetsResult(String),
+    GetRegisterFilesetResult(String),
+    ResponseError(Error),
+    ShowCard,
+    UpdateName(String),
+    SwitchCopyParams,
+    SwitchCopyFilesets,
+    ClearError,
+}
+
+impl Component for ModificationDerive {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            hide_card: true,
+            modification_name: String::new(),
+            copy_params: true,
+            copy_filesets: false,
+            new_modification_uuid: String::new(),
+            pending_requests: 0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestRegisterModification => {
+                let ipt_component_modification_data = register_component_modification::IptComponentModificationData{
+                    componentUuid: self.props.parent.component_uuid.clone(),
+                    parentModificationUuid: Some(self.props.parent.uuid.clone()),
+                    modificationName: self.modification_name.trim().to_string(),
+                    description: self.props.parent.description.clone(),
+                    actualStatusId: self.props.parent.actual_status.actual_status_id as i64,
+                };
+                self.pending_requests = 1;
+                spawn_local(async move {
+                    let res = make_query(RegisterComponentModification::build_query(
+                        register_component_modification::Variables { ipt_component_modification_data }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetRegisterModificationResult(res));
+                })
+            },
+            Msg::GetRegisterModificationResult(res) => {
+                match resp_parsing::<UUID>(res, "registerComponentModification") {
+                    Ok(result) => {
+                        debug!("Derived modification: {:?}", result);
+                        self.new_modification_uuid = result;
+                        self.copy_params();
+                        self.request_parent_filesets();
+                        self.finish_request();
+                    },
+                    Err(err) => {
+                        self.pending_requests = 0;
+                        link.send_message(Msg::ResponseError(err));
+                    },
+                }
+            },
+            Msg::GetPutParamsResult(res) => {
+                if let Err(err) = resp_parsing::<usize>(res, "putModificationParams") {
+                    link.send_message(Msg::ResponseError(err));
+                }
+                self.finish_request();
+            },
+            Msg::GetParentFilesetsResult(res) => {
+                match resp_parsing::<Vec<FilesetProgramInfo>>(res, "componentModificationFilesets") {
+                    Ok(result) => {
+                        for fileset in result {
+                            let ipt_fileset_program_data = register_modification_fileset::IptFilesetProgramData{
+                                modificationUuid: self.new_modification_uuid.clone(),
+                                programId: fileset.program.id as i64,
+                            };
+                            let link = link.clone();
+                            self.pending_requests += 1;
+                            spawn_local(async move {
+                                let res = make_query(RegisterModificationFileset::build_query(
+                                    register_modification_fileset::Variables { ipt_fileset_program_data }
+                                )).await.unwrap();
+                                link.send_message(Msg::GetRegisterFilesetResult(res));
+                            })
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+                self.finish_request();
+            },
+            Msg::GetRegisterFilesetResult(res) => {
+                if let Err(err) = resp_parsing::<UUID>(res, "registerModificationFileset") {
+                    link.send_message(Msg::ResponseError(err));
+                }
+                self.finish_request();
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ShowCard => {
+                self.hide_card = !self.hide_card;
+                if !self.hide_card {
+                    self.modification_name = format!("{} ({})", self.props.parent.modification_name, get_value_field(&322));
+                }
+            },
+            Msg::UpdateName(value) => self.modification_name = value,
+            Msg::SwitchCopyParams => self.copy_params = !self.copy_params,
+            Msg::SwitchCopyFilesets => self.copy_filesets = !self.copy_filesets,
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.parent.uuid == props.parent.uuid {
+            self.props = props;
+            return false
+        }
+        self.props = props;
+        self.hide_card = true;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_show_card = self.link.callback(|_| Msg::ShowCard);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            <button
+                id="derive-modification-btn"
+                class="button"
+                title={get_value_field(&537)}
+                onclick={onclick_show_card} >
+                <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+            </button>
+            {match self.hide_card {
+                true => html!{},
+                false => self.show_derive_modal(),
+            }}
+        </>}
+    }
+}
+
+impl ModificationDerive {
+    fn copy_params(&mut self) {
+        if !self.copy_params || self.props.parent.modification_params.is_empty() {
+            return
+        }
+        let ipt_modification_param_data = put_modification_params::IptModificationParamData{
+            modificationUuid: self.new_modification_uuid.clone(),
+            params: self.props.parent.modification_params.iter().map(|p| put_modification_params::IptParamData{
+                paramId: p.param.param_id as i64,
+                value: p.value.clone(),
+            }).collect(),
+        };
+        let link = self.link.clone();
+        self.pending_requests += 1;
+        spawn_local(async move {
+            let res = make_query(PutModificationParams::build_query(
+                put_modification_params::Variables { ipt_modification_param_data }
+            )).await.unwrap();
+            link.send_message(Msg::GetPutParamsResult(res));
+        })
+    }
+
+    fn request_parent_filesets(&mut self) {
+        if !self.copy_filesets {
+            return
+        }
+        let ipt_fileset_program_arg = component_modification_filesets::IptFilesetProgramArg{
+            modificationUuid: self.props.parent.uuid.clone(),
+            programIds: None,
+        };
+        let link = self.link.clone();
+        self.pending_requests += 1;
+        spawn_local(async move {
+            let res = make_query(ComponentModificationFilesets::build_query(
+                component_modification_filesets::Variables { ipt_fileset_program_arg }
+            )).await.unwrap();
+            link.send_message(Msg::GetParentFilesetsResult(res));
+        })
+    }
+
+    /// The derived modification is shown after all requests for copying are completed
+    fn finish_request(&mut self) {
+        self.pending_requests = self.pending_requests.saturating_sub(1);
+        if self.pending_requests == 0 && !self.new_modification_uuid.is_empty() {
+            self.hide_card = true;
+            self.props.callback_finish_derive.emit(std::mem::take(&mut self.new_modification_uuid));
+        }
+    }
+
+    fn show_derive_modal(&self) -> Html {
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowCard);
+        let oninput_name = self.link.callback(|ev: InputData| Msg::UpdateName(ev.value));
+        let onclick_copy_params = self.link.callback(|_| Msg::SwitchCopyParams);
+        let onclick_copy_filesets = self.link.callback(|_| Msg::SwitchCopyFilesets);
+        let onclick_derive = self.link.callback(|_| Msg::RequestRegisterModification);
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&537)}</p> // Create derived modification
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <p class="subtitle is-6">
+                            {format!("{}: {}", get_value_field(&538), self.props.parent.modification_name)} // Parent modification
+                        </p>
+                        <div class="field">
+                            <label class="label">{get_value_field(&176)}</label> // Modification name
+                            <input
+                                id="derive-modification-name"
+                                class="input"
+                                type="text"
+                                value={self.modification_name.clone()}
+                                oninput={oninput_name} />
+                        </div>
+                        <div class="field">
+                            <label class="checkbox">
+                                <input id="derive-copy-params" type="checkbox" checked={self.copy_params} onclick={onclick_copy_params} />
+                                {format!(" {} ({})", get_value_field(&539), self.props.parent.modification_params.len())} // Copy parameters
+                            </label>
+                        </div>
+                        <div class="field">
+                            <label class="checkbox">
+                                <input id="derive-copy-filesets" type="checkbox" checked={self.copy_filesets} onclick={onclick_copy_filesets} />
+                                {format!(" {}", get_value_field(&540))} // Copy filesets
+                            </label>
+                            <p class="help">{get_value_field(&541)}</p>
+                        </div>
+                        <button
+                            id="derive-modification-create"
+                            class={match self.pending_requests {
+                                0 => "button is-success is-fullwidth",
+                                _ => "button is-success is-fullwidth is-loading",
+                            }}
+                            disabled={self.modification_name.trim().is_empty() || self.pending_requests > 0}
+                            onclick={onclick_derive} >
+                            <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&537)}</span>
+                        </button>
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+}
@@ -0,0 +1,263 @@
+mod rows;
+
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+
+use rows::{file_diff, param_diff, DiffState, FileEntry};
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentModificationInfo, FilesetProgramInfo, ShowFileInfo};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    ComponentModificationFilesets, component_modification_filesets,
+    ComModFilesOfFileset, com_mod_files_of_fileset,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub modifications: Vec<ComponentModificationInfo>,
+}
+
+/// Parameters and files of the filesets of the modifications side by side with the differences highlighted
+pub struct ModificationsDiff {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    hide_identical: bool,
+    /// Files of the filesets by the modifications in the order of the props
+    files: Vec<Vec<FileEntry>>,
+    /// Number of requests for the filesets and files without a response
+    pending_requests: usize,
+}
+
+pub enum Msg {
+    RequestFilesets,
+    GetFilesetsResult(usize, String),
+    GetFilesResult(usize, String, String),
+    SwitchHideIdentical,
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for ModificationsDiff {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            hide_identical: false,
+            files: Vec::new(),
+            pending_requests: 0,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestFilesets);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestFilesets => {
+                self.pending_requests = 0;
+                self.files = vec![Vec::new(); self.props.modifications.len()];
+                for (index, modification) in self.props.modifications.iter().enumerate() {
+                    let ipt_fileset_program_arg = component_modification_filesets::IptFilesetProgramArg{
+                        modificationUuid: modification.uuid.clone(),
+                        programIds: None,
+                    };
+                    let link = link.clone();
+                    self.pending_requests += 1;
+                    spawn_local(async move {
+                        let res = make_query(ComponentModificationFilesets::build_query(
+                            component_modification_filesets::Variables { ipt_fileset_program_arg }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetFilesetsResult(index, res));
+                    })
+                }
+            },
+            Msg::GetFilesetsResult(index, res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing::<Vec<FilesetProgramInfo>>(res, "componentModificationFilesets") {
+                    Ok(result) => {
+                        for fileset in result.into_iter().filter(|f| f.files_count > 0) {
+                            let ipt_file_of_fileset_arg = com_mod_files_of_fileset::IptFileOfFilesetArg{
+                                filesetUuid: fileset.uuid,
+                                fileUuids: None,
+                            };
+                            let program = fileset.program.name;
+                            let link = link.clone();
+                            self.pending_requests += 1;
+                            spawn_local(async move {
+                                let res = make_query(ComModFilesOfFileset::build_query(com_mod_files_of_fileset::Variables {
+                                    ipt_file_of_fileset_arg,
+                                    ipt_sort: None,
+                                    ipt_paginate: None,
+                                })).await.unwrap();
+                                link.send_message(Msg::GetFilesResult(index, program, res));
+                            })
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetFilesResult(index, program, res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing::<Vec<ShowFileInfo>>(res, "componentModificationFilesOfFileset") {
+                    Ok(result) => {
+                        debug!("Files of the fileset {} for the comparison: {}", program, result.len());
+                        if let Some(files) = self.files.get_mut(index) {
+                            files.extend(result.iter().map(|file| FileEntry::new(&program, file)));
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::SwitchHideIdentical => self.hide_identical = !self.hide_identical,
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let same_modifications = self.props.modifications.len() == props.modifications.len()
+            && self.props.modifications.iter().zip(&props.modifications).all(|(old, new)| old.uuid == new.uuid);
+        self.props = props;
+        if !same_modifications {
+            self.link.send_message(Msg::RequestFilesets);
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_identical = self.link.callback(|_| Msg::SwitchHideIdentical);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            <div class="is-flex is-justify-content-space-between is-align-items-center mb-3">
+                <p class="title is-5 mb-0">{get_value_field(&542)}</p> // Compare parameters
+                <label class="checkbox">
+                    <input
+                        id="diff-hide-identical"
+                        type="checkbox"
+                        checked={self.hide_identical}
+                        onclick={onclick_hide_identical} />
+                    {format!(" {}", get_value_field(&543))} // Hide identical
+                </label>
+            </div>
+            {self.show_params_diff()}
+            <p class="title is-6 mt-4">
+                {get_value_field(&545)} // Fileset files
+                {match self.pending_requests {
+                    0 => html!{},
+                    _ => html!{<span class="icon"><i class="fas fa-spinner fa-pulse"></i></span>},
+                }}
+            </p>
+            {self.show_files_diff()}
+        </>}
+    }
+}
+
+impl ModificationsDiff {
+    fn is_shown(&self, state: DiffState) -> bool {
+        !(self.hide_identical && state == DiffState::Same)
+    }
+
+    fn show_heads(&self, titles: &[&str]) -> Html {
+        html!{
+            <thead>
+                <tr>
+                    {for titles.iter().map(|title| html!{<th>{title}</th>})}
+                    {for self.props.modifications.iter().map(|m| html!{<th>{m.modification_name.clone()}</th>})}
+                </tr>
+            </thead>
+        }
+    }
+
+    fn show_params_diff(&self) -> Html {
+        let rows = param_diff(&self.props.modifications);
+        let shown = rows.iter().filter(|row| self.is_shown(row.state)).collect::<Vec<_>>();
+        if shown.is_empty() {
+            return html!{<p class="help">{get_value_field(&546)}</p>} // No differences
+        }
+        html!{
+            <div class="table-container">
+                <table class="table is-fullwidth is-narrow is-bordered">
+                    {self.show_heads(&[get_value_field(&178)])} // Parameter
+                    <tbody>
+                        {for shown.into_iter().map(|row| html!{
+                            <tr>
+                                <th>{row.param.paramname.clone()}</th>
+                                {for row.values.iter().map(|value| match value {
+                                    Some(value) => html!{<td class={diff_class(row.state)}>{value.clone()}</td>},
+                                    None => show_missing_cell(),
+                                })}
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+
+    fn show_files_diff(&self) -> Html {
+        let rows = file_diff(&self.files);
+        let shown = rows.iter().filter(|row| self.is_shown(row.state)).collect::<Vec<_>>();
+        if shown.is_empty() {
+            return match self.pending_requests {
+                0 => html!{<p class="help">{get_value_field(&546)}</p>}, // No differences
+                _ => html!{},
+            }
+        }
+        html!{
+            <div class="table-container">
+                <table class="table is-fullwidth is-narrow is-bordered">
+                    {self.show_heads(&[get_value_field(&26), get_value_field(&120)])} // Program, Filename
+                    <tbody>
+                        {for shown.into_iter().map(|row| html!{
+                            <tr>
+                                <td>{row.program.clone()}</td>
+                                <th>{row.filename.clone()}</th>
+                                {for row.versions.iter().map(|version| match version {
+                                    Some(version) => html!{
+                                        <td class={diff_class(row.state)} title={version.sha256_hash.clone()}>
+                                            <span class="tag is-light mr-1" title={get_value_field(&308)}>
+                                                {format!("r{}", version.revision)}
+                                            </span>
+                                            <code>{version.sha256_hash.chars().take(12).collect::<String>()}</code>
+                                        </td>
+                                    },
+                                    None => show_missing_cell(),
+                                })}
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+}
+
+fn diff_class(state: DiffState) -> &'static str {
+    match state {
+        DiffState::Same => "",
+        DiffState::Different | DiffState::Partial => "has-background-warning-light",
+    }
+}
+
+fn show_missing_cell() -> Html {
+    html!{
+        <td class="has-background-danger-light has-text-centered" title={get_value_field(&544)}>
+            {"\u{2014}"} // Em dash
+        </td>
+    }
+}
@@ -0,0 +1,162 @@
+use crate::types::{ComponentModificationInfo, Param, ShowFileInfo};
+
+/// Result of comparing the values of one row across the modifications
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum DiffState {
+    Same,
+    Different,
+    /// The value is missing in some of the modifications
+    Partial,
+}
+
+impl DiffState {
+    fn of<T: PartialEq>(values: &[Option<T>]) -> Self {
+        if values.iter().any(|v| v.is_none()) {
+            return Self::Partial
+        }
+        match values.windows(2).all(|pair| pair[0] == pair[1]) {
+            true => Self::Same,
+            false => Self::Different,
+        }
+    }
+}
+
+/// Values of a parameter in the compared modifications, None if the modification has no such parameter
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ParamDiffRow {
+    pub(crate) param: Param,
+    pub(crate) values: Vec<Option<String>>,
+    pub(crate) state: DiffState,
+}
+
+/// Compares the parameters of the modifications in the order of their first appearance
+pub(crate) fn param_diff(modifications: &[ComponentModificationInfo]) -> Vec<ParamDiffRow> {
+    let mut params: Vec<Param> = Vec::new();
+    for modification in modifications {
+        for modification_param in &modification.modification_params {
+            if !params.iter().any(|p| p.param_id == modification_param.param.param_id) {
+                params.push(modification_param.param.clone());
+            }
+        }
+    }
+    params.into_iter().map(|param| {
+        let values = modifications.iter().map(|modification| {
+            modification.modification_params.iter()
+                .find(|p| p.param.param_id == param.param_id)
+                .map(|p| p.value.trim().to_string())
+        }).collect::<Vec<Option<String>>>();
+        let state = DiffState::of(&values);
+        ParamDiffRow { param, values, state }
+    }).collect()
+}
+
+/// Revision and the SHA-256 hash of a file in a fileset
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FileVersion {
+    pub(crate) revision: usize,
+    pub(crate) sha256_hash: String,
+}
+
+/// File of the fileset of a modification
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FileEntry {
+    pub(crate) program: String,
+    pub(crate) filename: String,
+    pub(crate) version: FileVersion,
+}
+
+impl FileEntry {
+    pub(crate) fn new(program: &str, file: &ShowFileInfo) -> Self {
+        Self {
+            program: program.to_string(),
+            filename: file.filename.clone(),
+            version: FileVersion { revision: file.revision, sha256_hash: file.sha256_hash.clone() },
+        }
+    }
+}
+
+/// Versions of a file in the filesets of the same program of the compared modifications
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FileDiffRow {
+    pub(crate) program: String,
+    pub(crate) filename: String,
+    pub(crate) versions: Vec<Option<FileVersion>>,
+    pub(crate) state: DiffState,
+}
+
+/// Compares the files of the filesets by the program and the name of the file,
+/// the files are compared by the hash, the revision is shown for reference
+pub(crate) fn file_diff(files: &[Vec<FileEntry>]) -> Vec<FileDiffRow> {
+    let mut keys: Vec<(&str, &str)> = Vec::new();
+    for modification_files in files {
+        for file in modification_files {
+            let key = (file.program.as_str(), file.filename.as_str());
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys.sort_unstable();
+    keys.into_iter().map(|(program, filename)| {
+        let versions = files.iter().map(|modification_files| {
+            modification_files.iter()
+                .find(|file| file.program == program && file.filename == filename)
+                .map(|file| file.version.clone())
+        }).collect::<Vec<Option<FileVersion>>>();
+        let hashes = versions.iter()
+            .map(|version| version.as_ref().map(|v| v.sha256_hash.as_str()))
+            .collect::<Vec<Option<&str>>>();
+        FileDiffRow {
+            program: program.to_string(),
+            filename: filename.to_string(),
+            state: DiffState::of(&hashes),
+            versions,
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn file(program: &str, filename: &str, revision: usize, hash: &str) -> FileEntry {
+        FileEntry {
+            program: program.to_string(),
+            filename: filename.to_string(),
+            version: FileVersion { revision, sha256_hash: hash.to_string() },
+        }
+    }
+
+    #[test]
+    fn diff_params() {
+        let rows = param_diff(&[
+            ComponentModificationInfo::default().with_params(&[(1, "P1", "10"), (2, "P2", "steel")]),
+            ComponentModificationInfo::default().with_params(&[(2, "P2", "steel "), (1, "P1", "12"), (3, "P3", "x")]),
+        ]);
+
+        assert_eq!(vec![1, 2, 3], rows.iter().map(|r| r.param.param_id).collect::<Vec<usize>>());
+        assert_eq!(
+            vec![DiffState::Different, DiffState::Same, DiffState::Partial],
+            rows.iter().map(|r| r.state).collect::<Vec<DiffState>>()
+        );
+        assert_eq!(vec![None, Some(String::from("x"))], rows[2].values);
+    }
+
+    #[test]
+    fn diff_files() {
+        let rows = file_diff(&[
+            vec![file("CAD", "a.step", 1, "h1"), file("CAD", "b.pdf", 2, "h2")],
+            vec![file("CAD", "a.step", 3, "h1"), file("CAD", "b.pdf", 2, "h3"), file("CAM", "a.nc", 1, "h4")],
+        ]);
+
+        assert_eq!(
+            vec![("CAD", "a.step"), ("CAD", "b.pdf"), ("CAM", "a.nc")],
+            rows.iter().map(|r| (r.program.as_str(), r.filename.as_str())).collect::<Vec<(&str, &str)>>()
+        );
+        assert_eq!(
+            vec![DiffState::Same, DiffState::Different, DiffState::Partial],
+            rows.iter().map(|r| r.state).collect::<Vec<DiffState>>()
+        );
+        assert_eq!(Some(FileVersion { revision: 3, sha256_hash: String::from("h1") }), rows[0].versions[1]);
+    }
+}
@@ -9,7 +9,8 @@ use crate::error::Error;
 use crate::fragments::buttons::{ft_delete_btn, ft_save_btn};
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::markdown_edit::MarkdownEditCard;
-use crate::services::{get_value_field, resp_parsing};
+use crate::fragments::component::{status_options, status_note_field, send_status_change};
+use crate::services::{check_status_note, get_value_field, is_allowed_transition, resp_parsing, StatusChange};
 use crate::types::{UUID, ComponentModificationInfo, ActualStatus, ModificationUpdatePreData};
 use crate::gqls::make_query;
 use crate::gqls::component::{
@@ -35,6 +36,8 @@ pub struct ModificationEdit {
     props: Props,
     link: ComponentLink<Self>,
     request_edit_modification: ModificationUpdatePreData,
+    /// Note required for the change of the status
+    status_note: String,
     modification_changed: bool,
     get_confirm: UUID,
     active_tab: ActiveTab,
@@ -49,6 +52,8 @@ pub enum Msg {
     UpdateEditName(String),
     UpdateEditDescription(String),
     UpdateEditActualStatusId(String),
+    UpdateStatusNote(String),
+    GetStatusChangeResult(String),
     ResetSelectModification,
     ChangeActiveTab(ActiveTab),
     ClearError,
@@ -69,6 +74,7 @@ impl Component for ModificationEdit {
             props,
             link,
             request_edit_modification,
+            status_note: String::new(),
             modification_changed: false,
             get_confirm: String::new(),
             active_tab: ActiveTab::Data,
@@ -79,6 +85,9 @@ impl Component for ModificationEdit {
         let link = self.link.clone();
         match msg {
             Msg::RequestUpdateModificationData => {
+                if self.status_change().is_some_and(|change| check_status_note(&change.note).is_err()) {
+                    return false
+                }
                 self.modification_changed = false;
                 let modification_uuid = self.props.modification.uuid.clone();
                 let ipt_update_component_modification_data = put_component_modification_update::IptUpdateComponentModificationData{
@@ -122,7 +131,17 @@ impl Component for ModificationEdit {
             },
             Msg::GetUpdateModificationResult(res) => {
                 match resp_parsing::<usize>(res, "putComponentModificationUpdate") {
-                    Ok(result) => debug!("putComponentModificationUpdate: {:?}", result),
+                    Ok(result) => {
+                        debug!("putComponentModificationUpdate: {:?}", result);
+                        if let Some(change) = self.status_change() {
+                            // record the transition in the discussion and take the new status as the current one
+                            send_status_change(&change, link.callback(Msg::GetStatusChangeResult));
+                            if let Some(status) = self.props.actual_statuses.iter().find(|s| s.actual_status_id == change.to_status_id) {
+                                self.props.modification.actual_status = status.clone();
+                            }
+                            self.status_note.clear();
+                        }
+                    },
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
                 // clear the check flags
@@ -148,9 +167,24 @@ impl Component for ModificationEdit {
                 self.modification_changed = true;
             },
             Msg::UpdateEditActualStatusId(data) => {
-                self.request_edit_modification.actual_status_id = data.parse::<usize>().unwrap_or_default();
+                let actual_status_id = data.parse::<usize>().unwrap_or_default();
+                let current_status_id = self.props.modification.actual_status.actual_status_id;
+                if !is_allowed_transition(&self.props.actual_statuses, current_status_id, actual_status_id) {
+                    return false
+                }
+                self.request_edit_modification.actual_status_id = actual_status_id;
+                self.modification_changed = true;
+            },
+            Msg::UpdateStatusNote(data) => {
+                self.status_note = data;
                 self.modification_changed = true;
             },
+            Msg::GetStatusChangeResult(res) => {
+                match resp_parsing::<UUID>(res, "registerDiscussionComment") {
+                    Ok(result) => debug!("Status change comment: {:?}", result),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
             Msg::ResetSelectModification => {
                 // clear the check flags
                 self.get_confirm.clear();
@@ -159,6 +193,7 @@ impl Component for ModificationEdit {
                 self.request_edit_modification.modification_name = self.props.modification.modification_name.clone();
                 self.request_edit_modification.description = self.props.modification.description.clone();
                 self.request_edit_modification.actual_status_id = self.props.modification.actual_status.actual_status_id;
+                self.status_note.clear();
             },
             Msg::ChangeActiveTab(set_tab) => self.active_tab = set_tab,
             Msg::ClearError => self.error = None,
@@ -268,6 +303,7 @@ impl ModificationEdit {
                             onclick_component_modification_update,
                             true,
                             !self.modification_changed
+                                || self.status_change().is_some_and(|change| check_status_note(&change.note).is_err())
                         )}
                     </div>
                 </div>
@@ -275,12 +311,32 @@ impl ModificationEdit {
         }
     }
 
+    /// Change of the status made in the form, None if the status is not changed
+    fn status_change(&self) -> Option<StatusChange> {
+        let from_status = &self.props.modification.actual_status;
+        let to_status_id = self.request_edit_modification.actual_status_id;
+        if from_status.actual_status_id == to_status_id {
+            return None
+        }
+        let to_status = self.props.actual_statuses.iter().find(|s| s.actual_status_id == to_status_id)?;
+        Some(StatusChange {
+            component_uuid: self.props.modification.component_uuid.clone(),
+            modification_uuid: self.props.modification.uuid.clone(),
+            from_status_id: from_status.actual_status_id,
+            to_status_id,
+            from_name: from_status.name.clone(),
+            to_name: to_status.name.clone(),
+            note: self.status_note.trim().to_string(),
+        })
+    }
+
     fn actual_status_block(&self) -> Html {
         let onchange_modification_actual_status_id =
             self.link.callback(|ev: ChangeData| Msg::UpdateEditActualStatusId(match ev {
               ChangeData::Select(el) => el.value(),
               _ => "1".to_string(),
           }));
+        let oninput_status_note = self.link.callback(|ev: InputData| Msg::UpdateStatusNote(ev.value));
         html!{
             <div class={"column"}>
             <div class={"columns"}>
@@ -294,18 +350,19 @@ impl ModificationEdit {
                         select={self.props.modification.actual_status.actual_status_id.to_string()}
                         onchange={onchange_modification_actual_status_id}
                         >
-                        {for self.props.actual_statuses.iter().map(|x|
-                            html!{
-                                <option value={x.actual_status_id.to_string()}
-                                    selected={x.actual_status_id == self.request_edit_modification.actual_status_id} >
-                                    {&x.name}
-                                </option>
-                            }
+                        {status_options(
+                            &self.props.actual_statuses,
+                            self.props.modification.actual_status.actual_status_id,
+                            self.request_edit_modification.actual_status_id,
                         )}
                     </select>
                     </div>
                 </div>
             </div>
+            {match self.status_change().is_some() {
+                true => status_note_field("update-modification-status-note", &self.status_note, oninput_status_note),
+                false => html!{},
+            }}
             </div>
         }
     }
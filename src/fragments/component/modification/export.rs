@@ -0,0 +1,303 @@
+use graphql_client::GraphQLQuery;
+use serde_json::json;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::types::{ComponentModificationInfo, Param, UUID};
+use crate::services::{
+    get_value_field, resp_parsing, copy_to_clipboard, write_delimited, write_xlsx, save_as_file, revoke_object_url,
+    ParsingSplit, Sheet,
+};
+use crate::gqls::make_query;
+use crate::gqls::component::{GetComponentModifications, get_component_modifications};
+
+/// Number of modifications requested per page for the export
+const EXPORT_PAGE_SIZE: i64 = 100;
+
+/// Format of the file with the modifications table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Tsv,
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl ExportFormat {
+    const ALL: [ExportFormat; 4] = [Self::Xlsx, Self::Csv, Self::Tsv, Self::Json];
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Tsv => "tsv",
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Tsv => "text/tab-separated-values",
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+}
+
+/// Loads all modifications of the component and saves them with parameters to a file or the clipboard
+pub struct ModificationsExport {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    modifications: Vec<ComponentModificationInfo>,
+    current_page: i64,
+    loading: bool,
+    copied: bool,
+    file_url: Option<String>,
+}
+
+pub enum Msg {
+    RequestModifications,
+    GetModificationsResult(String),
+    SaveFile(ExportFormat),
+    CopyTsv,
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for ModificationsExport {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            modifications: Vec::new(),
+            current_page: 1,
+            loading: true,
+            copied: false,
+            file_url: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestModifications);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestModifications => {
+                let component_uuid = self.props.component_uuid.clone();
+                let current_page = self.current_page;
+                spawn_local(async move {
+                    let res = make_query(GetComponentModifications::build_query(
+                        get_component_modifications::Variables {
+                            component_uuid,
+                            filter: None,
+                            ipt_sort: Some(get_component_modifications::IptSort {byField: "name".to_string(), asDesc: false}),
+                            ipt_paginate: Some(get_component_modifications::IptPaginate {currentPage: current_page, perPage: EXPORT_PAGE_SIZE}),
+                        }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetModificationsResult(res));
+                })
+            },
+            Msg::GetModificationsResult(res) => {
+                match resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications") {
+                    Ok(result) => {
+                        let full_page = result.len() as i64 == EXPORT_PAGE_SIZE;
+                        self.modifications.extend(result);
+                        debug!("Loaded modifications for export: {}", self.modifications.len());
+                        match full_page {
+                            true => {
+                                // the next page may contain more modifications
+                                self.current_page += 1;
+                                link.send_message(Msg::RequestModifications);
+                            },
+                            false => self.loading = false,
+                        }
+                    },
+                    Err(err) => {
+                        self.loading = false;
+                        link.send_message(Msg::ResponseError(err));
+                    },
+                }
+            },
+            Msg::SaveFile(format) => {
+                let data = match format {
+                    ExportFormat::Tsv => write_delimited(&self.export_rows(), ParsingSplit::Tab).into_bytes(),
+                    ExportFormat::Csv => write_delimited(&self.export_rows(), ParsingSplit::Comma).into_bytes(),
+                    ExportFormat::Json => self.export_json().into_bytes(),
+                    ExportFormat::Xlsx => {
+                        let sheet = Sheet {
+                            name: get_value_field(&100).to_string(), // Modifications
+                            rows: self.export_rows(),
+                        };
+                        match write_xlsx(&sheet) {
+                            Ok(data) => data,
+                            Err(err) => {
+                                self.error = Some(Error::BadRequest(err));
+                                return true
+                            },
+                        }
+                    },
+                };
+                let filename = format!(
+                    "modifications-{}.{}",
+                    self.props.component_uuid.chars().take(8).collect::<String>(),
+                    format.extension()
+                );
+                if let Some(url) = self.file_url.take() {
+                    revoke_object_url(&url);
+                }
+                self.file_url = save_as_file(&data, format.mime_type(), &filename);
+                return false
+            },
+            Msg::CopyTsv => {
+                // the table is written only for the copy, not on every render
+                copy_to_clipboard(&write_delimited(&self.export_rows(), ParsingSplit::Tab));
+                self.copied = true;
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            return false
+        }
+        self.props = props;
+        self.modifications.clear();
+        self.current_page = 1;
+        self.loading = true;
+        self.copied = false;
+        self.link.send_message(Msg::RequestModifications);
+        true
+    }
+
+    fn destroy(&mut self) {
+        if let Some(url) = self.file_url.take() {
+            revoke_object_url(&url);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_copy = self.link.callback(|_| Msg::CopyTsv);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            <p class="title is-5">{get_value_field(&512)}</p> // Export modifications
+            <p class="help">
+                {format!("{}: {}", get_value_field(&514), self.modifications.len())} // Modifications loaded
+                {match self.loading {
+                    true => html!{<span class="icon"><i class="fas fa-spinner fa-pulse"></i></span>},
+                    false => html!{},
+                }}
+            </p>
+            <div class="buttons mt-3">
+                {for ExportFormat::ALL.iter().map(|format| {
+                    let format = *format;
+                    let onclick_save = self.link.callback(move |_| Msg::SaveFile(format));
+                    html!{
+                        <button
+                            id={format!("export-modifications-{}", format.extension())}
+                            class="button"
+                            disabled={self.loading}
+                            onclick={onclick_save} >
+                            <span class="icon"><i class="fas fa-file-download" aria-hidden="true"></i></span>
+                            <span>{format.extension().to_uppercase()}</span>
+                        </button>
+                    }
+                })}
+                <button
+                    id="export-modifications-copy"
+                    class="button is-info"
+                    disabled={self.loading}
+                    onclick={onclick_copy} >
+                    <span class="icon"><i class={match self.copied {
+                        true => "fas fa-check",
+                        false => "far fa-copy",
+                    }} aria-hidden="true"></i></span>
+                    <span>{match self.copied {
+                        true => get_value_field(&323), // Copied
+                        false => get_value_field(&513), // Copy as TSV
+                    }}</span>
+                </button>
+            </div>
+        </>}
+    }
+}
+
+impl ModificationsExport {
+    /// Parameters of all modifications in the order of their first appearance
+    fn export_params(&self) -> Vec<Param> {
+        let mut params: Vec<Param> = Vec::new();
+        for modification in &self.modifications {
+            for modification_param in &modification.modification_params {
+                if !params.iter().any(|p| p.param_id == modification_param.param.param_id) {
+                    params.push(modification_param.param.clone());
+                }
+            }
+        }
+        params
+    }
+
+    /// Rows of the table with the headers in the format of the modifications import
+    fn export_rows(&self) -> Vec<Vec<String>> {
+        let params = self.export_params();
+        let mut headers = vec![
+            String::from("[ModificationName]"),
+            String::from("[ModificationDescription]"),
+            String::from("[ModificationActualStatusId]"),
+        ];
+        headers.extend(params.iter().map(|p| p.paramname.clone()));
+        let mut rows = vec![headers];
+        for modification in &self.modifications {
+            let mut row = vec![
+                modification.modification_name.clone(),
+                modification.description.clone(),
+                modification.actual_status.actual_status_id.to_string(),
+            ];
+            row.extend(params.iter().map(|param| {
+                modification.modification_params.iter()
+                    .find(|p| p.param.param_id == param.param_id)
+                    .map(|p| p.value.clone())
+                    .unwrap_or_default()
+            }));
+            rows.push(row);
+        }
+        rows
+    }
+
+    fn export_json(&self) -> String {
+        let modifications = self.modifications.iter().map(|modification| json!({
+            "uuid": modification.uuid,
+            "name": modification.modification_name,
+            "description": modification.description,
+            "actual_status": {
+                "id": modification.actual_status.actual_status_id,
+                "name": modification.actual_status.name,
+            },
+            "params": modification.modification_params.iter().map(|p| json!({
+                "param_id": p.param.param_id,
+                "paramname": p.param.paramname,
+                "value": p.value,
+            })).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>();
+        serde_json::to_string_pretty(&modifications).unwrap_or_default()
+    }
+}
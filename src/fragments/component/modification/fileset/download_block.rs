@@ -9,6 +9,8 @@ pub struct Props {
     pub current_filesets_program: Vec<FilesetProgramInfo>,
     pub callback_select_fileset_uuid: Callback<UUID>,
     pub callback_open_fileset: Callback<bool>,
+    #[prop_or_default]
+    pub initial_fileset_uuid: Option<UUID>,
 }
 
 pub struct ManageFilesOfFilesetBlock {
@@ -42,7 +44,9 @@ impl Component for ManageFilesOfFilesetBlock {
         match msg {
             Msg::ParseFirstFilesetUuid => {
                 self.select_fileset_uuid = self.props.current_filesets_program
-                    .first()
+                    .iter()
+                    .find(|fd| Some(&fd.uuid) == self.props.initial_fileset_uuid.as_ref())
+                    .or_else(|| self.props.current_filesets_program.first())
                     .map(|fd| {
                         debug!("mod fileset_uuid: {:?}", fd.uuid);
                         debug!("mod program_name: {:?}", fd.program.name);
@@ -71,7 +75,16 @@ impl Component for ManageFilesOfFilesetBlock {
         if self.props.select_modification_uuid == props.select_modification_uuid &&
             self.props.current_filesets_program.len() == props.current_filesets_program.len() {
             debug!("no change download block: {:?}", props.select_modification_uuid);
-            false
+            // select other fileset of the modification (e.g. from an annotation)
+            let select_fileset = props.initial_fileset_uuid.as_ref().is_some_and(|uuid| {
+                uuid != &self.select_fileset_uuid &&
+                props.current_filesets_program.iter().any(|fd| &fd.uuid == uuid)
+            });
+            self.props = props;
+            if select_fileset {
+                self.link.send_message(Msg::ParseFirstFilesetUuid);
+            }
+            select_fileset
         } else {
             debug!("change download block: {:?}", props.select_modification_uuid);
             self.props = props;
@@ -2,11 +2,13 @@ mod file;
 mod edit;
 mod download_block;
 mod show;
+mod model_select;
 
 pub use file::FilesetFilesBlock;
 pub use edit::ManageModificationFilesets;
 pub use download_block::ManageFilesOfFilesetBlock;
 pub use show::ModificationFilesetsCard;
+pub use model_select::{ModificationModelSelect, SelectedModel};
 
 use yew::{Component, ComponentLink, Html, Properties, ShouldRender, html};
 use log::debug;
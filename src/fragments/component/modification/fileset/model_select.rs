@@ -0,0 +1,255 @@
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing, ModelFormat};
+use crate::types::{UUID, ComponentModificationInfo, DownloadFile, FilesetProgramInfo, PaginateSet};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    ComponentModificationFilesets, component_modification_filesets,
+    ComModFilesetFiles, com_mod_fileset_files,
+};
+
+/// Selected modification and its model file (if the fileset contains a suitable model)
+pub type SelectedModel = (UUID, Option<DownloadFile>);
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub modifications: Vec<ComponentModificationInfo>,
+    pub select_modification_uuid: UUID,
+    pub callback_select_model: Callback<SelectedModel>,
+}
+
+/// Selects a modification, its fileset and a model file stored in a single file (STL or GLB)
+pub struct ModificationModelSelect {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    select_modification_uuid: UUID,
+    filesets: Vec<FilesetProgramInfo>,
+    select_fileset_uuid: UUID,
+    suitable_files: Vec<(DownloadFile, ModelFormat)>,
+    select_file_index: usize,
+}
+
+pub enum Msg {
+    RequestFilesets,
+    RequestFilesetFiles,
+    GetFilesetsResult(String),
+    GetFilesetFilesResult(String),
+    SelectModification(UUID),
+    SelectFileset(UUID),
+    SelectFile(usize),
+    CallbackSelectModel,
+    ResponseError(Error),
+    ClearError,
+    Ignore,
+}
+
+impl Component for ModificationModelSelect {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let select_modification_uuid = props.select_modification_uuid.clone();
+        Self {
+            error: None,
+            props,
+            link,
+            select_modification_uuid,
+            filesets: Vec::new(),
+            select_fileset_uuid: String::new(),
+            suitable_files: Vec::new(),
+            select_file_index: 0,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestFilesets);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+
+        match msg {
+            Msg::RequestFilesets => {
+                self.filesets.clear();
+                self.suitable_files.clear();
+                self.select_fileset_uuid.clear();
+                link.send_message(Msg::CallbackSelectModel);
+                if self.select_modification_uuid.len() == 36 {
+                    let ipt_fileset_program_arg = component_modification_filesets::IptFilesetProgramArg{
+                        modificationUuid: self.select_modification_uuid.clone(),
+                        programIds: None,
+                    };
+                    spawn_local(async move {
+                        let res = make_query(ComponentModificationFilesets::build_query(
+                            component_modification_filesets::Variables { ipt_fileset_program_arg }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetFilesetsResult(res));
+                    })
+                }
+            },
+            Msg::RequestFilesetFiles => {
+                self.suitable_files.clear();
+                self.select_file_index = 0;
+                link.send_message(Msg::CallbackSelectModel);
+                if self.select_fileset_uuid.len() == 36 {
+                    let page_set = PaginateSet::set(Some(1), Some(50));
+                    let ipt_file_of_fileset_arg = com_mod_fileset_files::IptFileOfFilesetArg{
+                        filesetUuid: self.select_fileset_uuid.clone(),
+                        fileUuids: None,
+                    };
+                    let ipt_paginate = Some(com_mod_fileset_files::IptPaginate {
+                        currentPage: page_set.current_page,
+                        perPage: page_set.per_page,
+                    });
+                    spawn_local(async move {
+                        let res = make_query(ComModFilesetFiles::build_query(com_mod_fileset_files::Variables {
+                            ipt_file_of_fileset_arg,
+                            ipt_paginate
+                        })).await.unwrap();
+                        link.send_message(Msg::GetFilesetFilesResult(res));
+                    })
+                }
+            },
+            Msg::GetFilesetsResult(res) => {
+                match resp_parsing::<Vec<FilesetProgramInfo>>(res, "componentModificationFilesets") {
+                    Ok(result) => {
+                        self.filesets = result;
+                        debug!("Filesets of modification for model: {:?}", self.filesets.len());
+                        if let Some(fileset) = self.filesets.first() {
+                            link.send_message(Msg::SelectFileset(fileset.uuid.clone()));
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetFilesetFilesResult(res) => {
+                match resp_parsing::<Vec<DownloadFile>>(res, "componentModificationFilesetFiles") {
+                    Ok(result) => {
+                        self.suitable_files = result
+                            .into_iter()
+                            .map(|file| {
+                                let model_format = ModelFormat::from_filename(&file.filename);
+                                (file, model_format)
+                            })
+                            .filter(|(_, model_format)| model_format.is_self_contained())
+                            .collect();
+                        debug!("Found {} model files", self.suitable_files.len());
+                        link.send_message(Msg::CallbackSelectModel);
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::SelectModification(modification_uuid) => {
+                self.select_modification_uuid = modification_uuid;
+                link.send_message(Msg::RequestFilesets);
+            },
+            Msg::SelectFileset(fileset_uuid) => {
+                self.select_fileset_uuid = fileset_uuid;
+                link.send_message(Msg::RequestFilesetFiles);
+            },
+            Msg::SelectFile(index) => {
+                self.select_file_index = index;
+                link.send_message(Msg::CallbackSelectModel);
+            },
+            Msg::CallbackSelectModel => {
+                self.props.callback_select_model.emit((
+                    self.select_modification_uuid.clone(),
+                    self.suitable_files.get(self.select_file_index).map(|(file, _)| file.clone()),
+                ));
+                return false
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.select_modification_uuid == props.select_modification_uuid &&
+            self.props.modifications.len() == props.modifications.len() {
+            false
+        } else {
+            if self.props.select_modification_uuid != props.select_modification_uuid {
+                self.select_modification_uuid = props.select_modification_uuid.clone();
+                self.link.send_message(Msg::RequestFilesets);
+            }
+            self.props = props;
+            true
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onchange_modification = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectModification(el.value()),
+            _ => Msg::Ignore,
+        });
+        let onchange_fileset = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectFileset(el.value()),
+            _ => Msg::Ignore,
+        });
+        let onchange_file = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectFile(el.value().parse::<usize>().unwrap_or_default()),
+            _ => Msg::Ignore,
+        });
+
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error}/>
+            <div class="columns">
+                <div class="column">
+                    <label class="label">{get_value_field(&463)}</label> // Modification
+                    <div class="select is-fullwidth">
+                        <select onchange={onchange_modification}>
+                            {for self.props.modifications.iter().map(|modification| html!{
+                                <option value={modification.uuid.clone()}
+                                        selected={modification.uuid == self.select_modification_uuid} >
+                                    {modification.modification_name.clone()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+                <div class="column">
+                    <label class="label">{get_value_field(&26)}</label> // Program
+                    <div class="select is-fullwidth">
+                        <select onchange={onchange_fileset}>
+                            {for self.filesets.iter().map(|fileset| html!{
+                                <option value={fileset.uuid.clone()}
+                                        selected={fileset.uuid == self.select_fileset_uuid} >
+                                    {fileset.program.name.clone()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+                <div class="column">
+                    <label class="label">{get_value_field(&434)}</label> // File
+                    {match self.suitable_files.is_empty() {
+                        true => html!{<p class="help is-danger">{get_value_field(&461)}</p>},
+                        false => html!{
+                            <div class="select is-fullwidth">
+                                <select onchange={onchange_file}>
+                                    {for self.suitable_files.iter().enumerate().map(|(index, (file, _))| html!{
+                                        <option value={index.to_string()}
+                                                selected={index == self.select_file_index} >
+                                            {file.filename.clone()}
+                                        </option>
+                                    })}
+                                </select>
+                            </div>
+                        },
+                    }}
+                </div>
+            </div>
+        </>}
+    }
+}
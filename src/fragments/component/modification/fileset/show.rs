@@ -16,6 +16,9 @@ pub struct Props {
     pub modification_uuid: UUID,
     pub callback_select_fileset: Callback<FilesetProgramInfo>,
     pub callback_open_fileset: Callback<bool>,
+    /// Fileset to select instead of the first one (e.g. from a view link)
+    #[prop_or_default]
+    pub select_fileset_uuid: Option<UUID>,
 }
 
 pub struct ModificationFilesetsCard {
@@ -79,7 +82,9 @@ impl Component for ModificationFilesetsCard {
                     Ok(res) => {
                         self.filesets_program = res;
                         self.select_fileset_uuid = self.filesets_program
-                            .first()
+                            .iter()
+                            .find(|m| Some(&m.uuid) == self.props.select_fileset_uuid.as_ref())
+                            .or_else(|| self.filesets_program.first())
                             .map(|m| m.uuid.clone())
                             .unwrap_or_default();
                         debug!("Update modification filesets list");
@@ -102,7 +107,10 @@ impl Component for ModificationFilesetsCard {
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props.modification_uuid == props.modification_uuid {
             debug!("No parsing filesets for modification: {:?}", props.modification_uuid);
-            false
+            // the fileset from the view link is selected by the block of filesets
+            let fileset_changed = self.props.select_fileset_uuid != props.select_fileset_uuid;
+            self.props = props;
+            fileset_changed
         } else {
             debug!("Parsing filesets for modification: {:?}", props.modification_uuid);
             self.props = props;
@@ -121,6 +129,7 @@ impl Component for ModificationFilesetsCard {
                 current_filesets_program={self.filesets_program.clone()}
                 callback_select_fileset_uuid={callback_select_fileset_uuid}
                 callback_open_fileset={self.props.callback_open_fileset.clone()}
+                initial_fileset_uuid={self.props.select_fileset_uuid.clone()}
             />
         </>}
     }
@@ -0,0 +1,268 @@
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use crate::services::{get_value_field, is_numeric_column, ColumnFilter, ModificationsFilter, NAME_COLUMN};
+use crate::types::{ActualStatus, ComponentModificationInfo};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    /// Modifications for the list of the columns and statuses
+    pub modifications: Vec<ComponentModificationInfo>,
+    pub filter: ModificationsFilter,
+    pub callback_change: Callback<ModificationsFilter>,
+}
+
+/// Column of the table available for the filtering
+struct FilterColumn {
+    param_id: usize,
+    title: String,
+    numeric: bool,
+}
+
+/// Filters by the columns (numeric ranges or text) and the status of the modifications
+pub struct ModificationsFilterBar {
+    props: Props,
+    link: ComponentLink<Self>,
+    open_filters: bool,
+    columns: Vec<FilterColumn>,
+    statuses: Vec<ActualStatus>,
+}
+
+pub enum Msg {
+    ShowFilters,
+    SetRangeMin(usize, String),
+    SetRangeMax(usize, String),
+    SetContains(usize, String),
+    SetStatus(String),
+    ResetFilters,
+    Ignore,
+}
+
+impl Component for ModificationsFilterBar {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut filter_bar = Self {
+            open_filters: props.filter.is_filtered(),
+            props,
+            link,
+            columns: Vec::new(),
+            statuses: Vec::new(),
+        };
+        filter_bar.collect_columns();
+        filter_bar
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut filter = self.props.filter.clone();
+        match msg {
+            Msg::ShowFilters => {
+                self.open_filters = !self.open_filters;
+                return true
+            },
+            Msg::SetRangeMin(param_id, value) => {
+                let max = range_bounds(&filter, param_id).1;
+                filter.set_column(param_id, Some(ColumnFilter::Range { min: parse_bound(&value), max }));
+            },
+            Msg::SetRangeMax(param_id, value) => {
+                let min = range_bounds(&filter, param_id).0;
+                filter.set_column(param_id, Some(ColumnFilter::Range { min, max: parse_bound(&value) }));
+            },
+            Msg::SetContains(param_id, value) => filter.set_column(param_id, Some(ColumnFilter::Contains(value))),
+            Msg::SetStatus(value) => filter.status_id = value.parse().ok(),
+            Msg::ResetFilters => {
+                filter.columns.clear();
+                filter.status_id = None;
+            },
+            Msg::Ignore => return false,
+        }
+        debug!("Modifications filter: {:?}", filter);
+        if filter != self.props.filter {
+            self.props.callback_change.emit(filter);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        self.collect_columns();
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_filters = self.link.callback(|_| Msg::ShowFilters);
+        let onclick_reset_filters = self.link.callback(|_| Msg::ResetFilters);
+        let filters_count = self.props.filter.columns.len() + self.props.filter.status_id.map_or(0, |_| 1);
+        html!{
+            <div class="mb-2">
+                <div class="buttons mb-1">
+                    <button id="modifications-filters"
+                        class="button is-small is-white"
+                        onclick={onclick_show_filters}
+                        title={get_value_field(&548)} >
+                        <span class="icon">
+                            <i class="fas fa-filter" style="color: #1872f0;" aria-hidden="true"></i>
+                        </span>
+                        <span>{format!("{} ({})", get_value_field(&548), filters_count)}</span> // Filters
+                    </button>
+                    {match filters_count > 0 {
+                        true => html!{
+                            <button id="reset-modifications-filters"
+                                class="button is-small is-white"
+                                onclick={onclick_reset_filters} >
+                                <span class="icon"><i class="fas fa-times" aria-hidden="true"></i></span>
+                                <span>{get_value_field(&88)}</span> // Clear
+                            </button>
+                        },
+                        false => html!{},
+                    }}
+                </div>
+                {match self.open_filters {
+                    true => self.show_filters(),
+                    false => html!{},
+                }}
+            </div>
+        }
+    }
+}
+
+impl ModificationsFilterBar {
+    fn collect_columns(&mut self) {
+        let modifications = &self.props.modifications;
+        self.columns = vec![FilterColumn {
+            param_id: NAME_COLUMN,
+            title: get_value_field(&110).to_string(), // Name
+            numeric: false,
+        }];
+        self.statuses.clear();
+        for modification in modifications {
+            for modification_param in &modification.modification_params {
+                let param = &modification_param.param;
+                if self.columns.iter().all(|column| column.param_id != param.param_id) {
+                    self.columns.push(FilterColumn {
+                        param_id: param.param_id,
+                        title: param.paramname.clone(),
+                        numeric: is_numeric_column(modifications, param.param_id),
+                    });
+                }
+            }
+            let status = &modification.actual_status;
+            if self.statuses.iter().all(|s| s.actual_status_id != status.actual_status_id) {
+                self.statuses.push(status.clone());
+            }
+        }
+        // the filter of the link can be set for the column of other type
+        for (param_id, column_filter) in &self.props.filter.columns {
+            if let Some(column) = self.columns.iter_mut().find(|column| &column.param_id == param_id) {
+                column.numeric = matches!(column_filter, ColumnFilter::Range { .. });
+            }
+        }
+    }
+
+    fn show_filters(&self) -> Html {
+        html!{
+            <div class="box">
+                <div class="columns is-multiline">
+                    {self.show_status_filter()}
+                    {for self.columns.iter().map(|column| self.show_column_filter(column))}
+                </div>
+                <p class="help">{get_value_field(&553)}</p>
+            </div>
+        }
+    }
+
+    fn show_status_filter(&self) -> Html {
+        let onchange_status = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SetStatus(el.value()),
+            _ => Msg::Ignore,
+        });
+        html!{
+            <div class="column is-3">
+                <label class="label is-small">{get_value_field(&96)}</label> // Life cycle stage
+                <div class="select is-small is-fullwidth">
+                    <select id="filter-modifications-status" onchange={onchange_status}>
+                        <option value="" selected={self.props.filter.status_id.is_none()}>
+                            {get_value_field(&552)} // Any
+                        </option>
+                        {for self.statuses.iter().map(|status| html!{
+                            <option value={status.actual_status_id.to_string()}
+                                selected={self.props.filter.status_id == Some(status.actual_status_id)} >
+                                {status.name.clone()}
+                            </option>
+                        })}
+                    </select>
+                </div>
+            </div>
+        }
+    }
+
+    fn show_column_filter(&self, column: &FilterColumn) -> Html {
+        let param_id = column.param_id;
+        if !column.numeric {
+            let value = match self.props.filter.columns.get(&param_id) {
+                Some(ColumnFilter::Contains(text)) => text.clone(),
+                _ => String::new(),
+            };
+            let onchange_contains = self.link.callback(move |ev: ChangeData| match ev {
+                ChangeData::Value(value) => Msg::SetContains(param_id, value),
+                _ => Msg::Ignore,
+            });
+            return html!{
+                <div class="column is-3">
+                    <label class="label is-small">{column.title.clone()}</label>
+                    <input class="input is-small"
+                        id={format!("filter-modifications-c{}", param_id)}
+                        type="text"
+                        placeholder={get_value_field(&554)} // Contains
+                        value={value}
+                        onchange={onchange_contains} />
+                </div>
+            }
+        }
+        let (min, max) = range_bounds(&self.props.filter, param_id);
+        let onchange_min = self.link.callback(move |ev: ChangeData| match ev {
+            ChangeData::Value(value) => Msg::SetRangeMin(param_id, value),
+            _ => Msg::Ignore,
+        });
+        let onchange_max = self.link.callback(move |ev: ChangeData| match ev {
+            ChangeData::Value(value) => Msg::SetRangeMax(param_id, value),
+            _ => Msg::Ignore,
+        });
+        html!{
+            <div class="column is-3">
+                <label class="label is-small">{column.title.clone()}</label>
+                <div class="field has-addons">
+                    <div class="control">
+                        <input class="input is-small"
+                            id={format!("filter-modifications-min{}", param_id)}
+                            type="number"
+                            step="any"
+                            placeholder={get_value_field(&550)} // From
+                            value={min.map(|min| min.to_string()).unwrap_or_default()}
+                            onchange={onchange_min} />
+                    </div>
+                    <div class="control">
+                        <input class="input is-small"
+                            id={format!("filter-modifications-max{}", param_id)}
+                            type="number"
+                            step="any"
+                            placeholder={get_value_field(&551)} // To
+                            value={max.map(|max| max.to_string()).unwrap_or_default()}
+                            onchange={onchange_max} />
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+fn range_bounds(filter: &ModificationsFilter, param_id: usize) -> (Option<f64>, Option<f64>) {
+    match filter.columns.get(&param_id) {
+        Some(ColumnFilter::Range { min, max }) => (*min, *max),
+        _ => (None, None),
+    }
+}
+
+fn parse_bound(value: &str) -> Option<f64> {
+    value.trim().replace(',', ".").parse::<f64>().ok().filter(|bound| bound.is_finite())
+}
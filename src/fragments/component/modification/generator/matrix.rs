@@ -0,0 +1,336 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+/// Most combinations of values before the exclusion rules are applied
+const MAX_COMBINATIONS: usize = 100_000;
+/// Most modifications created by the generator at once
+const MAX_MATRIX_ROWS: usize = 2_000;
+/// Most values in one range
+const MAX_RANGE_VALUES: usize = 1_000;
+
+/// Parameter of the matrix: the key used in the rules and in the name template,
+/// the name of the parameter and the text with its values
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct MatrixParam {
+    pub(crate) key: String,
+    pub(crate) paramname: String,
+    pub(crate) values: String,
+}
+
+/// Generated modification: the name by the template and the values in the order of the parameters
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MatrixRow {
+    pub(crate) name: String,
+    pub(crate) values: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Matrix {
+    pub(crate) rows: Vec<MatrixRow>,
+    /// Number of combinations removed by the exclusion rules
+    pub(crate) excluded: usize,
+}
+
+impl Matrix {
+    /// Names repeated in several rows
+    pub(crate) fn duplicate_names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.rows.iter()
+            .filter(|row| !names.insert(row.name.as_str()))
+            .map(|row| row.name.clone())
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Operators with two characters go first so that `<=` is not taken as `<`
+    const ALL: [(&'static str, CompareOp); 6] = [
+        ("<=", Self::Le),
+        (">=", Self::Ge),
+        ("!=", Self::Ne),
+        ("=", Self::Eq),
+        ("<", Self::Lt),
+        (">", Self::Gt),
+    ];
+
+    fn check(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::Ne => ordering != Ordering::Equal,
+            Self::Lt => ordering == Ordering::Less,
+            Self::Le => ordering != Ordering::Greater,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Right side of the condition: a value or the value of other parameter written as `{key}`
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Value(String),
+    Param(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Condition {
+    param: usize,
+    op: CompareOp,
+    operand: Operand,
+}
+
+/// Combination of values is excluded if all conditions of the rule are met
+#[derive(Clone, Debug, PartialEq)]
+struct ExclusionRule {
+    conditions: Vec<Condition>,
+}
+
+impl ExclusionRule {
+    fn excludes(&self, values: &[&str]) -> bool {
+        self.conditions.iter().all(|condition| {
+            let operand = match &condition.operand {
+                Operand::Value(value) => value.as_str(),
+                Operand::Param(index) => values[*index],
+            };
+            condition.op.check(compare_values(values[condition.param], operand))
+        })
+    }
+}
+
+/// Splits the value into the prefix, the number, the number of decimals and the suffix: `M3.5x` -> (`M`, 3.5, 1, `x`)
+fn split_number(value: &str) -> Option<(&str, f64, usize, &str)> {
+    let start = value.find(|c: char| c.is_ascii_digit())?;
+    let start = match value[..start].ends_with('-') && start == 1 {
+        true => 0,
+        false => start,
+    };
+    let mut end = start + 1;
+    let mut decimals = None;
+    for (index, c) in value[start + 1..].char_indices() {
+        match c {
+            '0'..='9' => decimals = decimals.map(|d| d + 1),
+            '.' if decimals.is_none() && value[start + 1 + index + 1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                decimals = Some(0)
+            },
+            _ => break,
+        }
+        end = start + 1 + index + c.len_utf8();
+    }
+    let number = value[start..end].parse::<f64>().ok()?;
+    Some((&value[..start], number, decimals.unwrap_or_default(), &value[end..]))
+}
+
+/// Values with the same prefix and suffix are compared as numbers, other values as text
+fn compare_values(left: &str, right: &str) -> Ordering {
+    match (split_number(left), split_number(right)) {
+        (Some((lp, ln, _, ls)), Some((rp, rn, _, rs))) if lp == rp && ls == rs => {
+            ln.partial_cmp(&rn).unwrap_or(Ordering::Equal)
+        },
+        _ => left.cmp(right),
+    }
+}
+
+/// Values of the range `from..to` or `from..to step N`, the ends may have the same prefix and suffix: `M3..M12`
+fn parse_range(from: &str, to: &str) -> Result<Vec<String>, String> {
+    let (to, step) = match to.split_once(" step ") {
+        Some((to, step)) => (to.trim(), Some(step.trim())),
+        None => (to.trim(), None),
+    };
+    let invalid_range = || format!("Invalid range: {}..{}", from, to);
+    let (prefix, first, first_decimals, suffix) = split_number(from).ok_or_else(invalid_range)?;
+    let (to_prefix, last, last_decimals, to_suffix) = split_number(to).ok_or_else(invalid_range)?;
+    if prefix != to_prefix || suffix != to_suffix {
+        return Err(invalid_range())
+    }
+    let (step, step_decimals) = match step {
+        Some(step) => match split_number(step) {
+            Some(("", step, decimals, "")) if step > 0.0 => (step, decimals),
+            _ => return Err(format!("Invalid step of the range: {}", step)),
+        },
+        None => (1.0, 0),
+    };
+    let decimals = first_decimals.max(last_decimals).max(step_decimals);
+    let count = ((last - first).abs() / step + 1e-9).floor() as usize + 1;
+    if count > MAX_RANGE_VALUES {
+        return Err(format!("Too many values in the range: {}..{}", from, to))
+    }
+    let step = match last < first {
+        true => -step,
+        false => step,
+    };
+    Ok((0..count).map(|index| format!("{}{:.*}{}", prefix, decimals, first + step * index as f64, suffix)).collect())
+}
+
+/// Parses values separated by commas or semicolons, each item is a value or a range
+pub(crate) fn parse_values(text: &str) -> Result<Vec<String>, String> {
+    let mut values: Vec<String> = Vec::new();
+    for item in text.split([',', ';']).map(str::trim).filter(|item| !item.is_empty()) {
+        let item_values = match item.split_once("..") {
+            Some((from, to)) => parse_range(from.trim(), to)?,
+            None => vec![item.to_string()],
+        };
+        for value in item_values {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+    }
+    Ok(values)
+}
+
+fn find_key(keys: &[&str], key: &str) -> Result<usize, String> {
+    keys.iter().position(|k| *k == key).ok_or_else(|| format!("Unknown parameter key: {}", key))
+}
+
+fn parse_condition(text: &str, keys: &[&str]) -> Result<Condition, String> {
+    let (position, symbol, op) = CompareOp::ALL.iter()
+        .filter_map(|(symbol, op)| text.find(symbol).map(|position| (position, *symbol, *op)))
+        .min_by_key(|(position, symbol, _)| (*position, usize::MAX - symbol.len()))
+        .ok_or_else(|| format!("Condition without a comparison: {}", text))?;
+    let param = find_key(keys, text[..position].trim())?;
+    let operand = text[position + symbol.len()..].trim();
+    let operand = match operand.strip_prefix('{').and_then(|o| o.strip_suffix('}')) {
+        Some(key) => Operand::Param(find_key(keys, key.trim())?),
+        None => Operand::Value(operand.to_string()),
+    };
+    Ok(Condition { param, op, operand })
+}
+
+/// Parses the exclusion rules: one rule per line, conditions are joined with `&`
+fn parse_rules(text: &str, keys: &[&str]) -> Result<Vec<ExclusionRule>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let conditions = line.split('&')
+                .map(|condition| parse_condition(condition.trim(), keys))
+                .collect::<Result<Vec<Condition>, String>>()?;
+            Ok(ExclusionRule { conditions })
+        })
+        .collect()
+}
+
+/// Replaces the keys in braces with the values: `M{d}x{l}` -> `M3x10`
+pub(crate) fn fill_template(template: &str, keys: &[&str], values: &[&str]) -> String {
+    let mut result = template.to_string();
+    for (key, value) in keys.iter().zip(values) {
+        result = result.replace(&format!("{{{}}}", key), value);
+    }
+    result
+}
+
+/// Generates all combinations of the values of the parameters without the excluded ones,
+/// the values of the first parameter change slowest
+pub(crate) fn generate_matrix(params: &[MatrixParam], rules: &str, template: &str) -> Result<Matrix, String> {
+    if params.is_empty() {
+        return Err(String::from("No parameters"))
+    }
+    let keys = params.iter().map(|p| p.key.trim()).collect::<Vec<&str>>();
+    for (index, key) in keys.iter().enumerate() {
+        if key.is_empty() || !key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(format!("Invalid parameter key: {:?}", key))
+        }
+        if keys[..index].contains(key) {
+            return Err(format!("Duplicate parameter key: {}", key))
+        }
+    }
+    if template.trim().is_empty() {
+        return Err(String::from("Empty name template"))
+    }
+    let rules = parse_rules(rules, &keys)?;
+    let values = params.iter()
+        .map(|p| parse_values(&p.values))
+        .collect::<Result<Vec<Vec<String>>, String>>()?;
+    if let Some(index) = values.iter().position(|v| v.is_empty()) {
+        return Err(format!("No values of the parameter: {}", keys[index]))
+    }
+    let combinations = values.iter().try_fold(1usize, |total, v| total.checked_mul(v.len()));
+    if combinations.map(|total| total > MAX_COMBINATIONS).unwrap_or(true) {
+        return Err(format!("Too many combinations, the limit is {}", MAX_COMBINATIONS))
+    }
+    let mut matrix = Matrix::default();
+    let mut indexes = vec![0; values.len()];
+    loop {
+        let row_values = indexes.iter().zip(&values).map(|(i, v)| v[*i].as_str()).collect::<Vec<&str>>();
+        match rules.iter().any(|rule| rule.excludes(&row_values)) {
+            true => matrix.excluded += 1,
+            false => matrix.rows.push(MatrixRow {
+                name: fill_template(template.trim(), &keys, &row_values),
+                values: row_values.iter().map(|v| v.to_string()).collect(),
+            }),
+        }
+        if matrix.rows.len() > MAX_MATRIX_ROWS {
+            return Err(format!("Too many modifications, the limit is {}", MAX_MATRIX_ROWS))
+        }
+        // the next combination, the last parameter changes fastest
+        let mut position = indexes.len();
+        loop {
+            if position == 0 {
+                return Ok(matrix)
+            }
+            position -= 1;
+            indexes[position] += 1;
+            if indexes[position] < values[position].len() {
+                break
+            }
+            indexes[position] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn param(key: &str, values: &str) -> MatrixParam {
+        MatrixParam { key: key.to_string(), paramname: key.to_uppercase(), values: values.to_string() }
+    }
+
+    #[test]
+    fn matrix_values() {
+        assert_eq!(vec!["M3", "M4", "M5", "M6", "M8"], parse_values("M3..M6, M8, M4").unwrap());
+        assert_eq!(vec!["6", "8", "10"], parse_values("6..10 step 2").unwrap());
+        assert_eq!(vec!["1.0", "0.5", "0.0"], parse_values("1..0 step 0.5").unwrap());
+        assert_eq!(vec!["0.25", "0.50", "0.75"], parse_values("0.25..0.75 step 0.25").unwrap());
+        assert_eq!(vec!["steel", "brass"], parse_values("steel; brass").unwrap());
+        assert!(parse_values("M3..6").is_err());
+        assert!(parse_values("1..5 step 0").is_err());
+    }
+
+    #[test]
+    fn matrix_generate() {
+        let params = vec![param("d", "M3..M5"), param("l", "6..12 step 2")];
+        let matrix = generate_matrix(&params, "d=M3 & l>8\nd = M5 & l != 6", "{d}x{l}").unwrap();
+
+        assert_eq!(
+            vec!["M3x6", "M3x8", "M4x6", "M4x8", "M4x10", "M4x12", "M5x6"],
+            matrix.rows.iter().map(|r| r.name.as_str()).collect::<Vec<&str>>()
+        );
+        assert_eq!(vec!["M4", "10"], matrix.rows[4].values);
+        assert_eq!(5, matrix.excluded);
+        assert!(matrix.duplicate_names().is_empty());
+
+        let matrix = generate_matrix(&[param("a", "1..3"), param("b", "1..3")], "b < {a}", "{a}-{b}").unwrap();
+
+        assert_eq!(
+            vec!["1-1", "1-2", "1-3", "2-2", "2-3", "3-3"],
+            matrix.rows.iter().map(|r| r.name.as_str()).collect::<Vec<&str>>()
+        );
+
+        let matrix = generate_matrix(&params, "", "{d}").unwrap();
+
+        assert_eq!(vec!["M3", "M4", "M5"], matrix.duplicate_names().into_iter().collect::<Vec<String>>());
+        assert!(generate_matrix(&params, "x=1", "{d}").is_err());
+        assert!(generate_matrix(&[param("d", "1"), param("d", "2")], "", "{d}").is_err());
+    }
+}
@@ -0,0 +1,485 @@
+mod matrix;
+
+use std::collections::{BTreeMap, BTreeSet};
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use log::debug;
+
+use matrix::{generate_matrix, parse_values, Matrix, MatrixParam, MatrixRow};
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::fragments::notification::show_notification;
+use crate::fragments::component::request_all_modifications;
+use crate::types::{ComponentModificationInfo, Param, UUID};
+use crate::services::{get_value_field, resp_parsing};
+use crate::gqls::make_query;
+use crate::gqls::relate::{RegisterParamsBulk, register_params_bulk};
+use crate::gqls::component::{
+    RegisterComponentModificationsBulk, register_component_modifications_bulk,
+};
+
+/// Number of generated rows shown in the preview
+const PREVIEW_ROWS: usize = 100;
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    pub callback_finish_generate: Callback<()>,
+}
+
+/// Creates modifications for all combinations of the values of the parameters
+pub struct ModificationsGenerator {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    hide_generator: bool,
+    params: Vec<MatrixParam>,
+    rules: String,
+    name_template: String,
+    description: String,
+    matrix: Result<Matrix, String>,
+    /// Names of the modifications of the component, None until received
+    existing_names: Option<BTreeSet<String>>,
+    /// IDs of the registered parameters by the names
+    param_ids: BTreeMap<String, usize>,
+    creating: bool,
+    stat_info: String,
+}
+
+pub enum Msg {
+    RequestComponentModifications,
+    GetComponentModificationsResult(Result<Vec<ComponentModificationInfo>, Error>),
+    RequestRegisterParams,
+    GetRegisterParamsResult(String),
+    RequestRegisterModifications,
+    GetRegisterModificationsResult(String),
+    ResponseError(Error),
+    ShowGenerator,
+    AddParam,
+    RemoveParam(usize),
+    UpdateParamKey(usize, String),
+    UpdateParamName(usize, String),
+    UpdateParamValues(usize, String),
+    UpdateRules(String),
+    UpdateNameTemplate(String),
+    UpdateDescription(String),
+    ClearError,
+}
+
+impl Component for ModificationsGenerator {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut generator = Self {
+            error: None,
+            props,
+            link,
+            hide_generator: true,
+            params: vec![
+                MatrixParam { key: String::from("d"), ..MatrixParam::default() },
+                MatrixParam { key: String::from("l"), ..MatrixParam::default() },
+            ],
+            rules: String::new(),
+            name_template: String::from("M{d}x{l}"),
+            description: String::new(),
+            matrix: Ok(Matrix::default()),
+            existing_names: None,
+            param_ids: BTreeMap::new(),
+            creating: false,
+            stat_info: String::new(),
+        };
+        generator.update_matrix();
+        generator
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestComponentModifications => {
+                let component_uuid = self.props.component_uuid.clone();
+                spawn_local(async move {
+                    link.send_message(Msg::GetComponentModificationsResult(request_all_modifications(component_uuid).await));
+                })
+            },
+            Msg::GetComponentModificationsResult(result) => {
+                match result {
+                    Ok(result) => self.existing_names = Some(
+                        result.into_iter().map(|m| m.modification_name.trim().to_string()).collect()
+                    ),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::RequestRegisterParams => {
+                let ipt_params_translate_list_data = self.params.iter()
+                    .map(|p| register_params_bulk::IptParamTranslateListData{
+                        langId: 1, // todo!(fix for different lang)
+                        paramname: p.paramname.trim().to_string(),
+                    })
+                    .collect();
+                self.creating = true;
+                spawn_local(async move {
+                    let res = make_query(RegisterParamsBulk::build_query(
+                        register_params_bulk::Variables { ipt_params_translate_list_data }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetRegisterParamsResult(res));
+                })
+            },
+            Msg::GetRegisterParamsResult(res) => {
+                match resp_parsing::<Vec<Param>>(res, "registerParamsBulk") {
+                    Ok(result) => {
+                        self.param_ids = result.into_iter().map(|p| (p.paramname, p.param_id)).collect();
+                        debug!("Registered parameters for the generator: {:?}", self.param_ids);
+                        link.send_message(Msg::RequestRegisterModifications);
+                    },
+                    Err(err) => {
+                        self.creating = false;
+                        link.send_message(Msg::ResponseError(err));
+                    },
+                }
+            },
+            Msg::RequestRegisterModifications => {
+                let param_ids = self.params.iter()
+                    .map(|p| self.param_ids.get(p.paramname.trim()).copied())
+                    .collect::<Vec<Option<usize>>>();
+                let modifications_data = self.new_rows().into_iter().map(|row| {
+                    register_component_modifications_bulk::IptModificationsData{
+                        modificationName: row.name.clone(),
+                        description: self.description.clone(),
+                        actualStatusId: 1,
+                        parameters: row.values.iter().zip(&param_ids)
+                            .filter_map(|(value, param_id)| param_id.map(|param_id| {
+                                register_component_modifications_bulk::IptParamData {
+                                    paramId: param_id as i64,
+                                    value: value.clone(),
+                                }
+                            }))
+                            .collect(),
+                    }
+                }).collect();
+                let ipt_multiple_modifications_data = register_component_modifications_bulk::IptMultipleModificationsData{
+                    componentUuid: self.props.component_uuid.clone(),
+                    modificationsData: modifications_data,
+                };
+                spawn_local(async move {
+                    let res = make_query(RegisterComponentModificationsBulk::build_query(
+                        register_component_modifications_bulk::Variables { ipt_multiple_modifications_data }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetRegisterModificationsResult(res));
+                })
+            },
+            Msg::GetRegisterModificationsResult(res) => {
+                self.creating = false;
+                match resp_parsing::<Vec<UUID>>(res, "registerComponentModificationsBulk") {
+                    Ok(result) => {
+                        self.stat_info = format!("{} {}", get_value_field(&213), result.len());
+                        self.hide_generator = true;
+                        self.existing_names = None;
+                        self.props.callback_finish_generate.emit(());
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ShowGenerator => {
+                self.hide_generator = !self.hide_generator;
+                if !self.hide_generator {
+                    self.stat_info.clear();
+                    link.send_message(Msg::RequestComponentModifications);
+                }
+            },
+            Msg::AddParam => {
+                self.params.push(MatrixParam::default());
+                self.update_matrix();
+            },
+            Msg::RemoveParam(index) => {
+                if index < self.params.len() {
+                    self.params.remove(index);
+                }
+                self.update_matrix();
+            },
+            Msg::UpdateParamKey(index, value) => {
+                if let Some(param) = self.params.get_mut(index) {
+                    param.key = value;
+                }
+                self.update_matrix();
+            },
+            Msg::UpdateParamName(index, value) => {
+                if let Some(param) = self.params.get_mut(index) {
+                    param.paramname = value;
+                }
+                return false
+            },
+            Msg::UpdateParamValues(index, value) => {
+                if let Some(param) = self.params.get_mut(index) {
+                    param.values = value;
+                }
+                self.update_matrix();
+            },
+            Msg::UpdateRules(value) => {
+                self.rules = value;
+                self.update_matrix();
+            },
+            Msg::UpdateNameTemplate(value) => {
+                self.name_template = value;
+                self.update_matrix();
+            },
+            Msg::UpdateDescription(value) => {
+                self.description = value;
+                return false
+            },
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            return false
+        }
+        self.props = props;
+        self.existing_names = None;
+        self.hide_generator = true;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_show_generator = self.link.callback(|_| Msg::ShowGenerator);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            {show_notification(&self.stat_info, "is-success", self.hide_generator && !self.stat_info.is_empty())}
+            <button
+                id="open-generator-btn"
+                class="button"
+                title={get_value_field(&521)}
+                onclick={onclick_show_generator} >
+                <span class="icon is-small"><i class="fas fa-th-list" aria-hidden="true"></i></span>
+            </button>
+            {match self.hide_generator {
+                true => html!{},
+                false => self.show_generator_modal(),
+            }}
+        </>}
+    }
+}
+
+impl ModificationsGenerator {
+    fn update_matrix(&mut self) {
+        self.matrix = generate_matrix(&self.params, &self.rules, &self.name_template);
+    }
+
+    fn is_existing(&self, row: &MatrixRow) -> bool {
+        self.existing_names.as_ref().map(|names| names.contains(row.name.trim())).unwrap_or_default()
+    }
+
+    /// Generated rows without the existing modifications
+    fn new_rows(&self) -> Vec<&MatrixRow> {
+        match &self.matrix {
+            Ok(matrix) => matrix.rows.iter().filter(|row| !self.is_existing(row)).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Creation is available if all parameters have names and the names of the modifications are unique
+    fn can_create(&self) -> bool {
+        let names_ok = self.matrix.as_ref().map(|m| m.duplicate_names().is_empty()).unwrap_or_default();
+        let params_ok = self.params.iter().all(|p| !p.paramname.trim().is_empty());
+        names_ok && params_ok && self.existing_names.is_some() && !self.new_rows().is_empty() && !self.creating
+    }
+
+    fn show_generator_modal(&self) -> Html {
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowGenerator);
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content" style="width: 90vw;">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&521)}</p> // Generate modifications
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <div class="columns">
+                            <div class="column is-5">
+                                {self.show_params()}
+                                {self.show_rules()}
+                            </div>
+                            <div class="column">
+                                {self.show_preview()}
+                            </div>
+                        </div>
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+
+    fn show_params(&self) -> Html {
+        let onclick_add_param = self.link.callback(|_| Msg::AddParam);
+        html!{<>
+            <table class="table is-fullwidth is-narrow">
+                <thead>
+                    <tr>
+                        <th>{get_value_field(&522)}</th> // Key
+                        <th>{get_value_field(&178)}</th> // Parameter
+                        <th>{get_value_field(&523)}</th> // Values
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for self.params.iter().enumerate().map(|(index, param)| self.show_param(index, param))}
+                </tbody>
+            </table>
+            <p class="help">{get_value_field(&524)}</p>
+            <button id="generator-add-param" class="button is-small mt-2" onclick={onclick_add_param}>
+                <span class="icon is-small"><i class="fas fa-plus" aria-hidden="true"></i></span>
+                <span>{get_value_field(&529)}</span> // Add parameter
+            </button>
+        </>}
+    }
+
+    fn show_param(&self, index: usize, param: &MatrixParam) -> Html {
+        let oninput_key = self.link.callback(move |ev: InputData| Msg::UpdateParamKey(index, ev.value));
+        let oninput_name = self.link.callback(move |ev: InputData| Msg::UpdateParamName(index, ev.value));
+        let oninput_values = self.link.callback(move |ev: InputData| Msg::UpdateParamValues(index, ev.value));
+        let onclick_remove = self.link.callback(move |_| Msg::RemoveParam(index));
+        let values_count = parse_values(&param.values).map(|values| values.len()).unwrap_or_default();
+        html!{
+            <tr>
+                <td style="width: 4em;">
+                    <input class="input is-small" type="text" value={param.key.clone()} oninput={oninput_key} />
+                </td>
+                <td>
+                    <input class="input is-small" type="text" value={param.paramname.clone()} oninput={oninput_name} />
+                </td>
+                <td>
+                    <input
+                        class="input is-small"
+                        type="text"
+                        placeholder="6..60 step 2"
+                        value={param.values.clone()}
+                        oninput={oninput_values} />
+                    <p class="help">{format!("{}: {}", get_value_field(&523), values_count)}</p>
+                </td>
+                <td>
+                    <button class="delete" disabled={self.params.len() < 2} onclick={onclick_remove} />
+                </td>
+            </tr>
+        }
+    }
+
+    fn show_rules(&self) -> Html {
+        let oninput_rules = self.link.callback(|ev: InputData| Msg::UpdateRules(ev.value));
+        let oninput_template = self.link.callback(|ev: InputData| Msg::UpdateNameTemplate(ev.value));
+        let oninput_description = self.link.callback(|ev: InputData| Msg::UpdateDescription(ev.value));
+        html!{<>
+            <div class="field mt-4">
+                <label class="label">{get_value_field(&525)}</label> // Exclusion rules
+                <textarea
+                    id="generator-rules"
+                    class="textarea is-small"
+                    rows="3"
+                    placeholder="d=M3 & l>30"
+                    value={self.rules.clone()}
+                    oninput={oninput_rules} />
+                <p class="help">{get_value_field(&526)}</p>
+            </div>
+            <div class="field">
+                <label class="label">{get_value_field(&527)}</label> // Name template
+                <input
+                    id="generator-name-template"
+                    class="input"
+                    type="text"
+                    value={self.name_template.clone()}
+                    oninput={oninput_template} />
+                <p class="help">{get_value_field(&528)}</p>
+            </div>
+            <div class="field">
+                <label class="label">{get_value_field(&61)}</label> // Description
+                <input
+                    id="generator-description"
+                    class="input"
+                    type="text"
+                    value={self.description.clone()}
+                    oninput={oninput_description} />
+            </div>
+        </>}
+    }
+
+    fn show_preview(&self) -> Html {
+        let matrix = match &self.matrix {
+            Ok(matrix) => matrix,
+            Err(err) => return html!{
+                <div class="notification is-warning is-light">{err.clone()}</div>
+            },
+        };
+        let onclick_create = self.link.callback(|_| Msg::RequestRegisterParams);
+        let duplicate_names = matrix.duplicate_names();
+        let new_rows = self.new_rows().len();
+        html!{<>
+            <div class="tags">
+                <span class="tag is-info is-light">
+                    {format!("{}: {}", get_value_field(&530), matrix.rows.len() + matrix.excluded)} // Combinations
+                </span>
+                <span class="tag is-light">
+                    {format!("{}: {}", get_value_field(&531), matrix.excluded)} // Excluded
+                </span>
+                <span class="tag is-light">
+                    {format!("{}: {}", get_value_field(&504), matrix.rows.len() - new_rows)} // Exists
+                </span>
+                <span class="tag is-success is-light">
+                    {format!("{}: {}", get_value_field(&511), new_rows)} // Will be imported
+                </span>
+            </div>
+            <div class="table-container" style="max-height: 50vh; overflow-y: auto;">
+                <table class="table is-fullwidth is-narrow is-striped">
+                    <thead>
+                        <tr>
+                            <th>{get_value_field(&110)}</th> // Name
+                            {for self.params.iter().map(|p| html!{
+                                <th>{match p.paramname.trim().is_empty() {
+                                    true => p.key.clone(),
+                                    false => p.paramname.clone(),
+                                }}</th>
+                            })}
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for matrix.rows.iter().take(PREVIEW_ROWS).map(|row| html!{
+                            <tr>
+                                <td>{row.name.clone()}</td>
+                                {for row.values.iter().map(|value| html!{<td>{value.clone()}</td>})}
+                                <td>
+                                    {match (duplicate_names.contains(&row.name), self.is_existing(row)) {
+                                        (true, _) => html!{<span class="tag is-danger is-light">{get_value_field(&532)}</span>},
+                                        (false, true) => html!{<span class="tag is-light">{get_value_field(&504)}</span>},
+                                        (false, false) => html!{<span class="tag is-success is-light">{get_value_field(&503)}</span>},
+                                    }}
+                                </td>
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+            {match matrix.rows.len() > PREVIEW_ROWS {
+                true => html!{<p class="help">{format!("… {}", matrix.rows.len() - PREVIEW_ROWS)}</p>},
+                false => html!{},
+            }}
+            <button
+                id="generator-create-btn"
+                class={match self.creating {
+                    true => "button is-success is-fullwidth mt-3 is-loading",
+                    false => "button is-success is-fullwidth mt-3",
+                }}
+                disabled={!self.can_create()}
+                onclick={onclick_create} >
+                <span class="icon is-small"><i class="fas fa-plus" aria-hidden="true"></i></span>
+                <span>{format!("{}: {}", get_value_field(&533), new_rows)}</span> // Create modifications
+            </button>
+        </>}
+    }
+}
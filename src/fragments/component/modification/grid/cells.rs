@@ -0,0 +1,276 @@
+use crate::services::{normalize_value, parsing_block, write_delimited, ParsingSplit, UnitError};
+use crate::types::{ComponentModificationInfo, Param, ParamValue, UUID};
+
+/// Position of a cell in the grid: (row, column)
+pub(crate) type CellPos = (usize, usize);
+
+/// Rectangular area of cells from the anchor to the cursor
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Selection {
+    pub(crate) anchor: CellPos,
+    pub(crate) cursor: CellPos,
+}
+
+impl Selection {
+    pub(crate) fn single(pos: CellPos) -> Self {
+        Self { anchor: pos, cursor: pos }
+    }
+
+    /// Returns the first and the last cells of the area
+    pub(crate) fn area(&self) -> (CellPos, CellPos) {
+        (
+            (self.anchor.0.min(self.cursor.0), self.anchor.1.min(self.cursor.1)),
+            (self.anchor.0.max(self.cursor.0), self.anchor.1.max(self.cursor.1)),
+        )
+    }
+
+    pub(crate) fn contains(&self, pos: CellPos) -> bool {
+        let (first, last) = self.area();
+        (first.0..=last.0).contains(&pos.0) && (first.1..=last.1).contains(&pos.1)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = CellPos> {
+        let (first, last) = self.area();
+        (first.0..=last.0).flat_map(move |row| (first.1..=last.1).map(move |column| (row, column)))
+    }
+}
+
+/// Changes of the parameters of a modification: new values and removed parameters
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ModificationChanges {
+    pub(crate) modification_uuid: UUID,
+    pub(crate) put_params: Vec<ParamValue>,
+    pub(crate) delete_params: Vec<usize>,
+}
+
+/// Values of parameters of modifications (rows) by parameters (columns) with the saved values
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct GridCells {
+    /// UUID and name of modifications
+    pub(crate) rows: Vec<(UUID, String)>,
+    pub(crate) columns: Vec<Param>,
+    original: Vec<Vec<String>>,
+    values: Vec<Vec<String>>,
+}
+
+impl GridCells {
+    pub(crate) fn new(modifications: &[ComponentModificationInfo]) -> Self {
+        let mut columns: Vec<Param> = Vec::new();
+        for modification in modifications {
+            for modification_param in &modification.modification_params {
+                if !columns.iter().any(|p| p.param_id == modification_param.param.param_id) {
+                    columns.push(modification_param.param.clone());
+                }
+            }
+        }
+        let original = modifications.iter().map(|modification| {
+            columns.iter().map(|param| {
+                modification.modification_params.iter()
+                    .find(|p| p.param.param_id == param.param_id)
+                    .map(|p| p.value.clone())
+                    .unwrap_or_default()
+            }).collect()
+        }).collect::<Vec<Vec<String>>>();
+        Self {
+            rows: modifications.iter().map(|m| (m.uuid.clone(), m.modification_name.clone())).collect(),
+            columns,
+            values: original.clone(),
+            original,
+        }
+    }
+
+    /// Returns true if the grids are built from the same saved values
+    pub(crate) fn same_source(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.columns == other.columns && self.original == other.original
+    }
+
+    /// Number of rows and columns
+    pub(crate) fn size(&self) -> (usize, usize) {
+        (self.rows.len(), self.columns.len())
+    }
+
+    pub(crate) fn value(&self, pos: CellPos) -> &str {
+        self.values.get(pos.0).and_then(|row| row.get(pos.1)).map(|v| v.as_str()).unwrap_or_default()
+    }
+
+    pub(crate) fn set(&mut self, pos: CellPos, value: String) {
+        if let Some(cell) = self.values.get_mut(pos.0).and_then(|row| row.get_mut(pos.1)) {
+            *cell = value;
+        }
+    }
+
+    /// Returns true if the value differs from the saved one
+    pub(crate) fn is_dirty(&self, pos: CellPos) -> bool {
+        self.original.get(pos.0).and_then(|row| row.get(pos.1)).map(|v| v.as_str()).unwrap_or_default() != self.value(pos)
+    }
+
+    /// Returns the error of the value for the parameters with a unit
+    pub(crate) fn value_error(&self, pos: CellPos) -> Option<UnitError> {
+        let param = self.columns.get(pos.1)?;
+        normalize_value(&param.paramname, self.value(pos)).err()
+    }
+
+    pub(crate) fn invalid_count(&self) -> usize {
+        let (rows, columns) = self.size();
+        (0..rows).flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .filter(|pos| self.value_error(*pos).is_some())
+            .count()
+    }
+
+    pub(crate) fn dirty_count(&self) -> usize {
+        let (rows, columns) = self.size();
+        (0..rows).flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .filter(|pos| self.is_dirty(*pos))
+            .count()
+    }
+
+    /// Pastes a block of values copied from a spreadsheet starting from the position,
+    /// the values outside the grid are ignored. Returns the area of the pasted values.
+    pub(crate) fn paste(&mut self, at: CellPos, text: &str) -> Selection {
+        let block = parsing_block(text, ParsingSplit::Tab);
+        let (rows, columns) = self.size();
+        let mut last = at;
+        for (shift_row, values) in block.iter().enumerate() {
+            for (shift_column, value) in values.iter().enumerate() {
+                let pos = (at.0 + shift_row, at.1 + shift_column);
+                if pos.0 < rows && pos.1 < columns {
+                    self.set(pos, value.clone());
+                    last = (last.0.max(pos.0), last.1.max(pos.1));
+                }
+            }
+        }
+        Selection { anchor: at, cursor: last }
+    }
+
+    /// Copies the values of the first row of the area to the other rows of the area
+    pub(crate) fn fill_down(&mut self, selection: Selection) {
+        let (first, last) = selection.area();
+        for column in first.1..=last.1 {
+            let value = self.value((first.0, column)).to_string();
+            for row in first.0 + 1..=last.0 {
+                self.set((row, column), value.clone());
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self, selection: Selection) {
+        for pos in selection.cells() {
+            self.set(pos, String::new());
+        }
+    }
+
+    /// Returns the values of the area as a text table to paste into a spreadsheet
+    pub(crate) fn copy(&self, selection: Selection) -> String {
+        let (first, last) = selection.area();
+        let rows = (first.0..=last.0).map(|row| {
+            (first.1..=last.1).map(|column| self.value((row, column)).to_string()).collect()
+        }).collect::<Vec<Vec<String>>>();
+        write_delimited(&rows, ParsingSplit::Tab)
+    }
+
+    /// Returns the saved values instead of the changed ones
+    pub(crate) fn discard(&mut self) {
+        self.values = self.original.clone();
+    }
+
+    /// Changed values by modifications: a cleared value removes the parameter from the modification,
+    /// values of the parameters with a unit are converted to the unit of the parameter
+    pub(crate) fn changes(&self) -> Vec<ModificationChanges> {
+        let mut result = Vec::new();
+        for (row, (modification_uuid, _)) in self.rows.iter().enumerate() {
+            let mut changes = ModificationChanges {
+                modification_uuid: modification_uuid.clone(),
+                put_params: Vec::new(),
+                delete_params: Vec::new(),
+            };
+            for (column, param) in self.columns.iter().enumerate().filter(|(column, _)| self.is_dirty((row, *column))) {
+                let value = self.value((row, column));
+                match normalize_value(&param.paramname, value).unwrap_or_else(|_| value.trim().to_string()) {
+                    value if value.is_empty() => changes.delete_params.push(param.param_id),
+                    value => changes.put_params.push(ParamValue { param_id: param.param_id, value }),
+                }
+            }
+            if !changes.put_params.is_empty() || !changes.delete_params.is_empty() {
+                result.push(changes);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn test_cells() -> GridCells {
+        let columns = vec![
+            Param { param_id: 1, lang_id: 1, paramname: String::from("Length [mm]") },
+            Param { param_id: 2, lang_id: 1, paramname: String::from("Width") },
+        ];
+        let original = vec![
+            vec![String::from("10"), String::from("1")],
+            vec![String::from("20"), String::new()],
+            vec![String::from("30"), String::from("3")],
+        ];
+        GridCells {
+            rows: vec![(String::from("m1"), String::from("M1")), (String::from("m2"), String::from("M2")), (String::from("m3"), String::from("M3"))],
+            columns,
+            values: original.clone(),
+            original,
+        }
+    }
+
+    #[test]
+    fn grid_paste_and_fill_down() {
+        let mut cells = test_cells();
+
+        let pasted = cells.paste((1, 1), "5\t6\t7\r\n\t8\r\n9\r\n");
+
+        assert_eq!(Selection { anchor: (1, 1), cursor: (2, 1) }, pasted);
+        assert_eq!(("5", ""), (cells.value((1, 1)), cells.value((2, 1))));
+        assert_eq!(2, cells.dirty_count());
+
+        cells.fill_down(Selection { anchor: (2, 0), cursor: (0, 0) });
+
+        assert_eq!(("10", "10", "10"), (cells.value((0, 0)), cells.value((1, 0)), cells.value((2, 0))));
+        assert_eq!("10\t5\r\n10\t\r\n", cells.copy(Selection { anchor: (1, 0), cursor: (2, 1) }));
+    }
+
+    #[test]
+    fn grid_changes() {
+        let mut cells = test_cells();
+        cells.set((0, 1), String::from(" 1.5 "));
+        cells.clear(Selection { anchor: (2, 1), cursor: (2, 1) });
+        cells.set((1, 0), String::from("2.5 cm"));
+
+        assert_eq!(
+            vec![
+                ModificationChanges {
+                    modification_uuid: String::from("m1"),
+                    put_params: vec![ParamValue { param_id: 2, value: String::from("1.5") }],
+                    delete_params: Vec::new(),
+                },
+                ModificationChanges {
+                    modification_uuid: String::from("m2"),
+                    put_params: vec![ParamValue { param_id: 1, value: String::from("25") }],
+                    delete_params: Vec::new(),
+                },
+                ModificationChanges {
+                    modification_uuid: String::from("m3"),
+                    put_params: Vec::new(),
+                    delete_params: vec![2],
+                },
+            ],
+            cells.changes()
+        );
+
+        cells.set((2, 0), String::from("2.5 kg"));
+
+        assert_eq!(Some(UnitError::OtherDimension(String::from("kg"))), cells.value_error((2, 0)));
+        assert_eq!(1, cells.invalid_count());
+
+        cells.discard();
+
+        assert_eq!(0, cells.dirty_count());
+    }
+}
@@ -0,0 +1,494 @@
+mod cells;
+
+use graphql_client::GraphQLQuery;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{DataTransfer, Event, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent};
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, NodeRef, Properties, ShouldRender};
+use log::debug;
+
+use cells::{CellPos, GridCells, Selection};
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::ComponentModificationInfo;
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    PutModificationParams, put_modification_params,
+    DeleteModificationParams, delete_modification_params,
+};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub modifications: Vec<ComponentModificationInfo>,
+    pub callback_saved: Callback<()>,
+}
+
+/// Editing the parameters of all modifications on the page as a spreadsheet
+pub struct ModificationsGridEdit {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    cells: GridCells,
+    selection: Selection,
+    /// The value of the edited cell (the cell under the cursor)
+    editing: Option<String>,
+    dragging: bool,
+    /// Number of save requests without a response
+    pending_requests: usize,
+    /// The changes are kept for a retry if any save request failed
+    save_failed: bool,
+    grid_ref: NodeRef,
+    input_ref: NodeRef,
+    need_focus: bool,
+}
+
+/// Keys handled by the grid
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridKey {
+    /// Moves the cursor by (rows, columns), with Shift the selection is extended
+    Move(isize, isize, bool),
+    /// Finishes the editing and moves the cursor by (rows, columns)
+    Commit(isize, isize),
+    /// Starts the editing with the current value or with the typed character
+    Edit(Option<String>),
+    Cancel,
+    Clear,
+    FillDown,
+    SelectAll,
+}
+
+impl GridKey {
+    fn from_event(ev: &KeyboardEvent, editing: bool) -> Option<Self> {
+        let key = ev.key();
+        let shift = ev.shift_key();
+        let ctrl = ev.ctrl_key() || ev.meta_key();
+        let tab_shift = match shift {
+            true => -1,
+            false => 1,
+        };
+        if editing {
+            return match key.as_str() {
+                "Enter" => Some(Self::Commit(tab_shift, 0)),
+                "Tab" => Some(Self::Commit(0, tab_shift)),
+                "Escape" => Some(Self::Cancel),
+                _ => None,
+            }
+        }
+        match key.as_str() {
+            "ArrowUp" => Some(Self::Move(-1, 0, shift)),
+            "ArrowDown" => Some(Self::Move(1, 0, shift)),
+            "ArrowLeft" => Some(Self::Move(0, -1, shift)),
+            "ArrowRight" => Some(Self::Move(0, 1, shift)),
+            "Tab" => Some(Self::Move(0, tab_shift, false)),
+            "Enter" | "F2" => Some(Self::Edit(None)),
+            "Escape" => Some(Self::Cancel),
+            "Delete" | "Backspace" => Some(Self::Clear),
+            "d" | "D" if ctrl => Some(Self::FillDown),
+            "a" | "A" if ctrl => Some(Self::SelectAll),
+            _ if !ctrl && !ev.alt_key() && key.chars().count() == 1 => Some(Self::Edit(Some(key))),
+            _ => None,
+        }
+    }
+}
+
+pub enum Msg {
+    KeyDown(GridKey),
+    Paste(String),
+    CellMouseDown(CellPos, bool),
+    CellMouseEnter(CellPos),
+    MouseUp,
+    StartEdit(CellPos),
+    EditInput(String),
+    CommitEdit,
+    FillDown,
+    Discard,
+    SaveAll,
+    GetSaveResult(String, &'static str),
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for ModificationsGridEdit {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let cells = GridCells::new(&props.modifications);
+        Self {
+            error: None,
+            props,
+            link,
+            cells,
+            selection: Selection::default(),
+            editing: None,
+            dragging: false,
+            pending_requests: 0,
+            save_failed: false,
+            grid_ref: NodeRef::default(),
+            input_ref: NodeRef::default(),
+            need_focus: false,
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.need_focus {
+            return
+        }
+        self.need_focus = false;
+        let element = match self.editing.is_some() {
+            true => self.input_ref.cast::<HtmlInputElement>().map(|input| input.unchecked_into::<HtmlElement>()),
+            false => self.grid_ref.cast::<HtmlElement>(),
+        };
+        if let Some(element) = element {
+            let _ = element.focus();
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::KeyDown(key) => {
+                let cursor = self.selection.cursor;
+                match key {
+                    GridKey::Move(rows, columns, extend) => self.move_cursor(rows, columns, extend),
+                    GridKey::Commit(rows, columns) => {
+                        self.commit_edit();
+                        self.move_cursor(rows, columns, false);
+                    },
+                    GridKey::Edit(value) => {
+                        if self.cells.size().1 == 0 {
+                            return false
+                        }
+                        self.selection = Selection::single(cursor);
+                        self.editing = Some(value.unwrap_or_else(|| self.cells.value(cursor).to_string()));
+                        self.need_focus = true;
+                    },
+                    GridKey::Cancel => {
+                        self.editing = None;
+                        self.selection = Selection::single(cursor);
+                        self.need_focus = true;
+                    },
+                    GridKey::Clear => self.cells.clear(self.selection),
+                    GridKey::FillDown => self.cells.fill_down(self.selection),
+                    GridKey::SelectAll => {
+                        let (rows, columns) = self.cells.size();
+                        self.selection = Selection {
+                            anchor: (0, 0),
+                            cursor: (rows.saturating_sub(1), columns.saturating_sub(1)),
+                        };
+                    },
+                }
+            },
+            Msg::Paste(text) => {
+                debug!("Paste to the grid: {:?}", text);
+                self.editing = None;
+                let (first, _) = self.selection.area();
+                self.selection = self.cells.paste(first, &text);
+                self.need_focus = true;
+            },
+            Msg::CellMouseDown(pos, extend) => {
+                self.commit_edit();
+                match extend {
+                    true => self.selection.cursor = pos,
+                    false => self.selection = Selection::single(pos),
+                }
+                self.dragging = true;
+            },
+            Msg::CellMouseEnter(pos) => {
+                if !self.dragging || self.selection.cursor == pos {
+                    return false
+                }
+                self.selection.cursor = pos;
+            },
+            Msg::MouseUp => {
+                self.dragging = false;
+                return false
+            },
+            Msg::StartEdit(pos) => {
+                self.selection = Selection::single(pos);
+                self.editing = Some(self.cells.value(pos).to_string());
+                self.need_focus = true;
+            },
+            Msg::EditInput(value) => {
+                self.editing = Some(value);
+                return false
+            },
+            Msg::CommitEdit => {
+                if self.editing.is_none() {
+                    return false
+                }
+                self.commit_edit();
+            },
+            Msg::FillDown => self.cells.fill_down(self.selection),
+            Msg::Discard => {
+                self.editing = None;
+                self.cells.discard();
+            },
+            Msg::SaveAll => {
+                self.commit_edit();
+                self.save_failed = false;
+                for changes in self.cells.changes() {
+                    if !changes.put_params.is_empty() {
+                        let ipt_modification_param_data = put_modification_params::IptModificationParamData{
+                            modificationUuid: changes.modification_uuid.clone(),
+                            params: changes.put_params.iter().map(|p| put_modification_params::IptParamData{
+                                paramId: p.param_id as i64,
+                                value: p.value.clone(),
+                            }).collect(),
+                        };
+                        let link = link.clone();
+                        self.pending_requests += 1;
+                        spawn_local(async move {
+                            let res = make_query(PutModificationParams::build_query(
+                                put_modification_params::Variables { ipt_modification_param_data }
+                            )).await.unwrap();
+                            link.send_message(Msg::GetSaveResult(res, "putModificationParams"));
+                        })
+                    }
+                    if !changes.delete_params.is_empty() {
+                        let del_modification_param_data = delete_modification_params::DelModificationParamData{
+                            modificationUuid: changes.modification_uuid.clone(),
+                            paramIds: changes.delete_params.iter().map(|param_id| *param_id as i64).collect(),
+                        };
+                        let link = link.clone();
+                        self.pending_requests += 1;
+                        spawn_local(async move {
+                            let res = make_query(DeleteModificationParams::build_query(
+                                delete_modification_params::Variables { del_modification_param_data }
+                            )).await.unwrap();
+                            link.send_message(Msg::GetSaveResult(res, "deleteModificationParams"));
+                        })
+                    }
+                }
+            },
+            Msg::GetSaveResult(res, key) => {
+                if let Err(err) = resp_parsing::<usize>(res, key) {
+                    self.save_failed = true;
+                    link.send_message(Msg::ResponseError(err));
+                }
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                if self.pending_requests == 0 && !self.save_failed {
+                    // the saved values are received with the modifications
+                    self.props.callback_saved.emit(());
+                }
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let cells = GridCells::new(&props.modifications);
+        self.props = props;
+        if self.cells.same_source(&cells) {
+            return false
+        }
+        // the changes are lost if other modifications are shown
+        self.cells = cells;
+        self.editing = None;
+        let (rows, columns) = self.cells.size();
+        if self.selection.cursor.0 >= rows || self.selection.cursor.1 >= columns {
+            self.selection = Selection::default();
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let editing = self.editing.is_some();
+        let onkeydown = self.link.batch_callback(move |ev: KeyboardEvent| {
+            GridKey::from_event(&ev, editing).map(|key| {
+                ev.prevent_default();
+                Msg::KeyDown(key)
+            })
+        });
+        let onpaste = self.link.batch_callback(move |ev: Event| {
+            let text = clipboard_data(&ev)
+                .and_then(|data| data.get_data("text/plain").ok())
+                .unwrap_or_default();
+            // a single value is pasted into the edited cell as usual
+            if editing && !text.contains(['\t', '\n']) {
+                return None
+            }
+            ev.prevent_default();
+            Some(Msg::Paste(text))
+        });
+        let copy_text = self.cells.copy(self.selection);
+        let oncopy = self.link.batch_callback(move |ev: Event| {
+            if editing {
+                return None
+            }
+            if let Some(data) = clipboard_data(&ev) {
+                if data.set_data("text/plain", &copy_text).is_ok() {
+                    ev.prevent_default();
+                }
+            }
+            None
+        });
+        let onmouseup = self.link.callback(|_| Msg::MouseUp);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            {self.show_grid_toolbar()}
+            <div
+                class="table-container"
+                tabindex="0"
+                style="outline: none;"
+                ref={self.grid_ref.clone()}
+                onkeydown={onkeydown}
+                onpaste={onpaste}
+                oncopy={oncopy}
+                onmouseup={onmouseup}
+                onmouseleave={self.link.callback(|_| Msg::MouseUp)} >
+                <table class="table is-bordered is-narrow" style="user-select: none;">
+                    <thead>
+                        <tr>
+                            <th>{get_value_field(&110)}</th> // Name
+                            {for self.cells.columns.iter().map(|param| html!{<th>{param.paramname.clone()}</th>})}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for self.cells.rows.iter().enumerate().map(|(row, (_, name))| html!{
+                            <tr>
+                                <th>{name.clone()}</th>
+                                {for (0..self.cells.columns.len()).map(|column| self.show_cell((row, column)))}
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+        </>}
+    }
+}
+
+/// Returns the data of the clipboard event (copy or paste)
+fn clipboard_data(ev: &Event) -> Option<DataTransfer> {
+    js_sys::Reflect::get(ev, &"clipboardData".into()).ok()
+        .and_then(|data| data.dyn_into::<DataTransfer>().ok())
+}
+
+impl ModificationsGridEdit {
+    /// Moves the cursor inside the grid, the selection is reset or extended to the cursor
+    fn move_cursor(&mut self, rows: isize, columns: isize, extend: bool) {
+        let (max_rows, max_columns) = self.cells.size();
+        if max_rows == 0 || max_columns == 0 {
+            return
+        }
+        let (row, column) = self.selection.cursor;
+        let cursor = (
+            (row as isize + rows).clamp(0, max_rows as isize - 1) as usize,
+            (column as isize + columns).clamp(0, max_columns as isize - 1) as usize,
+        );
+        match extend {
+            true => self.selection.cursor = cursor,
+            false => self.selection = Selection::single(cursor),
+        }
+    }
+
+    /// Saves the edited value to the cell under the cursor
+    fn commit_edit(&mut self) {
+        if let Some(value) = self.editing.take() {
+            self.cells.set(self.selection.cursor, value);
+            self.need_focus = true;
+        }
+    }
+
+    fn show_grid_toolbar(&self) -> Html {
+        let onclick_fill_down = self.link.callback(|_| Msg::FillDown);
+        let onclick_discard = self.link.callback(|_| Msg::Discard);
+        let onclick_save = self.link.callback(|_| Msg::SaveAll);
+        let dirty_count = self.cells.dirty_count();
+        let invalid_count = self.cells.invalid_count();
+        html!{
+            <div class="is-flex is-justify-content-space-between is-align-items-center mb-2">
+                <div>
+                    <p class="help">{get_value_field(&520)}</p>
+                    <p class="help is-info">{get_value_field(&709)}</p> // Only the modifications of the current page are edited
+                </div>
+                <div class="buttons mb-0">
+                    <span class="tag is-warning is-light mr-2">
+                        {format!("{}: {}", get_value_field(&519), dirty_count)} // Changed cells
+                    </span>
+                    {match invalid_count {
+                        0 => html!{},
+                        _ => html!{
+                            <span class="tag is-danger is-light mr-2">
+                                {format!("{}: {}", get_value_field(&564), invalid_count)} // Invalid values
+                            </span>
+                        },
+                    }}
+                    <button id="grid-fill-down" class="button is-small" onclick={onclick_fill_down}>
+                        <span class="icon"><i class="fas fa-arrow-down" aria-hidden="true"></i></span>
+                        <span>{get_value_field(&517)}</span> // Fill down
+                    </button>
+                    <button id="grid-discard" class="button is-small" disabled={dirty_count == 0} onclick={onclick_discard}>
+                        <span class="icon"><i class="fas fa-undo" aria-hidden="true"></i></span>
+                        <span>{get_value_field(&518)}</span> // Discard changes
+                    </button>
+                    <button
+                        id="grid-save-all"
+                        class={match self.pending_requests {
+                            0 => "button is-small is-success",
+                            _ => "button is-small is-success is-loading",
+                        }}
+                        disabled={dirty_count == 0 || invalid_count > 0 || self.pending_requests > 0}
+                        onclick={onclick_save} >
+                        <span class="icon"><i class="fas fa-save" aria-hidden="true"></i></span>
+                        <span>{get_value_field(&516)}</span> // Save all
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn show_cell(&self, pos: CellPos) -> Html {
+        let onmousedown = self.link.callback(move |ev: MouseEvent| Msg::CellMouseDown(pos, ev.shift_key()));
+        let onmouseenter = self.link.callback(move |_| Msg::CellMouseEnter(pos));
+        let ondblclick = self.link.callback(move |_| Msg::StartEdit(pos));
+        let cursor = self.selection.cursor == pos;
+        let mut style = String::from("min-width: 6em; white-space: pre-wrap; cursor: cell;");
+        if cursor {
+            style.push_str(" outline: 2px solid #1872f0; outline-offset: -2px;");
+        }
+        let value_error = self.cells.value_error(pos);
+        let class = match (self.selection.contains(pos), value_error.is_some(), self.cells.is_dirty(pos)) {
+            (true, _, _) => "has-background-info-light",
+            (false, true, _) => "has-background-danger-light",
+            (false, false, true) => "has-background-warning-light",
+            (false, false, false) => "",
+        };
+        match (cursor, &self.editing) {
+            (true, Some(value)) => {
+                let oninput = self.link.callback(|ev: InputData| Msg::EditInput(ev.value));
+                let onblur = self.link.callback(|_| Msg::CommitEdit);
+                html!{
+                    <td class={class} style={style}>
+                        <input
+                            class="input is-small"
+                            type="text"
+                            ref={self.input_ref.clone()}
+                            value={value.clone()}
+                            oninput={oninput}
+                            onblur={onblur} />
+                    </td>
+                }
+            },
+            _ => html!{
+                <td
+                    class={class}
+                    style={style}
+                    title={match (value_error, self.cells.is_dirty(pos)) {
+                        (Some(err), _) => err.message(),
+                        (None, true) => get_value_field(&519).to_string(), // Changed cells
+                        (None, false) => String::new(),
+                    }}
+                    onmousedown={onmousedown}
+                    onmouseenter={onmouseenter}
+                    ondblclick={ondblclick} >
+                    {self.cells.value(pos)}
+                </td>
+            },
+        }
+    }
+}
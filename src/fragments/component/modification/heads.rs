@@ -1,7 +1,7 @@
-use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
 use log::debug;
 use crate::types::{UUID, Param};
-use crate::services::get_value_field;
+use crate::services::{get_value_field, paramname_in_system, SortColumn, UnitSystem, NAME_COLUMN};
 use crate::services::content_adapter::Markdownable;
 
 #[derive(Clone, Debug, Properties)]
@@ -9,6 +9,16 @@ pub struct Props {
     pub show_new_column: bool,
     pub component_uuid: UUID,
     pub params: Vec<Param>,
+    #[prop_or_default]
+    pub show_check_column: bool,
+    /// Current sorting of the table, heads are clickable if the callback is set
+    #[prop_or_default]
+    pub sort: Option<SortColumn>,
+    #[prop_or_default]
+    pub callback_sort: Option<Callback<usize>>,
+    /// Units of the parameters are shown in the units of the system
+    #[prop_or_default]
+    pub unit_system: Option<UnitSystem>,
 }
 
 pub struct ModificationTableHeads {
@@ -28,7 +38,11 @@ impl Component for ModificationTableHeads {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         debug!("change self.props.params, old: {:?}, new: {:?}", self.props.params, props.params);
-        if self.props.component_uuid == props.component_uuid && self.props.params == props.params {
+        if self.props.component_uuid == props.component_uuid &&
+            self.props.params == props.params &&
+            self.props.show_check_column == props.show_check_column &&
+            self.props.sort == props.sort &&
+            self.props.unit_system == props.unit_system {
             false
         } else {
             self.props = props;
@@ -44,11 +58,16 @@ impl Component for ModificationTableHeads {
 impl ModificationTableHeads {
     fn show_modification_head(&self) -> Html {
         html!{<>
+            {match self.props.show_check_column {
+                true => html!{<th title={get_value_field(&542)}><i class="fas fa-check-square"></i></th>}, // Compare parameters
+                false => html!{},
+            }}
             <th>{get_value_field(&111)}</th>
             <th>{"\u{2116}"}</th> // Numero sign №
-            <th>{get_value_field(&110)}</th> // Name
+            {self.show_sortable_head(NAME_COLUMN, get_value_field(&110), html!{get_value_field(&110)})} // Name
             {for self.props.params.iter().map(|head| {
-                html!{<th title={get_value_field(&210)}>{head.paramname.to_markdown()}</th>}
+                let paramname = paramname_in_system(&head.paramname, self.props.unit_system);
+                self.show_sortable_head(head.param_id, get_value_field(&210), paramname.to_markdown())
             })}
             {match self.props.show_new_column {
                 true => html!{<th title={get_value_field(&130)}>{get_value_field(&117)}</th>}, // add
@@ -56,4 +75,22 @@ impl ModificationTableHeads {
             }}
         </>}
     }
+
+    fn show_sortable_head(&self, param_id: usize, title: &str, head: Html) -> Html {
+        let callback_sort = match &self.props.callback_sort {
+            Some(callback_sort) => callback_sort.reform(move |_| param_id),
+            None => return html!{<th title={title.to_string()}>{head}</th>},
+        };
+        let icon = match self.props.sort {
+            Some(SortColumn { param_id: id, desc: false }) if id == param_id => "fas fa-sort-up",
+            Some(SortColumn { param_id: id, desc: true }) if id == param_id => "fas fa-sort-down",
+            _ => "fas fa-sort has-text-grey-lighter",
+        };
+        html!{
+            <th class="is-clickable" title={get_value_field(&549)} onclick={callback_sort}> // Sort by the column
+                {head}
+                <span class="icon is-small ml-1"><i class={icon}></i></span>
+            </th>
+        }
+    }
 }
@@ -0,0 +1,136 @@
+use crate::services::get_value_field;
+use super::ModificationValueColumn;
+
+/// Purpose of a column of the imported table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnMapping {
+    Skip,
+    Value(ModificationValueColumn),
+    Param,
+}
+
+impl ColumnMapping {
+    /// Options of the select element for the column with the number
+    pub(crate) fn options(number: usize) -> [Self; 5] {
+        [
+            Self::Skip,
+            Self::Value(ModificationValueColumn::Name(number)),
+            Self::Value(ModificationValueColumn::Description(number)),
+            Self::Value(ModificationValueColumn::ActualStatusId(number)),
+            Self::Param,
+        ]
+    }
+
+    /// Returns the value for the option of the select element
+    pub(crate) fn to_value(self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::Value(ModificationValueColumn::Name(_)) => "name",
+            Self::Value(ModificationValueColumn::Description(_)) => "description",
+            Self::Value(ModificationValueColumn::ActualStatusId(_)) => "status",
+            Self::Param => "param",
+        }
+    }
+
+    /// Returns the purpose of the column with the number from the value of the select element
+    pub(crate) fn from_value(value: &str, number: usize) -> Self {
+        Self::options(number).iter().copied().find(|mapping| mapping.to_value() == value).unwrap_or(Self::Skip)
+    }
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::Skip => get_value_field(&501),
+            Self::Value(ModificationValueColumn::Name(_)) => get_value_field(&176),
+            Self::Value(ModificationValueColumn::Description(_)) => get_value_field(&61),
+            Self::Value(ModificationValueColumn::ActualStatusId(_)) => get_value_field(&502),
+            Self::Param => get_value_field(&178),
+        }
+    }
+}
+
+/// Returns the purpose of the columns by the headers: keywords set the modification data,
+/// other headers set the parameters, columns without a header are skipped.
+/// If the headers have no keywords, the first column with a header contains the names of modifications.
+pub(crate) fn default_mapping(headers: &[Option<String>], columns: usize) -> Vec<ColumnMapping> {
+    let mut mapping = (0..columns).map(|number| {
+        match headers.get(number).and_then(|h| h.as_deref()).map(str::trim) {
+            None | Some("") => ColumnMapping::Skip,
+            Some(header) => ModificationValueColumn::from_keyword(header, number)
+                .map(ColumnMapping::Value)
+                .unwrap_or(ColumnMapping::Param),
+        }
+    }).collect::<Vec<ColumnMapping>>();
+    if !mapping.iter().any(|m| matches!(m, ColumnMapping::Value(_))) {
+        if let Some((number, m)) = mapping.iter_mut().enumerate().find(|(_, m)| **m == ColumnMapping::Param) {
+            *m = ColumnMapping::Value(ModificationValueColumn::Name(number));
+        }
+    }
+    mapping
+}
+
+/// Adds the columns of the parameter template to the rows of the table.
+/// The empty table gets the header of the template, otherwise the header and the values are kept
+/// and the parameters missing in the header are added after the last column of the table.
+pub(crate) fn apply_template(rows: &mut Vec<Vec<String>>, paramnames: Vec<String>) {
+    if rows.iter().all(|row| row.iter().all(|cell| cell.trim().is_empty())) {
+        let mut header = vec!["[ModificationName]".to_string(), "[ModificationDescription]".to_string()];
+        header.extend(paramnames);
+        *rows = vec![header];
+        return
+    }
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+    let header = &mut rows[0];
+    // the values without a header do not get the names of the template
+    header.resize(columns, String::new());
+    for paramname in paramnames {
+        if !header.iter().any(|h| h.trim() == paramname.trim()) {
+            header.push(paramname);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn mapping_by_headers() {
+        let headers = vec![
+            Some(String::from("Length")),
+            Some(String::from("[ModificationName]")),
+            None,
+            Some(String::from("[ModificationActualStatusId]")),
+        ];
+        assert_eq!(
+            vec![
+                ColumnMapping::Param,
+                ColumnMapping::Value(ModificationValueColumn::Name(1)),
+                ColumnMapping::Skip,
+                ColumnMapping::Value(ModificationValueColumn::ActualStatusId(3)),
+                ColumnMapping::Skip,
+            ],
+            default_mapping(&headers, 5)
+        );
+
+        let headers = vec![None, Some(String::from("Designation")), Some(String::from("Length"))];
+        assert_eq!(
+            vec![ColumnMapping::Skip, ColumnMapping::Value(ModificationValueColumn::Name(1)), ColumnMapping::Param],
+            default_mapping(&headers, 3)
+        );
+        assert_eq!(ColumnMapping::Value(ModificationValueColumn::Description(2)), ColumnMapping::from_value("description", 2));
+    }
+    #[test]
+    fn template_columns() {
+        let mut rows = Vec::new();
+        apply_template(&mut rows, vec![String::from("Length")]);
+        assert_eq!(vec![vec!["[ModificationName]", "[ModificationDescription]", "Length"]], rows);
+
+        let mut rows = vec![
+            vec![String::from("Designation"), String::from("Material")],
+            vec![String::from("M8"), String::from("steel"), String::from("x")],
+        ];
+        apply_template(&mut rows, vec![String::from("Length"), String::from("Material ")]);
+        assert_eq!(vec!["Designation", "Material", "", "Length"], rows[0]);
+        assert_eq!(vec!["M8", "steel", "x"], rows[1]);
+    }
+}
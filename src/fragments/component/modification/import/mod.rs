@@ -1,26 +1,40 @@
 mod parser_raw;
+mod mapping;
 
 use std::collections::BTreeMap;
 use graphql_client::GraphQLQuery;
 use wasm_bindgen_futures::spawn_local;
 
-use parser_raw::parsing_text;
-use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use parser_raw::{parsing_text, parsing_line};
+use mapping::{ColumnMapping, apply_template, default_mapping};
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use yew::services::reader::{File, FileData, ReaderService, ReaderTask};
 use log::debug;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::ft_import_btn;
 use crate::fragments::notification::show_notification;
-use crate::types::{NewModificationsPreData, Param, ParamValue, UUID};
-use crate::services::{get_value_field, resp_parsing};
+use crate::fragments::component::{ParamTemplates, request_all_modifications};
+use crate::types::{ComponentModificationInfo, NewModificationsPreData, Param, ParamValue, Spec, UUID};
+use crate::services::{
+    get_value_field, resp_parsing, decode_text, is_spreadsheet_file, parsing_delimited, read_spreadsheet,
+    write_delimited, ParsingSplit, Sheet, TextEncoding,
+};
 use crate::services::content_adapter::Markdownable;
 use crate::gqls::make_query;
 use crate::gqls::relate::{RegisterParamsBulk, register_params_bulk};
-use crate::gqls::component::{RegisterComponentModificationsBulk, register_component_modifications_bulk};
+use crate::gqls::component::{
+    RegisterComponentModificationsBulk, register_component_modifications_bulk,
+    PutModificationParams, put_modification_params,
+    PutComponentModificationUpdate, put_component_modification_update,
+};
 
 #[derive(Clone, Debug, Properties)]
 pub struct Props {
     pub component_uuid: UUID,
+    /// Specs of the component for the choice of the parameter templates
+    #[prop_or_default]
+    pub component_specs: Vec<Spec>,
     pub callback_finish_import: Callback<()>,
 }
 
@@ -33,11 +47,37 @@ pub struct ImportModificationsData {
     value_column: Vec<ModificationValueColumn>,
     paramnames: Vec<String>,
     new_modifications: Vec<NewModificationsPreData>,
+    /// Rows of the existing modifications (if they are not skipped) with their UUIDs
+    updated_modifications: Vec<(UUID, NewModificationsPreData)>,
+    /// Requests of the import that have not been answered yet and the number of registered modifications
+    pending_requests: usize,
+    registered_count: usize,
     params_with_ids: BTreeMap<usize, (String, usize)>,
     stat_info: String,
+    /// Separator selected by the user, None for the auto detection
+    split: Option<ParsingSplit>,
+    file_encoding: Option<TextEncoding>,
+    reader: Option<ReaderTask>,
+    /// Name of the loaded XLSX or ODS file and its sheets
+    file_name: String,
+    sheets: Vec<Sheet>,
+    sheet_index: usize,
+    /// Rows of the text or the selected sheet, the header row is counted from 0
+    table: Vec<Vec<Option<String>>>,
+    header_row: usize,
+    column_mapping: Vec<ColumnMapping>,
+    /// Modifications of the component to compare with the imported rows, None until received
+    existing_modifications: Option<Vec<ComponentModificationInfo>>,
+    skip_existing: bool,
+    step: ImportStep,
 }
 
-#[derive(Debug)]
+/// Number of rows shown when the columns are selected
+const PREVIEW_ROWS: usize = 10;
+/// Number of rows shown in the comparison with the existing modifications
+const DIFF_ROWS: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ModificationValueColumn {
     // Uuid(usize),
     Name(usize),
@@ -45,17 +85,54 @@ pub enum ModificationValueColumn {
     ActualStatusId(usize),
 }
 
+impl ModificationValueColumn {
+    /// Recognizes the keyword in the header of the column with the number
+    pub(crate) fn from_keyword(keyword: &str, number: usize) -> Option<Self> {
+        match keyword {
+            // "[ModificationUuid]" => Some(Self::Uuid(number)),
+            "[ModificationName]" => Some(Self::Name(number)),
+            "[ModificationDescription]" => Some(Self::Description(number)),
+            "[ModificationActualStatusId]" => Some(Self::ActualStatusId(number)),
+            // todo!(обработка [ID:ЦИФРА])
+            _ => None,
+        }
+    }
+}
+
+/// Steps of the import: the source data, the purpose of the columns, the changes before the import
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ImportStep {
+    Source,
+    Mapping,
+    Preview,
+}
+
 pub enum Msg {
     RequestRegisterParams,
     GetRegisterParamsResult(String),
     RequestRegisterModifications,
     GetRegisterModificationsResult(String),
+    GetUpdateModificationResult(String, &'static str),
+    RequestComponentModifications,
+    GetComponentModificationsResult(Result<Vec<ComponentModificationInfo>, Error>),
     ResponseError(Error),
     ShowImport,
     UpdateData(String),
+    ApplyTemplate(Vec<Param>),
+    SelectSplit(Option<ParsingSplit>),
+    UploadFile(File),
+    GetFileData(FileData),
+    ShowMapping,
+    SelectSheet(usize),
+    SetHeaderRow(usize),
+    SetColumnMapping(usize, ColumnMapping),
+    ShowPreview,
+    SkipExisting,
+    Back,
     Parsing,
     PreparingImport,
     ClearError,
+    Ignore,
 }
 
 impl Component for ImportModificationsData {
@@ -71,8 +148,23 @@ impl Component for ImportModificationsData {
             value_column: Vec::new(),
             paramnames: Vec::new(),
             new_modifications: Vec::new(),
+            updated_modifications: Vec::new(),
+            pending_requests: 0,
+            registered_count: 0,
             params_with_ids: BTreeMap::new(),
             stat_info: String::new(),
+            split: None,
+            file_encoding: None,
+            reader: None,
+            file_name: String::new(),
+            sheets: Vec::new(),
+            sheet_index: 0,
+            table: Vec::new(),
+            header_row: 0,
+            column_mapping: Vec::new(),
+            existing_modifications: None,
+            skip_existing: true,
+            step: ImportStep::Source,
         }
     }
 
@@ -81,7 +173,7 @@ impl Component for ImportModificationsData {
         match msg {
             Msg::RequestRegisterParams => {
                 let mut ipt_params_translate_list_data = Vec::new();
-                for p in &self.paramnames {
+                for p in self.paramnames.iter().filter(|p| !p.is_empty()) {
                     ipt_params_translate_list_data.push(
                         register_params_bulk::IptParamTranslateListData{
                             langId: 1, // todo!(fix for different lang)
@@ -101,11 +193,11 @@ impl Component for ImportModificationsData {
                     Ok(result) => {
                         self.params_with_ids.clear();
                         for (number, p) in self.paramnames.iter().enumerate() {
-                            if &result[number].paramname == p {
-                                self.params_with_ids.insert(
-                                    number,
-                                    (result[number].paramname.clone(), result[number].param_id)
-                                );
+                            if p.is_empty() {
+                                continue;
+                            }
+                            if let Some(r) = result.get(number).filter(|r| &r.paramname == p) {
+                                self.params_with_ids.insert(number, (r.paramname.clone(), r.param_id));
                                 continue;
                             }
                             debug!("Parameter named {} not found by index", p);
@@ -125,6 +217,16 @@ impl Component for ImportModificationsData {
                 }
             },
             Msg::RequestRegisterModifications => {
+                self.error = None;
+                self.pending_requests = 0;
+                self.registered_count = 0;
+                self.request_update_modifications();
+                if self.new_modifications.is_empty() {
+                    if self.pending_requests == 0 {
+                        self.finish_import();
+                    }
+                    return true
+                }
                 let mut modifications_data= Vec::new();
                 for nm in &self.new_modifications {
                     let mut ipt_param_data = Vec::new();
@@ -147,6 +249,7 @@ impl Component for ImportModificationsData {
                     componentUuid: self.props.component_uuid.clone(),
                     modificationsData: modifications_data,
                 };
+                self.pending_requests += 1;
                 spawn_local(async move {
                     let res = make_query(RegisterComponentModificationsBulk::build_query(
                         register_component_modifications_bulk::Variables { ipt_multiple_modifications_data }
@@ -156,12 +259,26 @@ impl Component for ImportModificationsData {
             },
             Msg::GetRegisterModificationsResult(res) => {
                 match resp_parsing::<Vec<UUID>>(res, "registerComponentModificationsBulk") {
-                    Ok(result) => {
-                        self.new_modifications_raw.clear();
-                        self.stat_info = format!("{} {}", get_value_field(&213), result.len());
-                        self.hide_import = true;
-                        self.props.callback_finish_import.emit(());
-                    },
+                    Ok(result) => self.registered_count = result.len(),
+                    Err(err) => self.error = Some(err),
+                }
+                self.finish_request();
+            },
+            Msg::GetUpdateModificationResult(res, key) => {
+                if let Err(err) = resp_parsing::<usize>(res, key) {
+                    self.error = Some(err);
+                }
+                self.finish_request();
+            },
+            Msg::RequestComponentModifications => {
+                let component_uuid = self.props.component_uuid.clone();
+                spawn_local(async move {
+                    link.send_message(Msg::GetComponentModificationsResult(request_all_modifications(component_uuid).await));
+                })
+            },
+            Msg::GetComponentModificationsResult(result) => {
+                match result {
+                    Ok(result) => self.existing_modifications = Some(result),
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
@@ -169,49 +286,100 @@ impl Component for ImportModificationsData {
             Msg::ShowImport => {
                 self.stat_info.clear();
                 self.hide_import = !self.hide_import;
+                self.step = ImportStep::Source;
             },
             Msg::UpdateData(value) => {
                 self.new_modifications_raw = value;
-                if self.new_modifications_raw.is_empty() {
-                    self.stat_info.clear();
-                    return true
-                }
-                let mut header_count = 1;
-                let mut row_count = 0;
-                let mut processing_head = true;
-                for (_n, c) in self.new_modifications_raw.char_indices() {
-                    if c == '\t' && processing_head {
-                        header_count += 1;
-                    }
-                    if c == '\n' {
-                        processing_head = false;
-                        row_count += 1;
+                self.file_encoding = None;
+                self.clear_file();
+                self.update_stat_info();
+            },
+            Msg::ApplyTemplate(params) => {
+                let split = self.parsing_split();
+                let mut rows = parsing_delimited(&self.new_modifications_raw, split);
+                apply_template(&mut rows, params.into_iter().map(|p| p.paramname).collect());
+                self.new_modifications_raw = write_delimited(&rows, split);
+                self.file_encoding = None;
+                self.clear_file();
+                self.update_stat_info();
+            },
+            Msg::SelectSplit(split) => {
+                self.split = split;
+                self.update_stat_info();
+            },
+            Msg::UploadFile(file) => {
+                let callback = self.link.callback(Msg::GetFileData);
+                self.reader = ReaderService::read_file(file, callback).ok();
+                return false
+            },
+            Msg::GetFileData(data) => {
+                self.reader = None;
+                self.clear_file();
+                if is_spreadsheet_file(&data.name) {
+                    match read_spreadsheet(&data.name, &data.content) {
+                        Ok(sheets) => {
+                            debug!("File {:?} has sheets: {:?}", data.name, sheets.iter().map(|s| &s.name).collect::<Vec<_>>());
+                            self.new_modifications_raw.clear();
+                            self.file_encoding = None;
+                            self.file_name = data.name;
+                            self.sheets = sheets;
+                        },
+                        Err(err) => self.error = Some(Error::BadRequest(format!("{}: {}", get_value_field(&509), err))),
                     }
+                } else {
+                    let (text, encoding) = decode_text(&data.content);
+                    debug!("File {:?} decoded as {:?}", data.name, encoding);
+                    self.new_modifications_raw = text;
+                    self.file_encoding = Some(encoding);
                 }
-                self.stat_info = format!("{}: {}, {}: {}", get_value_field(&345), header_count, get_value_field(&346), row_count);
+                self.update_stat_info();
             },
-            Msg::Parsing => {
-                debug!("Test data: {}", self.new_modifications_raw);
-                let (headers, _) = parsing_text(&self.new_modifications_raw, true);
-                self.paramnames.clear();
-                self.value_column.clear();
-                // parse the first line into parameter names
-                for (number, h) in headers.into_iter().enumerate() {
-                    // keyword research
-                    match h {
-                        // Some("[ModificationUuid]") => self.value_column.push(ModificationValueColumn::Uuid(number)),
-                        Some("[ModificationName]") => self.value_column.push(ModificationValueColumn::Name(number)),
-                        Some("[ModificationDescription]") => self.value_column.push(ModificationValueColumn::Description(number)),
-                        Some("[ModificationActualStatusId]") => self.value_column.push(ModificationValueColumn::ActualStatusId(number)),
-                        Some(value) => {
-                            // todo!(обработка [ID:ЦИФРА])
-                            self.paramnames.push(value.to_string());
-                            continue;
-                        },
-                        None => (),
+            Msg::ShowMapping => {
+                self.set_table();
+                self.step = ImportStep::Mapping;
+            },
+            Msg::SelectSheet(index) => {
+                self.sheet_index = index;
+                self.set_table();
+                self.update_stat_info();
+            },
+            Msg::SetHeaderRow(row) => {
+                self.header_row = row.min(self.table.len().saturating_sub(1));
+                self.set_mapping();
+            },
+            Msg::SetColumnMapping(number, mapping) => {
+                if let Some(m) = self.column_mapping.get_mut(number) {
+                    *m = mapping;
+                }
+                // the modification data is taken from only one column
+                if let ColumnMapping::Value(_) = mapping {
+                    for (n, m) in self.column_mapping.iter_mut().enumerate() {
+                        if n != number && m.to_value() == mapping.to_value() {
+                            *m = ColumnMapping::Skip;
+                        }
                     }
-                    self.paramnames.push(String::new());
                 }
+            },
+            Msg::ShowPreview => {
+                self.existing_modifications = None;
+                self.step = ImportStep::Preview;
+                link.send_message(Msg::RequestComponentModifications);
+            },
+            Msg::SkipExisting => self.skip_existing = !self.skip_existing,
+            Msg::Back => self.step = match self.step {
+                ImportStep::Preview => ImportStep::Mapping,
+                _ => ImportStep::Source,
+            },
+            Msg::Parsing => {
+                let headers = self.headers();
+                self.paramnames = self.column_mapping.iter().enumerate().map(|(number, mapping)| match mapping {
+                    ColumnMapping::Param => headers.get(number).cloned().flatten().unwrap_or_default(),
+                    _ => String::new(),
+                }).collect();
+                self.value_column = self.column_mapping.iter().filter_map(|mapping| match mapping {
+                    ColumnMapping::Value(value) => Some(*value),
+                    _ => None,
+                }).collect();
                 debug!("Complete self.paramnames: {:?}", self.paramnames);
                 debug!("Complete self.value_column: {:?}", self.value_column);
                 link.send_message(Msg::RequestRegisterParams);
@@ -230,27 +398,30 @@ impl Component for ImportModificationsData {
                         ModificationValueColumn::ActualStatusId(number) => column_actual_status_id = Some(*number),
                     }
                 }
-                let (headers, values) = parsing_text(&self.new_modifications_raw, false);
-                debug!("Preparing parameters, headers {:?}, values {:?}", headers, values);
-                for row in values {
+                let mut new_modifications = Vec::new();
+                let mut updated_modifications = Vec::new();
+                for row in self.data_rows().filter(|row| self.is_imported(row)) {
                     let mut new_modification = NewModificationsPreData::new();
                     let mut params = Vec::new();
-                    for (number, value) in row.into_iter().enumerate() {
+                    for (number, value) in row.iter().enumerate() {
                         debug!("Preparing parameters, nuber {:?}, parameter {:?}", number, value);
                         if Some(number) == column_name {
-                            new_modification.modification_name = value.map(|s| s.to_string()).unwrap_or_default();
+                            new_modification.modification_name = value.clone().unwrap_or_default();
                             continue;
                         }
                         if Some(number) == column_description {
-                            new_modification.description = value.map(|s| s.to_string()).unwrap_or_default();
+                            new_modification.description = value.clone().unwrap_or_default();
                             continue;
                         }
                         if Some(number) == column_actual_status_id {
-                            new_modification.actual_status_id = value
-                                .map(|s| s.parse::<usize>().unwrap_or(1))
+                            new_modification.actual_status_id = value.as_ref()
+                                .map(|s| s.trim().parse::<usize>().unwrap_or(1))
                                 .unwrap_or(1);
                             continue;
                         }
+                        if self.column_mapping.get(number) != Some(&ColumnMapping::Param) {
+                            continue;
+                        }
                         match (value, self.params_with_ids.get(&number)) {
                             (Some(v), Some((paramname, param_id))) => {
                                 if param_id == &0 && v.is_empty() {
@@ -258,18 +429,25 @@ impl Component for ImportModificationsData {
                                     continue;
                                 }
                                 debug!("Add new param: paramname {:?}, param_id {:?}, value {:?}", paramname, param_id, v);
-                                params.push(ParamValue{ param_id: *param_id, value: v.to_string() });
+                                params.push(ParamValue{ param_id: *param_id, value: v.clone() });
                             },
                             _ => debug!("Parameter name or value not found"),
                         }
                     }
                     new_modification.params = params;
-                    // add a new modification with parameters for registration
-                    self.new_modifications.push(new_modification);
+                    match self.existing_modification(row) {
+                        // the parameters of the existing modification are updated instead of a new registration
+                        Some(existing) => updated_modifications.push((existing.uuid.clone(), new_modification)),
+                        // add a new modification with parameters for registration
+                        None => new_modifications.push(new_modification),
+                    }
                 }
+                self.new_modifications = new_modifications;
+                self.updated_modifications = updated_modifications;
                 link.send_message(Msg::RequestRegisterModifications);
             },
             Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
         }
         true
     }
@@ -298,10 +476,177 @@ impl Component for ImportModificationsData {
 }
 
 impl ImportModificationsData {
+    /// Separator selected by the user or detected by the data
+    fn parsing_split(&self) -> ParsingSplit {
+        self.split.unwrap_or_else(|| ParsingSplit::detect(&self.new_modifications_raw))
+    }
+
+    fn clear_file(&mut self) {
+        self.file_name.clear();
+        self.sheets.clear();
+        self.sheet_index = 0;
+    }
+
+    fn update_stat_info(&mut self) {
+        if let Some(sheet) = self.sheets.get(self.sheet_index) {
+            self.stat_info = format!(
+                "{}, {}: {}, {}: {}",
+                self.file_name,
+                get_value_field(&498), sheet.name,
+                get_value_field(&346), sheet.rows.len()
+            );
+            return
+        }
+        if self.new_modifications_raw.is_empty() {
+            self.stat_info.clear();
+            return
+        }
+        let (headers, values) = parsing_text(&self.new_modifications_raw, false, self.parsing_split());
+        self.stat_info = format!("{}: {}, {}: {}", get_value_field(&345), headers.len(), get_value_field(&346), values.len());
+        if let Some(encoding) = self.file_encoding {
+            self.stat_info.push_str(&format!(", {}: {}", get_value_field(&496), encoding.label()));
+        }
+    }
+
+    /// Fills the table with the rows of the selected sheet or the text and resets the purpose of the columns
+    fn set_table(&mut self) {
+        self.table = match self.sheets.get(self.sheet_index) {
+            Some(sheet) => sheet.rows.iter().cloned().map(parsing_line).collect(),
+            None => parsing_delimited(&self.new_modifications_raw, self.parsing_split())
+                .into_iter()
+                .map(parsing_line)
+                .collect(),
+        };
+        self.header_row = 0;
+        self.set_mapping();
+    }
+
+    fn set_mapping(&mut self) {
+        let columns = self.table.iter().skip(self.header_row).map(|row| row.len()).max().unwrap_or_default();
+        self.column_mapping = default_mapping(&self.headers(), columns);
+    }
+
+    fn headers(&self) -> Vec<Option<String>> {
+        self.table.get(self.header_row).cloned().unwrap_or_default()
+    }
+
+    /// Rows of the table after the header row
+    fn data_rows(&self) -> impl Iterator<Item = &Vec<Option<String>>> {
+        self.table.iter().skip(self.header_row + 1)
+    }
+
+    fn name_column(&self) -> Option<usize> {
+        self.column_mapping.iter().find_map(|mapping| match mapping {
+            ColumnMapping::Value(ModificationValueColumn::Name(number)) => Some(*number),
+            _ => None,
+        })
+    }
+
+    fn row_name<'a>(&self, row: &'a [Option<String>]) -> Option<&'a str> {
+        self.name_column()
+            .and_then(|number| row.get(number))
+            .and_then(|value| value.as_deref())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+
+    fn existing_modification(&self, row: &[Option<String>]) -> Option<&ComponentModificationInfo> {
+        let name = self.row_name(row)?;
+        self.existing_modifications.as_ref()?.iter().find(|m| m.modification_name.trim() == name)
+    }
+
+    /// Sends the parameters and the description of the existing modifications,
+    /// the status is not changed by the import because a status change requires a note
+    fn request_update_modifications(&mut self) {
+        let update_description = self.value_column.iter()
+            .any(|column| matches!(column, ModificationValueColumn::Description(_)));
+        for (modification_uuid, data) in &self.updated_modifications {
+            if !data.params.is_empty() {
+                let ipt_modification_param_data = put_modification_params::IptModificationParamData{
+                    modificationUuid: modification_uuid.clone(),
+                    params: data.params.iter().map(|p| put_modification_params::IptParamData{
+                        paramId: p.param_id as i64,
+                        value: p.value.clone(),
+                    }).collect(),
+                };
+                let link = self.link.clone();
+                self.pending_requests += 1;
+                spawn_local(async move {
+                    let res = make_query(PutModificationParams::build_query(
+                        put_modification_params::Variables { ipt_modification_param_data }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetUpdateModificationResult(res, "putModificationParams"));
+                })
+            }
+            if update_description {
+                let modification_uuid = modification_uuid.clone();
+                let ipt_update_component_modification_data = put_component_modification_update::IptUpdateComponentModificationData{
+                    modificationName: None,
+                    description: Some(data.description.clone()),
+                    actualStatusId: None,
+                };
+                let link = self.link.clone();
+                self.pending_requests += 1;
+                spawn_local(async move {
+                    let res = make_query(PutComponentModificationUpdate::build_query(
+                        put_component_modification_update::Variables {
+                            modification_uuid,
+                            ipt_update_component_modification_data
+                        }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetUpdateModificationResult(res, "putComponentModificationUpdate"));
+                })
+            }
+        }
+    }
+
+    /// Closes the import after the answers to all requests, the import stays open if there are errors
+    fn finish_request(&mut self) {
+        self.pending_requests = self.pending_requests.saturating_sub(1);
+        if self.pending_requests == 0 && self.error.is_none() {
+            self.finish_import();
+        }
+    }
+
+    fn finish_import(&mut self) {
+        self.new_modifications_raw.clear();
+        self.clear_file();
+        self.stat_info = format!("{} {}", get_value_field(&213), self.registered_count);
+        if !self.updated_modifications.is_empty() {
+            self.stat_info.push_str(&format!(", {} {}", get_value_field(&706), self.updated_modifications.len()));
+        }
+        self.hide_import = true;
+        self.step = ImportStep::Source;
+        self.props.callback_finish_import.emit(());
+    }
+
+    /// Rows without a name are not imported, as well as the rows of existing modifications if they are skipped
+    fn is_imported(&self, row: &[Option<String>]) -> bool {
+        self.row_name(row).is_some() && !(self.skip_existing && self.existing_modification(row).is_some())
+    }
+
+    /// Returns the current value of the existing modification if the value of the cell differs from it
+    /// and will be updated (the status of the existing modification is not changed by the import)
+    fn changed_value(&self, existing: &ComponentModificationInfo, number: usize, value: &str, header: &str) -> Option<String> {
+        if self.skip_existing {
+            return None
+        }
+        let current = match self.column_mapping.get(number)? {
+            ColumnMapping::Value(ModificationValueColumn::Description(_)) => existing.description.clone(),
+            ColumnMapping::Param => existing.modification_params.iter()
+                .find(|p| p.param.paramname == header)
+                .map(|p| p.value.clone())
+                .unwrap_or_default(),
+            _ => return None,
+        };
+        match current.trim() == value.trim() {
+            true => None,
+            false => Some(current),
+        }
+    }
+
     fn show_import_modal(&self) -> Html {
         let onclick_hide_modal = self.link.callback(|_| Msg::ShowImport);
-        let oninput_data = self.link.callback(|ev: InputData| Msg::UpdateData(ev.value));
-        let onclick_subbmit = self.link.callback(|_| Msg::Parsing);
         let class_modal = match &self.hide_import {
             true => "modal",
             false => "modal is-active",
@@ -309,41 +654,325 @@ impl ImportModificationsData {
         html!{
             <div class={class_modal}>
               <div class={"modal-background"} onclick={onclick_hide_modal.clone()} />
-                <div class={"modal-content"}>
+                <div class={"modal-content"} style={match self.step {
+                    ImportStep::Source => "",
+                    _ => "width: 90vw;",
+                }}>
                   <div class={"card"}>
                     <header class={"modal-card-head"}>
                       <p class={"modal-card-title"}>{get_value_field(&342)}</p>
                       <button class={"delete"} aria-label="close" onclick={onclick_hide_modal.clone()} />
                     </header>
                     <section class={"modal-card-body"}>
-                        <div class={"column"}>
-                            <div class={"subtitle is-6"}>
-                                {get_value_field(&234)}<br/>
-                                {get_value_field(&343).to_markdown()}
-                            </div>
-                            <textarea
-                                id={"update-description"}
-                                class={"textarea"}
-                                type={"text"}
-                                placeholder={format!("{}\n{}", get_value_field(&208), get_value_field(&344))}
-                                value={self.new_modifications_raw.clone()}
-                                oninput={oninput_data}
-                                />
-                            <p class={"help"}>{self.stat_info.clone()}</p>
-                        </div>
-                        <div class={"column"}>
-                            {ft_import_btn(
-                                "import-modifications-btn",
-                                onclick_subbmit,
-                                get_value_field(&342),
-                                true,
-                                self.new_modifications_raw.is_empty()
-                            )}
-                        </div>
+                        {match self.step {
+                            ImportStep::Source => self.show_input_data(),
+                            ImportStep::Mapping => self.show_mapping_data(),
+                            ImportStep::Preview => self.show_preview_data(),
+                        }}
                       </section>
                   </div>
                 </div>
               </div>
         }
     }
+
+    fn show_input_data(&self) -> Html {
+        let oninput_data = self.link.callback(|ev: InputData| Msg::UpdateData(ev.value));
+        let onclick_next = self.link.callback(|_| Msg::ShowMapping);
+        html!{<>
+            <div class={"column"}>
+                <div class={"subtitle is-6"}>
+                    {get_value_field(&234)}<br/>
+                    {get_value_field(&343).to_markdown()}
+                </div>
+                <textarea
+                    id={"update-description"}
+                    class={"textarea"}
+                    type={"text"}
+                    placeholder={format!("{}\n{}", get_value_field(&208), get_value_field(&344))}
+                    value={self.new_modifications_raw.clone()}
+                    oninput={oninput_data}
+                    />
+                <p class={"help"}>{self.stat_info.clone()}</p>
+            </div>
+            {self.show_source_options()}
+            <div class={"column"}>
+                <button
+                    id={"mapping-modifications-btn"}
+                    class={"button is-link is-fullwidth"}
+                    disabled={self.new_modifications_raw.is_empty() && self.sheets.is_empty()}
+                    onclick={onclick_next} >
+                    <span class={"icon is-small"}><i class={"fas fa-table"} aria-hidden="true"></i></span>
+                    <span>{get_value_field(&510)}</span> // Next
+                </button>
+            </div>
+        </>}
+    }
+
+    /// Shows the file input and the select of the separator
+    fn show_source_options(&self) -> Html {
+        let onclick_apply_template = self.link.callback(Msg::ApplyTemplate);
+        let onchange_file = self.link.callback(|value: ChangeData| match value {
+            ChangeData::Files(files) => match files.get(0) {
+                Some(file) => Msg::UploadFile(file),
+                None => Msg::Ignore,
+            },
+            _ => Msg::Ignore,
+        });
+        let onchange_split = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectSplit(ParsingSplit::from_value(&el.value())),
+            _ => Msg::Ignore,
+        });
+        html!{
+            <div class={"column is-flex is-justify-content-space-between is-align-items-center"}>
+                <div class={"file is-small"}>
+                    <label class={"file-label"}>
+                        <input class={"file-input"} type={"file"} accept={".csv,.tsv,.txt,.xlsx,.ods"} onchange={onchange_file} />
+                        <span class={"file-cta"}>
+                            <span class={"file-icon"}><i class={"fas fa-file-excel"}></i></span>
+                            <span class={"file-label"}>{get_value_field(&508)}</span> // Or load a file (CSV, XLSX, ODS)
+                        </span>
+                    </label>
+                </div>
+                <div class={"field has-addons"} hidden={!self.sheets.is_empty()}>
+                    <p class={"control"}>
+                        <span class={"button is-small is-static"}>{get_value_field(&490)}</span> // Separator
+                    </p>
+                    <div class={"control select is-small"}>
+                        <select id={"import-split"} onchange={onchange_split}>
+                            <option value={""} selected={self.split.is_none()}>{get_value_field(&491)}</option> // Auto
+                            {for ParsingSplit::ALL.iter().map(|split| html!{
+                                <option value={split.to_value()} selected={self.split == Some(*split)}>
+                                    {split.label()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+                <ParamTemplates
+                    component_specs={self.props.component_specs.clone()}
+                    callback_apply={onclick_apply_template}
+                    disabled={!self.sheets.is_empty()}
+                    />
+            </div>
+        }
+    }
+
+    /// Shows the select of the sheet and the header row, the purpose of the columns with the first rows
+    fn show_mapping_data(&self) -> Html {
+        let onclick_back = self.link.callback(|_| Msg::Back);
+        let onclick_next = self.link.callback(|_| Msg::ShowPreview);
+        let onchange_sheet = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => el.value().parse::<usize>().map(Msg::SelectSheet).unwrap_or(Msg::Ignore),
+            _ => Msg::Ignore,
+        });
+        let oninput_header_row = self.link.callback(|ev: InputData| match ev.value.parse::<usize>() {
+            Ok(row) if row > 0 => Msg::SetHeaderRow(row - 1),
+            _ => Msg::Ignore,
+        });
+        let headers = self.headers();
+        html!{<>
+            <div class={"column is-flex is-align-items-center"}>
+                {match self.sheets.len() > 1 {
+                    true => html!{
+                        <div class={"field has-addons mr-3 mb-0"}>
+                            <p class={"control"}>
+                                <span class={"button is-small is-static"}>{get_value_field(&498)}</span> // Sheet
+                            </p>
+                            <div class={"control select is-small"}>
+                                <select id={"import-sheet"} onchange={onchange_sheet}>
+                                    {for self.sheets.iter().enumerate().map(|(index, sheet)| html!{
+                                        <option value={index.to_string()} selected={index == self.sheet_index}>
+                                            {sheet.name.clone()}
+                                        </option>
+                                    })}
+                                </select>
+                            </div>
+                        </div>
+                    },
+                    false => html!{},
+                }}
+                <div class={"field has-addons mb-0"}>
+                    <p class={"control"}>
+                        <span class={"button is-small is-static"}>{get_value_field(&499)}</span> // Header row
+                    </p>
+                    <p class={"control"}>
+                        <input
+                            id={"import-header-row"}
+                            class={"input is-small"}
+                            type={"number"}
+                            min={"1"}
+                            max={self.table.len().max(1).to_string()}
+                            value={(self.header_row + 1).to_string()}
+                            oninput={oninput_header_row}
+                            />
+                    </p>
+                </div>
+            </div>
+            <div class={"column"}>
+                <p class={"help"}>{get_value_field(&500)}</p>
+                <div class={"table-container"}>
+                    <table class={"table is-fullwidth is-striped is-narrow"}>
+                        <thead>
+                            <tr>
+                                {for self.column_mapping.iter().enumerate().map(|(number, mapping)| {
+                                    let header = headers.get(number).cloned().flatten().unwrap_or_default();
+                                    self.show_column_select(number, *mapping, header)
+                                })}
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for self.data_rows().take(PREVIEW_ROWS).map(|row| html!{
+                                <tr>
+                                    {for (0..self.column_mapping.len()).map(|number| html!{
+                                        <td style={"white-space: pre-wrap;"}>
+                                            {row.get(number).cloned().flatten().unwrap_or_default()}
+                                        </td>
+                                    })}
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                </div>
+                {match self.name_column() {
+                    Some(_) => html!{},
+                    None => html!{<p class={"help is-danger"}>{get_value_field(&507)}</p>},
+                }}
+            </div>
+            <div class={"column buttons"}>
+                <button class={"button"} onclick={onclick_back}>{get_value_field(&115)}</button> // Back
+                <button
+                    id={"preview-modifications-btn"}
+                    class={"button is-link"}
+                    disabled={self.name_column().is_none()}
+                    onclick={onclick_next} >
+                    <span class={"icon is-small"}><i class={"fas fa-table"} aria-hidden="true"></i></span>
+                    <span>{get_value_field(&335)}</span> // Preview
+                </button>
+            </div>
+        </>}
+    }
+
+    fn show_column_select(&self, number: usize, mapping: ColumnMapping, header: String) -> Html {
+        let onchange_mapping = self.link.callback(move |ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SetColumnMapping(number, ColumnMapping::from_value(&el.value(), number)),
+            _ => Msg::Ignore,
+        });
+        let class_header = match mapping {
+            ColumnMapping::Skip => "has-text-grey-light",
+            ColumnMapping::Value(_) => "has-text-info",
+            ColumnMapping::Param => "",
+        };
+        html!{
+            <th>
+                <p class={class_header}>{header.clone()}</p>
+                <div class={"select is-small"}>
+                    <select id={format!("import-column-{}", number)} onchange={onchange_mapping}>
+                        {for ColumnMapping::options(number).iter().map(|option| html!{
+                            <option
+                                value={option.to_value()}
+                                selected={*option == mapping}
+                                disabled={*option == ColumnMapping::Param && header.trim().is_empty()} >
+                                {option.label()}
+                            </option>
+                        })}
+                    </select>
+                </div>
+            </th>
+        }
+    }
+
+    /// Shows the rows as they will be imported, compared with the existing modifications
+    fn show_preview_data(&self) -> Html {
+        let onclick_back = self.link.callback(|_| Msg::Back);
+        let onclick_subbmit = self.link.callback(|_| Msg::Parsing);
+        let onclick_skip_existing = self.link.callback(|_| Msg::SkipExisting);
+        let headers = self.headers();
+        let columns = self.column_mapping.iter().enumerate()
+            .filter(|(_, mapping)| **mapping != ColumnMapping::Skip)
+            .map(|(number, mapping)| (number, *mapping))
+            .collect::<Vec<(usize, ColumnMapping)>>();
+        let imported = self.data_rows().filter(|row| self.is_imported(row)).count();
+        html!{<>
+            <div class={"column"}>
+                <div class={"is-flex is-justify-content-space-between"}>
+                    <p class={"help"}>{format!("{}: {}", get_value_field(&511), imported)}</p> // Will be imported
+                    <label class={"checkbox"}>
+                        <input
+                            id={"import-skip-existing"}
+                            type={"checkbox"}
+                            checked={self.skip_existing}
+                            onclick={onclick_skip_existing} />
+                        {" "}{get_value_field(&505)} // Skip existing modifications
+                    </label>
+                </div>
+                <div class={"table-container"}>
+                    <table class={"table is-fullwidth is-narrow"}>
+                        <thead>
+                            <tr>
+                                <th></th>
+                                {for columns.iter().map(|(number, mapping)| html!{
+                                    <th class={match mapping {
+                                        ColumnMapping::Value(_) => "has-text-info",
+                                        _ => "",
+                                    }}>
+                                        {match mapping {
+                                            ColumnMapping::Param => headers.get(*number).cloned().flatten().unwrap_or_default(),
+                                            _ => mapping.label().to_string(),
+                                        }}
+                                    </th>
+                                })}
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for self.data_rows().take(DIFF_ROWS).map(|row| {
+                                let existing = self.existing_modification(row);
+                                let class_row = match self.is_imported(row) {
+                                    true => "",
+                                    false => "has-text-grey-light",
+                                };
+                                html!{
+                                    <tr class={class_row}>
+                                        <td>{match (self.existing_modifications.is_some(), existing) {
+                                            (false, _) => html!{<span class={"icon"}><i class={"fas fa-spinner fa-pulse"}></i></span>},
+                                            (true, Some(_)) => match self.skip_existing {
+                                                true => html!{<span class={"tag is-light"}>{get_value_field(&704)}</span>}, // Skipped
+                                                false => html!{<span class={"tag is-warning"}>{get_value_field(&705)}</span>}, // Update
+                                            },
+                                            (true, None) => html!{<span class={"tag is-success"}>{get_value_field(&503)}</span>}, // New
+                                        }}</td>
+                                        {for columns.iter().map(|(number, _)| {
+                                            let value = row.get(*number).cloned().flatten().unwrap_or_default();
+                                            let header = headers.get(*number).cloned().flatten().unwrap_or_default();
+                                            match existing.and_then(|m| self.changed_value(m, *number, &value, &header)) {
+                                                Some(current) => html!{
+                                                    <td
+                                                        class={"has-background-warning-light"}
+                                                        style={"white-space: pre-wrap;"}
+                                                        title={format!("{} {}", get_value_field(&506), current)}>
+                                                        {value}
+                                                    </td>
+                                                },
+                                                None => html!{<td style={"white-space: pre-wrap;"}>{value}</td>},
+                                            }
+                                        })}
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                </div>
+            </div>
+            <div class={"column buttons"}>
+                <button class={"button"} onclick={onclick_back}>{get_value_field(&115)}</button> // Back
+                {ft_import_btn(
+                    "import-modifications-btn",
+                    onclick_subbmit,
+                    get_value_field(&342),
+                    true,
+                    self.existing_modifications.is_none() || imported == 0
+                )}
+            </div>
+        </>}
+    }
 }
@@ -9,9 +9,9 @@ use crate::error::Error;
 use crate::fragments::{
     buttons::{ft_delete_btn, ft_save_btn},
     list_errors::ListErrors,
-    component::param::RegisterParamnameBlock,
+    component::param::{RegisterParamnameBlock, ft_unit_help},
 };
-use crate::services::{get_value_field, resp_parsing};
+use crate::services::{get_value_field, normalize_value, resp_parsing, value_in_system, UnitSystem};
 use crate::types::{UUID, Param, ParamValue};
 use crate::gqls::{
     make_query,
@@ -33,6 +33,14 @@ pub struct Props {
     pub callback_new_modification_param: Option<Callback<UUID>>,
     pub callback_select_modification: Option<Callback<UUID>>,
     pub ordinal_indicator: usize,
+    /// The modification is checked for the comparison
+    #[prop_or_default]
+    pub checked: bool,
+    #[prop_or_default]
+    pub callback_check_modification: Option<Callback<UUID>>,
+    /// Values of the parameters with units are shown in the units of the system
+    #[prop_or_default]
+    pub unit_system: Option<UnitSystem>,
 }
 
 pub struct ModificationTableItem {
@@ -68,6 +76,7 @@ pub enum Msg {
     GetDeleteParamResult(String),
     ModificationAction,
     SelectModification,
+    CheckModification,
     UpdateValue(String),
     ShowNewParamCard,
     ShowAddParamCard(usize),
@@ -126,6 +135,11 @@ impl Component for ModificationTableItem {
                 link.send_message(Msg::RequestAddParamData);
             },
             Msg::RequestAddParamData => {
+                // the value is saved in the unit of the parameter
+                match normalize_value(self.paramname(self.request_add_param.param_id), &self.request_add_param.value) {
+                    Ok(value) => self.request_add_param.value = value,
+                    Err(_) => return true,
+                }
                 debug!("RequestAddParamData: {:?}", self.request_add_param);
                 let ipt_param_data = put_modification_params::IptParamData{
                     paramId: self.request_add_param.param_id as i64,
@@ -143,7 +157,11 @@ impl Component for ModificationTableItem {
                 })
             },
             Msg::RequestUpdateParamData => {
-                debug!("RequestUpdateParamData: {:?}", self.request_add_param);
+                match normalize_value(self.paramname(self.request_edit_param.param_id), &self.request_edit_param.value) {
+                    Ok(value) => self.request_edit_param.value = value,
+                    Err(_) => return true,
+                }
+                debug!("RequestUpdateParamData: {:?}", self.request_edit_param);
                 let ipt_param_data = put_modification_params::IptParamData{
                     paramId: self.request_edit_param.param_id as i64,
                     value: self.request_edit_param.value.clone(),
@@ -259,6 +277,12 @@ impl Component for ModificationTableItem {
                 }
                 self.open_modification_card = !self.open_modification_card;
             },
+            Msg::CheckModification => {
+                if let Some(check_modification) = &self.props.callback_check_modification {
+                    check_modification.emit(self.modification_uuid.clone());
+                }
+                return false
+            },
             Msg::SelectModification => {
                 debug!("Callback ITEM, modification uuid: {:?}, self.props.m...uuid: {:?} (Show modifications)",
                     self.modification_uuid,
@@ -332,8 +356,12 @@ impl Component for ModificationTableItem {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.modification_uuid == props.modification_uuid &&
-              self.select_item == props.select_item {
-            false
+              self.select_item == props.select_item &&
+              self.props.checked == props.checked {
+            // the changed values of the parameters are kept
+            let change_units = self.props.unit_system != props.unit_system;
+            self.props.unit_system = props.unit_system;
+            change_units
         } else {
             self.modification_uuid = props.modification_uuid.clone();
             self.open_modification_card = props.open_modification_card;
@@ -355,6 +383,15 @@ impl Component for ModificationTableItem {
 }
 
 impl ModificationTableItem {
+    /// Name of the parameter of the column (with the unit, if declared)
+    fn paramname(&self, param_id: usize) -> &str {
+        self.props.collect_heads
+            .iter()
+            .find(|param| param.param_id == param_id)
+            .map(|param| param.paramname.as_str())
+            .unwrap_or_default()
+    }
+
     fn show_modification_row(&self) -> Html {
         let onclick_modification_action = self.link.callback(|_| Msg::ModificationAction);
         let onclick_select_modification = self.link.callback(|_| Msg::SelectModification);
@@ -371,6 +408,17 @@ impl ModificationTableItem {
         };
 
         html!{<tr class={class_style}>
+            {match &self.props.callback_check_modification {
+                Some(_) => {
+                    let onclick_check = self.link.callback(|_| Msg::CheckModification);
+                    html!{
+                        <td class="is-narrow">
+                            <input type="checkbox" checked={self.props.checked} onclick={onclick_check} />
+                        </td>
+                    }
+                },
+                None => html!{},
+            }}
             <td class="is-narrow" onclick={onclick_modification_action}>
                 <a style={style_btn}>
                     <span class="icon">
@@ -403,7 +451,7 @@ impl ModificationTableItem {
                     match self.collect_item.get(&param.param_id) {
                         Some(value) => html!{<ModificationTableItemModule
                             param_id={param.param_id}
-                            value={Some(value.clone())}
+                            value={Some(value_in_system(&param.paramname, value, self.props.unit_system))}
                             callback_change_param={Some(onclick_edit_param_card.clone())}
                         />},
                         None => html!{<ModificationTableItemModule
@@ -425,7 +473,7 @@ impl ModificationTableItem {
                     match self.collect_item.get(&param.param_id) {
                         Some(value) => html!{<ModificationTableItemModule
                             param_id={param.param_id}
-                            value={Some(value.clone())}
+                            value={Some(value_in_system(&param.paramname, value, self.props.unit_system))}
                             callback_change_param={None}
                         />},
                         None => html!{<ModificationTableItemModule
@@ -499,12 +547,14 @@ impl ModificationTableItem {
                               placeholder={get_value_field(&133)}
                               value={self.request_add_param.value.clone()}
                               oninput={oninput_param_value} />
+                      {ft_unit_help(self.paramname(self.request_add_param.param_id), &self.request_add_param.value)}
                       <br/>
                       {ft_save_btn(
                         "update-modification-param",
                         onclick_param_add,
                         true,
-                        !self.update_add_param
+                        !self.update_add_param ||
+                            normalize_value(self.paramname(self.request_add_param.param_id), &self.request_add_param.value).is_err()
                       )}
                     </div>
                   </article>
@@ -545,6 +595,7 @@ impl ModificationTableItem {
                               placeholder={get_value_field(&134)}
                               value={self.request_edit_param.value.clone()}
                               oninput={oninput_param_value} />
+                      {ft_unit_help(self.paramname(self.request_edit_param.param_id), &self.request_edit_param.value)}
                       <br/>
                       <div class="columns">
                         <div class="column">
@@ -560,7 +611,8 @@ impl ModificationTableItem {
                             "update-modification-param",
                             onclick_param_update,
                             true,
-                            !self.update_edit_param
+                            !self.update_edit_param ||
+                                normalize_value(self.paramname(self.request_edit_param.param_id), &self.request_edit_param.value).is_err()
                           )}
                         </div>
                       </div>
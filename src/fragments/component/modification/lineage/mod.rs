@@ -0,0 +1,175 @@
+mod tree;
+
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+use yew::{classes, html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+
+use tree::{lineage_trees, subtree_uuids, LineageNode};
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{get_value_field, resp_parsing};
+use crate::types::{ComponentModificationInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{GetComponentModifications, get_component_modifications};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    /// The lineage is requested again if the number of modifications is changed
+    pub modifications_count: i64,
+    /// Root of the subtree by which the modifications are filtered
+    pub subtree_root: Option<UUID>,
+    /// Returns the root of the selected subtree with the UUIDs of its modifications, or None to show all modifications
+    pub callback_select_subtree: Callback<Option<(UUID, Vec<UUID>)>>,
+}
+
+/// Shows which modifications are derived from which and selects a subtree to filter the table
+pub struct ModificationsLineage {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    trees: Vec<LineageNode>,
+    loading: bool,
+}
+
+pub enum Msg {
+    RequestModifications,
+    GetModificationsResult(String),
+    SelectSubtree(UUID),
+    ShowAll,
+    ClearError,
+}
+
+impl Component for ModificationsLineage {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            trees: Vec::new(),
+            loading: true,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestModifications);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestModifications => {
+                let component_uuid = self.props.component_uuid.clone();
+                self.loading = true;
+                spawn_local(async move {
+                    let res = make_query(GetComponentModifications::build_query(
+                        get_component_modifications::Variables {
+                            component_uuid,
+                            filter: None,
+                            ipt_sort: Some(get_component_modifications::IptSort {byField: "name".to_string(), asDesc: false}),
+                            ipt_paginate: None,
+                        }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetModificationsResult(res));
+                })
+            },
+            Msg::GetModificationsResult(res) => {
+                self.loading = false;
+                match resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications") {
+                    Ok(result) => self.trees = lineage_trees(&result),
+                    Err(err) => self.error = Some(err),
+                }
+            },
+            Msg::SelectSubtree(uuid) => {
+                match self.props.subtree_root.as_ref() == Some(&uuid) {
+                    true => self.props.callback_select_subtree.emit(None),
+                    false => {
+                        let uuids = subtree_uuids(&self.trees, &uuid);
+                        self.props.callback_select_subtree.emit(Some((uuid, uuids)));
+                    },
+                }
+                return false
+            },
+            Msg::ShowAll => {
+                self.props.callback_select_subtree.emit(None);
+                return false
+            },
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let reload = self.props.component_uuid != props.component_uuid
+            || self.props.modifications_count != props.modifications_count;
+        self.props = props;
+        if reload {
+            self.link.send_message(Msg::RequestModifications);
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_show_all = self.link.callback(|_| Msg::ShowAll);
+        html!{
+            <aside class="menu">
+                <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                <p class="menu-label">
+                    {get_value_field(&534)} // Lineage
+                    {match self.loading {
+                        true => html!{<span class="icon"><i class="fas fa-spinner fa-pulse"></i></span>},
+                        false => html!{},
+                    }}
+                </p>
+                <ul class="menu-list">
+                    {for self.trees.iter().map(|node| self.show_node(node))}
+                </ul>
+                <button
+                    id="lineage-show-all"
+                    class="button is-small is-fullwidth mt-2"
+                    hidden={self.props.subtree_root.is_none()}
+                    onclick={onclick_show_all} >
+                    <span class="icon is-small"><i class="fas fa-times" aria-hidden="true"></i></span>
+                    <span>{get_value_field(&535)}</span> // Show all modifications
+                </button>
+            </aside>
+        }
+    }
+}
+
+impl ModificationsLineage {
+    fn show_node(&self, node: &LineageNode) -> Html {
+        let uuid = node.uuid.clone();
+        let onclick_select = self.link.callback(move |_| Msg::SelectSubtree(uuid.clone()));
+        let descendants = node.descendants();
+        html!{
+            <li>
+                <a
+                    class={classes!(match self.props.subtree_root.as_ref() == Some(&node.uuid) {
+                        true => "is-active",
+                        false => "",
+                    })}
+                    title={get_value_field(&536)} // Filter the table by the modification and derived ones
+                    onclick={onclick_select} >
+                    <span>{node.name.clone()}</span>
+                    {match descendants {
+                        0 => html!{},
+                        _ => html!{<span class="tag is-rounded is-light ml-2">{descendants}</span>},
+                    }}
+                </a>
+                {match node.children.is_empty() {
+                    true => html!{},
+                    false => html!{
+                        <ul>{for node.children.iter().map(|child| self.show_node(child))}</ul>
+                    },
+                }}
+            </li>
+        }
+    }
+}
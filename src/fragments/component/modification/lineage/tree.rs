@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, BTreeSet};
+use crate::types::{ComponentModificationInfo, UUID};
+
+/// Node of the lineage: the modification with the modifications derived from it
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LineageNode {
+    pub(crate) uuid: UUID,
+    pub(crate) name: String,
+    pub(crate) children: Vec<LineageNode>,
+}
+
+impl LineageNode {
+    /// Number of the modifications derived from this one, directly or through other modifications
+    pub(crate) fn descendants(&self) -> usize {
+        self.children.iter().map(|child| child.descendants() + 1).sum()
+    }
+}
+
+/// Builds the lineage trees of the modifications by the parent modification.
+/// A modification without a parent among the modifications is a root, as well as its own parent.
+pub(crate) fn lineage_trees(modifications: &[ComponentModificationInfo]) -> Vec<LineageNode> {
+    let uuids = modifications.iter().map(|m| m.uuid.as_str()).collect::<BTreeSet<&str>>();
+    let mut children: BTreeMap<&str, Vec<&ComponentModificationInfo>> = BTreeMap::new();
+    let mut roots = Vec::new();
+    for modification in modifications {
+        let parent = modification.parent_modification_uuid.as_str();
+        match parent != modification.uuid && uuids.contains(parent) {
+            true => children.entry(parent).or_default().push(modification),
+            false => roots.push(modification),
+        }
+    }
+    let mut visited = BTreeSet::new();
+    let mut trees = roots.into_iter()
+        .map(|root| build_node(root, &children, &mut visited))
+        .collect::<Vec<LineageNode>>();
+    // modifications in a cycle of parents have no root, the first of them becomes the root
+    for modification in modifications {
+        if !visited.contains(modification.uuid.as_str()) {
+            trees.push(build_node(modification, &children, &mut visited));
+        }
+    }
+    trees
+}
+
+fn build_node<'a>(
+    modification: &'a ComponentModificationInfo,
+    children: &BTreeMap<&str, Vec<&'a ComponentModificationInfo>>,
+    visited: &mut BTreeSet<&'a str>,
+) -> LineageNode {
+    visited.insert(modification.uuid.as_str());
+    let mut node = LineageNode {
+        uuid: modification.uuid.clone(),
+        name: modification.modification_name.clone(),
+        children: Vec::new(),
+    };
+    for child in children.get(modification.uuid.as_str()).map(|c| c.as_slice()).unwrap_or_default() {
+        if !visited.contains(child.uuid.as_str()) {
+            node.children.push(build_node(child, children, visited));
+        }
+    }
+    node
+}
+
+/// UUIDs of the modification and all modifications derived from it
+pub(crate) fn subtree_uuids(trees: &[LineageNode], uuid: &str) -> Vec<UUID> {
+    fn find<'a>(nodes: &'a [LineageNode], uuid: &str) -> Option<&'a LineageNode> {
+        nodes.iter().find_map(|node| match node.uuid == uuid {
+            true => Some(node),
+            false => find(&node.children, uuid),
+        })
+    }
+    fn collect(node: &LineageNode, result: &mut Vec<UUID>) {
+        result.push(node.uuid.clone());
+        for child in &node.children {
+            collect(child, result);
+        }
+    }
+    let mut result = Vec::new();
+    if let Some(node) = find(trees, uuid) {
+        collect(node, &mut result);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn modification(uuid: &str, parent: &str) -> ComponentModificationInfo {
+        ComponentModificationInfo {
+            uuid: uuid.to_string(),
+            component_uuid: String::from("c"),
+            parent_modification_uuid: parent.to_string(),
+            modification_name: uuid.to_uppercase(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lineage_subtree() {
+        let modifications = vec![
+            modification("c", "b"),
+            modification("a", "00000000-0000-0000-0000-000000000000"),
+            modification("b", "a"),
+            modification("d", "a"),
+            modification("e", "e"),
+            modification("x", "y"),
+            modification("y", "x"),
+        ];
+        let trees = lineage_trees(&modifications);
+
+        assert_eq!(vec!["a", "e", "x"], trees.iter().map(|n| n.uuid.as_str()).collect::<Vec<&str>>());
+        assert_eq!(3, trees[0].descendants());
+        assert_eq!(vec!["b", "d"], trees[0].children.iter().map(|n| n.uuid.as_str()).collect::<Vec<&str>>());
+        assert_eq!(vec!["a", "b", "c", "d"], subtree_uuids(&trees, "a"));
+        assert_eq!(vec!["b", "c"], subtree_uuids(&trees, "b"));
+        assert_eq!(vec!["x", "y"], subtree_uuids(&trees, "x"));
+        assert!(subtree_uuids(&trees, "z").is_empty());
+    }
+}
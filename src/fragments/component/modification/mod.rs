@@ -8,6 +8,15 @@ mod fileset;
 mod item_module;
 mod table;
 mod show;
+mod compare;
+mod export;
+mod grid;
+mod generator;
+mod lineage;
+mod derive;
+mod diff;
+mod filter;
+mod pages;
 
 pub use file::{ModificationFilesTableCard, ManageModificationFilesCard};
 pub use table_edit::ModificationsTableEdit;
@@ -15,7 +24,8 @@ pub use edit::ModificationEdit;
 pub use heads::ModificationTableHeads;
 pub use import::ImportModificationsData;
 pub use item::ModificationTableItem;
-pub use fileset::{FilesOfFilesetCard, ModificationFilesetsCard};
+pub use fileset::{FilesOfFilesetCard, ModificationFilesetsCard, ModificationModelSelect, SelectedModel};
 pub use item_module::ModificationTableItemModule;
 pub use table::ModificationsTable;
-pub use show::ModificationsTableCard;
\ No newline at end of file
+pub use show::ModificationsTableCard;
+pub(crate) use pages::request_all_modifications;
\ No newline at end of file
@@ -0,0 +1,33 @@
+use graphql_client::GraphQLQuery;
+use crate::error::Error;
+use crate::services::resp_parsing;
+use crate::types::{ComponentModificationInfo, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{GetComponentModifications, get_component_modifications};
+
+/// Number of modifications requested per page when all modifications of the component are needed
+const MODIFICATIONS_PAGE_SIZE: i64 = 100;
+
+/// Requests all modifications of the component sorted by the names,
+/// the next page is requested while the page is full
+pub(crate) async fn request_all_modifications(component_uuid: UUID) -> Result<Vec<ComponentModificationInfo>, Error> {
+    let mut modifications = Vec::new();
+    let mut current_page = 1;
+    loop {
+        let res = make_query(GetComponentModifications::build_query(
+            get_component_modifications::Variables {
+                component_uuid: component_uuid.clone(),
+                filter: None,
+                ipt_sort: Some(get_component_modifications::IptSort {byField: "name".to_string(), asDesc: false}),
+                ipt_paginate: Some(get_component_modifications::IptPaginate {currentPage: current_page, perPage: MODIFICATIONS_PAGE_SIZE}),
+            }
+        )).await.unwrap();
+        let page = resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications")?;
+        let full_page = page.len() as i64 == MODIFICATIONS_PAGE_SIZE;
+        modifications.extend(page);
+        if !full_page {
+            return Ok(modifications)
+        }
+        current_page += 1;
+    }
+}
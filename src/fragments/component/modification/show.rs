@@ -1,18 +1,21 @@
 use yew::{Callback, Component, ComponentLink, Html, Properties, ShouldRender, html};
 use graphql_client::GraphQLQuery;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::spawn_local;
 use log::debug;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::paginate::Paginate;
 use crate::services::content_adapter::{DateDisplay, Markdownable};
-use crate::services::{get_value_field, resp_parsing, set_focus};
+use crate::services::{get_value_field, resp_parsing, set_focus, ComponentViewLink, ModificationsFilter};
 use crate::types::{UUID, ComponentModificationInfo, PaginateSet};
 use crate::routes::other_component::modification::ImportModificationsData;
 use crate::gqls::make_query;
 use crate::gqls::component::{GetComponentModifications, get_component_modifications};
 use super::table::ModificationsTable;
 use super::file::ModificationFilesTableCard;
+use super::filter::ModificationsFilterBar;
+use super::pages::request_all_modifications;
 
 #[derive(Clone, Debug, Properties)]
 pub struct Props {
@@ -20,6 +23,12 @@ pub struct Props {
     pub modifications_count: i64,
     pub callback_select_modification: Option<Callback<UUID>>,
     pub user_owner: bool,
+    /// Modification to select after loading instead of the first one (e.g. from a view link)
+    #[prop_or_default]
+    pub select_modification_uuid: Option<UUID>,
+    /// Filters and sorting of the table from the view link
+    #[prop_or_default]
+    pub filter: ModificationsFilter,
 }
 
 pub struct ModificationsTableCard {
@@ -28,8 +37,13 @@ pub struct ModificationsTableCard {
     link: ComponentLink<Self>,
     component_uuid: UUID,
     select_modification_uuid: UUID,
+    initial_modification_uuid: Option<UUID>,
     open_modification_card: bool,
     modifications: Vec<ComponentModificationInfo>,
+    filter: ModificationsFilter,
+    /// All modifications of the component, received for the filtering and sorting on the client
+    all_modifications: Option<Vec<ComponentModificationInfo>>,
+    filtered_count: i64,
     skip_change_page: bool,
     page_set: PaginateSet,
     current_items: i64,
@@ -38,6 +52,10 @@ pub struct ModificationsTableCard {
 pub enum Msg {
     RequestComponentModificationsData,
     GetComponentModificationsResult(String),
+    GetAllModificationsResult(Result<Vec<ComponentModificationInfo>, Error>),
+    ApplyFilter,
+    ChangeFilter(ModificationsFilter),
+    SortColumn(usize),
     ResponseError(Error),
     SelectModification(UUID),
     CallOfChange,
@@ -53,14 +71,20 @@ impl Component for ModificationsTableCard {
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         let component_uuid = props.component_uuid.clone();
+        let initial_modification_uuid = props.select_modification_uuid.clone();
+        let filter = props.filter.clone();
         Self {
             error: None,
             props,
             link,
             component_uuid,
             select_modification_uuid: String::new(),
+            initial_modification_uuid,
             open_modification_card: true,
             modifications: Vec::new(),
+            filter,
+            all_modifications: None,
+            filtered_count: 0,
             skip_change_page: false,
             page_set: PaginateSet::new(),
             current_items: 0,
@@ -83,14 +107,22 @@ impl Component for ModificationsTableCard {
                     return true
                 }
                 let component_uuid = self.component_uuid.clone();
+                // all modifications are requested by pages for the filtering and sorting by parameters
+                if self.filter.is_client_side() {
+                    spawn_local(async move {
+                        link.send_message(Msg::GetAllModificationsResult(request_all_modifications(component_uuid).await));
+                    });
+                    return true
+                }
                 let ipt_sort = Some(get_component_modifications::IptSort {
                     byField: "name".to_string(),
-                    asDesc: false,
+                    asDesc: self.filter.server_desc(),
                 });
                 let ipt_paginate = Some(get_component_modifications::IptPaginate {
                     currentPage: self.page_set.current_page,
                     perPage: self.page_set.per_page,
                 });
+                self.all_modifications = None;
                 spawn_local(async move {
                     let res = make_query(GetComponentModifications::build_query(
                         get_component_modifications::Variables {
@@ -107,14 +139,54 @@ impl Component for ModificationsTableCard {
                 match resp_parsing(res, "componentModifications") {
                     Ok(result) => {
                         self.modifications = result;
-                        self.current_items = self.modifications.len() as i64;
-                        self.select_modification_uuid = self.modifications.first().map(|m| m.uuid.clone()).unwrap_or_default();
-                        self.link.send_message(Msg::CallOfChange);
+                        self.select_after_load();
                         debug!("Update modifications list");
                     },
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
+            Msg::GetAllModificationsResult(result) => {
+                match result {
+                    Ok(result) => {
+                        self.all_modifications = Some(result);
+                        link.send_message(Msg::ApplyFilter);
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::ApplyFilter => {
+                let all_modifications = match &self.all_modifications {
+                    Some(all_modifications) => all_modifications,
+                    None => return false,
+                };
+                let filtered = self.filter.apply(all_modifications);
+                self.filtered_count = filtered.len() as i64;
+                let skip = (self.page_set.current_page.max(1) - 1) * self.page_set.per_page;
+                self.modifications = filtered
+                    .into_iter()
+                    .skip(skip as usize)
+                    .take(self.page_set.per_page as usize)
+                    .collect();
+                self.select_after_load();
+                debug!("Filtered modifications: {}", self.filtered_count);
+            },
+            Msg::ChangeFilter(filter) => {
+                if self.filter == filter {
+                    return false
+                }
+                self.filter = filter;
+                self.page_set.to(1);
+                set_link_filter(&self.filter);
+                match self.filter.is_client_side() && self.all_modifications.is_some() {
+                    true => link.send_message(Msg::ApplyFilter),
+                    false => link.send_message(Msg::RequestComponentModificationsData),
+                }
+            },
+            Msg::SortColumn(param_id) => {
+                let mut filter = self.filter.clone();
+                filter.switch_sort(param_id);
+                link.send_message(Msg::ChangeFilter(filter));
+            },
             Msg::SelectModification(modification_uuid) => {
                 debug!("Callback CARD, modification uuid set: {:?}, old: {:?} (Show modifications)",
                     modification_uuid,
@@ -154,7 +226,10 @@ impl Component for ModificationsTableCard {
                     return true
                 }
                 self.page_set = page_set;
-                self.link.send_message(Msg::RequestComponentModificationsData);
+                match self.all_modifications.is_some() {
+                    true => self.link.send_message(Msg::ApplyFilter),
+                    false => self.link.send_message(Msg::RequestComponentModificationsData),
+                }
             },
             Msg::Focuser => set_focus("show-modification-card"),
             Msg::ClearError => self.error = None,
@@ -165,9 +240,23 @@ impl Component for ModificationsTableCard {
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         debug!("Show modifications CARD, modification uuid: {:?}", self.select_modification_uuid);
         if self.props.component_uuid == props.component_uuid {
-            false
+            // select the modification from the view link if it is on the current page
+            let select_uuid = props.select_modification_uuid.clone()
+                .filter(|uuid| uuid != &self.select_modification_uuid)
+                .filter(|uuid| self.modifications.iter().any(|m| &m.uuid == uuid));
+            self.props = props;
+            match select_uuid {
+                Some(modification_uuid) => {
+                    self.select_modification_uuid = modification_uuid;
+                    self.link.send_message(Msg::CallOfChange);
+                    true
+                },
+                None => false,
+            }
         } else {
             self.component_uuid = props.component_uuid.clone();
+            self.filter = props.filter.clone();
+            self.all_modifications = None;
             self.props = props;
             true
         }
@@ -208,26 +297,55 @@ impl ModificationsTableCard {
     fn show_modifications_table(&self) -> Html {
         let onclick_paginate = self.link.callback(|page_set| Msg::ChangePaginate(page_set));
         let onclick_select_modification = self.link.callback(|value: UUID| Msg::SelectModification(value));
+        let callback_change_filter = self.link.callback(Msg::ChangeFilter);
+        let onclick_sort_column = self.link.callback(Msg::SortColumn);
+        let total_items = match self.all_modifications.is_some() {
+            true => self.filtered_count,
+            false => self.props.modifications_count,
+        };
         html!{
             <div class="card-content">
+                <ModificationsFilterBar
+                    modifications={self.all_modifications.clone().unwrap_or_else(|| self.modifications.clone())}
+                    filter={self.filter.clone()}
+                    callback_change={callback_change_filter}
+                    />
                 <ModificationsTable
                     modifications={self.modifications.clone()}
                     select_modification_uuid={self.select_modification_uuid.clone()}
                     callback_select_modification={onclick_select_modification}
                     open_modification_card={self.open_modification_card}
                     numero_offset={self.page_set.numero_offset()}
+                    sort={self.filter.sort}
+                    callback_sort={onclick_sort_column}
                 />
+                // the paginate is created again on the first page after changing the filters
                 <Paginate
+                    key={self.filter.to_params().join("&")}
                     callback_change={onclick_paginate}
                     current_items={self.current_items}
                     current_page={Some(self.page_set.current_page)}
                     per_page={Some(self.page_set.per_page)}
-                    total_items={self.props.modifications_count}
+                    total_items={total_items}
                 />
             </div>
         }
     }
 
+    /// Selects the modification from the view link or the first one on the page
+    fn select_after_load(&mut self) {
+        self.current_items = self.modifications.len() as i64;
+        let initial_uuid = self.initial_modification_uuid.take();
+        let select_uuid = initial_uuid.as_ref().unwrap_or(&self.select_modification_uuid);
+        self.select_modification_uuid = self.modifications
+            .iter()
+            .find(|m| &m.uuid == select_uuid)
+            .or_else(|| self.modifications.first())
+            .map(|m| m.uuid.clone())
+            .unwrap_or_default();
+        self.link.send_message(Msg::CallOfChange);
+    }
+
     fn show_modification_card(&self) -> Html {
         let modification_data = self.modifications.iter().find(|x| x.uuid == self.select_modification_uuid);
         match modification_data {
@@ -270,4 +388,22 @@ impl ModificationsTableCard {
             None => html!{},
         }
     }
-}
\ No newline at end of file
+}
+
+/// Replaces the filters in the link of the page without reloading it, so the link can be shared
+fn set_link_filter(filter: &ModificationsFilter) {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+    let location = window.location();
+    let (href, hash) = match (location.href(), location.hash()) {
+        (Ok(href), Ok(hash)) => (href, hash),
+        _ => return,
+    };
+    let (_, mut view_link) = ComponentViewLink::from_fragment(&hash);
+    view_link.filter = filter.clone();
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&view_link.to_href(&href)));
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 use yew::{Callback, Component, ComponentLink, Html, Properties, ShouldRender, html};
 use log::debug;
-use crate::{services::get_classes_table, types::{ComponentModificationInfo, Param, UUID}};
-use super::{heads::ModificationTableHeads, item::ModificationTableItem};
+use crate::{services::{get_classes_table, get_value_field, param_unit, SortColumn, UnitSystem}, types::{ComponentModificationInfo, Param, UUID}};
+use crate::fragments::component::AddToBom;
+use super::{heads::ModificationTableHeads, item::ModificationTableItem, compare::CompareModificationModels, export::ModificationsExport, diff::ModificationsDiff};
 
 #[derive(Clone, Debug, Properties)]
 pub struct Props {
@@ -12,6 +13,10 @@ pub struct Props {
     pub callback_select_modification: Option<Callback<UUID>>,
     pub callback_new_modification_param: Option<Callback<UUID>>,
     pub numero_offset: usize,
+    #[prop_or_default]
+    pub sort: Option<SortColumn>,
+    #[prop_or_default]
+    pub callback_sort: Option<Callback<usize>>,
 }
 
 pub struct ModificationsTable {
@@ -21,12 +26,24 @@ pub struct ModificationsTable {
     collect_heads: Vec<Param>,
     collect_items: Vec<(UUID, HashMap<usize, String>)>,
     collect_columns: HashMap<usize, String>,
+    open_compare_card: bool,
+    open_export_card: bool,
+    /// Modifications checked for the comparison of parameters
+    checked_modifications: Vec<UUID>,
+    open_diff_card: bool,
+    /// Values of the parameters with units are shown in the units of the system, `None` to show the saved values
+    unit_system: Option<UnitSystem>,
 }
 
 pub enum Msg {
     RebuildTable,
     ParseParams,
     SelectModification(UUID),
+    ShowCompareCard,
+    ShowExportCard,
+    CheckModification(UUID),
+    ShowDiffCard,
+    SetUnitSystem(UnitSystem),
 }
 
 impl Component for ModificationsTable {
@@ -41,6 +58,11 @@ impl Component for ModificationsTable {
             collect_heads: Vec::new(),
             collect_items: Vec::new(),
             collect_columns: HashMap::new(),
+            open_compare_card: false,
+            open_export_card: false,
+            checked_modifications: Vec::new(),
+            open_diff_card: false,
+            unit_system: None,
         }
     }
 
@@ -61,6 +83,8 @@ impl Component for ModificationsTable {
                 };
                 self.collect_items.clear();
                 self.collect_columns.clear();
+                let modifications = &self.props.modifications;
+                self.checked_modifications.retain(|uuid| modifications.iter().any(|m| &m.uuid == uuid));
                 if self.component_uuid.len() == 36 {
                     self.link.send_message(Msg::ParseParams);
                 }
@@ -110,6 +134,23 @@ impl Component for ModificationsTable {
                     select_modification.emit(modification_uuid);
                 }
             },
+            Msg::ShowCompareCard => self.open_compare_card = !self.open_compare_card,
+            Msg::ShowExportCard => self.open_export_card = !self.open_export_card,
+            Msg::CheckModification(modification_uuid) => {
+                match self.checked_modifications.iter().position(|uuid| uuid == &modification_uuid) {
+                    Some(index) => {
+                        self.checked_modifications.remove(index);
+                    },
+                    None => self.checked_modifications.push(modification_uuid),
+                }
+            },
+            Msg::ShowDiffCard => self.open_diff_card = !self.open_diff_card,
+            Msg::SetUnitSystem(unit_system) => {
+                self.unit_system = match self.unit_system == Some(unit_system) {
+                    true => None,
+                    false => Some(unit_system),
+                };
+            },
         }
         true
     }
@@ -119,9 +160,14 @@ impl Component for ModificationsTable {
             self.props.select_modification_uuid,
             props.select_modification_uuid
         );
+        // the filtered or sorted modifications can be the same number, but in other order
+        let same_order = self.props.modifications.iter()
+            .zip(&props.modifications)
+            .all(|(old, new)| old.uuid == new.uuid);
         if self.props.select_modification_uuid == props.select_modification_uuid &&
         self.props.modifications.len() == props.modifications.len() &&
-        self.props.numero_offset == props.numero_offset {
+        self.props.numero_offset == props.numero_offset &&
+        self.props.sort == props.sort && same_order {
             if self.props.callback_new_modification_param.is_some() {
                 // need further verification of the change
                 let mut modification_name = &String::new();
@@ -156,15 +202,21 @@ impl Component for ModificationsTable {
 
     fn view(&self) -> Html {
         let onclick_select_modification = self.link.callback(|value: UUID| Msg::SelectModification(value));
+        let onclick_check_modification = self.link.callback(Msg::CheckModification);
         let classes_table = get_classes_table(self.collect_items.len());
         html!{
             <div class={"content"}>
+                {self.show_table_actions()}
                 <div class={"table-container"}>
                     <table class={classes_table}>
                         <ModificationTableHeads
                             show_new_column={self.props.callback_new_modification_param.is_some()}
                             component_uuid={self.component_uuid.clone()}
                             params={self.collect_heads.clone()}
+                            show_check_column={true}
+                            sort={self.props.sort}
+                            callback_sort={self.props.callback_sort.clone()}
+                            unit_system={self.unit_system}
                             />
                         {for self.collect_items.iter().enumerate().map(|(numer, (modification_uuid, item))| {
                             html!{<ModificationTableItem
@@ -177,6 +229,9 @@ impl Component for ModificationsTable {
                                 callback_new_modification_param={self.props.callback_new_modification_param.clone()}
                                 callback_select_modification={onclick_select_modification.clone()}
                                 ordinal_indicator={self.props.numero_offset+numer}
+                                checked={self.checked_modifications.contains(modification_uuid)}
+                                callback_check_modification={Some(onclick_check_modification.clone())}
+                                unit_system={self.unit_system}
                             />}
                         })}
                     </table>
@@ -185,3 +240,127 @@ impl Component for ModificationsTable {
         }
     }
 }
+
+impl ModificationsTable {
+    /// Checked modifications in the order of the table
+    fn checked_data(&self) -> Vec<ComponentModificationInfo> {
+        self.props.modifications.iter()
+            .filter(|m| self.checked_modifications.contains(&m.uuid))
+            .cloned()
+            .collect()
+    }
+
+    /// Buttons to show the values in metric or imperial units, if the parameters have units
+    fn show_unit_system_toggle(&self) -> Html {
+        if self.collect_heads.iter().all(|param| param_unit(&param.paramname).is_none()) {
+            return html!{}
+        }
+        let show_button = |unit_system: UnitSystem, id: &str, title: &'static str| {
+            let onclick = self.link.callback(move |_| Msg::SetUnitSystem(unit_system));
+            let class = match self.unit_system == Some(unit_system) {
+                true => "button is-small is-info is-selected",
+                false => "button is-small is-white",
+            };
+            html!{<button id={id.to_string()} class={class} onclick={onclick}>{title}</button>}
+        };
+        html!{
+            <div class="buttons has-addons mb-0 mr-2" title={get_value_field(&563)}>
+                {show_button(UnitSystem::Metric, "show-metric-units", get_value_field(&561))} // Metric
+                {show_button(UnitSystem::Imperial, "show-imperial-units", get_value_field(&562))} // Imperial
+            </div>
+        }
+    }
+
+    fn show_table_actions(&self) -> Html {
+        if self.props.modifications.is_empty() {
+            return html!{}
+        }
+        let onclick_compare_card = self.link.callback(|_| Msg::ShowCompareCard);
+        let onclick_export_card = self.link.callback(|_| Msg::ShowExportCard);
+        let onclick_diff_card = self.link.callback(|_| Msg::ShowDiffCard);
+        html!{<>
+            <div class="buttons is-right mb-0">
+                {self.show_unit_system_toggle()}
+                {match self.props.select_modification_uuid.is_empty() {
+                    true => html!{},
+                    false => html!{<AddToBom
+                        component_uuid={self.component_uuid.clone()}
+                        modification_uuid={self.props.select_modification_uuid.clone()}
+                        />},
+                }}
+                <button id="compare-modification-params"
+                    class="button is-small is-white"
+                    disabled={self.checked_modifications.len() < 2}
+                    onclick={onclick_diff_card.clone()}
+                    title={get_value_field(&547)} >
+                    <span class="icon">
+                        <i class="fas fa-columns" style="color: #1872f0;" aria-hidden="true"></i>
+                    </span>
+                    <span>{format!("{} ({})", get_value_field(&542), self.checked_modifications.len())}</span> // Compare parameters
+                </button>
+                <button id="export-modifications"
+                    class="button is-small is-white"
+                    onclick={onclick_export_card.clone()}
+                    title={get_value_field(&512)} >
+                    <span class="icon">
+                        <i class="fas fa-file-export" style="color: #1872f0;" aria-hidden="true"></i>
+                    </span>
+                    <span>{get_value_field(&348)}</span> // Export
+                </button>
+                {match self.props.modifications.len() > 1 {
+                    true => html!{
+                        <button id="compare-modification-models"
+                            class="button is-small is-white"
+                            onclick={onclick_compare_card.clone()}
+                            title={get_value_field(&464)} >
+                            <span class="icon">
+                                <i class="fas fa-clone" style="color: #1872f0;" aria-hidden="true"></i>
+                            </span>
+                            <span>{get_value_field(&464)}</span> // Compare models
+                        </button>
+                    },
+                    false => html!{},
+                }}
+            </div>
+            {match self.open_compare_card {
+                true => html!{
+                    <div class="modal is-active">
+                        <div class="modal-background" onclick={onclick_compare_card.clone()} />
+                        <div class="modal-content box" style="width: 90vw;">
+                            <CompareModificationModels
+                                modifications={self.props.modifications.clone()}
+                                select_modification_uuid={self.props.select_modification_uuid.clone()}
+                                />
+                        </div>
+                        <button class="modal-close is-large" aria-label="close" onclick={onclick_compare_card} />
+                    </div>
+                },
+                false => html!{},
+            }}
+            {match self.open_diff_card && self.checked_modifications.len() > 1 {
+                true => html!{
+                    <div class="modal is-active">
+                        <div class="modal-background" onclick={onclick_diff_card.clone()} />
+                        <div class="modal-content box" style="width: 90vw;">
+                            <ModificationsDiff modifications={self.checked_data()} />
+                        </div>
+                        <button class="modal-close is-large" aria-label="close" onclick={onclick_diff_card} />
+                    </div>
+                },
+                false => html!{},
+            }}
+            {match self.open_export_card {
+                true => html!{
+                    <div class="modal is-active">
+                        <div class="modal-background" onclick={onclick_export_card.clone()} />
+                        <div class="modal-content box">
+                            <ModificationsExport component_uuid={self.component_uuid.clone()} />
+                        </div>
+                        <button class="modal-close is-large" aria-label="close" onclick={onclick_export_card} />
+                    </div>
+                },
+                false => html!{},
+            }}
+        </>}
+    }
+}
@@ -6,24 +6,33 @@ use wasm_bindgen_futures::spawn_local;
 
 use super::ModificationEdit;
 use super::table::ModificationsTable;
+use super::grid::ModificationsGridEdit;
+use super::generator::ModificationsGenerator;
+use super::lineage::ModificationsLineage;
+use super::derive::ModificationDerive;
 use crate::error::Error;
 use crate::fragments::component::modification::ImportModificationsData;
+use crate::fragments::component::ParamTemplates;
 use crate::fragments::paginate::Paginate;
 use crate::fragments::buttons::ft_add_btn;
 use crate::fragments::list_errors::ListErrors;
-use crate::services::{get_value_field, resp_parsing, set_focus};
-use crate::types::{UUID, ComponentModificationInfo, ActualStatus, ModificationUpdatePreData, PaginateSet};
+use crate::services::{get_value_field, missing_params, resp_parsing, set_focus};
+use crate::types::{UUID, ComponentModificationInfo, ActualStatus, ModificationUpdatePreData, PaginateSet, Param, Spec};
 use crate::gqls::make_query;
 use crate::gqls::component::{
     RegisterComponentModification, register_component_modification,
     GetComponentModifications, get_component_modifications,
     ComponentActualStatuses, component_actual_statuses,
+    PutModificationParams, put_modification_params,
 };
 
 #[derive(Clone, Debug, Properties)]
 pub struct Props {
     pub current_component_uuid: UUID,
     pub modifications_count: i64,
+    /// Specs of the component for the choice of the parameter templates
+    #[prop_or_default]
+    pub component_specs: Vec<Spec>,
 }
 
 pub struct ModificationsTableEdit {
@@ -36,6 +45,10 @@ pub struct ModificationsTableEdit {
     invalid_modification_uuids: BTreeSet<UUID>,
     open_add_modification_card: bool,
     open_edit_modification_card: bool,
+    grid_mode: bool,
+    show_lineage: bool,
+    /// Root of the selected subtree of the lineage with the UUIDs of its modifications
+    subtree_filter: Option<(UUID, Vec<UUID>)>,
     skip_change_page: bool,
     page_set: PaginateSet,
     current_items: i64,
@@ -56,6 +69,12 @@ pub enum Msg {
     ChangeNewModificationParam(UUID),
     ChangeSelectModification(UUID),
     ChangePaginate(PaginateSet),
+    SwitchGridMode,
+    SwitchLineage,
+    SelectSubtree(Option<(UUID, Vec<UUID>)>),
+    FinishDerive(UUID),
+    ApplyTemplate(Vec<Param>),
+    GetApplyTemplateResult(String),
     Focuser,
     ClearError,
 }
@@ -76,6 +95,9 @@ impl Component for ModificationsTableEdit {
             invalid_modification_uuids: BTreeSet::new(),
             open_add_modification_card: false,
             open_edit_modification_card: false,
+            grid_mode: false,
+            show_lineage: false,
+            subtree_filter: None,
             skip_change_page: false,
             page_set: PaginateSet::new(),
             current_items: 0,
@@ -119,7 +141,7 @@ impl Component for ModificationsTableEdit {
                 let (filter, ipt_sort, ipt_paginate) = match self.open_add_modification_card {
                     true => (Some(vec![self.select_modification_uuid.clone()]), None, None),
                     false => (
-                        None,
+                        self.subtree_filter.as_ref().map(|(_, uuids)| uuids.clone()),
                         Some(get_component_modifications::IptSort {byField: "name".to_string(), asDesc: false}),
                         Some(get_component_modifications::IptPaginate {currentPage: self.page_set.current_page, perPage: self.page_set.per_page})
                     ),
@@ -255,6 +277,68 @@ impl Component for ModificationsTableEdit {
                 self.page_set = page_set;
                 self.link.send_message(Msg::RequestComponentModificationsData);
             },
+            Msg::SwitchGridMode => {
+                self.grid_mode = !self.grid_mode;
+                self.open_edit_modification_card = false;
+            },
+            Msg::SwitchLineage => {
+                self.show_lineage = !self.show_lineage;
+                if !self.show_lineage && self.subtree_filter.is_some() {
+                    link.send_message(Msg::SelectSubtree(None));
+                }
+            },
+            Msg::SelectSubtree(subtree_filter) => {
+                self.subtree_filter = subtree_filter;
+                self.page_set.current_page = 1;
+                link.send_message(Msg::RequestComponentModificationsData);
+            },
+            Msg::FinishDerive(modification_uuid) => {
+                // the derived modification is opened as a new one and belongs to the subtree of its parent
+                if let Some((_, uuids)) = self.subtree_filter.as_mut() {
+                    uuids.push(modification_uuid.clone());
+                }
+                self.select_modification_uuid = modification_uuid;
+                self.total_items += 1;
+                self.open_add_modification_card = true;
+                self.open_edit_modification_card = false;
+                link.send_message(Msg::RequestComponentModificationsData);
+            },
+            Msg::ApplyTemplate(params) => {
+                let modification = match self.current_modifications.iter().find(|m| m.uuid == self.select_modification_uuid) {
+                    Some(modification) => modification,
+                    None => return false,
+                };
+                // the parameters of the selected modification get empty values, the added ones are skipped
+                let param_ids = modification.modification_params.iter().map(|mp| mp.param.param_id).collect();
+                let params: Vec<put_modification_params::IptParamData> = missing_params(&params, &param_ids).iter()
+                    .map(|p| put_modification_params::IptParamData{
+                        paramId: p.param_id as i64,
+                        value: String::new(),
+                    })
+                    .collect();
+                if params.is_empty() {
+                    return false
+                }
+                let ipt_modification_param_data = put_modification_params::IptModificationParamData{
+                    modificationUuid: modification.uuid.clone(),
+                    params,
+                };
+                spawn_local(async move {
+                    let res = make_query(PutModificationParams::build_query(
+                        put_modification_params::Variables { ipt_modification_param_data }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetApplyTemplateResult(res));
+                })
+            },
+            Msg::GetApplyTemplateResult(res) => {
+                match resp_parsing::<usize>(res, "putModificationParams") {
+                    Ok(result) => {
+                        debug!("putModificationParams: {:?}", result);
+                        link.send_message(Msg::RequestComponentModificationsData);
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
             Msg::Focuser => set_focus("show-modification-edit"),
             Msg::ClearError => self.error = None,
         }
@@ -275,6 +359,10 @@ impl Component for ModificationsTableEdit {
         let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
         let onclick_add_new_modification = self.link.callback(|_| Msg::AddNewModification);
         let callback_finish_import = self.link.callback(|_| Msg::RequestComponentModificationsData);
+        let callback_finish_generate = self.link.callback(|_| Msg::RequestComponentModificationsData);
+        let onclick_grid_mode = self.link.callback(|_| Msg::SwitchGridMode);
+        let onclick_lineage = self.link.callback(|_| Msg::SwitchLineage);
+        let onclick_apply_template = self.link.callback(Msg::ApplyTemplate);
         html!{
             <div class="card">
                 <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
@@ -284,8 +372,39 @@ impl Component for ModificationsTableEdit {
                         <div class="buttons right-side">
                             <ImportModificationsData
                                 component_uuid={self.props.current_component_uuid.clone()}
+                                component_specs={self.props.component_specs.clone()}
                                 callback_finish_import={callback_finish_import}
                                 />
+                            <ParamTemplates
+                                component_specs={self.props.component_specs.clone()}
+                                callback_apply={onclick_apply_template}
+                                disabled={self.select_modification_uuid.is_empty() || self.grid_mode}
+                                />
+                            <ModificationsGenerator
+                                component_uuid={self.props.current_component_uuid.clone()}
+                                callback_finish_generate={callback_finish_generate}
+                                />
+                            {self.show_derive_button()}
+                            <button
+                                id="switch-modifications-lineage"
+                                class={match self.show_lineage {
+                                    true => "button is-info",
+                                    false => "button",
+                                }}
+                                title={get_value_field(&534)}
+                                onclick={onclick_lineage} >
+                                <span class="icon"><i class="fas fa-sitemap" aria-hidden="true"></i></span>
+                            </button>
+                            <button
+                                id="switch-modifications-grid"
+                                class={match self.grid_mode {
+                                    true => "button is-info",
+                                    false => "button",
+                                }}
+                                onclick={onclick_grid_mode} >
+                                <span class="icon"><i class="fas fa-th" aria-hidden="true"></i></span>
+                                <span>{get_value_field(&515)}</span> // Grid editing
+                            </button>
                             {ft_add_btn(
                                 "add-component-modification",
                                 get_value_field(&174),
@@ -317,27 +436,73 @@ impl ModificationsTableEdit {
                 modifications.push(m.clone());
             }
         }
+        let callback_grid_saved = self.link.callback(|_| Msg::RequestComponentModificationsData);
+        let total_items = match &self.subtree_filter {
+            Some((_, uuids)) => uuids.len() as i64,
+            None => self.total_items,
+        };
         html!{
             <div class="card-content">
-                <ModificationsTable
-                    modifications={modifications}
-                    select_modification_uuid={self.select_modification_uuid.clone()}
-                    open_modification_card={self.open_edit_modification_card}
-                    callback_select_modification={onclick_select_modification}
-                    callback_new_modification_param={Some(onclick_new_modification_param)}
-                    numero_offset={self.page_set.numero_offset()}
-                />
-                <Paginate
-                    callback_change={onclick_paginate}
-                    current_items={self.current_items}
-                    current_page={Some(self.page_set.current_page)}
-                    per_page={Some(self.page_set.per_page)}
-                    total_items={self.total_items}
-                />
+                <div class="columns">
+                    {match self.show_lineage {
+                        true => html!{
+                            <div class="column is-3">
+                                <ModificationsLineage
+                                    component_uuid={self.props.current_component_uuid.clone()}
+                                    modifications_count={self.total_items}
+                                    subtree_root={self.subtree_filter.as_ref().map(|(root, _)| root.clone())}
+                                    callback_select_subtree={self.link.callback(Msg::SelectSubtree)}
+                                    />
+                            </div>
+                        },
+                        false => html!{},
+                    }}
+                    <div class="column">
+                        {match self.grid_mode {
+                            true => html!{
+                                <ModificationsGridEdit
+                                    modifications={modifications}
+                                    callback_saved={callback_grid_saved}
+                                />
+                            },
+                            false => html!{
+                                <ModificationsTable
+                                    modifications={modifications}
+                                    select_modification_uuid={self.select_modification_uuid.clone()}
+                                    open_modification_card={self.open_edit_modification_card}
+                                    callback_select_modification={onclick_select_modification}
+                                    callback_new_modification_param={Some(onclick_new_modification_param)}
+                                    numero_offset={self.page_set.numero_offset()}
+                                />
+                            },
+                        }}
+                        <Paginate
+                            callback_change={onclick_paginate}
+                            current_items={self.current_items}
+                            current_page={Some(self.page_set.current_page)}
+                            per_page={Some(self.page_set.per_page)}
+                            total_items={total_items}
+                        />
+                    </div>
+                </div>
             </div>
         }
     }
 
+    /// Shows the button for creating a modification derived from the selected one
+    fn show_derive_button(&self) -> Html {
+        let callback_finish_derive = self.link.callback(Msg::FinishDerive);
+        match self.current_modifications.iter().find(|m| m.uuid == self.select_modification_uuid) {
+            Some(parent) => html!{
+                <ModificationDerive
+                    parent={parent.clone()}
+                    callback_finish_derive={callback_finish_derive}
+                    />
+            },
+            None => html!{},
+        }
+    }
+
     fn show_modification_edit(&self) -> Html {
         let callback_delete_modification = self.link.callback(|value| Msg::SetModificationAsRemote(value));
         let modification_data = self.current_modifications.iter().find(|x| x.uuid == self.select_modification_uuid);
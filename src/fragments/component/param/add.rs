@@ -6,11 +6,12 @@ use wasm_bindgen_futures::spawn_local;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::ft_save_btn;
-use crate::services::{get_value_field, resp_parsing};
+use crate::services::{get_value_field, normalize_value, resp_parsing};
 use crate::services::content_adapter::Markdownable;
 use crate::gqls::make_query;
 use crate::gqls::relate::{RegisterParam, register_param};
 use crate::types::{Param, ParamValue};
+use super::ft_unit_help;
 
 #[derive(Clone, Debug, Properties)]
 pub struct Props {
@@ -76,10 +77,13 @@ impl Component for RegisterParamnameBlock {
                 match resp_parsing::<Param>(res, "registerParam") {
                     Ok(result) => {
                         debug!("registerParam: {:?}", result);
+                        // the value is checked by the unit from the name of the parameter
+                        let value = normalize_value(&result.paramname, &self.set_param_value)
+                            .unwrap_or_else(|_| self.set_param_value.clone());
                         self.props.callback_add_param.emit(
                             ParamValue{
                                 param_id: result.param_id,
-                                value: self.set_param_value.clone()
+                                value,
                             }
                         );
                         self.active_loading_btn = false;
@@ -126,6 +130,7 @@ impl RegisterParamnameBlock {
             <div class="column">
                 <label class="label">{get_value_field(&178)}</label>
                 <p class="help">{get_value_field(&336)}<span>{" "}</span>{get_value_field(&337)}</p>
+                <p class="help">{get_value_field(&560)}</p> // Unit in square brackets
                 <input
                     id="paramname"
                     class="input is-fullwidth"
@@ -153,6 +158,7 @@ impl RegisterParamnameBlock {
                     value={self.set_param_value.clone()}
                     oninput={oninput_set_param_value}
                     />
+                {ft_unit_help(&self.request_new_paramname, &self.set_param_value)}
             </div>
             <div class="column">
                 {ft_save_btn(
@@ -161,7 +167,8 @@ impl RegisterParamnameBlock {
                     true,
                     self.disable_btn ||
                         self.request_new_paramname.is_empty() ||
-                        self.set_param_value.is_empty()
+                        self.set_param_value.is_empty() ||
+                        normalize_value(&self.request_new_paramname, &self.set_param_value).is_err()
                 )}
             </div>
         </>}
@@ -4,14 +4,15 @@ use graphql_client::GraphQLQuery;
 use wasm_bindgen_futures::spawn_local;
 
 use parser_raw::parsing_single;
-use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use yew::services::reader::{File, FileData, ReaderService, ReaderTask};
 use log::debug;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::ft_import_btn;
 use crate::fragments::notification::show_notification;
 use crate::types::{Param, ParamValue, UUID};
-use crate::services::{get_value_field, resp_parsing};
+use crate::services::{get_value_field, resp_parsing, decode_text, ParsingSplit, TextEncoding};
 use crate::services::content_adapter::Markdownable;
 use crate::gqls::make_query;
 use crate::gqls::relate::{RegisterParamsBulk, register_params_bulk};
@@ -31,8 +32,16 @@ pub struct ImportParamsData {
     parsed_params: Vec<(String, String)>,
     new_params: Vec<ParamValue>,
     stat_info: String,
+    /// Separator selected by the user, None for the auto detection
+    split: Option<ParsingSplit>,
+    file_encoding: Option<TextEncoding>,
+    reader: Option<ReaderTask>,
+    show_preview: bool,
 }
 
+/// Number of rows shown in the preview before the import
+const PREVIEW_ROWS: usize = 20;
+
 pub enum Msg {
     RequestRegisterParams,
     GetRegisterParamsResult(String),
@@ -40,7 +49,12 @@ pub enum Msg {
     ResponseError(Error),
     ShowImport,
     UpdateData(String),
+    SelectSplit(Option<ParsingSplit>),
+    UploadFile(File),
+    GetFileData(FileData),
+    ShowPreview,
     ClearError,
+    Ignore,
 }
 
 impl Component for ImportParamsData {
@@ -56,6 +70,10 @@ impl Component for ImportParamsData {
             parsed_params: Vec::new(),
             new_params: Vec::new(),
             stat_info: String::new(),
+            split: None,
+            file_encoding: None,
+            reader: None,
+            show_preview: false,
         }
     }
 
@@ -64,7 +82,7 @@ impl Component for ImportParamsData {
         match msg {
             Msg::RequestRegisterParams => {
                 debug!("Raw data: {}", self.new_params_raw);
-                self.parsed_params = parsing_single(&self.new_params_raw);
+                self.parsed_params = parsing_single(&self.new_params_raw, self.parsing_split());
                 debug!("Complete self.parsed_params: {:?}", self.parsed_params);
                 let mut ipt_params_translate_list_data = Vec::new();
                 for (p, _) in &self.parsed_params {
@@ -105,6 +123,7 @@ impl Component for ImportParamsData {
                 self.new_params_raw.clear();
                 // self.stat_info = format!("{} {}", get_value_field(&213), result);
                 self.hide_import = true;
+                self.show_preview = false;
                 self.props.callback_add_params.emit(self.new_params.clone());
                 self.new_params.clear();
             },
@@ -112,22 +131,33 @@ impl Component for ImportParamsData {
             Msg::ShowImport => {
                 self.stat_info.clear();
                 self.hide_import = !self.hide_import;
+                self.show_preview = false;
             },
             Msg::UpdateData(value) => {
                 self.new_params_raw = value;
-                if self.new_params_raw.is_empty() {
-                    self.stat_info.clear();
-                    return true
-                }
-                let mut row_count = 0;
-                for (_n, c) in self.new_params_raw.char_indices() {
-                    if c == '\n' {
-                        row_count += 1;
-                    }
-                }
-                self.stat_info = format!("{}: {}", get_value_field(&346), row_count);
+                self.file_encoding = None;
+                self.update_stat_info();
+            },
+            Msg::SelectSplit(split) => {
+                self.split = split;
+                self.update_stat_info();
             },
+            Msg::UploadFile(file) => {
+                let callback = self.link.callback(Msg::GetFileData);
+                self.reader = ReaderService::read_file(file, callback).ok();
+                return false
+            },
+            Msg::GetFileData(data) => {
+                self.reader = None;
+                let (text, encoding) = decode_text(&data.content);
+                debug!("File {:?} decoded as {:?}", data.name, encoding);
+                self.new_params_raw = text;
+                self.file_encoding = Some(encoding);
+                self.update_stat_info();
+            },
+            Msg::ShowPreview => self.show_preview = !self.show_preview,
             Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
         }
         true
     }
@@ -156,10 +186,25 @@ impl Component for ImportParamsData {
 }
 
 impl ImportParamsData {
+    /// Separator selected by the user or detected by the data
+    fn parsing_split(&self) -> ParsingSplit {
+        self.split.unwrap_or_else(|| ParsingSplit::detect(&self.new_params_raw))
+    }
+
+    fn update_stat_info(&mut self) {
+        if self.new_params_raw.is_empty() {
+            self.stat_info.clear();
+            return
+        }
+        let row_count = parsing_single(&self.new_params_raw, self.parsing_split()).len();
+        self.stat_info = format!("{}: {}", get_value_field(&346), row_count);
+        if let Some(encoding) = self.file_encoding {
+            self.stat_info.push_str(&format!(", {}: {}", get_value_field(&496), encoding.label()));
+        }
+    }
+
     fn show_import_modal(&self) -> Html {
         let onclick_hide_modal = self.link.callback(|_| Msg::ShowImport);
-        let oninput_data = self.link.callback(|ev: InputData| Msg::UpdateData(ev.value));
-        let onclick_subbmit = self.link.callback(|_| Msg::RequestRegisterParams);
         let class_modal = match &self.hide_import {
             true => "modal",
             false => "modal is-active",
@@ -176,34 +221,123 @@ impl ImportParamsData {
                       <button class={"delete"} aria-label="close" onclick={onclick_hide_modal.clone()} />
                     </header>
                     <section class={"modal-card-body"}>
-                        <div class={"column"}>
-                            <div class={"subtitle is-6"}>
-                                {get_value_field(&234)}<br/>
-                                {get_value_field(&235).to_markdown()}
-                            </div>
-                            <textarea
-                                id={"update-description"}
-                                class={"textarea"}
-                                type={"text"}
-                                placeholder={get_value_field(&208)}
-                                value={self.new_params_raw.clone()}
-                                oninput={oninput_data}
-                                />
-                            <p class={"help"}>{self.stat_info.clone()}</p>
-                        </div>
-                        <div class={"column"}>
-                            {ft_import_btn(
-                                "import-params-btn",
-                                onclick_subbmit,
-                                get_value_field(&209),
-                                true,
-                                self.new_params_raw.is_empty()
-                            )}
-                        </div>
+                        {match self.show_preview {
+                            true => self.show_preview_data(),
+                            false => self.show_input_data(),
+                        }}
                       </section>
                   </div>
                 </div>
               </div>
         }
     }
+
+    fn show_input_data(&self) -> Html {
+        let oninput_data = self.link.callback(|ev: InputData| Msg::UpdateData(ev.value));
+        let onclick_preview = self.link.callback(|_| Msg::ShowPreview);
+        html!{<>
+            <div class={"column"}>
+                <div class={"subtitle is-6"}>
+                    {get_value_field(&234)}<br/>
+                    {get_value_field(&235).to_markdown()}
+                </div>
+                <textarea
+                    id={"update-description"}
+                    class={"textarea"}
+                    type={"text"}
+                    placeholder={get_value_field(&208)}
+                    value={self.new_params_raw.clone()}
+                    oninput={oninput_data}
+                    />
+                <p class={"help"}>{self.stat_info.clone()}</p>
+            </div>
+            {self.show_source_options()}
+            <div class={"column"}>
+                <button
+                    id={"preview-params-btn"}
+                    class={"button is-link is-fullwidth"}
+                    disabled={self.new_params_raw.is_empty()}
+                    onclick={onclick_preview} >
+                    <span class={"icon is-small"}><i class={"fas fa-table"} aria-hidden="true"></i></span>
+                    <span>{get_value_field(&335)}</span> // Preview
+                </button>
+            </div>
+        </>}
+    }
+
+    /// Shows the file input and the select of the separator
+    fn show_source_options(&self) -> Html {
+        let onchange_file = self.link.callback(|value: ChangeData| match value {
+            ChangeData::Files(files) => match files.get(0) {
+                Some(file) => Msg::UploadFile(file),
+                None => Msg::Ignore,
+            },
+            _ => Msg::Ignore,
+        });
+        let onchange_split = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectSplit(ParsingSplit::from_value(&el.value())),
+            _ => Msg::Ignore,
+        });
+        html!{
+            <div class={"column is-flex is-justify-content-space-between is-align-items-center"}>
+                <div class={"file is-small"}>
+                    <label class={"file-label"}>
+                        <input class={"file-input"} type={"file"} accept={".csv,.tsv,.txt"} onchange={onchange_file} />
+                        <span class={"file-cta"}>
+                            <span class={"file-icon"}><i class={"fas fa-file-csv"}></i></span>
+                            <span class={"file-label"}>{get_value_field(&495)}</span> // Or load a CSV file
+                        </span>
+                    </label>
+                </div>
+                <div class={"field has-addons"}>
+                    <p class={"control"}>
+                        <span class={"button is-small is-static"}>{get_value_field(&490)}</span> // Separator
+                    </p>
+                    <div class={"control select is-small"}>
+                        <select id={"import-split"} onchange={onchange_split}>
+                            <option value={""} selected={self.split.is_none()}>{get_value_field(&491)}</option> // Auto
+                            {for ParsingSplit::ALL.iter().map(|split| html!{
+                                <option value={split.to_value()} selected={self.split == Some(*split)}>
+                                    {split.label()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    /// Shows the parameters and values as they will be imported
+    fn show_preview_data(&self) -> Html {
+        let onclick_back = self.link.callback(|_| Msg::ShowPreview);
+        let onclick_subbmit = self.link.callback(|_| Msg::RequestRegisterParams);
+        let parsed_params = parsing_single(&self.new_params_raw, self.parsing_split());
+        html!{<>
+            <div class={"column"}>
+                <p class={"help"}>{get_value_field(&497)}</p>
+                <p class={"help"}>{self.stat_info.clone()}</p>
+                <table class={"table is-fullwidth is-striped is-narrow"}>
+                    <tbody>
+                        {for parsed_params.iter().take(PREVIEW_ROWS).map(|(paramname, value)| html!{
+                            <tr>
+                                <th>{paramname.clone()}</th>
+                                <td style={"white-space: pre-wrap;"}>{value.clone()}</td>
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+            <div class={"column buttons"}>
+                <button class={"button"} onclick={onclick_back}>{get_value_field(&115)}</button> // Back
+                {ft_import_btn(
+                    "import-params-btn",
+                    onclick_subbmit,
+                    get_value_field(&209),
+                    true,
+                    parsed_params.is_empty()
+                )}
+            </div>
+        </>}
+    }
 }
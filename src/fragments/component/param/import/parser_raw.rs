@@ -1,36 +1,20 @@
-use regex::Regex;
+use crate::services::{ParsingSplit, parsing_delimited};
 
 /// Returns arrays of received data, containing pair of header and value.
-pub(crate) fn parsing_single(data: &str) -> Vec<(String, String)> {
-    let mut lines = data.lines();
-    let mut result = Vec::new();
-    for line in lines.by_ref() {
-        if line.is_empty() {
-            continue;
-        }
-        if let Some((header, value)) = parsing_hp(line) {
-            result.push((header.to_string(), value.to_string()));
-        }
-    }
-    result
+pub(crate) fn parsing_single(data: &str, split: ParsingSplit) -> Vec<(String, String)> {
+    parsing_delimited(data, split)
+        .into_iter()
+        .filter_map(parsing_hp)
+        .collect()
 }
 
-pub(crate) fn parsing_hp(text: &str) -> Option<(&str, &str)> {
-    let re = r"([^\t\n]*)";
-    let mut header = "";
-    let mut value = "";
-    for m in Regex::new(re).unwrap().find_iter(text) {
-        if header.is_empty() {
-            header = m.as_str();
-            continue;
-        }
-        value = m.as_str();
-        break;
-    }
-    if header.is_empty() || value.is_empty() {
-        return None;
+/// Takes the first two values of the row, the row is skipped if one of them is empty
+pub(crate) fn parsing_hp(values: Vec<String>) -> Option<(String, String)> {
+    let mut values = values.into_iter();
+    match (values.next(), values.next()) {
+        (Some(header), Some(value)) if !header.is_empty() && !value.is_empty() => Some((header, value)),
+        _ => None,
     }
-    Some((header, value))
 }
 
 #[cfg(test)]
@@ -59,7 +43,18 @@ Param 7	lal so r32 m";
             (String::from("Param 7"), String::from("lal so r32 m"))
             ];
         // let output_test = (vec![Some("")], vec![vec![Some("")]]);
-        let result = parsing_single(input_test);
+        let result = parsing_single(input_test, ParsingSplit::Tab);
+        assert_eq!(output_test, result)
+    }
+
+    #[test]
+    fn parsing_semicolon() {
+        let input_test = "\u{feff}Length, mm;\"1,5\"\r\nNote;\"Line 1\r\nLine 2\"\r\n;Bad\r\n";
+        let output_test = vec![
+            (String::from("Length, mm"), String::from("1,5")),
+            (String::from("Note"), String::from("Line 1\r\nLine 2")),
+        ];
+        let result = parsing_single(input_test, ParsingSplit::detect(input_test));
         assert_eq!(output_test, result)
     }
 }
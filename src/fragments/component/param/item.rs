@@ -8,7 +8,8 @@ use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::{ft_save_btn, ft_delete_small_btn};
 use crate::services::content_adapter::Markdownable;
 use crate::types::{UUID, ComponentParam};
-use crate::services::{get_value_field, resp_parsing};
+use super::ft_unit_help;
+use crate::services::{get_value_field, normalize_value, resp_parsing};
 use crate::gqls::make_query;
 use crate::gqls::component::{
     PutComponentParams, put_component_params,
@@ -72,6 +73,11 @@ impl Component for ComponentParamTag {
         match msg {
             Msg::ChangeParamValue => self.hide_edit_param_value = !self.hide_edit_param_value,
             Msg::RequestChangeValue => {
+                // the value is saved in the unit of the parameter
+                match normalize_value(&self.props.param_data.param.paramname, &self.request_set_param_value) {
+                    Ok(value) => self.request_set_param_value = value,
+                    Err(_) => return true,
+                }
                 let ipt_param_data = put_component_params::IptParamData{
                     paramId: self.props.param_data.param.param_id as i64,
                     value: self.request_set_param_value.clone(),
@@ -225,6 +231,7 @@ impl ComponentParamTag {
                                 value={self.request_set_param_value.clone()}
                                 oninput={oninput_set_param_value}
                                 />
+                            {ft_unit_help(&self.props.param_data.param.paramname, &self.request_set_param_value)}
                         </div>
                         <div class="column">
                             {ft_save_btn(
@@ -232,7 +239,8 @@ impl ComponentParamTag {
                                 onclick_change_param_value,
                                 true,
                                 self.request_set_param_value.is_empty() ||
-                                    self.current_param_value == self.request_set_param_value
+                                    self.current_param_value == self.request_set_param_value ||
+                                    normalize_value(&self.props.param_data.param.paramname, &self.request_set_param_value).is_err()
                             )}
                         </div>
                       </section>
@@ -1,10 +1,14 @@
 mod item;
 mod add;
 mod import;
+mod unit;
+mod template;
 
 use import::ImportParamsData;
 pub use item::ComponentParamTag;
 pub use add::RegisterParamnameBlock;
+pub use unit::ft_unit_help;
+pub use template::ParamTemplates;
 
 use std::collections::BTreeSet;
 use yew::{Component, ComponentLink, Html, Properties, ShouldRender, html};
@@ -16,8 +20,8 @@ use crate::error::Error;
 use crate::fragments::buttons::ft_add_btn;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::paginate::Paginate;
-use crate::types::{ComponentParam, PaginateSet, Param, ParamValue, UUID};
-use crate::services::{get_classes_table, get_value_field, resp_parsing, resp_parsing_two_level};
+use crate::types::{ComponentParam, PaginateSet, Param, ParamValue, Spec, UUID};
+use crate::services::{get_classes_table, get_value_field, missing_params, resp_parsing, resp_parsing_two_level};
 use crate::gqls::{
     make_query,
     relate::{GetParams, get_params},
@@ -32,6 +36,9 @@ pub struct Props {
     pub show_manage_btn: bool,
     pub component_uuid: UUID,
     pub params_count: i64,
+    /// Specs of the component for the choice of the parameter templates
+    #[prop_or_default]
+    pub component_specs: Vec<Spec>,
 }
 
 pub struct ComponentParamsTags {
@@ -55,6 +62,8 @@ pub enum Msg {
     DeleteComponentParam(usize),
     RequestParamsList,
     RequestAddParams(Vec<ParamValue>),
+    ApplyTemplate(Vec<Param>),
+    GetTemplateParamsResult(Vec<Param>, String),
     RequestComponentParams,
     GetParamsListResult(String),
     GetComponentParamsResult(String),
@@ -137,6 +146,16 @@ impl Component for ComponentParamsTags {
                     link.send_message(Msg::GetAddParamResult(res));
                 })
             },
+            Msg::ApplyTemplate(params) => {
+                // all parameters of the component are requested to skip the added ones, not only the current page
+                let component_uuid = self.props.component_uuid.clone();
+                spawn_local(async move {
+                    let res = make_query(GetComponentParams::build_query(
+                        get_component_params::Variables { component_uuid, ipt_sort: None, ipt_paginate: None }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetTemplateParamsResult(params, res));
+                })
+            },
             Msg::RequestComponentParams => {
                 if self.props.component_uuid.len() != 36 {
                     return true
@@ -190,6 +209,21 @@ impl Component for ComponentParamsTags {
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
+            Msg::GetTemplateParamsResult(params, res) => {
+                match resp_parsing_two_level::<ComponentParam>(res, "component", "componentParams") {
+                    Ok(result) => {
+                        let param_ids = result.iter().map(|cp| cp.param.param_id).collect();
+                        let params: Vec<ParamValue> = missing_params(&params, &param_ids).into_iter()
+                            .map(|p| ParamValue { param_id: p.param_id, value: String::new() })
+                            .collect();
+                        debug!("Template params to add: {:?}", params);
+                        if !params.is_empty() {
+                            link.send_message(Msg::RequestAddParams(params));
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
             Msg::UpdateParamValue(data) => self.request_set_param_value = data,
             Msg::ChangeHideAddParam => {
                 if self.hide_add_param_modal && self.param_list.is_empty() {
@@ -240,6 +274,7 @@ impl Component for ComponentParamsTags {
 
     fn view(&self) -> Html {
         let onclick_add_params = self.link.callback(|params| Msg::RequestAddParams(params));
+        let onclick_apply_template = self.link.callback(Msg::ApplyTemplate);
         let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
         let onclick_paginate = self.link.callback(|page_set| Msg::ChangePaginate(page_set));
         html!{<>
@@ -258,6 +293,10 @@ impl Component for ComponentParamsTags {
                                 component_uuid={self.props.component_uuid.clone()}
                                 callback_add_params={onclick_add_params.clone()}
                             />
+                            <ParamTemplates
+                                component_specs={self.props.component_specs.clone()}
+                                callback_apply={onclick_apply_template}
+                            />
                             {ft_add_btn(
                                 "add-param-component",
                                 get_value_field(&180),
@@ -0,0 +1,392 @@
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::fragments::buttons::{ft_add_btn, ft_save_btn};
+use crate::fragments::list_errors::ListErrors;
+use crate::types::{Param, Spec};
+use crate::services::{
+    get_value_field, resp_parsing, load_templates, save_templates, sort_templates, upsert_template, ParamTemplate,
+};
+use crate::gqls::make_query;
+use crate::gqls::relate::{GetParams, get_params};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    /// Specs of the component, the templates of these specs are offered first
+    #[prop_or_default]
+    pub component_specs: Vec<Spec>,
+    /// Parameters of the applied template in its order
+    pub callback_apply: Callback<Vec<Param>>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Button with the list of the parameter templates to apply and the editor of the templates
+pub struct ParamTemplates {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    hide_modal: bool,
+    templates: Vec<ParamTemplate>,
+    param_list: Vec<Param>,
+    edit_template: ParamTemplate,
+    select_param_id: usize,
+}
+
+pub enum Msg {
+    ShowModal,
+    RequestParamsList,
+    GetParamsListResult(String),
+    Apply(usize),
+    Edit(usize),
+    Delete(usize),
+    UpdateName(String),
+    SelectSpec(String),
+    SelectParam(String),
+    AddParam,
+    MoveParamUp(usize),
+    RemoveParam(usize),
+    SaveTemplate,
+    ResponseError(Error),
+    ClearError,
+    Ignore,
+}
+
+impl Component for ParamTemplates {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            hide_modal: true,
+            templates: Vec::new(),
+            param_list: Vec::new(),
+            edit_template: ParamTemplate::default(),
+            select_param_id: 0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::ShowModal => {
+                self.hide_modal = !self.hide_modal;
+                if !self.hide_modal {
+                    // the templates can be changed on another page
+                    self.templates = load_templates(&self.props.component_specs);
+                    if self.param_list.is_empty() {
+                        link.send_message(Msg::RequestParamsList);
+                    }
+                }
+            },
+            Msg::RequestParamsList => {
+                spawn_local(async move {
+                    let res = make_query(GetParams::build_query(
+                        get_params::Variables { param_ids: None, ipt_paginate: None }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetParamsListResult(res));
+                })
+            },
+            Msg::GetParamsListResult(res) => {
+                match resp_parsing::<Vec<Param>>(res, "params") {
+                    Ok(result) => {
+                        debug!("params: {:?}", result.len());
+                        self.param_list = result;
+                        self.set_select_param();
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::Apply(index) => {
+                if let Some(template) = self.templates.get(index) {
+                    self.props.callback_apply.emit(template.params.clone());
+                    self.hide_modal = true;
+                }
+            },
+            Msg::Edit(index) => {
+                if let Some(template) = self.templates.get(index) {
+                    self.edit_template = template.clone();
+                    self.set_select_param();
+                }
+            },
+            Msg::Delete(index) => {
+                if index < self.templates.len() {
+                    self.templates.remove(index);
+                    save_templates(&self.templates);
+                }
+            },
+            Msg::UpdateName(value) => self.edit_template.name = value,
+            Msg::SelectSpec(value) => {
+                self.edit_template.spec_id = value.parse().ok();
+                self.edit_template.spec = self.props.component_specs.iter()
+                    .find(|s| Some(s.spec_id) == self.edit_template.spec_id)
+                    .map(|s| s.spec.clone())
+                    .unwrap_or_default();
+            },
+            Msg::SelectParam(value) => self.select_param_id = value.parse().unwrap_or_default(),
+            Msg::AddParam => {
+                if let Some(param) = self.param_list.iter().find(|p| p.param_id == self.select_param_id) {
+                    self.edit_template.params.push(param.clone());
+                    self.set_select_param();
+                }
+            },
+            Msg::MoveParamUp(index) => {
+                if index > 0 && index < self.edit_template.params.len() {
+                    self.edit_template.params.swap(index - 1, index);
+                }
+            },
+            Msg::RemoveParam(index) => {
+                if index < self.edit_template.params.len() {
+                    self.edit_template.params.remove(index);
+                    self.set_select_param();
+                }
+            },
+            Msg::SaveTemplate => {
+                let mut template = std::mem::take(&mut self.edit_template);
+                template.name = template.name.trim().to_string();
+                upsert_template(&mut self.templates, template);
+                sort_templates(&mut self.templates, &self.props.component_specs);
+                save_templates(&self.templates);
+                self.set_select_param();
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_show_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{<>
+            <button
+                id="param-templates-btn"
+                class="button"
+                disabled={self.props.disabled}
+                onclick={onclick_show_modal}
+                title={get_value_field(&565)} >
+                <span class="icon is-small">
+                    <i class="fas fa-clipboard-list" style="color: #1872f0;" aria-hidden="true"></i>
+                </span>
+                <span>{get_value_field(&565)}</span> // Parameter templates
+            </button>
+            {match self.hide_modal {
+                true => html!{},
+                false => self.show_modal(),
+            }}
+        </>}
+    }
+}
+
+impl ParamTemplates {
+    /// Selects the first parameter which is not in the edited template
+    fn set_select_param(&mut self) {
+        let params = &self.edit_template.params;
+        self.select_param_id = self.param_list.iter()
+            .find(|p| params.iter().all(|tp| tp.param_id != p.param_id))
+            .map(|p| p.param_id)
+            .unwrap_or_default();
+    }
+
+    fn show_modal(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let onclick_hide_modal = self.link.callback(|_| Msg::ShowModal);
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_hide_modal.clone()} />
+                <div class="modal-content">
+                  <div class="card">
+                    <header class="modal-card-head">
+                      <p class="modal-card-title">{get_value_field(&565)}</p> // Parameter templates
+                      <button class="delete" aria-label="close" onclick={onclick_hide_modal} />
+                    </header>
+                    <section class="modal-card-body">
+                        <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                        {self.show_templates()}
+                        <hr/>
+                        {self.show_edit_template()}
+                    </section>
+                  </div>
+                </div>
+              </div>
+        }
+    }
+
+    fn show_templates(&self) -> Html {
+        if self.templates.is_empty() {
+            return html!{<p class="help">{get_value_field(&571)}</p>}
+        }
+        html!{<>
+            <table class="table is-fullwidth is-narrow">
+                <tbody>
+                    {for self.templates.iter().enumerate().map(|(index, template)| self.show_template(index, template))}
+                </tbody>
+            </table>
+            <p class="help">{get_value_field(&574)}</p>
+        </>}
+    }
+
+    fn show_template(&self, index: usize, template: &ParamTemplate) -> Html {
+        let onclick_apply = self.link.callback(move |_| Msg::Apply(index));
+        let onclick_edit = self.link.callback(move |_| Msg::Edit(index));
+        let onclick_delete = self.link.callback(move |_| Msg::Delete(index));
+        let for_specs = template.for_specs(&self.props.component_specs);
+        html!{
+            <tr class={match for_specs {
+                true => "",
+                false => "has-text-grey-light",
+            }}>
+                <td>
+                    <p class="has-text-weight-bold">{template.name.clone()}</p>
+                    <p class="is-size-7">
+                        {template.params.iter().map(|p| p.paramname.as_str()).collect::<Vec<_>>().join(", ")}
+                    </p>
+                </td>
+                <td>
+                    {match template.spec_id {
+                        Some(_) => html!{<span class="tag is-info is-light">{template.spec.clone()}</span>},
+                        None => html!{},
+                    }}
+                </td>
+                <td>
+                    <div class="buttons is-right">
+                        <button class="button is-small is-link" onclick={onclick_apply}>
+                            {get_value_field(&566)} // Apply
+                        </button>
+                        <button class="button is-small" onclick={onclick_edit} title={get_value_field(&59)}>
+                            <span class="icon"><i class="fas fa-pen" aria-hidden="true"></i></span>
+                        </button>
+                        <button class="button is-small is-danger is-outlined" onclick={onclick_delete} title={get_value_field(&135)}>
+                            <span class="icon"><i class="fa fa-trash" aria-hidden="true"></i></span>
+                        </button>
+                    </div>
+                </td>
+            </tr>
+        }
+    }
+
+    fn show_edit_template(&self) -> Html {
+        let oninput_name = self.link.callback(|ev: InputData| Msg::UpdateName(ev.value));
+        let onchange_spec = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectSpec(el.value()),
+            _ => Msg::Ignore,
+        });
+        let onclick_save = self.link.callback(|_| Msg::SaveTemplate);
+        let template = &self.edit_template;
+        // the spec of the edited template may be not in the specs of the component
+        let spec_missing = template.spec_id.is_some_and(|spec_id| self.props.component_specs.iter().all(|s| s.spec_id != spec_id));
+        html!{<>
+            <div class="columns">
+                <div class="column">
+                    <label class="label">{get_value_field(&567)}</label> // Template name
+                    <input
+                        id="param-template-name"
+                        class="input"
+                        type="text"
+                        value={template.name.clone()}
+                        oninput={oninput_name} />
+                </div>
+                <div class="column">
+                    <label class="label">{get_value_field(&573)}</label> // Category
+                    <div class="select is-fullwidth">
+                        <select id="param-template-spec" onchange={onchange_spec}>
+                            <option value="" selected={template.spec_id.is_none()}>{get_value_field(&568)}</option> // Without category
+                            {match spec_missing {
+                                true => html!{
+                                    <option value={template.spec_id.unwrap_or_default().to_string()} selected={true}>
+                                        {template.spec.clone()}
+                                    </option>
+                                },
+                                false => html!{},
+                            }}
+                            {for self.props.component_specs.iter().map(|spec| html!{
+                                <option value={spec.spec_id.to_string()} selected={template.spec_id == Some(spec.spec_id)}>
+                                    {spec.spec.clone()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+            </div>
+            <label class="label">{get_value_field(&569)}</label> // Template parameters
+            {self.show_template_params()}
+            {self.show_add_param()}
+            <br/>
+            {ft_save_btn(
+                "save-param-template",
+                onclick_save,
+                true,
+                template.name.trim().is_empty() || template.params.is_empty()
+            )}
+        </>}
+    }
+
+    fn show_template_params(&self) -> Html {
+        html!{
+            <table class="table is-fullwidth is-narrow">
+                <tbody>
+                    {for self.edit_template.params.iter().enumerate().map(|(index, param)| {
+                        let onclick_up = self.link.callback(move |_| Msg::MoveParamUp(index));
+                        let onclick_remove = self.link.callback(move |_| Msg::RemoveParam(index));
+                        html!{
+                            <tr>
+                                <td>{index + 1}</td>
+                                <td>{param.paramname.clone()}</td>
+                                <td>
+                                    <div class="buttons is-right">
+                                        <button class="button is-small is-white" onclick={onclick_up} disabled={index == 0} title={get_value_field(&572)}>
+                                            <span class="icon"><i class="fas fa-arrow-up" aria-hidden="true"></i></span>
+                                        </button>
+                                        <button class="button is-small is-white" onclick={onclick_remove} title={get_value_field(&135)}>
+                                            <span class="icon"><i class="fas fa-times" aria-hidden="true"></i></span>
+                                        </button>
+                                    </div>
+                                </td>
+                            </tr>
+                        }
+                    })}
+                </tbody>
+            </table>
+        }
+    }
+
+    fn show_add_param(&self) -> Html {
+        let onchange_param = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectParam(el.value()),
+            _ => Msg::Ignore,
+        });
+        let onclick_add = self.link.callback(|_| Msg::AddParam);
+        let params = &self.edit_template.params;
+        html!{
+            <div class="field has-addons">
+                <div class="control is-expanded">
+                    <div class="select is-fullwidth">
+                        <select id="param-template-add" onchange={onchange_param}>
+                            {for self.param_list.iter()
+                                .filter(|p| params.iter().all(|tp| tp.param_id != p.param_id))
+                                .map(|p| html!{
+                                    <option value={p.param_id.to_string()} selected={p.param_id == self.select_param_id}>
+                                        {p.paramname.clone()}
+                                    </option>
+                                })}
+                        </select>
+                    </div>
+                </div>
+                <div class="control">
+                    {ft_add_btn("add-param-template", get_value_field(&178), onclick_add, false, self.select_param_id == 0)}
+                </div>
+            </div>
+        }
+    }
+}
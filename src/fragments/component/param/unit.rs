@@ -0,0 +1,20 @@
+use yew::{html, Html};
+use crate::services::{get_value_field, normalize_value, param_unit};
+
+/// Shows the unit of the parameter and the result of checking the entered value,
+/// nothing for parameters without a unit
+pub fn ft_unit_help(paramname: &str, value: &str) -> Html {
+    let unit = match param_unit(paramname) {
+        Some(unit) => unit,
+        None => return html!{},
+    };
+    match normalize_value(paramname, value) {
+        Err(err) => html!{<p class="help is-danger">{err.message()}</p>},
+        Ok(saved) if !saved.is_empty() && saved != value.trim() => html!{
+            <p class="help">
+                {format!("{}: {}. {}: {}", get_value_field(&558), unit.symbol, get_value_field(&559), saved)}
+            </p>
+        },
+        Ok(_) => html!{<p class="help">{format!("{}: {}", get_value_field(&558), unit.symbol)}</p>},
+    }
+}
@@ -0,0 +1,69 @@
+mod timeline;
+
+pub use timeline::StatusTimeline;
+
+use yew::{html, Callback, Html, InputData};
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::services::{allowed_statuses, check_status_note, get_value_field, StatusChange};
+use crate::types::ActualStatus;
+use crate::gqls::make_query;
+use crate::gqls::discussion::{RegisterDiscussionComment, register_discussion_comment};
+
+/// Options of the select of the status: the current status and the statuses of the allowed transitions
+pub(crate) fn status_options(statuses: &[ActualStatus], current_id: usize, selected_id: usize) -> Html {
+    html!{<>
+        {for allowed_statuses(statuses, current_id).into_iter().map(|status| html!{
+            <option value={status.actual_status_id.to_string()}
+                selected={status.actual_status_id == selected_id} >
+                {&status.name}
+            </option>
+        })}
+    </>}
+}
+
+/// Field for the note required for the status change, shown only when the status is changed
+pub(crate) fn status_note_field(id: &str, note: &str, oninput: Callback<InputData>) -> Html {
+    let error = check_status_note(note).err();
+    html!{
+        <div class="field">
+            <label class="label" for={id.to_string()}>{get_value_field(&634)}</label> // Note to the status change
+            <textarea
+                id={id.to_string()}
+                class={match error.is_some() {
+                    true => "textarea is-danger",
+                    false => "textarea",
+                }}
+                rows="3"
+                placeholder={get_value_field(&635)}
+                value={note.to_string()}
+                oninput={oninput} />
+            <p class="help">{get_value_field(&640)}</p> // The status is changed to the next or previous stage...
+            {match error {
+                Some(err) => html!{<p class="help is-danger">{err.message()}</p>},
+                None => html!{},
+            }}
+        </div>
+    }
+}
+
+/// Saves the status change as a comment in the discussion of the component,
+/// the callback gets the response of `registerDiscussionComment`
+pub(crate) fn send_status_change(change: &StatusChange, callback: Callback<String>) {
+    let ipt_discussion_comment_data = register_discussion_comment::IptDiscussionCommentData{
+        objectDiscussion: register_discussion_comment::IptDiscussionArg{
+            objectUuid: change.component_uuid.clone(),
+            toObject: register_discussion_comment::ToObject::COMPONENT,
+        },
+        discussionUuid: None,
+        parentCommentUuid: None,
+        messageContent: change.to_message(),
+    };
+    spawn_local(async move {
+        let res = make_query(RegisterDiscussionComment::build_query(
+            register_discussion_comment::Variables{ipt_discussion_comment_data}
+        )).await.unwrap();
+        callback.emit(res);
+    })
+}
@@ -0,0 +1,159 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::content_adapter::{DateDisplay, Markdownable};
+use crate::services::{get_value_field, resp_parsing, StatusChange};
+use crate::types::{DiscussionCommentData, DiscussionInfo, Pathname, UUID};
+use crate::gqls::make_query;
+use crate::gqls::discussion::{GetDiscussions, get_discussions};
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+}
+
+/// Timeline of the status changes of the component and its modifications (the newest first),
+/// the changes are read from the comments of the discussion of the component
+pub struct StatusTimeline {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    entries: Vec<(DiscussionCommentData, StatusChange)>,
+    loading: bool,
+}
+
+pub enum Msg {
+    RequestHistory,
+    GetHistoryResult(String),
+    ResponseError(Error),
+    ClearError,
+}
+
+impl Component for StatusTimeline {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            entries: Vec::new(),
+            loading: true,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestHistory);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestHistory => {
+                self.loading = true;
+                let ipt_object_discussions_arg = get_discussions::IptObjectDiscussionsArg{
+                    objectUuid: self.props.component_uuid.clone(),
+                    toObject: get_discussions::ToObject::COMPONENT,
+                    filterByUuids: None,
+                };
+                let ipt_sort = Some(get_discussions::IptSort {
+                    byField: "createdAt".to_string(),
+                    asDesc: true,
+                });
+                spawn_local(async move {
+                    let res = make_query(GetDiscussions::build_query(
+                        get_discussions::Variables{ ipt_object_discussions_arg, ipt_sort, ipt_paginate: None }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetHistoryResult(res));
+                })
+            },
+            Msg::GetHistoryResult(res) => {
+                self.loading = false;
+                match resp_parsing::<Vec<DiscussionInfo>>(res, "discussions") {
+                    Ok(result) => {
+                        self.entries = result.into_iter()
+                            .flat_map(|discussion| discussion.comments)
+                            .filter_map(|comment| StatusChange::from_message(&comment.message_content)
+                                .filter(|change| change.component_uuid == self.props.component_uuid)
+                                .map(|change| (comment, change)))
+                            .collect();
+                        self.entries.sort_by_key(|(comment, _)| std::cmp::Reverse(comment.created_at));
+                        debug!("Status changes: {}", self.entries.len());
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            return false
+        }
+        self.props = props;
+        self.entries.clear();
+        self.link.send_message(Msg::RequestHistory);
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+            {match (self.loading, self.entries.is_empty()) {
+                (true, _) => html!{<span class="icon"><i class="fas fa-spinner fa-pulse"></i></span>},
+                (false, true) => html!{<p class="help">{get_value_field(&639)}</p>}, // No status changes yet
+                (false, false) => html!{
+                    <ul id="status-timeline" class="status-timeline">
+                        {for self.entries.iter().map(|(comment, change)| self.show_entry(comment, change))}
+                    </ul>
+                },
+            }}
+        </>}
+    }
+}
+
+impl StatusTimeline {
+    fn show_entry(&self, comment: &DiscussionCommentData, change: &StatusChange) -> Html {
+        let (summary, details) = change.split_note();
+        html!{
+            <li class="box p-3 mb-2">
+                <div class="is-flex is-flex-wrap-wrap is-align-items-center mb-1">
+                    <span class="tag is-info is-light mr-2">
+                        <span>{&change.from_name}</span>
+                        <span class="icon"><i class="fas fa-long-arrow-alt-right" aria-hidden="true"></i></span>
+                        <span>{&change.to_name}</span>
+                    </span>
+                    {match change.modification_uuid.is_empty() {
+                        true => html!{},
+                        false => html!{
+                            <a class="tag is-light mr-2" href={format!("{}?mod={}",
+                                Pathname::Component(change.component_uuid.clone()).get_pathname(),
+                                change.modification_uuid,
+                            )}>
+                                {get_value_field(&463)} // Modification
+                            </a>
+                        },
+                    }}
+                    <span class="mr-2">{comment.created_at.date_to_display()}</span>
+                    <span class="has-text-grey">{format!("@{}", comment.author.username)}</span>
+                </div>
+                <p class="has-text-weight-bold">{summary}</p>
+                {match details.is_empty() {
+                    true => html!{},
+                    false => details.to_markdown(),
+                }}
+            </li>
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, Properties, ShouldRender, classes};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::fragments::notification::show_notification;
+use crate::fragments::component::modification::{ModificationModelSelect, SelectedModel};
+use crate::services::{
+    get_value_field, resp_parsing, put_file, render_thumbnail, create_object_url, revoke_object_url,
+    ModelFormat, ThumbnailView, UploadData,
+};
+use crate::types::{UUID, ComponentModificationInfo, DownloadFile, PaginateSet, UploadFile};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentModifications, get_component_modifications,
+    UploadComponentFavicon, upload_component_favicon,
+};
+use crate::gqls::relate::{ConfirmUploadCompleted, confirm_upload_completed};
+
+// 1. Select the modification, the fileset and the model file (STL or GLB)
+// 2. Render the model offscreen from the selected view
+// 3. Show the image and upload it as the component preview image (favicon)
+
+pub struct GenerateThumbnailCard {
+    error: Option<Error>,
+    props: Props,
+    link: ComponentLink<Self>,
+    modifications: Vec<ComponentModificationInfo>,
+    select_modification_uuid: UUID,
+    select_model: Option<DownloadFile>,
+    select_view: ThumbnailView,
+    image_data: Vec<u8>,
+    image_url: Option<String>,
+    rendering: bool,
+    uploading: bool,
+    request_upload_file: Option<UploadFile>,
+    get_result_up_completed: bool,
+}
+
+#[derive(PartialEq, Clone, Debug, Properties)]
+pub struct Props {
+    pub component_uuid: UUID,
+    pub callback: Callback<()>,
+}
+
+pub enum Msg {
+    RequestModifications,
+    RequestRenderThumbnail,
+    RequestUploadData,
+    RequestUploadCompleted,
+    GetModificationsResult(String),
+    GetThumbnailResult(Result<Vec<u8>, Error>),
+    GetUploadData(String),
+    GetUploadFile(Result<Option<String>, Error>),
+    GetUploadCompleted(String),
+    SelectModel(SelectedModel),
+    SelectView(usize),
+    ResponseError(Error),
+    ClearError,
+    Ignore,
+}
+
+impl Component for GenerateThumbnailCard {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            error: None,
+            props,
+            link,
+            modifications: Vec::new(),
+            select_modification_uuid: String::new(),
+            select_model: None,
+            select_view: ThumbnailView::Isometric,
+            image_data: Vec::new(),
+            image_url: None,
+            rendering: false,
+            uploading: false,
+            request_upload_file: None,
+            get_result_up_completed: false,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestModifications);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+
+        match msg {
+            Msg::RequestModifications => {
+                let component_uuid = self.props.component_uuid.clone();
+                let page_set = PaginateSet::set(Some(1), Some(50));
+                let ipt_sort = Some(get_component_modifications::IptSort {
+                    byField: "name".to_string(),
+                    asDesc: false,
+                });
+                let ipt_paginate = Some(get_component_modifications::IptPaginate {
+                    currentPage: page_set.current_page,
+                    perPage: page_set.per_page,
+                });
+                spawn_local(async move {
+                    let res = make_query(GetComponentModifications::build_query(
+                        get_component_modifications::Variables {
+                            component_uuid,
+                            filter: None,
+                            ipt_sort,
+                            ipt_paginate,
+                        }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetModificationsResult(res));
+                })
+            },
+            Msg::RequestRenderThumbnail => {
+                if let Some(file) = self.select_model.clone() {
+                    let model_format = ModelFormat::from_filename(&file.filename);
+                    self.rendering = true;
+                    let view = self.select_view;
+                    spawn_local(async move {
+                        let res = render_thumbnail(&file, model_format, view).await;
+                        link.send_message(Msg::GetThumbnailResult(res));
+                    })
+                }
+            },
+            Msg::RequestUploadData => {
+                if self.image_data.is_empty() {
+                    return false
+                }
+                self.uploading = true;
+                let ipt_component_favicon_data = upload_component_favicon::IptComponentFaviconData {
+                    componentUuid: self.props.component_uuid.clone(),
+                    filename: self.thumbnail_filename(),
+                };
+                spawn_local(async move {
+                    let res = make_query(UploadComponentFavicon::build_query(
+                        upload_component_favicon::Variables { ipt_component_favicon_data },
+                    )).await.unwrap();
+                    link.send_message(Msg::GetUploadData(res));
+                });
+            },
+            Msg::RequestUploadCompleted => {
+                let file_uuids = self.request_upload_file.iter().map(|uf| uf.file_uuid.clone()).collect();
+                spawn_local(async move {
+                    let res = make_query(ConfirmUploadCompleted::build_query(
+                        confirm_upload_completed::Variables { file_uuids }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetUploadCompleted(res));
+                });
+            },
+            Msg::GetModificationsResult(res) => {
+                match resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications") {
+                    Ok(result) => {
+                        self.modifications = result;
+                        self.select_modification_uuid = self.modifications.first().map(|m| m.uuid.clone()).unwrap_or_default();
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetThumbnailResult(res) => {
+                self.rendering = false;
+                match res {
+                    Ok(data) => {
+                        self.clear_image();
+                        self.image_url = create_object_url(&data, "image/png");
+                        self.image_data = data;
+                        self.get_result_up_completed = false;
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetUploadData(res) => {
+                match resp_parsing::<Option<UploadFile>>(res, "uploadComponentFavicon") {
+                    Ok(Some(upload_file)) => {
+                        let upload_data = UploadData {
+                            filename: upload_file.filename.clone(),
+                            upload_url: upload_file.upload_url.clone(),
+                            file_data: self.image_data.clone(),
+                        };
+                        self.request_upload_file = Some(upload_file);
+                        put_file(
+                            upload_data,
+                            self.link.callback(Msg::GetUploadFile),
+                            self.link.callback(|_| Msg::Ignore),
+                        );
+                    },
+                    Ok(None) => {
+                        self.uploading = false;
+                        link.send_message(Msg::ResponseError(Error::BadRequest(get_value_field(&457).to_string())));
+                    },
+                    Err(err) => {
+                        self.uploading = false;
+                        link.send_message(Msg::ResponseError(err));
+                    },
+                }
+            },
+            Msg::GetUploadFile(Ok(_)) => link.send_message(Msg::RequestUploadCompleted),
+            Msg::GetUploadFile(Err(err)) => {
+                self.uploading = false;
+                link.send_message(Msg::ResponseError(err));
+            },
+            Msg::GetUploadCompleted(res) => {
+                self.uploading = false;
+                match resp_parsing::<usize>(res, "uploadCompleted") {
+                    Ok(confirmations) => {
+                        debug!("Confirmation upload of thumbnail: {:?}", confirmations);
+                        self.get_result_up_completed = confirmations > 0;
+                        self.request_upload_file = None;
+                        self.props.callback.emit(());
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            // the modification is only set initially, further it is selected in the ModificationModelSelect
+            Msg::SelectModel((_, select_model)) => self.select_model = select_model,
+            Msg::SelectView(index) => self.select_view = ThumbnailView::from_index(index),
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.component_uuid == props.component_uuid {
+            false
+        } else {
+            self.props = props;
+            self.clear_image();
+            self.link.send_message(Msg::RequestModifications);
+            true
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
+            {show_notification(get_value_field(&92), "is-success", self.get_result_up_completed)}
+            <p class="help">{get_value_field(&459)}</p>
+            {self.show_selects()}
+            {self.show_result()}
+        </>}
+    }
+
+    fn destroy(&mut self) {
+        self.clear_image();
+    }
+}
+
+impl GenerateThumbnailCard {
+    fn show_selects(&self) -> Html {
+        let callback_select_model = self.link.callback(|value: SelectedModel| Msg::SelectModel(value));
+        let onchange_view = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectView(el.value().parse::<usize>().unwrap_or_default()),
+            _ => Msg::Ignore,
+        });
+        let onclick_generate = self.link.callback(|_| Msg::RequestRenderThumbnail);
+        let class_btn = match self.rendering {
+            true => classes!("button", "is-info", "is-fullwidth", "is-loading"),
+            false => classes!("button", "is-info", "is-fullwidth"),
+        };
+
+        html!{<>
+            {match self.modifications.is_empty() {
+                true => html!{},
+                false => html!{
+                    <ModificationModelSelect
+                        modifications={self.modifications.clone()}
+                        select_modification_uuid={self.select_modification_uuid.clone()}
+                        callback_select_model={callback_select_model}
+                        />
+                },
+            }}
+            <div class="columns">
+                <div class="column">
+                    <label class="label">{get_value_field(&419)}</label> // View
+                    <div class="select is-fullwidth">
+                        <select id="thumbnail-view" onchange={onchange_view}>
+                            {for ThumbnailView::ALL.iter().enumerate().map(|(index, view)| html!{
+                                <option value={index.to_string()}
+                                        selected={view == &self.select_view} >
+                                    {view.label()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+                <div class="column is-narrow" style="align-self: flex-end;">
+                    <button id="thumbnail-generate"
+                        class={class_btn}
+                        disabled={self.select_model.is_none() || self.rendering || self.uploading}
+                        onclick={onclick_generate} >
+                        <span class="icon">
+                            <i class="fas fa-camera" aria-hidden="true"></i>
+                        </span>
+                        <span>{get_value_field(&460)}</span>
+                    </button>
+                </div>
+            </div>
+        </>}
+    }
+
+    fn show_result(&self) -> Html {
+        let image_url = match &self.image_url {
+            Some(url) => url.clone(),
+            None => return html!{},
+        };
+        let onclick_upload = self.link.callback(|_| Msg::RequestUploadData);
+        let class_btn = match self.uploading {
+            true => classes!("button", "is-success", "is-fullwidth", "is-loading"),
+            false => classes!("button", "is-success", "is-fullwidth"),
+        };
+
+        html!{
+            <div class="columns">
+                <div class="column is-half">
+                    <figure class="image is-square">
+                        <img src={image_url} alt={get_value_field(&335)} />
+                    </figure>
+                </div>
+                <div class="column" style="align-self: flex-end;">
+                    <button id="thumbnail-upload"
+                        class={class_btn}
+                        disabled={self.uploading || self.get_result_up_completed}
+                        onclick={onclick_upload} >
+                        <span class="icon">
+                            <i class="fas fa-upload" aria-hidden="true"></i>
+                        </span>
+                        <span>{get_value_field(&462)}</span>
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn thumbnail_filename(&self) -> String {
+        format!("thumbnail_{}.png", format!("{:?}", self.select_view).to_lowercase())
+    }
+
+    fn clear_image(&mut self) {
+        if let Some(url) = self.image_url.take() {
+            revoke_object_url(&url);
+        }
+        self.image_data.clear();
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use chrono::NaiveDateTime;
-use yew::{classes, html, Component, ComponentLink, FocusEvent, Html, InputData, Properties, ShouldRender};
+use yew::{classes, html, Callback, Component, ComponentLink, FocusEvent, Html, InputData, Properties, ShouldRender};
 use graphql_client::GraphQLQuery;
 use wasm_bindgen_futures::spawn_local;
 use log::debug;
@@ -9,7 +9,7 @@ use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::user::GoToUser;
 use crate::services::content_adapter::{DateDisplay, Markdownable};
-use crate::services::{get_logged_user, get_value_field, resp_parsing};
+use crate::services::{get_logged_user, get_value_field, resp_parsing, split_annotations, ComponentViewLink};
 use crate::types::{DiscussionCommentData, DiscussionInfo, ObjectType, ShowUserShort, SlimUser, ToObject, UUID};
 use crate::get_gql_to_object;
 use crate::gqls::make_query;
@@ -43,6 +43,15 @@ pub struct DiscussionCommentsBlock {
 pub struct Props {
     pub discussion_uuid: Option<UUID>,
     pub object_type: ObjectType,
+    /// Annotation (pin on the 3D model) to attach to the new comment
+    #[prop_or_default]
+    pub annotation: Option<ComponentViewLink>,
+    /// Opens the viewer for an annotation of a comment (annotations are shown as plain links if not set)
+    #[prop_or_default]
+    pub callback_open_annotation: Option<Callback<ComponentViewLink>>,
+    /// Called when the annotation is sent with a comment or removed
+    #[prop_or_default]
+    pub callback_clear_annotation: Option<Callback<()>>,
 }
 
 // Msg for DiscussionCommentsBlock
@@ -63,6 +72,8 @@ pub enum Msg {
     DeleteComment(UUID, UUID),
     DeleteCommentResult(String),
     ClearReplies(UUID),
+    OpenAnnotation(ComponentViewLink),
+    ClearAnnotation,
     ResetCommentFields,
     ResponseError(Error),
     ClearError,
@@ -174,7 +185,7 @@ impl Component for DiscussionCommentsBlock {
                     },
                     discussionUuid: None, // self.discussion_uuid.clone()
                     parentCommentUuid: self.parent_comment_uuid.clone(),
-                    messageContent: self.new_comment.clone()
+                    messageContent: self.message_with_annotation(),
                 };
                 spawn_local(async move {
                     let res = make_query(RegisterDiscussionComment::build_query(
@@ -187,6 +198,9 @@ impl Component for DiscussionCommentsBlock {
                 match resp_parsing::<UUID>(res, "registerDiscussionComment") {
                     Ok(result) => {
                         debug!("registerDiscussionComment: {:?}", result);
+                        if self.props.annotation.is_some() {
+                            link.send_message(Msg::ClearAnnotation);
+                        }
                         if let Some(pcu) = &self.parent_comment_uuid {
                             // Get a thread with replies to a comment
                             link.send_message(Msg::FetchReplies(pcu.clone()));
@@ -302,6 +316,16 @@ impl Component for DiscussionCommentsBlock {
                 // Delete comment replies thread (clear)
                 self.replies.remove(&parent_uuid);
             },
+            Msg::OpenAnnotation(view_link) => {
+                if let Some(open_annotation) = &self.props.callback_open_annotation {
+                    open_annotation.emit(view_link);
+                }
+            },
+            Msg::ClearAnnotation => {
+                if let Some(clear_annotation) = &self.props.callback_clear_annotation {
+                    clear_annotation.emit(());
+                }
+            },
             Msg::ResetCommentFields => {
                 // Clear UUID for reply to comment
                 self.parent_comment_uuid = None;
@@ -324,7 +348,10 @@ impl Component for DiscussionCommentsBlock {
         // Update discussion_uuid if changed
         if self.props.object_type.uuid == props.object_type.uuid &&
             self.props.discussion_uuid == props.discussion_uuid {
-            false
+            // the attached annotation is changed without reloading comments
+            let annotation_changed = self.props.annotation != props.annotation;
+            self.props = props;
+            annotation_changed
         } else {
             self.props = props;
             // Update comments for new discussion
@@ -476,6 +503,7 @@ impl DiscussionCommentsBlock {
         html! {
             <div class="box">
                 <form onsubmit={onsubmit_add_comment}>
+                    {self.view_attached_annotation()}
                     <textarea
                         class="textarea"
                         placeholder={get_value_field(&384)}
@@ -522,10 +550,69 @@ impl DiscussionCommentsBlock {
     fn message_block(&self, comment_uuid: &UUID, message_content: &str) -> Html {
         match &self.edit_comment_uuid.as_ref().map(|ec_uuid| ec_uuid == comment_uuid) {
             Some(true) => self.view_form_edit(),
+            _ if self.props.callback_open_annotation.is_some() => self.view_annotated_message(message_content),
             _ => html!{message_content.to_markdown()},
         }
     }
 
+    // View of the message text with buttons to open its annotations in the viewer
+    fn view_annotated_message(&self, message_content: &str) -> Html {
+        let (text, annotations) = split_annotations(message_content);
+        html!{<>
+            {text.to_markdown()}
+            {self.view_annotation_buttons(annotations)}
+        </>}
+    }
+
+    // Buttons to show the annotations of the message on the model
+    fn view_annotation_buttons(&self, annotations: Vec<ComponentViewLink>) -> Html {
+        if annotations.is_empty() {
+            return html!{}
+        }
+        html!{
+            <div class="buttons">
+                {for annotations.into_iter().map(|view_link| {
+                    let onclick_open_annotation = self.link.callback(move |_| Msg::OpenAnnotation(view_link.clone()));
+                    html!{
+                        <button class="button is-small is-light is-info" onclick={onclick_open_annotation}>
+                            <span class="icon"><i class="fas fa-map-pin" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&479)}</span> // Show on the model
+                        </button>
+                    }
+                })}
+            </div>
+        }
+    }
+
+    // View of the annotation that will be attached to the new comment
+    fn view_attached_annotation(&self) -> Html {
+        if self.props.annotation.is_none() {
+            return html!{}
+        }
+        let onclick_clear_annotation = self.link.callback(|_| Msg::ClearAnnotation);
+        html!{
+            <div class="tags has-addons mb-1">
+                <span class="tag is-info is-light">
+                    <span class="icon"><i class="fas fa-map-pin" aria-hidden="true"></i></span>
+                    <span>{get_value_field(&478)}</span> // Annotation on the 3D model
+                </span>
+                <a class="tag is-delete" onclick={onclick_clear_annotation}></a>
+            </div>
+        }
+    }
+
+    // Returns the text of the new comment with the markdown link of the attached annotation
+    fn message_with_annotation(&self) -> String {
+        match &self.props.annotation {
+            Some(view_link) => format!(
+                "{}\n\n{}",
+                self.new_comment,
+                view_link.to_annotation(&self.props.object_type.uuid, get_value_field(&478)),
+            ),
+            None => self.new_comment.clone(),
+        }
+    }
+
     // View to display a button for opening a message edit form for a reply to reply to a comment
     fn to_edit_btn(&self, comment_uuid: UUID, parent_comment_uuid: UUID, message_content: &str) -> Html {
         let e_message_content = message_content.to_string();
@@ -0,0 +1,468 @@
+use std::collections::BTreeSet;
+use yew::{html, Component, ComponentLink, Html, MouseEvent, NodeRef, Properties, ShouldRender, WheelEvent};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{CanvasRenderingContext2d, Element, HtmlCanvasElement};
+use log::debug;
+use crate::error::Error;
+use crate::fragments::list_errors::ListErrors;
+use crate::services::{
+    get_value_field, load_drawing, create_object_url, revoke_object_url,
+    DrawingFormat, DrawingView, DxfDrawing, DxfLayer, Point,
+};
+use crate::types::DownloadFile;
+
+// 1. Download the file of the drawing
+// 2. DXF: parse the entities and fit them to the canvas, SVG: show the image by a local URL
+// 3. Pan by dragging and zoom by the wheel or the buttons
+// 4. DXF: toggle the layers and measure the distance between two points
+
+/// Zoom step of the buttons and the mouse wheel
+const ZOOM_STEP: f64 = 1.25;
+
+pub struct DrawingShowcase {
+    props: Props,
+    link: ComponentLink<Self>,
+    error: Option<Error>,
+    drawing_format: DrawingFormat,
+    area_ref: NodeRef,
+    canvas_ref: NodeRef,
+    dxf: Option<DxfDrawing>,
+    svg_url: Option<String>,
+    view: DrawingView,
+    need_fit: bool,
+    hidden_layers: BTreeSet<String>,
+    show_layers: bool,
+    drag: Option<Point>,
+    moved: bool,
+    measure_mode: bool,
+    measure_points: Vec<Point>,
+    cursor: Option<Point>,
+}
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub file: DownloadFile,
+}
+
+pub enum Msg {
+    RequestDrawing,
+    GetDrawingResult(Result<String, Error>),
+    MouseDown(Point),
+    MouseMove(Point),
+    MouseUp(Point),
+    MouseLeave,
+    Wheel(f64, Point),
+    ZoomIn,
+    ZoomOut,
+    FitView,
+    ToggleLayer(String),
+    ShowLayers,
+    MeasureMode,
+    ClearError,
+}
+
+impl Component for DrawingShowcase {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let drawing_format = DrawingFormat::from_filename(&props.file.filename);
+        Self {
+            props,
+            link,
+            error: None,
+            drawing_format,
+            area_ref: NodeRef::default(),
+            canvas_ref: NodeRef::default(),
+            dxf: None,
+            svg_url: None,
+            view: DrawingView::default(),
+            need_fit: true,
+            hidden_layers: BTreeSet::new(),
+            show_layers: false,
+            drag: None,
+            moved: false,
+            measure_mode: false,
+            measure_points: Vec::new(),
+            cursor: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestDrawing);
+        }
+        self.draw_dxf();
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestDrawing => {
+                if !self.drawing_format.is_drawing_format() {
+                    return false
+                }
+                let url = self.props.file.download_url.clone();
+                spawn_local(async move {
+                    let res = load_drawing(&url).await;
+                    link.send_message(Msg::GetDrawingResult(res));
+                })
+            },
+            Msg::GetDrawingResult(res) => {
+                let content = match res {
+                    Ok(content) => content,
+                    Err(err) => {
+                        self.error = Some(err);
+                        return true
+                    },
+                };
+                match self.drawing_format {
+                    DrawingFormat::Dxf => match DxfDrawing::parse(&content) {
+                        Ok(drawing) => {
+                            debug!("DXF entities: {:?}, layers: {:?}", drawing.entities.len(), drawing.layers.len());
+                            self.hidden_layers = drawing.layers
+                                .iter()
+                                .filter(|layer| !layer.visible)
+                                .map(|layer| layer.name.clone())
+                                .collect();
+                            self.dxf = Some(drawing);
+                            self.need_fit = true;
+                        },
+                        Err(err) => self.error = Some(Error::BadRequest(
+                            format!("{} {}: {}", get_value_field(&487), self.props.file.filename, err)
+                        )),
+                    },
+                    DrawingFormat::Svg => {
+                        self.svg_url = create_object_url(content.as_bytes(), "image/svg+xml");
+                        self.view = DrawingView::default();
+                    },
+                    DrawingFormat::Unknown => (),
+                }
+            },
+            Msg::MouseDown(point) => {
+                self.drag = Some(point);
+                self.moved = false;
+                return false
+            },
+            Msg::MouseMove(point) => {
+                match self.drag {
+                    Some(last) => {
+                        // small movements are clicks
+                        if !self.moved && (point.0 - last.0).abs() + (point.1 - last.1).abs() < 3.0 {
+                            return false
+                        }
+                        self.moved = true;
+                        self.view.pan(point.0 - last.0, point.1 - last.1);
+                        self.drag = Some(point);
+                    },
+                    None => {
+                        if !self.measure_mode || self.measure_points.len() != 1 {
+                            return false
+                        }
+                        self.cursor = Some(self.view.to_drawing(point));
+                    },
+                }
+            },
+            Msg::MouseUp(point) => {
+                let click = self.drag.take().is_some() && !self.moved;
+                if !click || !self.measure_mode {
+                    return false
+                }
+                if self.measure_points.len() == 2 {
+                    self.measure_points.clear();
+                }
+                self.measure_points.push(self.view.to_drawing(point));
+                self.cursor = None;
+            },
+            Msg::MouseLeave => {
+                self.drag = None;
+                self.cursor = None;
+            },
+            Msg::Wheel(delta, point) => match delta < 0.0 {
+                true => self.view.zoom(ZOOM_STEP, point),
+                false => self.view.zoom(1.0 / ZOOM_STEP, point),
+            },
+            Msg::ZoomIn => self.view.zoom(ZOOM_STEP, self.view_center()),
+            Msg::ZoomOut => self.view.zoom(1.0 / ZOOM_STEP, self.view_center()),
+            Msg::FitView => {
+                self.view = DrawingView::default();
+                self.need_fit = true;
+            },
+            Msg::ToggleLayer(name) => {
+                if !self.hidden_layers.remove(&name) {
+                    self.hidden_layers.insert(name);
+                }
+            },
+            Msg::ShowLayers => self.show_layers = !self.show_layers,
+            Msg::MeasureMode => {
+                self.measure_mode = !self.measure_mode;
+                self.measure_points.clear();
+                self.cursor = None;
+            },
+            Msg::ClearError => self.error = None,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.file.uuid == props.file.uuid {
+            return false
+        }
+        self.revoke_svg();
+        self.drawing_format = DrawingFormat::from_filename(&props.file.filename);
+        self.props = props;
+        self.dxf = None;
+        self.view = DrawingView::default();
+        self.need_fit = true;
+        self.hidden_layers.clear();
+        self.measure_points.clear();
+        self.link.send_message(Msg::RequestDrawing);
+        true
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        let point_ref = self.point_ref();
+        let onmousedown = self.link.callback(move |e: MouseEvent| Msg::MouseDown(mouse_point(&e, &point_ref)));
+        let point_ref = self.point_ref();
+        let onmousemove = self.link.callback(move |e: MouseEvent| Msg::MouseMove(mouse_point(&e, &point_ref)));
+        let point_ref = self.point_ref();
+        let onmouseup = self.link.callback(move |e: MouseEvent| Msg::MouseUp(mouse_point(&e, &point_ref)));
+        let onmouseleave = self.link.callback(|_| Msg::MouseLeave);
+        let point_ref = self.point_ref();
+        let onwheel = self.link.callback(move |e: WheelEvent| {
+            e.prevent_default();
+            Msg::Wheel(e.delta_y(), mouse_point(&e, &point_ref))
+        });
+        let cursor = match self.measure_mode {
+            true => "crosshair",
+            false => "grab",
+        };
+        let area_style = format!(
+            "position: relative; width: 100%; height: 60vh; overflow: hidden; background: #ffffff; cursor: {};",
+            cursor
+        );
+
+        html!{<>
+            <ListErrors error={self.error.clone()} clear_error={onclick_clear_error}/>
+            {self.show_toolbar()}
+            <div
+                ref={self.area_ref.clone()}
+                style={area_style}
+                onmousedown={onmousedown}
+                onmousemove={onmousemove}
+                onmouseup={onmouseup}
+                onmouseleave={onmouseleave}
+                onwheel={onwheel} >
+              {match self.drawing_format {
+                  DrawingFormat::Dxf => html!{
+                      <canvas ref={self.canvas_ref.clone()} style="display: block; width: 100%; height: 100%;" />
+                  },
+                  _ => self.show_svg(),
+              }}
+              {self.show_layers_panel()}
+            </div>
+        </>}
+    }
+
+    fn destroy(&mut self) {
+        self.revoke_svg();
+    }
+}
+
+impl DrawingShowcase {
+    fn show_toolbar(&self) -> Html {
+        let onclick_zoom_in = self.link.callback(|_| Msg::ZoomIn);
+        let onclick_zoom_out = self.link.callback(|_| Msg::ZoomOut);
+        let onclick_fit = self.link.callback(|_| Msg::FitView);
+        let onclick_layers = self.link.callback(|_| Msg::ShowLayers);
+        let onclick_measure = self.link.callback(|_| Msg::MeasureMode);
+        let is_dxf = self.drawing_format == DrawingFormat::Dxf;
+        let class_active = |active: bool| match active {
+            true => "button is-small is-info is-light",
+            false => "button is-small",
+        };
+
+        html!{
+            <div class="is-flex is-align-items-center mb-1">
+                <div class="buttons has-addons mb-0">
+                    <button class="button is-small" onclick={onclick_zoom_in} title={get_value_field(&481)}>
+                        <span class="icon is-small"><i class="fas fa-search-plus"></i></span>
+                    </button>
+                    <button class="button is-small" onclick={onclick_zoom_out} title={get_value_field(&482)}>
+                        <span class="icon is-small"><i class="fas fa-search-minus"></i></span>
+                    </button>
+                    <button class="button is-small" onclick={onclick_fit} title={get_value_field(&480)}>
+                        <span class="icon is-small"><i class="fas fa-expand"></i></span>
+                    </button>
+                    {match is_dxf {
+                        true => html!{<>
+                            <button class={class_active(self.show_layers)} onclick={onclick_layers} title={get_value_field(&483)}>
+                                <span class="icon is-small"><i class="fas fa-layer-group"></i></span>
+                            </button>
+                            <button class={class_active(self.measure_mode)} onclick={onclick_measure} title={get_value_field(&484)}>
+                                <span class="icon is-small"><i class="fas fa-ruler"></i></span>
+                            </button>
+                        </>},
+                        false => html!{},
+                    }}
+                </div>
+                <span class="help has-text-grey ml-2">
+                    {match self.measure_mode {
+                        true => self.show_measure(),
+                        false => html!{get_value_field(&488)}, // Drag: pan | Wheel: zoom
+                    }}
+                </span>
+            </div>
+        }
+    }
+
+    fn show_measure(&self) -> Html {
+        let end = self.measure_points.get(1).or(self.cursor.as_ref());
+        match (self.measure_points.first(), end) {
+            (Some(start), Some(end)) => {
+                let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                html!{
+                    <span class="has-text-weight-bold">
+                        {format!("{}: {:.3} (ΔX {:.3}, ΔY {:.3})", get_value_field(&486), dx.hypot(dy), dx, dy)}
+                    </span>
+                }
+            },
+            _ => html!{get_value_field(&485)}, // Click two points on the drawing
+        }
+    }
+
+    fn show_layers_panel(&self) -> Html {
+        let Some(drawing) = self.dxf.as_ref().filter(|_| self.show_layers) else {
+            return html!{}
+        };
+        // the panel should not pan the drawing
+        let onmousedown = self.link.batch_callback(|e: MouseEvent| {
+            e.stop_propagation();
+            None
+        });
+        html!{
+            <div class="box p-2" style="position: absolute; top: 0.5rem; right: 0.5rem; max-height: 90%; overflow-y: auto; cursor: default;" onmousedown={onmousedown}>
+                <p class="has-text-weight-bold">{get_value_field(&483)}</p>
+                {for drawing.layers.iter().map(|layer| self.show_layer(layer))}
+            </div>
+        }
+    }
+
+    fn show_layer(&self, layer: &DxfLayer) -> Html {
+        let name = layer.name.clone();
+        let onclick_layer = self.link.callback(move |_| Msg::ToggleLayer(name.clone()));
+        html!{
+            <label class="checkbox is-block">
+                <input type="checkbox" checked={!self.hidden_layers.contains(&layer.name)} onclick={onclick_layer} />
+                <span class="ml-1">{layer.name.clone()}</span>
+            </label>
+        }
+    }
+
+    fn show_svg(&self) -> Html {
+        let Some(svg_url) = &self.svg_url else {
+            return html!{}
+        };
+        let style = format!(
+            "display: block; width: 100%; height: 100%; object-fit: contain; pointer-events: none; transform-origin: 0 0; transform: translate({}px, {}px) scale({});",
+            self.view.offset.0, self.view.offset.1, self.view.scale
+        );
+        html!{<img src={svg_url.clone()} style={style} alt={self.props.file.filename.clone()} />}
+    }
+
+    /// Center of the drawing area on the screen
+    fn view_center(&self) -> Point {
+        match self.area_ref.cast::<Element>() {
+            Some(area) => (area.client_width() as f64 / 2.0, area.client_height() as f64 / 2.0),
+            None => (0.0, 0.0),
+        }
+    }
+
+    /// Draws the DXF drawing and the measurement on the canvas
+    fn draw_dxf(&mut self) {
+        let Some(canvas) = self.canvas_ref.cast::<HtmlCanvasElement>() else {
+            return
+        };
+        let Some(bounds) = self.dxf.as_ref().map(|drawing| drawing.bounds()) else {
+            return
+        };
+        let (width, height) = (canvas.client_width().max(1) as u32, canvas.client_height().max(1) as u32);
+        if canvas.width() != width || canvas.height() != height {
+            canvas.set_width(width);
+            canvas.set_height(height);
+        }
+        if self.need_fit {
+            self.need_fit = false;
+            if let Some(bounds) = bounds {
+                self.view = DrawingView::fit(bounds, width as f64, height as f64);
+            }
+        }
+        let Some(ctx) = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|ctx| ctx.dyn_into::<CanvasRenderingContext2d>().ok()) else {
+            return
+        };
+        ctx.set_fill_style_str("#ffffff");
+        ctx.fill_rect(0.0, 0.0, width as f64, height as f64);
+        if let Some(drawing) = &self.dxf {
+            drawing.draw(&ctx, &self.view, &self.hidden_layers);
+        }
+        self.draw_measure(&ctx);
+    }
+
+    fn draw_measure(&self, ctx: &CanvasRenderingContext2d) {
+        let points = self.measure_points
+            .iter()
+            .chain(self.cursor.iter())
+            .map(|point| self.view.to_screen(*point))
+            .collect::<Vec<Point>>();
+        if points.is_empty() {
+            return
+        }
+        let color = "#ed760e";
+        ctx.set_stroke_style_str(color);
+        ctx.set_fill_style_str(color);
+        ctx.set_line_width(1.5);
+        ctx.begin_path();
+        for (index, point) in points.iter().enumerate() {
+            match index {
+                0 => ctx.move_to(point.0, point.1),
+                _ => ctx.line_to(point.0, point.1),
+            }
+        }
+        ctx.stroke();
+        for point in points.iter() {
+            ctx.fill_rect(point.0 - 3.0, point.1 - 3.0, 6.0, 6.0);
+        }
+    }
+
+    /// Element of the drawing the mouse points are measured from: the canvas of DXF or the area of SVG
+    fn point_ref(&self) -> NodeRef {
+        match self.drawing_format {
+            DrawingFormat::Dxf => self.canvas_ref.clone(),
+            _ => self.area_ref.clone(),
+        }
+    }
+
+    fn revoke_svg(&mut self) {
+        if let Some(svg_url) = self.svg_url.take() {
+            revoke_object_url(&svg_url);
+        }
+    }
+}
+
+/// Point of the mouse on the element, the offset of the event is not used
+/// because it is measured from the target (it may be an overlay over the drawing)
+fn mouse_point(e: &MouseEvent, node_ref: &NodeRef) -> Point {
+    match node_ref.cast::<Element>() {
+        Some(element) => {
+            let rect = element.get_bounding_client_rect();
+            (e.client_x() as f64 - rect.left(), e.client_y() as f64 - rect.top())
+        },
+        None => (e.offset_x() as f64, e.offset_y() as f64),
+    }
+}
@@ -7,9 +7,11 @@ use log::debug;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::{ft_download_btn, ft_download_full_btn};
-use crate::types::{ShowFileInfo, UUID};
+use crate::fragments::three_compare::ThreeCompare;
+use crate::fragments::drawing_showcase::DrawingShowcase;
+use crate::types::{DownloadFile, ShowFileInfo, UUID};
 use crate::services::content_adapter::{ContentDisplay, DateDisplay};
-use crate::services::{Size, get_value_field, resp_parsing};
+use crate::services::{Size, ModelFormat, DrawingFormat, get_value_field, resp_parsing};
 use crate::gqls::make_query;
 use crate::gqls::relate::{
   ShowFileRevisions, show_file_revisions,
@@ -24,6 +26,8 @@ pub struct FileShowcase {
   active_revision: UUID,
   files_deleted_list: BTreeSet<UUID>,
   get_confirm: UUID,
+  compare_revision: Option<UUID>,
+  preview_drawing: Option<UUID>,
 }
 
 #[derive(Properties, Clone)]
@@ -43,6 +47,8 @@ pub enum Msg {
   GetActiveRevResult(String, String),
   ClickFileInfo,
   ClickDeleteFile(UUID),
+  CompareRevision(UUID),
+  PreviewDrawing(UUID),
   ResponseError(Error),
   ClearError,
   Ignore,
@@ -61,6 +67,8 @@ impl Component for FileShowcase {
         active_revision: String::new(),
         files_deleted_list: BTreeSet::new(),
         get_confirm: String::new(),
+        compare_revision: None,
+        preview_drawing: None,
       }
     }
 
@@ -89,6 +97,8 @@ impl Component for FileShowcase {
           })
         },
         Msg::ParsingFiles(res) => {
+          self.compare_revision = None;
+          self.preview_drawing = None;
           match resp_parsing(res, "showFileRevisions") {
             Ok(file_arr) => self.file_arr = file_arr,
             Err(err) => link.send_message(Msg::ResponseError(err)),
@@ -108,6 +118,7 @@ impl Component for FileShowcase {
               debug!("changeActiveFileRevision {:?}: {:?}", file_uuid, res);
               if res {
                 self.active_revision = file_uuid;
+                self.compare_revision = None;
               }
             },
             Err(err) => link.send_message(Msg::ResponseError(err)),
@@ -117,6 +128,8 @@ impl Component for FileShowcase {
           self.props.open_modal_frame = !self.props.open_modal_frame;
           self.props.file_info_callback.emit(());
           self.get_confirm.clear();
+          self.compare_revision = None;
+          self.preview_drawing = None;
         },
         Msg::ClickDeleteFile(file_uuid) => {
           if self.get_confirm == file_uuid {
@@ -128,6 +141,21 @@ impl Component for FileShowcase {
             self.get_confirm = file_uuid;
           }
         },
+        Msg::CompareRevision(file_uuid) => {
+          self.compare_revision = match self.compare_revision.as_ref() == Some(&file_uuid)
+            || file_uuid == self.active_revision {
+            true => None,
+            false => Some(file_uuid),
+          };
+          self.preview_drawing = None;
+        },
+        Msg::PreviewDrawing(file_uuid) => {
+          self.preview_drawing = match self.preview_drawing.as_ref() == Some(&file_uuid) {
+            true => None,
+            false => Some(file_uuid),
+          };
+          self.compare_revision = None;
+        },
         Msg::ResponseError(err) => self.error = Some(err),
         Msg::ClearError => self.error = None,
         Msg::Ignore => {}
@@ -142,6 +170,7 @@ impl Component for FileShowcase {
         false
       } else {
         self.files_deleted_list.clear();
+        self.preview_drawing = None;
         if self.props.file_info.filename != props.file_info.filename {
           self.active_revision = props.file_info.uuid.clone();
         }
@@ -164,7 +193,10 @@ impl Component for FileShowcase {
       html!{
         <div class={class_modal}>
           <div class="modal-background" onclick={onclick_file_info.clone()} />
-          <div class="modal-content box">
+          <div class="modal-content box" style={match self.compare_revision.is_some() || self.preview_drawing.is_some() {
+            true => "width: 90vw;",
+            false => "",
+          }}>
             <ListErrors error={self.error.clone()} clear_error={onclick_clear_error}/>
             {match self.props.show_revisions {
               true => self.show_revisions(),
@@ -196,9 +228,46 @@ impl FileShowcase {
           })}
         </tbody>
       </table>
+      {self.show_compare_revisions()}
+      {self.show_preview_drawing()}
     </>}
   }
 
+  /// Shows the models of the active and the selected revisions for comparison
+  fn show_compare_revisions(&self) -> Html {
+    let Some(compare_uuid) = &self.compare_revision else {
+      return html!{}
+    };
+    let active_file = self.file_arr.iter().find(|f| f.uuid == self.active_revision);
+    let compare_file = self.file_arr.iter().find(|f| &f.uuid == compare_uuid);
+    match (active_file, compare_file) {
+      (Some(active_file), Some(compare_file)) => html!{
+        <ThreeCompare
+          left_file={DownloadFile::from(compare_file)}
+          left_title={format!("{} {}", get_value_field(&308), compare_file.revision)}
+          right_file={DownloadFile::from(active_file)}
+          right_title={format!("{} {}", get_value_field(&308), active_file.revision)}
+          />
+      },
+      _ => html!{},
+    }
+  }
+
+  /// Shows the drawing (DXF, SVG) of the selected revision or of the file
+  fn show_preview_drawing(&self) -> Html {
+    let Some(preview_uuid) = &self.preview_drawing else {
+      return html!{}
+    };
+    let file = match &self.props.file_info.uuid == preview_uuid {
+      true => Some(&self.props.file_info),
+      false => self.file_arr.iter().find(|f| &f.uuid == preview_uuid),
+    };
+    match file {
+      Some(file) => html!{<DrawingShowcase file={DownloadFile::from(file)} />},
+      None => html!{},
+    }
+  }
+
   /// Sets title for table with revisions file
   fn set_title(&self) -> Html {
     html!{
@@ -237,12 +306,15 @@ impl FileShowcase {
           true => html!{
             <div class="buttons">
               {self.show_set_active_btn(file_info.uuid.clone())}
+              {self.show_compare_btn(file_info)}
+              {self.show_preview_btn(file_info)}
               {self.show_delete_btn(file_info.uuid.clone())}
             </div>
           },
           false => html!{
             <div class="buttons">
               {ft_download_btn(self.props.file_info.download_url.clone(), true)}
+              {self.show_preview_btn(file_info)}
               {self.show_delete_btn(file_info.uuid.clone())}
             </div>
           },
@@ -263,9 +335,28 @@ impl FileShowcase {
         },
         false => {ft_download_full_btn(self.props.file_info.download_url.clone())},
       }}
+      {self.show_preview_full_btn()}
+      {self.show_preview_drawing()}
     </>}
   }
 
+  fn show_preview_full_btn(&self) -> Html {
+    if !DrawingFormat::from_filename(&self.props.file_info.filename).is_drawing_format() {
+      return html!{}
+    }
+    let file_uuid = self.props.file_info.uuid.clone();
+    let onclick_preview_btn = self.link.callback(move |_| Msg::PreviewDrawing(file_uuid.clone()));
+
+    html!{
+      <button class="button is-info is-light is-fullwidth mt-2 mb-2" onclick={onclick_preview_btn} >
+        <span class="icon">
+          <i class="fas fa-eye" aria-hidden="true"></i>
+        </span>
+        <span>{get_value_field(&335)}</span>
+      </button>
+    }
+  }
+
   /// Show modal with info about a file
   fn show_full_info_file(&self) -> Html {
     html!{
@@ -317,6 +408,48 @@ impl FileShowcase {
     }
   }
 
+  fn show_compare_btn(&self, file_info: &ShowFileInfo) -> Html {
+    // the active revision is compared with the other revisions, not with itself
+    if file_info.uuid == self.active_revision
+      || !ModelFormat::from_filename(&file_info.filename).is_self_contained() {
+      return html!{}
+    }
+    let file_uuid = file_info.uuid.clone();
+    let style_icon = match self.compare_revision.as_ref() == Some(&file_info.uuid) {
+      true => "color: #ed760e;",
+      false => "color: #1872f0;",
+    };
+    let onclick_compare_btn = self.link.callback(move |_| Msg::CompareRevision(file_uuid.clone()));
+
+    html!{
+      <button class="button is-white" onclick={onclick_compare_btn} title={get_value_field(&471)}>
+        <span class="icon">
+          <i class="fas fa-clone" style={style_icon} aria-hidden="true"></i>
+        </span>
+      </button>
+    }
+  }
+
+  fn show_preview_btn(&self, file_info: &ShowFileInfo) -> Html {
+    if !DrawingFormat::from_filename(&file_info.filename).is_drawing_format() {
+      return html!{}
+    }
+    let file_uuid = file_info.uuid.clone();
+    let style_icon = match self.preview_drawing.as_ref() == Some(&file_info.uuid) {
+      true => "color: #ed760e;",
+      false => "color: #1872f0;",
+    };
+    let onclick_preview_btn = self.link.callback(move |_| Msg::PreviewDrawing(file_uuid.clone()));
+
+    html!{
+      <button class="button is-white" onclick={onclick_preview_btn} title={get_value_field(&335)}>
+        <span class="icon">
+          <i class="fas fa-eye" style={style_icon} aria-hidden="true"></i>
+        </span>
+      </button>
+    }
+  }
+
   fn show_delete_btn(&self, file_uuid: UUID) -> Html {
     let file_uuid_cl = file_uuid.clone();
     let onclick_delete_btn =
@@ -19,6 +19,7 @@ pub enum CurrentPage {
     Register,
     Login,
     Notifications,
+    Bom,
     Home,
     Search,
     Settings,
@@ -123,6 +124,7 @@ impl Component for Header {
                     "#/notifications" => CurrentPage::Notifications,
                     "#/search" => CurrentPage::Search,
                     "#/settings" => CurrentPage::Settings,
+                    path if path.starts_with("#/bom") => CurrentPage::Bom,
                     path => {
                         let mut set_page = CurrentPage::Unknown;
                         if path.starts_with("#/@") {
@@ -147,6 +149,7 @@ impl Component for Header {
             Msg::ChangePointTo(go_to) => {
                 match go_to {
                     CurrentPage::Notifications => self.router_agent.send(ChangeRoute(AppRoute::Notifications.into())),
+                    CurrentPage::Bom => self.router_agent.send(ChangeRoute(AppRoute::Bom.into())),
                     CurrentPage::Settings => self.router_agent.send(ChangeRoute(AppRoute::Settings.into())),
                     CurrentPage::SelfProfile => {
                         if let Some(cu) = &self.current_user {
@@ -322,6 +325,7 @@ impl Header {
 
         html!{
             <div class="buttons navbar-item">
+                {self.bom_btn()}
                 {self.notification_btn()}
                 {self.profile_btn(user_info.username.clone())}
                 <button id="header-logout" class="button" onclick={logout} title={{get_value_field(&17)}} >
@@ -337,6 +341,19 @@ impl Header {
         }
     }
 
+    fn bom_btn(&self) -> Html {
+        let (class_btn, is_disabled) = match self.open_page {
+            CurrentPage::Bom => ("button is-active", true),
+            _ => ("button", false),
+        };
+        let onclick_bom_btn = self.link.callback(|_| Msg::ChangePointTo(CurrentPage::Bom));
+        html!{
+            <button id="header-bom" class={class_btn} disabled={is_disabled} onclick={onclick_bom_btn} title={get_value_field(&602)} >
+                <span class="icon"><i class="fas fa-clipboard-list" style={self.style_color.clone()}></i></span>
+            </button>
+        }
+    }
+
     fn notification_btn(&self) -> Html {
         let (class_btn, is_disabled) = match self.open_page {
             CurrentPage::Notifications => ("button is-active", true),
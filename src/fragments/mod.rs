@@ -6,6 +6,7 @@ pub mod standard;
 pub mod supplier_service;
 pub mod user;
 pub mod buttons;
+pub mod bulk_actions;
 pub mod file;
 pub mod clipboard;
 pub mod conditions;
@@ -24,6 +25,8 @@ pub mod upload_favicon;
 pub mod img_showcase;
 pub mod type_access;
 pub mod three_showcase;
+pub mod three_compare;
+pub mod drawing_showcase;
 pub mod search;
 
 pub use list_item::ListState;
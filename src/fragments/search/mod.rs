@@ -1,8 +1,16 @@
 mod catalog_spec;
+mod query;
 mod search_bar;
+mod unified;
 
 pub use catalog_spec::CatalogSpec;
 pub use search_bar::SearchBar;
+pub use unified::SearchTarget;
+pub(crate) use query::{
+    QueryError, QueryPrefix, QuerySuggestions, SearchQuery,
+    complete_query, replace_last_token, typed_filter, is_uuid, match_name,
+};
+pub(crate) use unified::rank_by_text;
 
 use crate::services::compare_op_uuid;
 use crate::types::UUID;
@@ -0,0 +1,346 @@
+use crate::fragments::search::SearchArg;
+use crate::services::get_value_field;
+
+/// Maximum number of the autocompletion options
+const MAX_COMPLETIONS: usize = 8;
+
+/// Filter of the search query written as `prefix:value`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryPrefix {
+    Spec,
+    Keyword,
+    Owner,
+    Company,
+    Standard,
+    Favorite,
+    Param,
+}
+
+impl QueryPrefix {
+    pub(crate) const ALL: [QueryPrefix; 7] = [
+        QueryPrefix::Spec,
+        QueryPrefix::Keyword,
+        QueryPrefix::Owner,
+        QueryPrefix::Company,
+        QueryPrefix::Standard,
+        QueryPrefix::Favorite,
+        QueryPrefix::Param,
+    ];
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            QueryPrefix::Spec => "spec",
+            QueryPrefix::Keyword => "kw",
+            QueryPrefix::Owner => "owner",
+            QueryPrefix::Company => "company",
+            QueryPrefix::Standard => "standard",
+            QueryPrefix::Favorite => "fav",
+            QueryPrefix::Param => "param",
+        }
+    }
+
+    pub(crate) fn description(&self) -> &'static str {
+        match self {
+            QueryPrefix::Spec => get_value_field(&679),
+            QueryPrefix::Keyword => get_value_field(&680),
+            QueryPrefix::Owner => get_value_field(&681),
+            QueryPrefix::Company => get_value_field(&682),
+            QueryPrefix::Standard => get_value_field(&683),
+            QueryPrefix::Favorite => get_value_field(&684),
+            QueryPrefix::Param => get_value_field(&685),
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|p| p.name() == name.to_lowercase()).copied()
+    }
+}
+
+/// Error of the search query, shown under the search bar
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryError {
+    EmptyValue(QueryPrefix),
+    UnclosedQuote,
+    InvalidFlag(String),
+    InvalidParam(String),
+    NotFound(QueryPrefix, String),
+}
+
+impl QueryError {
+    pub(crate) fn message(&self) -> String {
+        match self {
+            QueryError::EmptyValue(prefix) => format!("{} \"{}:\"", get_value_field(&687), prefix.name()),
+            QueryError::UnclosedQuote => get_value_field(&688).to_string(),
+            QueryError::InvalidFlag(value) => format!("{}: \"{}\"", get_value_field(&689), value),
+            QueryError::InvalidParam(value) => format!("{}: \"{}\"", get_value_field(&690), value),
+            QueryError::NotFound(prefix, value) =>
+                format!("{} \"{}:{}\"", get_value_field(&691), prefix.name(), value),
+        }
+    }
+}
+
+/// Search query parsed from the text of the search bar, for example
+/// `bolt spec:"Fasteners" kw:stainless owner:@alice company:acme fav:yes param:diameter=8`
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SearchQuery {
+    pub text: Vec<String>,
+    pub spec: Option<String>,
+    pub keywords: Vec<String>,
+    pub owner: Option<String>,
+    pub company: Option<String>,
+    pub standard: Option<String>,
+    pub favorite: Option<bool>,
+    pub params: Vec<(String, String)>,
+}
+
+impl SearchQuery {
+    pub(crate) fn parse(text: &str) -> Result<Self, QueryError> {
+        let mut query = SearchQuery::default();
+        for (prefix, value) in tokenize(text)? {
+            let kind = match prefix {
+                Some(kind) => kind,
+                None => {
+                    query.text.push(value);
+                    continue
+                },
+            };
+            if value.is_empty() {
+                return Err(QueryError::EmptyValue(kind))
+            }
+            match kind {
+                QueryPrefix::Spec => query.spec = Some(value),
+                QueryPrefix::Keyword => query.keywords.push(value),
+                QueryPrefix::Owner => query.owner = Some(value.trim_start_matches('@').to_string()),
+                QueryPrefix::Company => query.company = Some(value),
+                QueryPrefix::Standard => query.standard = Some(value),
+                QueryPrefix::Favorite => query.favorite = Some(parse_flag(&value)?),
+                QueryPrefix::Param => match value.split_once('=') {
+                    Some((name, param_value)) if !name.trim().is_empty() && !param_value.trim().is_empty() =>
+                        query.params.push((name.trim().to_string(), param_value.trim().to_string())),
+                    _ => return Err(QueryError::InvalidParam(value)),
+                },
+            }
+        }
+        Ok(query)
+    }
+
+    /// Sets the text and the flags of the search argument,
+    /// the filters by names (spec, owner, company, standard) are resolved by requests
+    pub(crate) fn apply(&self, arg: &mut SearchArg) {
+        let mut terms = self.text.clone();
+        terms.extend(self.keywords.iter().cloned());
+        terms.extend(self.params.iter().map(|(_, value)| value.clone()));
+        arg.search = terms.join(" ");
+        if !self.keywords.is_empty() {
+            arg.by_keywords = true;
+        }
+        if !self.params.is_empty() {
+            arg.by_params = true;
+        }
+        if let Some(favorite) = self.favorite {
+            arg.favorite = favorite;
+        }
+    }
+}
+
+fn parse_flag(value: &str) -> Result<bool, QueryError> {
+    match value.to_lowercase().as_str() {
+        "yes" | "y" | "true" | "1" => Ok(true),
+        "no" | "n" | "false" | "0" => Ok(false),
+        _ => Err(QueryError::InvalidFlag(value.to_string())),
+    }
+}
+
+/// Splits the text into the words, the quoted text is one word without quotes,
+/// the word starting with the name of a filter and a colon (outside quotes) is a filter.
+/// Other words with a colon (e.g. `ISO:4762` or an URL) are the search text.
+fn tokenize(text: &str) -> Result<Vec<(Option<QueryPrefix>, String)>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut prefix: Option<QueryPrefix> = None;
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut was_quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                was_quoted = true;
+            },
+            c if c.is_whitespace() && !in_quotes => {
+                if prefix.is_some() || !value.is_empty() {
+                    tokens.push((prefix.take(), std::mem::take(&mut value)));
+                }
+                was_quoted = false;
+            },
+            ':' if !in_quotes && !was_quoted && prefix.is_none() && QueryPrefix::from_name(&value).is_some() => {
+                prefix = QueryPrefix::from_name(&std::mem::take(&mut value));
+            },
+            c => value.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(QueryError::UnclosedQuote)
+    }
+    if prefix.is_some() || !value.is_empty() {
+        tokens.push((prefix, value));
+    }
+    Ok(tokens)
+}
+
+/// Start of the last word of the text (the word being typed)
+fn last_token_start(text: &str) -> usize {
+    let mut start = 0;
+    let mut in_quotes = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => start = index + c.len_utf8(),
+            _ => {},
+        }
+    }
+    start
+}
+
+/// Filter and value of the word being typed, if the word is a filter
+pub(crate) fn typed_filter(text: &str) -> Option<(QueryPrefix, String)> {
+    let token = &text[last_token_start(text)..];
+    let (prefix, value) = token.split_once(':')?;
+    QueryPrefix::from_name(prefix).map(|p| (p, value.trim_start_matches('"').to_string()))
+}
+
+/// Values known for the autocompletion of the filters
+#[derive(Clone, Default, Debug)]
+pub struct QuerySuggestions {
+    pub specs: Vec<String>,
+    pub companies: Vec<String>,
+    pub params: Vec<String>,
+}
+
+/// Options to replace the word being typed: filters by the beginning of the word
+/// or values of the filter by the beginning of the value
+pub(crate) fn complete_query(text: &str, suggestions: &QuerySuggestions) -> Vec<String> {
+    let token = &text[last_token_start(text)..];
+    if token.is_empty() {
+        return Vec::new()
+    }
+    let (prefix, value) = match token.split_once(':') {
+        Some((prefix, value)) => (prefix, value.trim_start_matches('"').to_lowercase()),
+        None => {
+            let typed = token.to_lowercase();
+            return QueryPrefix::ALL.iter()
+                .filter(|p| p.name().starts_with(&typed) && p.name() != typed)
+                .map(|p| format!("{}:", p.name()))
+                .collect()
+        },
+    };
+    let kind = match QueryPrefix::from_name(prefix) {
+        Some(kind) => kind,
+        None => return Vec::new(),
+    };
+    let values: Vec<String> = match kind {
+        QueryPrefix::Favorite => vec!["yes".to_string(), "no".to_string()],
+        QueryPrefix::Spec => suggestions.specs.clone(),
+        QueryPrefix::Company => suggestions.companies.clone(),
+        QueryPrefix::Param => {
+            if value.contains('=') {
+                return Vec::new()
+            }
+            suggestions.params.iter().map(|name| format!("{}=", name)).collect()
+        },
+        _ => Vec::new(),
+    };
+    values.into_iter()
+        .filter(|v| v.to_lowercase().contains(&value) && v.to_lowercase() != value)
+        .take(MAX_COMPLETIONS)
+        .map(|v| match v.contains(char::is_whitespace) {
+            true => format!("{}:\"{}\"", kind.name(), v),
+            false => format!("{}:{}", kind.name(), v),
+        })
+        .collect()
+}
+
+/// Replaces the word being typed with the option of the autocompletion,
+/// a space is added after the completed value
+pub(crate) fn replace_last_token(text: &str, completion: &str) -> String {
+    let head = &text[..last_token_start(text)];
+    match completion.ends_with(':') || completion.ends_with('=') {
+        true => format!("{}{}", head, completion),
+        false => format!("{}{} ", head, completion),
+    }
+}
+
+/// Text looks like the UUID, then the filter value is used without searching by name
+pub(crate) fn is_uuid(text: &str) -> bool {
+    text.len() == 36 && text.char_indices().all(|(index, c)| match index {
+        8 | 13 | 18 | 23 => c == '-',
+        _ => c.is_ascii_hexdigit(),
+    })
+}
+
+/// Item with the name equal to the filter value (case insensitive),
+/// otherwise the first item with the name containing the value
+pub(crate) fn match_name<'a, T>(items: &'a [T], value: &str, name: impl Fn(&T) -> &str) -> Option<&'a T> {
+    let value = value.to_lowercase();
+    items.iter().find(|item| name(item).to_lowercase() == value)
+        .or_else(|| items.iter().find(|item| name(item).to_lowercase().contains(&value)))
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn parse_search_query() {
+        let query = SearchQuery::parse(
+            r#"bolt spec:"Fasteners" kw:stainless owner:@alice company:acme fav:yes param:diameter=8"#
+        ).unwrap();
+        assert_eq!(vec!["bolt"], query.text);
+        assert_eq!(Some("Fasteners"), query.spec.as_deref());
+        assert_eq!(vec!["stainless"], query.keywords);
+        assert_eq!(Some("alice"), query.owner.as_deref());
+        assert_eq!(Some("acme"), query.company.as_deref());
+        assert_eq!(Some(true), query.favorite);
+        assert_eq!(vec![("diameter".to_string(), "8".to_string())], query.params);
+
+        let mut arg = SearchArg::default();
+        query.apply(&mut arg);
+        assert_eq!("bolt stainless 8", arg.search);
+        assert!(arg.by_keywords && arg.by_params && arg.favorite && !arg.by_specs);
+
+        assert_eq!(vec!["M8:x", "nut"], SearchQuery::parse(r#""M8:x" nut"#).unwrap().text);
+        assert_eq!(
+            vec!["ISO:4762", "https://cadbase.rs/search"],
+            SearchQuery::parse("ISO:4762 https://cadbase.rs/search kw:steel").unwrap().text
+        );
+        assert_eq!(Some("Gears"), SearchQuery::parse("size:8 Spec:Gears").unwrap().spec.as_deref());
+        assert_eq!(Err(QueryError::EmptyValue(QueryPrefix::Keyword)), SearchQuery::parse("bolt kw:"));
+        assert_eq!(Err(QueryError::UnclosedQuote), SearchQuery::parse(r#"spec:"Fast"#));
+        assert_eq!(Err(QueryError::InvalidFlag("maybe".to_string())), SearchQuery::parse("fav:maybe"));
+        assert_eq!(Err(QueryError::InvalidParam("diameter".to_string())), SearchQuery::parse("param:diameter"));
+    }
+
+    #[test]
+    fn complete_search_query() {
+        let suggestions = QuerySuggestions {
+            specs: vec!["Fasteners".to_string(), "Fasteners / Bolts".to_string()],
+            companies: vec!["Acme".to_string()],
+            params: vec!["diameter".to_string()],
+        };
+        assert_eq!(vec!["spec:", "standard:"], complete_query("bolt s", &suggestions));
+        assert_eq!(vec!["spec:Fasteners", "spec:\"Fasteners / Bolts\""], complete_query("bolt spec:fas", &suggestions));
+        assert_eq!(vec!["fav:yes", "fav:no"], complete_query("fav:", &suggestions));
+        assert_eq!(vec!["param:diameter="], complete_query("param:dia", &suggestions));
+        assert!(complete_query("bolt ", &suggestions).is_empty());
+
+        assert_eq!("bolt spec:Fasteners ", replace_last_token("bolt spec:fas", "spec:Fasteners"));
+        assert_eq!("bolt spec:", replace_last_token("bolt s", "spec:"));
+        assert_eq!(Some((QueryPrefix::Spec, "Fas".to_string())), typed_filter(r#"bolt spec:"Fas"#));
+        assert!(is_uuid("6e5b8a1c-3f2d-4a7b-9c0e-1d2f3a4b5c6d"));
+        assert!(!is_uuid("acme"));
+
+        let names = vec!["Acme Tools", "Acme", "Bolt Inc"];
+        assert_eq!(Some(&"Acme"), match_name(&names, "acme", |n| n));
+        assert_eq!(Some(&"Bolt Inc"), match_name(&names, "bolt", |n| n));
+        assert_eq!(None, match_name(&names, "nut", |n| n));
+    }
+}
@@ -1,21 +1,45 @@
 use std::time::Duration;
-use yew::{classes, html, Component, ComponentLink, Html, Properties, ShouldRender, InputData};
+use yew::{classes, html, Callback, Component, ComponentLink, Html, Properties, ShouldRender, InputData, MouseEvent};
 use yew::services::timeout::{TimeoutService, TimeoutTask};
 use web_sys::KeyboardEvent;
 use wasm_bindgen_futures::spawn_local;
 use graphql_client::GraphQLQuery;
 use log::debug;
 use crate::error::Error;
-use crate::fragments::search::SearchArg;
+use crate::fragments::search::{
+    SearchArg, SearchQuery, SearchTarget, QueryError, QueryPrefix, QuerySuggestions,
+    complete_query, replace_last_token, typed_filter, is_uuid, match_name, rank_by_text,
+};
 use crate::fragments::{
     list_errors::ListErrors,
+    list_empty::ListEmpty,
     component::CatalogComponents,
+    company::ListItemCompany,
+    standard::ListItemStandard,
+    supplier_service::ListItemService,
+    user::ListItemUser,
     responsive::resizer,
 };
-use crate::services::{resp_parsing, get_value_field};
-use crate::types::{ShowComponentShort, ComponentsQueryArg};
+use crate::services::{
+    resp_parsing, get_logged_user, get_value_field,
+    SavedSearch, load_saved_searches, save_saved_searches, add_saved_search,
+};
+use crate::types::{
+    ShowComponentShort, ComponentsQueryArg, SpecPathInfo, Param,
+    ShowCompanyShort, ShowStandardShort, ShowServiceShort, ShowUserShort, UserInfo, UUID,
+};
 use crate::gqls::make_query;
 use crate::gqls::component::{SearchByComponents, search_by_components};
+use crate::gqls::relate::{SearchSpecs, search_specs, GetParams, get_params};
+use crate::gqls::company::{GetCompaniesShortList, get_companies_short_list};
+use crate::gqls::standard::{GetStandardsShortList, get_standards_short_list};
+use crate::gqls::supplier_service::{GetServicesShortList, get_services_short_list};
+use crate::gqls::user::{GetUserData, get_user_data, GetUsersShortList, get_users_short_list};
+
+/// Number of the objects of each type in the grouped results
+const GROUP_SIZE: usize = 3;
+/// Number of the companies requested at once to find the company of the filter or to list the companies
+const COMPANIES_PAGE: usize = 50;
 
 #[derive(PartialEq)]
 pub enum RequestStatus {
@@ -35,11 +59,38 @@ pub struct SearchBar {
     is_focused: bool,
     debounce_timeout: Option<TimeoutTask>,
     focus_timeout: Option<TimeoutTask>,
+    query_error: Option<QueryError>,
+    suggestions: QuerySuggestions,
+    completions: Vec<String>,
+    suggest_timeout: Option<TimeoutTask>,
+    companies_requested: bool,
+    params_requested: bool,
+    target: SearchTarget,
+    /// Text of the query without the filters, used to rank the objects loaded by the short lists
+    search_text: String,
+    entities_requested: bool,
+    companies: Vec<ShowCompanyShort>,
+    standards: Vec<ShowStandardShort>,
+    services: Vec<ShowServiceShort>,
+    users: Vec<ShowUserShort>,
+    found_companies: Vec<ShowCompanyShort>,
+    found_standards: Vec<ShowStandardShort>,
+    found_services: Vec<ShowServiceShort>,
+    found_users: Vec<ShowUserShort>,
+    /// Search text received by the props, the text is replaced only when the page changes it
+    props_search: String,
+    /// Arguments of the last search with the filters of the query resolved
+    run_arg: Option<SearchArg>,
+    show_save: bool,
+    save_name: String,
+    callback_saved: Callback<()>,
 }
 
 #[derive(Properties, Clone)]
 pub struct Props {
     pub search_arg: Option<SearchArg>,
+    #[prop_or_default]
+    pub callback_saved: Callback<()>,
 }
 
 #[derive(Clone)]
@@ -47,12 +98,26 @@ pub enum Msg {
     InputSearch(String),
     GetSearchByComponentsResult(String),
     Search,
+    SetQueryError(QueryError),
+    SuggestSpecs(String),
+    GetSpecSuggestions(String),
+    GetCompanySuggestions(String),
+    GetParamSuggestions(String),
+    Complete(String),
+    CompleteFirst,
+    GetEntities(SearchTarget, String),
+    SearchResolved(SearchArg),
+    ShowSaveSearch,
+    InputSaveName(String),
+    SaveSearch,
+    SwitchTarget(SearchTarget),
     SetFocus(bool),
     KeyPress(KeyboardEvent),
     SetFocusAfterDelay(bool),
     AutoSearch,
     ResponseError(Error),
     ClearError,
+    Ignore,
 }
 
 impl Component for SearchBar {
@@ -60,6 +125,7 @@ impl Component for SearchBar {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let props_search = props.search_arg.as_ref().map(|arg| arg.search.clone()).unwrap_or_default();
         Self {
             error: None,
             link,
@@ -69,7 +135,29 @@ impl Component for SearchBar {
             request_status: RequestStatus::None,
             is_focused: false,
             debounce_timeout: None,
-            focus_timeout: None
+            focus_timeout: None,
+            query_error: None,
+            suggestions: QuerySuggestions::default(),
+            completions: Vec::new(),
+            suggest_timeout: None,
+            companies_requested: false,
+            params_requested: false,
+            target: SearchTarget::All,
+            search_text: String::new(),
+            entities_requested: false,
+            companies: Vec::new(),
+            standards: Vec::new(),
+            services: Vec::new(),
+            users: Vec::new(),
+            found_companies: Vec::new(),
+            found_standards: Vec::new(),
+            found_services: Vec::new(),
+            found_users: Vec::new(),
+            props_search,
+            run_arg: None,
+            show_save: false,
+            save_name: String::new(),
+            callback_saved: props.callback_saved,
         }
     }
 
@@ -84,6 +172,8 @@ impl Component for SearchBar {
         match msg {
             Msg::InputSearch(value) => {
                 self.search_arg.search = value;
+                self.query_error = None;
+                self.update_completions();
                 self.debounce_timeout = None;
                 if !self.search_arg.search.is_empty() {
                     let timeout = TimeoutService::spawn(
@@ -101,9 +191,31 @@ impl Component for SearchBar {
             },
             Msg::Search => {
                 self.request_status = RequestStatus::Loading;
+                self.completions.clear();
                 if !self.search_arg.search.is_empty() {
-                    let ipt_search_arg = search_by_components::IptSearchArg::get_ipt(&self.search_arg);
+                    let query = match SearchQuery::parse(&self.search_arg.search) {
+                        Ok(query) => query,
+                        Err(err) => {
+                            link.send_message(Msg::SetQueryError(err));
+                            return true
+                        },
+                    };
+                    self.query_error = None;
+                    self.search_text = query.text.join(" ");
+                    self.load_entities();
+                    self.rank_entities();
+                    let mut search_arg = self.search_arg.clone();
+                    query.apply(&mut search_arg);
                     spawn_local(async move {
+                        let search_arg = match resolve_filters(&query, search_arg).await {
+                            Ok(search_arg) => search_arg,
+                            Err(err) => {
+                                link.send_message(Msg::SetQueryError(err));
+                                return
+                            },
+                        };
+                        let ipt_search_arg = search_by_components::IptSearchArg::get_ipt(&search_arg);
+                        link.send_message(Msg::SearchResolved(search_arg));
                         let res = make_query(SearchByComponents::build_query(search_by_components::Variables {
                             ipt_search_arg
                         })).await.unwrap();
@@ -116,6 +228,9 @@ impl Component for SearchBar {
                 } else {
                     self.request_status = RequestStatus::Success;
                     self.found_components.clear();
+                    self.run_arg = None;
+                    self.search_text.clear();
+                    self.rank_entities();
                 }
             },
             Msg::GetSearchByComponentsResult(res) => {
@@ -132,6 +247,93 @@ impl Component for SearchBar {
                     },
                 }
             },
+            Msg::SetQueryError(err) => {
+                self.found_components.clear();
+                self.request_status = RequestStatus::None;
+                self.query_error = Some(err);
+                self.run_arg = None;
+            },
+            Msg::SuggestSpecs(text) => {
+                spawn_local(async move {
+                    let ipt_search_spec_arg = search_specs::IptSearchSpecArg {
+                        text,
+                        splitChar: None,
+                        depthLevel: None,
+                    };
+                    let res = make_query(SearchSpecs::build_query(search_specs::Variables {
+                        ipt_search_spec_arg
+                    })).await.unwrap();
+                    link.send_message(Msg::GetSpecSuggestions(res));
+                });
+            },
+            Msg::GetSpecSuggestions(res) => {
+                match resp_parsing::<Vec<SpecPathInfo>>(res, "searchSpecs") {
+                    Ok(result) => self.suggestions.specs = result.into_iter().map(|s| s.path).collect(),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+                self.completions = complete_query(&self.search_arg.search, &self.suggestions);
+            },
+            Msg::GetCompanySuggestions(res) => {
+                match resp_parsing::<Vec<ShowCompanyShort>>(res, "companies") {
+                    Ok(result) => self.suggestions.companies = result.into_iter().map(|c| c.shortname).collect(),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+                self.completions = complete_query(&self.search_arg.search, &self.suggestions);
+            },
+            Msg::GetParamSuggestions(res) => {
+                match resp_parsing::<Vec<Param>>(res, "params") {
+                    Ok(result) => self.suggestions.params = result.into_iter().map(|p| p.paramname).collect(),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+                self.completions = complete_query(&self.search_arg.search, &self.suggestions);
+            },
+            Msg::Complete(completion) => {
+                self.search_arg.search = replace_last_token(&self.search_arg.search, &completion);
+                self.update_completions();
+            },
+            Msg::GetEntities(target, res) => {
+                let parsed = match target {
+                    SearchTarget::Companies => resp_parsing::<Vec<ShowCompanyShort>>(res, "companies").map(|list| {
+                        // the next page is requested while the page is full
+                        let full_page = list.len() == COMPANIES_PAGE;
+                        self.companies.extend(list);
+                        if full_page {
+                            self.request_companies(self.companies.len() / COMPANIES_PAGE + 1);
+                        }
+                    }),
+                    SearchTarget::Standards => resp_parsing(res, "standards").map(|list| self.standards = list),
+                    SearchTarget::Services => resp_parsing(res, "services").map(|list| self.services = list),
+                    SearchTarget::Users => resp_parsing(res, "users").map(|list| self.users = list),
+                    _ => Ok(()),
+                };
+                match parsed {
+                    Ok(()) => self.rank_entities(),
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::SwitchTarget(target) => self.target = target,
+            Msg::SearchResolved(search_arg) => self.run_arg = Some(search_arg),
+            Msg::ShowSaveSearch => self.show_save = !self.show_save,
+            Msg::InputSaveName(name) => self.save_name = name,
+            Msg::SaveSearch => {
+                let name = self.save_name.trim().to_string();
+                if let (false, Some(run_arg)) = (name.is_empty(), &self.run_arg) {
+                    let mut searches = load_saved_searches();
+                    add_saved_search(
+                        &mut searches,
+                        SavedSearch::new(name, &self.search_arg, run_arg, &self.found_components),
+                    );
+                    save_saved_searches(&searches);
+                    self.show_save = false;
+                    self.save_name.clear();
+                    self.callback_saved.emit(());
+                }
+            },
+            Msg::CompleteFirst => {
+                if let Some(completion) = self.completions.first().cloned() {
+                    link.send_message(Msg::Complete(completion));
+                }
+            },
             Msg::SetFocus(focused) => {
                 let timeout = TimeoutService::spawn(
                   Duration::from_millis(200),
@@ -150,6 +352,7 @@ impl Component for SearchBar {
             }
             Msg::ResponseError(err) => self.error = Some(err),
             Msg::ClearError => self.error = None,
+            Msg::Ignore => {},
         }
         
         true
@@ -166,13 +369,23 @@ impl Component for SearchBar {
             .map(|p_arg| self.search_arg.partial_comparison(p_arg))
             .unwrap_or(false);
 
+        // the page changes the text only when a saved search is opened
+        let new_search = props.search_arg
+            .as_ref()
+            .map(|p_arg| p_arg.search != self.props_search)
+            .unwrap_or(false);
+
         debug!("flag: {:?}, {:?}, {}", props.search_arg.clone().unwrap(), self.search_arg, flag);
 
-        if flag && self.has_props == props.search_arg.is_some() {
+        if flag && !new_search && self.has_props == props.search_arg.is_some() {
             false
         } else {
             if let Some(sa) = props.search_arg {
-                let search = self.search_arg.search.clone();
+                let search = match new_search {
+                    true => sa.search.clone(),
+                    false => self.search_arg.search.clone(),
+                };
+                self.props_search = sa.search.clone();
                 self.search_arg = sa;
                 self.search_arg.search = search;
                 self.has_props = true;
@@ -189,6 +402,8 @@ impl Component for SearchBar {
         html! {<>
             <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
             {self.show_input_block()}
+            {self.show_query_error()}
+            {self.show_save_search()}
             {match self.has_props {
                 true => self.result_area(),
                 false => html!{},
@@ -198,21 +413,136 @@ impl Component for SearchBar {
 }
 
 impl SearchBar {
+    /// Options of the autocompletion for the word being typed,
+    /// the values of the filters are requested when the filter is typed
+    fn update_completions(&mut self) {
+        self.completions = complete_query(&self.search_arg.search, &self.suggestions);
+        let link = self.link.clone();
+        match typed_filter(&self.search_arg.search) {
+            Some((QueryPrefix::Spec, value)) if value.chars().count() > 1 => {
+                self.suggest_timeout = Some(TimeoutService::spawn(
+                    Duration::from_millis(300),
+                    link.callback(move |_| Msg::SuggestSpecs(value.clone()))
+                ));
+            },
+            Some((QueryPrefix::Company, _)) if !self.companies_requested => {
+                self.companies_requested = true;
+                spawn_local(async move {
+                    let res = make_query(GetCompaniesShortList::build_query(get_companies_short_list::Variables {
+                        ipt_companies_arg: None,
+                        ipt_paginate: None,
+                    })).await.unwrap();
+                    link.send_message(Msg::GetCompanySuggestions(res));
+                });
+            },
+            Some((QueryPrefix::Param, _)) if !self.params_requested => {
+                self.params_requested = true;
+                spawn_local(async move {
+                    let res = make_query(GetParams::build_query(
+                        get_params::Variables { param_ids: None, ipt_paginate: None }
+                    )).await.unwrap();
+                    link.send_message(Msg::GetParamSuggestions(res));
+                });
+            },
+            _ => {},
+        }
+    }
+
+    /// Requests the short lists of the objects without the backend search, once per search bar
+    fn load_entities(&mut self) {
+        if self.entities_requested {
+            return
+        }
+        self.entities_requested = true;
+        self.companies.clear();
+        self.request_companies(1);
+        for target in SearchTarget::LOCAL {
+            if target == SearchTarget::Companies {
+                continue
+            }
+            let link = self.link.clone();
+            spawn_local(async move {
+                let res = match target {
+                    SearchTarget::Standards => make_query(GetStandardsShortList::build_query(
+                        get_standards_short_list::Variables { ipt_standards_arg: None }
+                    )).await.unwrap(),
+                    SearchTarget::Services => make_query(GetServicesShortList::build_query(get_services_short_list::Variables {
+                        ipt_services_arg: None,
+                        ipt_paginate: None,
+                        images_only: None,
+                    })).await.unwrap(),
+                    _ => make_query(GetUsersShortList::build_query(get_users_short_list::Variables {
+                        ipt_users_arg: None,
+                    })).await.unwrap(),
+                };
+                link.send_message(Msg::GetEntities(target, res));
+            });
+        }
+    }
+
+    /// Requests the page of the companies, the companies are received by pages as in `find_company`
+    fn request_companies(&self, current_page: usize) {
+        let link = self.link.clone();
+        spawn_local(async move {
+            let res = make_query(GetCompaniesShortList::build_query(get_companies_short_list::Variables {
+                ipt_companies_arg: None,
+                ipt_paginate: Some(get_companies_short_list::IptPaginate {
+                    currentPage: current_page as i64,
+                    perPage: COMPANIES_PAGE as i64,
+                }),
+            })).await.unwrap();
+            link.send_message(Msg::GetEntities(SearchTarget::Companies, res));
+        });
+    }
+
+    fn rank_entities(&mut self) {
+        let text = &self.search_text;
+        self.found_companies = rank_by_text(&self.companies, text, |c| vec![&c.shortname, &c.inn, &c.description]);
+        self.found_standards = rank_by_text(&self.standards, text, |s| vec![&s.name, &s.description, &s.owner_company.shortname]);
+        self.found_services = rank_by_text(&self.services, text, |s| vec![&s.name, &s.description, &s.owner_company.shortname]);
+        self.found_users = rank_by_text(&self.users, text, |u| vec![&u.username, &u.firstname, &u.lastname]);
+    }
+
+    fn found_count(&self, target: SearchTarget) -> usize {
+        match target {
+            SearchTarget::All => SearchTarget::ALL[1..].iter().map(|t| self.found_count(*t)).sum(),
+            SearchTarget::Components => self.found_components.len(),
+            SearchTarget::Companies => self.found_companies.len(),
+            SearchTarget::Standards => self.found_standards.len(),
+            SearchTarget::Services => self.found_services.len(),
+            SearchTarget::Users => self.found_users.len(),
+        }
+    }
+
     fn result_area(&self) -> Html {
-        let (arguments, component_list) = match self.search_arg.search.is_empty() {
-            true => (Some(ComponentsQueryArg::set_by_arg(&self.search_arg)), None),
-            false => (None, Some(self.found_components.clone())),
+        // without the search text the components are shown by the filters, as the catalog
+        let (arguments, component_list, target) = match self.search_arg.search.is_empty() {
+            true => (Some(ComponentsQueryArg::set_by_arg(&self.search_arg)), None, SearchTarget::Components),
+            false => (None, Some(self.found_components.clone()), self.target),
         };
         html!{
             <div class={"search-result-list"}>
+                {match self.search_arg.search.is_empty() {
+                    true => html!{},
+                    false => self.show_target_tabs(),
+                }}
                 <div class={"columns is-mobile"}>
                     <div class={"column is-flex"}>
                         <div id={"search-result-list-items"} class="card-relate-data" style={resizer("search-result-list", 1)}>
-                            <CatalogComponents
-                                show_create_btn={false}
-                                arguments={arguments}
-                                component_list={component_list}
-                                />
+                            {match target {
+                                SearchTarget::All => self.show_grouped_results(),
+                                SearchTarget::Components => html!{
+                                    <CatalogComponents
+                                        show_create_btn={false}
+                                        arguments={arguments}
+                                        component_list={component_list}
+                                        />
+                                },
+                                _ => match self.found_count(target) {
+                                    0 => html!{<ListEmpty />},
+                                    _ => self.show_entities(target, usize::MAX),
+                                },
+                            }}
                         </div>
                     </div>
                 </div>
@@ -220,10 +550,109 @@ impl SearchBar {
         }
     }
 
+    fn show_target_tabs(&self) -> Html {
+        html!{
+            <div class={"tabs"}>
+                <ul>
+                    {for SearchTarget::ALL.iter().map(|target| {
+                        let target = *target;
+                        let onclick = self.link.callback(move |_| Msg::SwitchTarget(target));
+                        html!{
+                            <li class={if self.target == target { "is-active" } else { "" }}>
+                                <a onclick={onclick}>
+                                    {target.label()}
+                                    {match target {
+                                        SearchTarget::All => html!{},
+                                        _ => html!{<span class={"tag is-rounded ml-1"}>{self.found_count(target)}</span>},
+                                    }}
+                                </a>
+                            </li>
+                        }
+                    })}
+                </ul>
+            </div>
+        }
+    }
+
+    fn show_grouped_results(&self) -> Html {
+        if self.found_count(SearchTarget::All) == 0 {
+            return html!{<ListEmpty />}
+        }
+        html!{<>
+            {for SearchTarget::ALL[1..].iter().filter(|t| self.found_count(**t) > 0).map(|target| {
+                let target = *target;
+                let onclick_show_all = self.link.callback(move |_| Msg::SwitchTarget(target));
+                html!{
+                    <div class={"block"}>
+                        <div class={"is-flex is-justify-content-space-between is-align-items-center"}>
+                            <p class={"title is-5 mb-2"}>
+                                {target.label()}
+                                <span class={"tag is-rounded ml-1"}>{self.found_count(target)}</span>
+                            </p>
+                            {match self.found_count(target) > GROUP_SIZE {
+                                true => html!{
+                                    <a onclick={onclick_show_all}>{get_value_field(&696)}</a> // Show all
+                                },
+                                false => html!{},
+                            }}
+                        </div>
+                        {match target {
+                            SearchTarget::Components => html!{
+                                <CatalogComponents
+                                    show_create_btn={false}
+                                    arguments={None}
+                                    component_list={Some(self.found_components.iter().take(GROUP_SIZE).cloned().collect::<Vec<_>>())}
+                                    />
+                            },
+                            _ => self.show_entities(target, GROUP_SIZE),
+                        }}
+                    </div>
+                }
+            })}
+        </>}
+    }
+
+    /// Found objects of the type by the list items of the catalogs
+    fn show_entities(&self, target: SearchTarget, limit: usize) -> Html {
+        match target {
+            SearchTarget::Companies => html!{<>
+                {for self.found_companies.iter().take(limit).map(|x| html!{
+                    <ListItemCompany data={x.clone()} show_list={true} />
+                })}
+            </>},
+            SearchTarget::Standards => html!{<>
+                {for self.found_standards.iter().take(limit).map(|x| html!{
+                    <ListItemStandard data={x.clone()} show_list={true} />
+                })}
+            </>},
+            SearchTarget::Services => html!{<>
+                {for self.found_services.iter().take(limit).map(|x| html!{
+                    <ListItemService data={x.clone()} show_list={true} />
+                })}
+            </>},
+            SearchTarget::Users => html!{<>
+                {for self.found_users.iter().take(limit).map(|x| html!{
+                    <ListItemUser data={x.clone()} show_list={true} />
+                })}
+            </>},
+            _ => html!{},
+        }
+    }
+
     fn show_input_block(&self) -> Html {
         let is_loading = if self.request_status == RequestStatus::Loading { "is-loading" } else { "" };
         let mut bar_class = classes!("field", "is-relative");
         if self.has_props { bar_class.push(vec!["has-addons", "column", "p-0", "m-0", "is-three-quarters"]); }
+        let has_completions = !self.completions.is_empty();
+        let onkeydown_complete = self.link.callback(move |e: KeyboardEvent| {
+            match has_completions && e.key() == "Tab" {
+                true => {
+                    e.prevent_default();
+                    Msg::CompleteFirst
+                },
+                false => Msg::Ignore,
+            }
+        });
         html! {
             <div class={bar_class}>
                 <div class={classes!("control", "has-icons-left", "has-icons-right", is_loading)} style={"width: 100%;"}>
@@ -232,21 +661,38 @@ impl SearchBar {
                     onfocus={self.link.callback(|_| Msg::SetFocus(true))}
                     onblur={self.link.callback(|_| Msg::SetFocus(false))}
                     onkeypress={self.link.callback(|e: KeyboardEvent| Msg::KeyPress(e))}
+                    onkeydown={onkeydown_complete}
                     placeholder={get_value_field(&351)} // Enter search text
                     value={self.search_arg.search.clone()}
                     />
                 <span class={"icon is-small is-left"}>
                     <i class={"fas fa-search fa-xs"}></i>
                 </span>
+                {self.show_completions()}
                 </div>
                 {match self.has_props {
-                    true => html!{
+                    true => html!{<>
                         <div class={"control"}>
                             <button class="button is-info search-button" onclick={self.link.callback(|_| Msg::Search)}>
                                 {get_value_field(&349)}
                             </button>
                         </div>
-                    },
+                        {match get_logged_user().is_some() {
+                            true => html!{
+                                <div class={"control"}>
+                                    <button
+                                        id={"search-save-btn"}
+                                        class={"button"}
+                                        title={get_value_field(&697)} // Save search
+                                        disabled={self.run_arg.is_none()}
+                                        onclick={self.link.callback(|_| Msg::ShowSaveSearch)} >
+                                        <span class={"icon"}><i class={"fas fa-bookmark"}></i></span>
+                                    </button>
+                                </div>
+                            },
+                            false => html!{},
+                        }}
+                    </>},
                     false => self.show_dropdown(),
                 }}
             </div>
@@ -254,13 +700,13 @@ impl SearchBar {
     }
 
     fn show_dropdown(&self) -> Html {
-        let show_dropdown = if self.request_status == RequestStatus::Success && self.is_focused { "is-active" } else { "" };
+        let show_dropdown = if self.request_status == RequestStatus::Success && self.is_focused && self.completions.is_empty() { "is-active" } else { "" };
         html! {
             <div class={classes!("dropdown", "is-absolute", show_dropdown)}>
               <div class={"dropdown-menu"} id={"component-dropdown-menu"} role={"menu"}>
                 <div class={"dropdown-content"}>
                   {
-                    if self.request_status == RequestStatus::Success && self.found_components.is_empty() {
+                    if self.request_status == RequestStatus::Success && self.found_count(SearchTarget::All) == 0 {
                         html! {
                             <div class={"dropdown-item has-text-grey"}>
                                 <span class={"icon-text"}>
@@ -272,7 +718,7 @@ impl SearchBar {
                             </div>
                         }
                     } else {
-                        html! {
+                        html! {<>
                             {for self.found_components.iter().map(|x| {
                                 html!{
                                     <a href={format!("#/component/{}", x.uuid)} class={"dropdown-item"}>
@@ -280,7 +726,14 @@ impl SearchBar {
                                     </a>
                                 }
                             })}
-                        }
+                            {for SearchTarget::LOCAL.iter().filter(|t| self.found_count(**t) > 0).map(|target| html!{<>
+                                <hr class={"dropdown-divider"} />
+                                <div class={"dropdown-item has-text-grey is-size-7"}>{target.label()}</div>
+                                {for self.dropdown_links(*target).into_iter().map(|(href, name)| html!{
+                                    <a href={href} class={"dropdown-item"}>{name}</a>
+                                })}
+                            </>})}
+                        </>}
                     }
                   }
                 </div>
@@ -288,4 +741,183 @@ impl SearchBar {
             </div>
         }
     }
+
+    /// Links to the pages of the first found objects of the type
+    fn dropdown_links(&self, target: SearchTarget) -> Vec<(String, String)> {
+        match target {
+            SearchTarget::Companies => self.found_companies.iter().take(GROUP_SIZE)
+                .map(|x| (format!("#/company/{}", x.uuid), x.shortname.clone())).collect(),
+            SearchTarget::Standards => self.found_standards.iter().take(GROUP_SIZE)
+                .map(|x| (format!("#/standard/{}", x.uuid), x.name.clone())).collect(),
+            SearchTarget::Services => self.found_services.iter().take(GROUP_SIZE)
+                .map(|x| (format!("#/service/{}", x.uuid), x.name.clone())).collect(),
+            SearchTarget::Users => self.found_users.iter().take(GROUP_SIZE)
+                .map(|x| (format!("#/@{}", x.username), x.username.clone())).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn show_completions(&self) -> Html {
+        if !self.is_focused || self.completions.is_empty() {
+            return html!{}
+        }
+        html!{
+            <div class={"dropdown is-active is-absolute"}>
+              <div class={"dropdown-menu"} id={"search-completions-menu"} role={"menu"}>
+                <div class={"dropdown-content"}>
+                  {for self.completions.iter().map(|completion| {
+                      let description = completion.strip_suffix(':')
+                          .and_then(QueryPrefix::from_name)
+                          .map(|prefix| prefix.description())
+                          .unwrap_or_default();
+                      let value = completion.clone();
+                      // mousedown is used so the search input does not lose the focus
+                      let onmousedown = self.link.callback(move |e: MouseEvent| {
+                          e.prevent_default();
+                          Msg::Complete(value.clone())
+                      });
+                      html!{
+                          <a class={"dropdown-item"} onmousedown={onmousedown}>
+                              <span class={"has-text-weight-bold"}>{completion.clone()}</span>
+                              {" "}<span class={"has-text-grey"}>{description}</span>
+                          </a>
+                      }
+                  })}
+                  <div class={"dropdown-item has-text-grey is-size-7"}>
+                      {get_value_field(&692)} // Tab to complete
+                  </div>
+                </div>
+              </div>
+            </div>
+        }
+    }
+
+    fn show_save_search(&self) -> Html {
+        if !self.show_save || self.run_arg.is_none() {
+            return html!{}
+        }
+        let oninput_name = self.link.callback(|ev: InputData| Msg::InputSaveName(ev.value));
+        let onclick_save = self.link.callback(|_| Msg::SaveSearch);
+        let onclick_cancel = self.link.callback(|_| Msg::ShowSaveSearch);
+        html!{
+            <div class={"field has-addons column p-0 mx-0 mt-2 is-three-quarters"}>
+                <div class={"control is-expanded"}>
+                    <input
+                        id={"search-save-name"}
+                        class={"input"}
+                        type={"text"}
+                        placeholder={get_value_field(&698)} // Name of the search
+                        value={self.save_name.clone()}
+                        oninput={oninput_name} />
+                </div>
+                <div class={"control"}>
+                    <button
+                        class={"button is-info"}
+                        disabled={self.save_name.trim().is_empty()}
+                        onclick={onclick_save} >
+                        {get_value_field(&46)} // Save
+                    </button>
+                </div>
+                <div class={"control"}>
+                    <button class={"button"} onclick={onclick_cancel}>
+                        {get_value_field(&221)} // Cancel
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn show_query_error(&self) -> Html {
+        match &self.query_error {
+            Some(err) => html!{
+                <p id={"search-query-error"} class={"help is-danger"}>{err.message()}</p>
+            },
+            None => html!{},
+        }
+    }
+}
+
+/// Resolves the filters of the query by names into the UUIDs and the spec ID
+async fn resolve_filters(query: &SearchQuery, mut search_arg: SearchArg) -> Result<SearchArg, QueryError> {
+    if let Some(spec) = &query.spec {
+        let ipt_search_spec_arg = search_specs::IptSearchSpecArg {
+            text: spec.clone(),
+            splitChar: None,
+            depthLevel: None,
+        };
+        let res = make_query(SearchSpecs::build_query(search_specs::Variables {
+            ipt_search_spec_arg
+        })).await.unwrap();
+        let specs = resp_parsing::<Vec<SpecPathInfo>>(res, "searchSpecs").unwrap_or_default();
+        match match_name(&specs, spec, |s| &s.path) {
+            Some(found) => search_arg.set_spec_id(found.spec_id),
+            None => return Err(QueryError::NotFound(QueryPrefix::Spec, spec.clone())),
+        }
+    }
+    if let Some(owner) = &query.owner {
+        search_arg.user_uuid = match is_uuid(owner) {
+            true => Some(owner.clone()),
+            false => {
+                let ipt_get_user_arg = get_user_data::IptGetUserArg {
+                    userUuid: None,
+                    username: Some(owner.clone()),
+                };
+                let res = make_query(GetUserData::build_query(
+                    get_user_data::Variables { ipt_get_user_arg }
+                )).await.unwrap();
+                match resp_parsing::<UserInfo>(res, "user") {
+                    Ok(user) => Some(user.uuid),
+                    Err(_) => return Err(QueryError::NotFound(QueryPrefix::Owner, owner.clone())),
+                }
+            },
+        };
+    }
+    if let Some(company) = &query.company {
+        search_arg.company_uuid = match is_uuid(company) {
+            true => Some(company.clone()),
+            false => match find_company(company).await {
+                Some(company_uuid) => Some(company_uuid),
+                None => return Err(QueryError::NotFound(QueryPrefix::Company, company.clone())),
+            },
+        };
+    }
+    if let Some(standard) = &query.standard {
+        search_arg.standard_uuid = match is_uuid(standard) {
+            true => Some(standard.clone()),
+            false => {
+                let res = make_query(GetStandardsShortList::build_query(
+                    get_standards_short_list::Variables { ipt_standards_arg: None }
+                )).await.unwrap();
+                let standards = resp_parsing::<Vec<ShowStandardShort>>(res, "standards").unwrap_or_default();
+                match match_name(&standards, standard, |s| &s.name) {
+                    Some(found) => Some(found.uuid.clone()),
+                    None => return Err(QueryError::NotFound(QueryPrefix::Standard, standard.clone())),
+                }
+            },
+        };
+    }
+    Ok(search_arg)
+}
+
+/// Requests the companies by pages until the page with the company matching the name,
+/// the company with the equal name is preferred within the page
+async fn find_company(name: &str) -> Option<UUID> {
+    let mut current_page = 1;
+    loop {
+        let res = make_query(GetCompaniesShortList::build_query(get_companies_short_list::Variables {
+            ipt_companies_arg: None,
+            ipt_paginate: Some(get_companies_short_list::IptPaginate {
+                currentPage: current_page,
+                perPage: COMPANIES_PAGE as i64,
+            }),
+        })).await.unwrap();
+        let companies = resp_parsing::<Vec<ShowCompanyShort>>(res, "companies").unwrap_or_default();
+        if let Some(found) = match_name(&companies, name, |c| &c.shortname) {
+            return Some(found.uuid.clone())
+        }
+        if companies.len() < COMPANIES_PAGE {
+            return None
+        }
+        current_page += 1;
+    }
 }
@@ -0,0 +1,109 @@
+use crate::services::get_value_field;
+
+/// Type of the objects found by the search bar: the components are searched by the backend,
+/// the other objects are filtered and ranked on the client from the short lists
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchTarget {
+    All,
+    Components,
+    Companies,
+    Standards,
+    Services,
+    Users,
+}
+
+impl SearchTarget {
+    pub(crate) const ALL: [SearchTarget; 6] = [
+        SearchTarget::All,
+        SearchTarget::Components,
+        SearchTarget::Companies,
+        SearchTarget::Standards,
+        SearchTarget::Services,
+        SearchTarget::Users,
+    ];
+
+    /// Types without the backend search, loaded once by the short lists
+    pub(crate) const LOCAL: [SearchTarget; 4] = [
+        SearchTarget::Companies,
+        SearchTarget::Standards,
+        SearchTarget::Services,
+        SearchTarget::Users,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            SearchTarget::All => get_value_field(&695),
+            SearchTarget::Components => get_value_field(&154),
+            SearchTarget::Companies => get_value_field(&693),
+            SearchTarget::Standards => get_value_field(&103),
+            SearchTarget::Services => get_value_field(&379),
+            SearchTarget::Users => get_value_field(&694),
+        }
+    }
+}
+
+/// Score of the word in the field: equal, starts the field, equal or starts a word of the field, contained
+fn field_score(field: &str, word: &str) -> usize {
+    let field = field.to_lowercase();
+    let mut field_words = field.split(|c: char| !c.is_alphanumeric());
+    if field == word {
+        5
+    } else if field.starts_with(word) {
+        4
+    } else if field_words.clone().any(|w| w == word) {
+        3
+    } else if field_words.any(|w| w.starts_with(word)) {
+        2
+    } else if field.contains(word) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Items with all words of the text in the fields, sorted by the relevance.
+/// The first field (name) has double weight.
+pub(crate) fn rank_by_text<T: Clone>(items: &[T], text: &str, fields: impl Fn(&T) -> Vec<&str>) -> Vec<T> {
+    let words: Vec<String> = text.split_whitespace().map(|w| w.to_lowercase()).collect();
+    if words.is_empty() {
+        return Vec::new()
+    }
+    let mut scored: Vec<(usize, &T)> = items.iter().filter_map(|item| {
+        let item_fields = fields(item);
+        let mut total = 0;
+        for word in &words {
+            let best = item_fields.iter().enumerate()
+                .map(|(index, field)| field_score(field, word) * if index == 0 { 2 } else { 1 })
+                .max()
+                .unwrap_or_default();
+            if best == 0 {
+                return None
+            }
+            total += best;
+        }
+        Some((total, item))
+    }).collect();
+    // the sort is stable, items with the same score keep the order of the list
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, item)| item.clone()).collect()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn rank_objects_by_text() {
+        let standards = vec![
+            ("ISO 47620", "Other"),
+            ("Socket screws", "ISO 4762 hexagon"),
+            ("ISO 4762", "Hexagon socket head cap screws"),
+            ("DIN 912", "Screws"),
+        ];
+        let found = rank_by_text(&standards, "iso 4762", |s| vec![s.0, s.1]);
+        assert_eq!(found.iter().map(|s| s.0).collect::<Vec<_>>(), vec!["ISO 4762", "ISO 47620", "Socket screws"]);
+        assert_eq!(rank_by_text(&standards, "screws", |s| vec![s.0, s.1])[0].0, "Socket screws");
+        assert!(rank_by_text(&standards, "  ", |s| vec![s.0]).is_empty());
+        assert!(rank_by_text(&standards, "iso bolt", |s| vec![s.0, s.1]).is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use log::debug;
+use crate::services::{get_value_field, compare_models, CompareMode};
+use crate::types::DownloadFile;
+
+// 1. Get two model files (modifications or revisions of a file)
+// 2. Render the containers for the selected mode
+// 3. Start the comparison after the containers are in the DOM
+
+pub struct ThreeCompare {
+    props: Props,
+    link: ComponentLink<Self>,
+    mode: CompareMode,
+    need_start: bool,
+}
+
+#[derive(Clone, Debug, Properties)]
+pub struct Props {
+    pub left_file: DownloadFile,
+    pub left_title: String,
+    pub right_file: DownloadFile,
+    pub right_title: String,
+}
+
+pub enum Msg {
+    ChangeMode(CompareMode),
+}
+
+impl Component for ThreeCompare {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            mode: CompareMode::SideBySide,
+            need_start: true,
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.need_start {
+            self.need_start = false;
+            debug!("Compare {:?} and {:?} ({:?})", self.props.left_file.uuid, self.props.right_file.uuid, self.mode);
+            compare_models(&self.props.left_file, &self.props.right_file, self.mode);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeMode(mode) => {
+                if self.mode == mode {
+                    return false
+                }
+                self.mode = mode;
+                self.need_start = true;
+            },
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.left_file.uuid == props.left_file.uuid &&
+            self.props.right_file.uuid == props.right_file.uuid {
+            false
+        } else {
+            self.props = props;
+            self.need_start = true;
+            true
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_side_by_side = self.link.callback(|_| Msg::ChangeMode(CompareMode::SideBySide));
+        let onclick_overlay = self.link.callback(|_| Msg::ChangeMode(CompareMode::Overlay));
+        let (class_side_by_side, class_overlay) = match self.mode {
+            CompareMode::SideBySide => ("is-active", ""),
+            CompareMode::Overlay => ("", "is-active"),
+        };
+        let container_style = "display: block; width: 100%; height: 50vh; overflow: hidden;";
+
+        html!{<>
+            <div class="tabs is-toggle is-small is-centered">
+                <ul>
+                    <li class={class_side_by_side} onclick={onclick_side_by_side}>
+                        <a>
+                            <span class="icon is-small"><i class="fas fa-columns" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&465)}</span> // Side by side
+                        </a>
+                    </li>
+                    <li class={class_overlay} onclick={onclick_overlay}>
+                        <a>
+                            <span class="icon is-small"><i class="fas fa-clone" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&466)}</span> // Overlay
+                        </a>
+                    </li>
+                </ul>
+            </div>
+            <compare-hull>
+                {match self.mode {
+                    CompareMode::SideBySide => html!{
+                        <div class="columns">
+                            <div class="column">
+                                <p class="overflow-title has-text-weight-bold" style="color: #1872f0;">
+                                    {self.props.left_title.clone()}
+                                </p>
+                                <l-container style={container_style}></l-container>
+                            </div>
+                            <div class="column">
+                                <p class="overflow-title has-text-weight-bold" style="color: #ed760e;">
+                                    {self.props.right_title.clone()}
+                                </p>
+                                <r-container style={container_style}></r-container>
+                            </div>
+                        </div>
+                    },
+                    CompareMode::Overlay => html!{<>
+                        {self.show_legend()}
+                        <o-container style={container_style}></o-container>
+                    </>},
+                }}
+            </compare-hull>
+        </>}
+    }
+}
+
+impl ThreeCompare {
+    fn show_legend(&self) -> Html {
+        html!{
+            <div class="tags">
+                <span class="tag is-light">
+                    {get_value_field(&467)}{": "}{self.props.left_title.clone()} // Translucent
+                </span>
+                <span class="tag is-success">{self.props.right_title.clone()}{": "}{get_value_field(&468)}</span> // Matches
+                <span class="tag is-warning">{get_value_field(&469)}</span> // Small deviation
+                <span class="tag is-danger">{get_value_field(&470)}</span> // Large deviation
+            </div>
+        }
+    }
+}
@@ -1,9 +1,13 @@
-use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender, classes};
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, Properties, ShouldRender, classes};
 use wasm_bindgen_futures::spawn_local;
 use graphql_client::GraphQLQuery;
 use log::debug;
 use crate::fragments::list_errors::ListErrors;
-use crate::services::{get_value_field, is_gltf_resource, preview_model, resp_parsing, ModelFormat, ResourceMapping};
+use crate::fragments::drawing_showcase::DrawingShowcase;
+use crate::services::{
+    get_value_field, is_gltf_resource, preview_model, get_viewer_state, set_pin_mode, resp_parsing, set_clipboard,
+    ModelFormat, DrawingFormat, ResourceMapping, ComponentViewLink, ViewerState,
+};
 use crate::error::Error;
 use crate::types::{DownloadFile, PaginateSet, UUID};
 use crate::gqls::make_query;
@@ -14,6 +18,9 @@ use crate::gqls::component::{ComModFilesetFiles, com_mod_fileset_files};
 // 3. Select the files and resources suitable for display
 // 4. Activate the view button
 // 5. Start viewing (when the button is clicked)
+// 6. Restore the file and the viewer state from the view link (if set)
+// 7. Place a pin on the model and pass it with the view for a comment (annotation)
+// 8. Show 2D drawings (DXF, SVG) of the fileset if selected or if there are no models
 
 pub struct ThreeShowcase {
     props: Props,
@@ -25,11 +32,29 @@ pub struct ThreeShowcase {
     selected_file: Option<(DownloadFile, ModelFormat)>,
     suitable_files: Vec<(DownloadFile, ModelFormat)>,
     resource_files: Vec<DownloadFile>,
+    drawing_files: Vec<DownloadFile>,
+    selected_drawing: Option<DownloadFile>,
+    /// Start the viewer after the container is shown
+    need_show: bool,
+    viewer_state: Option<ViewerState>,
+    view_link: Option<String>,
+    link_copied: bool,
+    pin_mode: bool,
+    pin_missing: bool,
 }
 
 #[derive(PartialEq, Clone, Debug, Properties)]
 pub struct Props {
     pub fileset_uuid: UUID,
+    /// Modification of the fileset, used in the view link
+    #[prop_or_default]
+    pub modification_uuid: UUID,
+    /// File and viewer state to restore (from the view link)
+    #[prop_or_default]
+    pub view_link: Option<ComponentViewLink>,
+    /// Receives the view with a pin for the comment, the annotation button is hidden if not set
+    #[prop_or_default]
+    pub callback_annotation: Option<Callback<ComponentViewLink>>,
     // pub program_id: usize,
     // pub callback_three_view: Callback<bool>,
 }
@@ -39,9 +64,16 @@ pub enum Msg {
     RequestDownloadFilesetFiles,
     ResponseError(Error),
     GetDownloadFilesetFilesResult(String),
+    SelectFile(UUID),
     ChangeTypeShow,
     ShowThree,
+    ShowViewLink,
+    ViewLinkCopied,
+    StartAnnotation,
+    FinishAnnotation,
+    CancelAnnotation,
     ClearError,
+    Ignore,
 }
 
 impl Component for ThreeShowcase {
@@ -49,6 +81,7 @@ impl Component for ThreeShowcase {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let viewer_state = link_viewer_state(&props);
         ThreeShowcase {
             props,
             link,
@@ -59,6 +92,14 @@ impl Component for ThreeShowcase {
             selected_file: None,
             suitable_files: Vec::new(),
             resource_files: Vec::new(),
+            drawing_files: Vec::new(),
+            selected_drawing: None,
+            need_show: false,
+            viewer_state,
+            view_link: None,
+            link_copied: false,
+            pin_mode: false,
+            pin_missing: false,
         }
     }
 
@@ -66,6 +107,10 @@ impl Component for ThreeShowcase {
         if first_render {
             self.link.send_message(Msg::RequestDownloadFilesetFiles);
         }
+        if self.need_show {
+            self.need_show = false;
+            self.link.send_message(Msg::ShowThree);
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -101,28 +146,31 @@ impl Component for ThreeShowcase {
                         self.file_arr = result;
                         self.suitable_files.clear();
                         self.resource_files.clear();
+                        self.drawing_files.clear();
                         debug!("componentModificationFilesetFiles: {:?}", self.file_arr);
                         for file in self.file_arr.iter() {
                             let model_format = ModelFormat::from_filename(&file.filename);
                             if model_format.is_3d_format() {
                                 self.suitable_files.push((file.clone(), model_format));
                             }
-                        }
-                        if let Some((file, model_format)) = self.suitable_files.first() {
-                            self.selected_file = Some((file.clone(), *model_format));
-                            debug!("Found {} files for show, selected: {:?}", self.suitable_files.len(), self.selected_file);
-                            if model_format == &ModelFormat::GLTF {
-                                debug!("{:?} is ModelFormat::GLTF", model_format);
-                                self.reassemble_resources();
+                            if DrawingFormat::from_filename(&file.filename).is_drawing_format() {
+                                self.drawing_files.push(file.clone());
                             }
-                            debug!("Resource files: {:?}", self.resource_files);
-                            link.send_message(Msg::ShowThree);
                         }
+                        self.select_file(self.link_file_uuid());
                     },
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
+            Msg::SelectFile(file_uuid) => {
+                link.send_message(Msg::CancelAnnotation);
+                self.select_file(Some(file_uuid));
+            },
             Msg::ChangeTypeShow => {
+                // keep the camera and display settings for the viewer in the other mode
+                if let Some(viewer_state) = get_viewer_state() {
+                    self.viewer_state = Some(viewer_state);
+                }
                 self.full_screen = !self.full_screen;
                 link.send_message(Msg::ShowThree);
             },
@@ -138,11 +186,73 @@ impl Component for ThreeShowcase {
                                 download_url: rf.download_url.clone(),
                             })
                             .collect(),
-                        self.full_screen
+                        self.full_screen,
+                        self.viewer_state.clone(),
                     );
                 }
             },
+            Msg::ShowViewLink => {
+                if self.view_link.is_some() {
+                    self.view_link = None;
+                    return true
+                }
+                let location = web_sys::window().map(|window| window.location());
+                // the table of modifications keeps its filters in the link of the page
+                let filter = location.as_ref()
+                    .and_then(|location| location.hash().ok())
+                    .map(|hash| ComponentViewLink::from_fragment(&hash).1.filter)
+                    .unwrap_or_default();
+                let view_link = ComponentViewLink {
+                    modification_uuid: Some(self.props.modification_uuid.clone()).filter(|uuid| uuid.len() == 36),
+                    fileset_uuid: Some(self.props.fileset_uuid.clone()),
+                    file_uuid: self.selected_file
+                        .as_ref()
+                        .map(|(file, _)| file.uuid.clone())
+                        .or_else(|| self.selected_drawing.as_ref().map(|file| file.uuid.clone())),
+                    // the state of the 3D viewer is not used for drawings
+                    viewer: match self.selected_drawing.is_some() {
+                        true => ViewerState::default(),
+                        false => get_viewer_state().unwrap_or_default(),
+                    },
+                    filter,
+                };
+                let href = location
+                    .and_then(|location| location.href().ok())
+                    .unwrap_or_default();
+                self.view_link = Some(view_link.to_href(&href));
+                self.link_copied = false;
+                set_clipboard(".view-link-copy");
+            },
+            Msg::ViewLinkCopied => self.link_copied = true,
+            Msg::StartAnnotation => {
+                self.pin_mode = true;
+                self.pin_missing = false;
+                set_pin_mode(true);
+            },
+            Msg::FinishAnnotation => {
+                let viewer = get_viewer_state().unwrap_or_default();
+                if viewer.pin.is_none() {
+                    self.pin_missing = true;
+                    return true
+                }
+                if let Some(callback) = &self.props.callback_annotation {
+                    callback.emit(ComponentViewLink {
+                        modification_uuid: Some(self.props.modification_uuid.clone()).filter(|uuid| uuid.len() == 36),
+                        fileset_uuid: Some(self.props.fileset_uuid.clone()),
+                        file_uuid: self.selected_file.as_ref().map(|(file, _)| file.uuid.clone()),
+                        viewer,
+                        ..ComponentViewLink::default()
+                    });
+                }
+                link.send_message(Msg::CancelAnnotation);
+            },
+            Msg::CancelAnnotation => {
+                self.pin_mode = false;
+                self.pin_missing = false;
+                set_pin_mode(false);
+            },
             Msg::ClearError => self.error = None,
+            Msg::Ignore => {},
         };
         true
     }
@@ -150,14 +260,27 @@ impl Component for ThreeShowcase {
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props.fileset_uuid == props.fileset_uuid {
             debug!("no change: {:?}", self.props.fileset_uuid);
+            let open_view = props.view_link.is_some() && self.props.view_link != props.view_link;
+            self.props = props;
+            if let Some(viewer_state) = link_viewer_state(&self.props).filter(|_| open_view) {
+                // the same fileset, but other file or camera (e.g. from an annotation)
+                self.viewer_state = Some(viewer_state);
+                self.select_file(self.link_file_uuid());
+                return true
+            }
             false
         } else {
             self.props = props;
+            self.viewer_state = link_viewer_state(&self.props);
+            self.view_link = None;
+            self.pin_mode = false;
             self.file_arr.clear();
             self.full_screen = false;
             self.selected_file = None;
             self.suitable_files.clear();
             self.resource_files.clear();
+            self.drawing_files.clear();
+            self.selected_drawing = None;
             self.link.send_message(Msg::RequestDownloadFilesetFiles);
             debug!("change: {:?}", self.props.fileset_uuid);
             true
@@ -167,6 +290,7 @@ impl Component for ThreeShowcase {
     fn view(&self) -> Html {
         let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
         let onclick_full_screen = self.link.callback(|_| Msg::ChangeTypeShow);
+        let onclick_view_link = self.link.callback(|_| Msg::ShowViewLink);
         let mut container_style = "display: block; width: 100%; height: 100%; min-height: 25vh; overflow: hidden;";
         let mut b_container_style = "";
         // let mut scene_hull_class = classes!("column", "is-one-quarter");
@@ -174,7 +298,10 @@ impl Component for ThreeShowcase {
         let mut class_icon = classes!("fas");
         let mut class_modal = classes!("modal");
         if self.selected_file.is_none() {
-            container_style = "padding-left: 0.75rem;";
+            container_style = match self.selected_drawing.is_some() {
+                true => "display: none;",
+                false => "padding-left: 0.75rem;",
+            };
         }
         let text_full_screen = match self.full_screen {
             true => {
@@ -195,26 +322,53 @@ impl Component for ThreeShowcase {
         html!{<>
             <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
             <scene-hull class={scene_hull_class}>
-                {match self.selected_file.is_none() {
-                    true => html!{
+                {self.show_select_file()}
+                {match (&self.selected_file, &self.selected_drawing) {
+                    (None, Some(drawing_file)) => html!{<>
+                        <div class="is-flex is-justify-content-flex-end">
+                            <button
+                                id="three-link-button"
+                                class={"button is-ghost"}
+                                onclick={onclick_view_link.clone()}
+                                title={get_value_field(&472)} >
+                              <span class="icon is-small">
+                                <i class="fas fa-link" style="color: #1872f0;"></i>
+                              </span>
+                            </button>
+                        </div>
+                        <DrawingShowcase file={drawing_file.clone()} />
+                    </>},
+                    (None, None) => html!{
                         <div class="text-center">
                             <span>{get_value_field(&297)}</span>
                         </div>
                     },
-                    false => html!{
-                        <button
-                            id="three-size-button"
-                            class={"button is-ghost"}
-                            onclick={onclick_full_screen.clone()}
-                            style={"position: absolute;"}
-                            aria-label={text_full_screen} >
-                          <span class="icon is-small">
-                            <i class={class_icon} style="color: #1872f0;"></i>
-                          </span>
-                          <span class="help has-text-grey is-pulled-right mr-2 mt-2">{get_value_field(&436)}</span> // F: fullscreen | 1-5: views
-                        </button>
+                    (Some(_), _) => html!{
+                        <div class="is-flex" style={"position: absolute;"}>
+                            <button
+                                id="three-size-button"
+                                class={"button is-ghost"}
+                                onclick={onclick_full_screen.clone()}
+                                aria-label={text_full_screen} >
+                              <span class="icon is-small">
+                                <i class={class_icon} style="color: #1872f0;"></i>
+                              </span>
+                              <span class="help has-text-grey is-pulled-right mr-2 mt-2">{get_value_field(&436)}</span> // F: fullscreen | 1-5: views
+                            </button>
+                            <button
+                                id="three-link-button"
+                                class={"button is-ghost"}
+                                onclick={onclick_view_link.clone()}
+                                title={get_value_field(&472)} >
+                              <span class="icon is-small">
+                                <i class="fas fa-link" style="color: #1872f0;"></i>
+                              </span>
+                            </button>
+                            {self.show_annotation_btns()}
+                        </div>
                     },
                 }}
+                {self.show_view_link()}
                 // <PreviewModel/>
                 <a-container style={container_style}></a-container>
                 <div class={class_modal}>
@@ -241,6 +395,143 @@ impl Component for ThreeShowcase {
 }
 
 impl ThreeShowcase {
+    /// File from the view link
+    fn link_file_uuid(&self) -> Option<UUID> {
+        self.props.view_link.as_ref().and_then(|vl| vl.file_uuid.clone())
+    }
+
+    /// Selects the file by UUID or the first suitable file (models come before drawings) and starts viewing
+    fn select_file(&mut self, file_uuid: Option<UUID>) {
+        let select_drawing = self.drawing_files
+            .iter()
+            .find(|file| Some(&file.uuid) == file_uuid.as_ref())
+            .cloned();
+        let select_file = match select_drawing.is_some() {
+            true => None,
+            false => self.suitable_files
+                .iter()
+                .find(|(file, _)| Some(&file.uuid) == file_uuid.as_ref())
+                .or_else(|| self.suitable_files.first())
+                .cloned(),
+        };
+        self.selected_drawing = match select_file.is_some() {
+            true => None,
+            false => select_drawing.or_else(|| self.drawing_files.first().cloned()),
+        };
+        if self.selected_drawing.is_some() {
+            self.selected_file = None;
+            self.full_screen = false;
+        }
+        if let Some((file, model_format)) = select_file {
+            self.selected_file = Some((file, model_format));
+            debug!("Found {} files for show, selected: {:?}", self.suitable_files.len(), self.selected_file);
+            self.resource_files.clear();
+            if model_format == ModelFormat::GLTF {
+                debug!("{:?} is ModelFormat::GLTF", model_format);
+                self.reassemble_resources();
+            }
+            debug!("Resource files: {:?}", self.resource_files);
+            self.need_show = true;
+        }
+    }
+
+    /// Shows the list of models and drawings if there is more than one file to view
+    fn show_select_file(&self) -> Html {
+        if self.suitable_files.len() + self.drawing_files.len() < 2 {
+            return html!{}
+        }
+        let selected_uuid = self.selected_file
+            .as_ref()
+            .map(|(file, _)| &file.uuid)
+            .or_else(|| self.selected_drawing.as_ref().map(|file| &file.uuid));
+        let onchange_file = self.link.callback(|ev: ChangeData| match ev {
+            ChangeData::Select(el) => Msg::SelectFile(el.value()),
+            _ => Msg::Ignore,
+        });
+        html!{
+            <div class="select is-small mb-1" title={get_value_field(&489)}>
+                <select id="three-select-file" onchange={onchange_file}>
+                    {for self.suitable_files
+                        .iter()
+                        .map(|(file, _)| file)
+                        .chain(self.drawing_files.iter())
+                        .map(|file| html!{
+                            <option value={file.uuid.clone()} selected={Some(&file.uuid) == selected_uuid}>
+                                {file.filename.clone()}
+                            </option>
+                        })
+                    }
+                </select>
+            </div>
+        }
+    }
+
+    fn show_annotation_btns(&self) -> Html {
+        if self.props.callback_annotation.is_none() {
+            return html!{}
+        }
+        let onclick_start = self.link.callback(|_| Msg::StartAnnotation);
+        let onclick_finish = self.link.callback(|_| Msg::FinishAnnotation);
+        let onclick_cancel = self.link.callback(|_| Msg::CancelAnnotation);
+        match self.pin_mode {
+            true => html!{<>
+                <button
+                    id="three-annotation-button"
+                    class={"button is-small is-info mt-1"}
+                    onclick={onclick_finish} >
+                  <span class="icon is-small"><i class="far fa-comment"></i></span>
+                  <span>{get_value_field(&476)}</span> // Comment on the pin
+                </button>
+                <button class={"button is-small is-white mt-1 ml-1"} onclick={onclick_cancel} >
+                  {get_value_field(&221)} // Cancel
+                </button>
+                {match self.pin_missing {
+                    true => html!{<span class="help is-danger ml-2 mt-2">{get_value_field(&477)}</span>},
+                    false => html!{<span class="help has-text-grey ml-2 mt-2">{get_value_field(&475)}</span>},
+                }}
+            </>},
+            false => html!{
+                <button
+                    id="three-annotation-button"
+                    class={"button is-ghost"}
+                    onclick={onclick_start}
+                    title={get_value_field(&474)} >
+                  <span class="icon is-small">
+                    <i class="fas fa-map-pin" style="color: #1872f0;"></i>
+                  </span>
+                </button>
+            },
+        }
+    }
+
+    fn show_view_link(&self) -> Html {
+        let Some(view_link) = &self.view_link else {
+            return html!{}
+        };
+        let onclick_view_link = self.link.callback(|_| Msg::ShowViewLink);
+        let onclick_copied = self.link.callback(|_| Msg::ViewLinkCopied);
+        html!{
+            <div class="modal is-active">
+              <div class="modal-background" onclick={onclick_view_link.clone()} />
+              <div class="modal-content">
+                <div class="card column">
+                  <p class="help">{get_value_field(&473)}</p> // Link to the current view
+                  <div class="clipboardBox">
+                    <input id="three-view-link" type="text" class="input is-link inputBox" readonly={true} value={view_link.clone()} />
+                    <button class="btn button is-info view-link-copy" onclick={onclick_copied} data-clipboard-target="#three-view-link" style="margin-bottom: 0;">
+                      {match self.link_copied {
+                        true => html!{<>{get_value_field(&323)}<i class="copyIcon fas fa-check"></i></>},
+                        false => html!{get_value_field(&322)},
+                      }}
+                    </button>
+                  </div>
+                </div>
+              </div>
+              <button class="modal-close is-large" aria-label="close" onclick={onclick_view_link} />
+            </div>
+        }
+    }
+
     fn reassemble_resources(&mut self) {
         if let Some((select_df, _select_mf)) = &self.selected_file {
             let base_name = select_df.filename.split('.').next().unwrap_or("");
@@ -254,4 +545,12 @@ impl ThreeShowcase {
                 .collect();
         }
     }
-}
\ No newline at end of file
+}
+
+/// Returns the viewer state from the view link if the link is for the shown fileset
+fn link_viewer_state(props: &Props) -> Option<ViewerState> {
+    props.view_link
+        .as_ref()
+        .filter(|vl| vl.fileset_uuid.is_none() || vl.fileset_uuid.as_ref() == Some(&props.fileset_uuid))
+        .map(|vl| vl.viewer.clone())
+}
@@ -11,6 +11,15 @@ use crate::types::UUID;
 )]
 pub(crate) struct GetComponentDataOpt;
 
+/// Получение родительского компонента (для цепочки производных компонентов)
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schema.graphql",
+    query_path = "./graphql/components.graphql",
+    response_derives = "Debug"
+)]
+pub(crate) struct GetComponentParent;
+
 /// Создание нового компонента
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -7,4 +7,4 @@ pub(crate) mod standard;
 pub(crate) mod supplier_service;
 pub(crate) mod user;
 
-pub use query::make_query;
+pub use query::{make_query, make_raw_query};
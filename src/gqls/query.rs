@@ -33,6 +33,15 @@ where
 {
     let query = serde_json::json!(build_query);
     // debug("Update: {:?}", query);
+    send_query(query).await
+}
+
+/// Sends the query built at runtime, e.g. with a field alias for each object of a list
+pub async fn make_raw_query(query: String) -> Result<String, FetchError> {
+    send_query(serde_json::json!({ "query": query })).await
+}
+
+async fn send_query(query: serde_json::Value) -> Result<String, FetchError> {
     let mut opts = RequestInit::new();
     opts.method("POST");
     opts.body(Some(&JsValue::from_str(query.to_string().as_str())));
@@ -11,6 +11,15 @@ use crate::types::UUID;
 )]
 pub(crate) struct ConfirmUploadCompleted;
 
+/// Получение списка типов доступа
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schema.graphql",
+    query_path = "./graphql/relate.graphql",
+    response_derives = "Debug"
+)]
+pub(crate) struct GetTypesAccess;
+
 /// Получение путей каталогов
 #[derive(GraphQLQuery)]
 #[graphql(
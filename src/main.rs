@@ -1,6 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::eval_order_dependence)]
+#![allow(clippy::unnecessary_map_or)]
 
 // #[macro_use]
 // extern crate log;
@@ -0,0 +1,448 @@
+use std::collections::BTreeSet;
+use yew::{agent::Bridged, html, Bridge, Component, ComponentLink, Html, InputData, ShouldRender};
+use yew_router::{agent::RouteRequest::ChangeRoute, prelude::RouteAgent, service::RouteService};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::routes::AppRoute;
+use crate::fragments::{clipboard::ShareLinkBtn, list_errors::ListErrors};
+use crate::services::{
+    add_bom_line, bom_from_query, bom_rows, bom_to_query, get_value_field, group_by_supplier, load_bom,
+    resp_parsing, revoke_object_url, save_as_file, save_bom, title_changer, write_delimited, BomGroup, BomLine,
+    ParsingSplit,
+};
+use crate::types::{ComponentModificationInfo, Pathname, ShowComponentShort, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentsShortList, get_components_short_list,
+    GetComponentModifications, get_component_modifications,
+};
+
+/// Bill of materials collected by the user (saved in the local storage) or opened from a shared link.
+/// The names and suppliers of the lines are requested again by the UUIDs when the page is opened.
+pub struct BomWorkspace {
+    error: Option<Error>,
+    link: ComponentLink<Self>,
+    router_agent: Box<dyn Bridge<RouteAgent>>,
+    lines: Vec<BomLine>,
+    /// The lines are opened from a link and are not saved until the user adds them to the own BOM
+    shared: bool,
+    /// Number of the requests for names and suppliers without a response
+    pending_requests: usize,
+    file_url: Option<String>,
+}
+
+pub enum Msg {
+    RequestNames,
+    GetComponentsResult(String),
+    GetModificationsResult(String),
+    UpdateQuantity(usize, String),
+    UpdateNote(usize, String),
+    RemoveLine(usize),
+    ClearBom,
+    /// Saves the shared lines replacing (true) or supplementing (false) the own BOM
+    ImportShared(bool),
+    ExportCsv,
+    ExportJson,
+    ResponseError(Error),
+    ClearError,
+    Ignore,
+}
+
+impl Component for BomWorkspace {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let route_service: RouteService<()> = RouteService::new();
+        let shared_lines = route_service.get_fragment()
+            .split_once('?')
+            .map(|(_, query)| bom_from_query(query))
+            .unwrap_or_default();
+        let shared = !shared_lines.is_empty();
+        Self {
+            error: None,
+            router_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+            lines: match shared {
+                true => shared_lines,
+                false => load_bom(),
+            },
+            shared,
+            pending_requests: 0,
+            file_url: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            title_changer::set_title(get_value_field(&602));
+            self.link.send_message(Msg::RequestNames);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestNames => {
+                let components_uuids: BTreeSet<UUID> = self.lines.iter().map(|l| l.component_uuid.clone()).collect();
+                if components_uuids.is_empty() {
+                    return false
+                }
+                let ipt_components_arg = Some(get_components_short_list::IptComponentsArg {
+                    componentsUuids: Some(components_uuids.iter().cloned().collect()),
+                    companyUuid: None,
+                    standardUuid: None,
+                    serviceUuid: None,
+                    userUuid: None,
+                    specId: None,
+                    favorite: None,
+                });
+                let ipt_paginate = Some(get_components_short_list::IptPaginate {
+                    currentPage: 1,
+                    perPage: components_uuids.len() as i64,
+                });
+                self.pending_requests = components_uuids.len() + 1;
+                let link_components = link.clone();
+                spawn_local(async move {
+                    let res = make_query(GetComponentsShortList::build_query(
+                        get_components_short_list::Variables { ipt_components_arg, ipt_sort: None, ipt_paginate }
+                    )).await.unwrap();
+                    link_components.send_message(Msg::GetComponentsResult(res));
+                });
+                for component_uuid in components_uuids {
+                    let filter = Some(self.lines.iter()
+                        .filter(|l| l.component_uuid == component_uuid)
+                        .map(|l| l.modification_uuid.clone())
+                        .collect());
+                    let link = link.clone();
+                    spawn_local(async move {
+                        let res = make_query(GetComponentModifications::build_query(
+                            get_component_modifications::Variables { component_uuid, filter, ipt_sort: None, ipt_paginate: None }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetModificationsResult(res));
+                    });
+                }
+            },
+            Msg::GetComponentsResult(res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing::<Vec<ShowComponentShort>>(res, "components") {
+                    Ok(result) => {
+                        for component in result {
+                            for line in self.lines.iter_mut().filter(|l| l.component_uuid == component.uuid) {
+                                line.component_name = component.name.clone();
+                                line.suppliers = component.component_suppliers.iter()
+                                    .map(|s| s.supplier.shortname.clone())
+                                    .collect();
+                            }
+                        }
+                        self.save_lines();
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetModificationsResult(res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing::<Vec<ComponentModificationInfo>>(res, "componentModifications") {
+                    Ok(result) => {
+                        debug!("Modifications of BOM: {}", result.len());
+                        for modification in result {
+                            for line in self.lines.iter_mut().filter(|l| l.modification_uuid == modification.uuid) {
+                                line.modification_name = modification.modification_name.clone();
+                            }
+                        }
+                        self.save_lines();
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::UpdateQuantity(index, value) => {
+                match (self.lines.get_mut(index), value.trim().parse::<usize>()) {
+                    (Some(line), Ok(quantity)) if quantity > 0 => line.quantity = quantity,
+                    _ => return false,
+                }
+                self.save_lines();
+            },
+            Msg::UpdateNote(index, value) => {
+                if let Some(line) = self.lines.get_mut(index) {
+                    line.note = value;
+                }
+                self.save_lines();
+                return false
+            },
+            Msg::RemoveLine(index) => {
+                if index < self.lines.len() {
+                    self.lines.remove(index);
+                }
+                self.save_lines();
+            },
+            Msg::ClearBom => {
+                self.lines.clear();
+                self.save_lines();
+            },
+            Msg::ImportShared(replace) => {
+                let shared_lines = std::mem::take(&mut self.lines);
+                self.lines = match replace {
+                    true => Vec::new(),
+                    false => load_bom(),
+                };
+                for line in shared_lines {
+                    add_bom_line(&mut self.lines, line);
+                }
+                self.shared = false;
+                self.save_lines();
+                self.router_agent.send(ChangeRoute(AppRoute::Bom.into()));
+            },
+            Msg::ExportCsv => {
+                let data = write_delimited(&self.export_rows(), ParsingSplit::Comma).into_bytes();
+                self.save_file(&data, "text/csv", "csv");
+                return false
+            },
+            Msg::ExportJson => {
+                let data = serde_json::to_string_pretty(&self.lines).unwrap_or_default().into_bytes();
+                self.save_file(&data, "application/json", "json");
+                return false
+            },
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn destroy(&mut self) {
+        if let Some(url) = self.file_url.take() {
+            revoke_object_url(&url);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        html!{
+            <div class="settings-page">
+                <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                <div class="container page">
+                    <div class="row">
+                        <h4 id="show-bom" class="title is-4">
+                            {get_value_field(&602)} // Bill of materials
+                            {match self.pending_requests > 0 {
+                                true => html!{<span class="icon ml-2"><i class="fas fa-spinner fa-pulse"></i></span>},
+                                false => html!{},
+                            }}
+                        </h4>
+                        {self.show_shared_notice()}
+                        {match self.lines.is_empty() {
+                            true => html!{
+                                <div class="card">
+                                    <div class="column">
+                                        <p class="help">{get_value_field(&610)}</p> // The bill of materials is empty...
+                                    </div>
+                                </div>
+                            },
+                            false => html!{<>
+                                {self.show_actions()}
+                                {self.show_lines()}
+                            </>},
+                        }}
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl BomWorkspace {
+    fn save_lines(&self) {
+        if !self.shared {
+            save_bom(&self.lines);
+        }
+    }
+
+    fn export_rows(&self) -> Vec<Vec<String>> {
+        let mut rows = vec![vec![
+            get_value_field(&158).to_string(), // Supplier
+            get_value_field(&352).to_string(), // Component
+            get_value_field(&463).to_string(), // Modification
+            get_value_field(&603).to_string(), // Quantity
+            get_value_field(&604).to_string(), // Note
+            get_value_field(&614).to_string(), // Component UUID
+            get_value_field(&615).to_string(), // Modification UUID
+        ]];
+        rows.extend(bom_rows(&self.lines));
+        rows
+    }
+
+    fn save_file(&mut self, data: &[u8], mime_type: &str, extension: &str) {
+        if let Some(url) = self.file_url.take() {
+            revoke_object_url(&url);
+        }
+        self.file_url = save_as_file(data, mime_type, &format!("bom.{}", extension));
+    }
+
+    fn share_link(&self) -> String {
+        let href = web_sys::window()
+            .and_then(|window| window.location().href().ok())
+            .unwrap_or_default();
+        let base = href.split('?').next().unwrap_or_default();
+        format!("{}?{}", base, bom_to_query(&self.lines))
+    }
+
+    fn show_shared_notice(&self) -> Html {
+        if !self.shared {
+            return html!{}
+        }
+        let onclick_replace = self.link.callback(|_| Msg::ImportShared(true));
+        let onclick_merge = self.link.callback(|_| Msg::ImportShared(false));
+        html!{
+            <div class="notification is-info is-light">
+                <p class="mb-2">{get_value_field(&611)}</p> // Shared bill of materials
+                <div class="buttons">
+                    <button id="bom-import-merge" class="button is-info" onclick={onclick_merge}>
+                        {get_value_field(&613)} // Add to my BOM
+                    </button>
+                    <button id="bom-import-replace" class="button" onclick={onclick_replace}>
+                        {get_value_field(&612)} // Replace my BOM
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn show_actions(&self) -> Html {
+        let onclick_csv = self.link.callback(|_| Msg::ExportCsv);
+        let onclick_json = self.link.callback(|_| Msg::ExportJson);
+        let onclick_clear = self.link.callback(|_| Msg::ClearBom);
+        let share_link = self.share_link();
+        html!{
+            <div class="buttons is-right">
+                <button id="bom-export-csv" class="button" onclick={onclick_csv}>
+                    <span class="icon"><i class="fas fa-file-csv" style="color: #1872f0;" aria-hidden="true"></i></span>
+                    <span>{format!("{} CSV", get_value_field(&348))}</span> // Export
+                </button>
+                <button id="bom-export-json" class="button" onclick={onclick_json}>
+                    <span class="icon"><i class="fas fa-file-code" style="color: #1872f0;" aria-hidden="true"></i></span>
+                    <span>{format!("{} JSON", get_value_field(&348))}</span> // Export
+                </button>
+                <ShareLinkBtn key={share_link.as_str()} share_link={share_link.clone()} />
+                {match self.shared {
+                    true => html!{},
+                    false => html!{
+                        <button id="bom-clear" class="button is-danger is-outlined" onclick={onclick_clear}>
+                            <span class="icon"><i class="fas fa-trash" aria-hidden="true"></i></span>
+                            <span>{get_value_field(&88)}</span> // Clear
+                        </button>
+                    },
+                }}
+            </div>
+        }
+    }
+
+    fn show_lines(&self) -> Html {
+        let groups = group_by_supplier(&self.lines);
+        let components: BTreeSet<&str> = self.lines.iter().map(|l| l.component_uuid.as_str()).collect();
+        let total: usize = self.lines.iter().map(|l| l.quantity).sum();
+        html!{
+            <div class="table-container">
+                <table class="table is-fullwidth is-striped is-narrow">
+                    <thead>
+                        <tr>
+                            <th>{get_value_field(&352)}</th> // Component
+                            <th>{get_value_field(&463)}</th> // Modification
+                            <th>{get_value_field(&603)}</th> // Quantity
+                            <th>{get_value_field(&604)}</th> // Note
+                            <th></th>
+                        </tr>
+                    </thead>
+                    {for groups.iter().map(|group| self.show_group(group))}
+                    <tfoot>
+                        <tr>
+                            <th>{format!("{}: {}", get_value_field(&154), components.len())}</th> // Components
+                            <th>{format!("{}: {}", get_value_field(&608), self.lines.len())}</th> // Lines
+                            <th colspan="3">{format!("{}: {}", get_value_field(&609), total)}</th> // Total quantity
+                        </tr>
+                    </tfoot>
+                </table>
+            </div>
+        }
+    }
+
+    fn show_group(&self, group: &BomGroup) -> Html {
+        html!{
+            <tbody>
+                <tr class="has-background-light">
+                    <th colspan="2">
+                        <span class="icon"><i class="fas fa-truck" aria-hidden="true"></i></span>
+                        {match group.supplier.is_empty() {
+                            true => get_value_field(&607).to_string(), // Without supplier
+                            false => format!("{}: {}", get_value_field(&158), group.supplier), // Supplier
+                        }}
+                    </th>
+                    <th colspan="3">
+                        {format!("{}: {}, {}: {}",
+                            get_value_field(&608), group.lines.len(), // Lines
+                            get_value_field(&609), group.quantity, // Total quantity
+                        )}
+                    </th>
+                </tr>
+                {for group.lines.iter().map(|(index, line)| self.show_line(*index, line))}
+            </tbody>
+        }
+    }
+
+    fn show_line(&self, index: usize, line: &BomLine) -> Html {
+        let oninput_quantity = self.link.callback(move |ev: InputData| Msg::UpdateQuantity(index, ev.value));
+        let oninput_note = self.link.callback(move |ev: InputData| Msg::UpdateNote(index, ev.value));
+        let onclick_remove = self.link.callback(move |_| Msg::RemoveLine(index));
+        let href = format!("{}?mod={}", Pathname::Component(line.component_uuid.clone()).get_pathname(), line.modification_uuid);
+        html!{
+            <tr>
+                <td>
+                    <a href={Pathname::Component(line.component_uuid.clone()).get_pathname()}>
+                        {match line.component_name.is_empty() {
+                            true => line.component_uuid.clone(),
+                            false => line.component_name.clone(),
+                        }}
+                    </a>
+                    {match line.suppliers.len() > 1 {
+                        true => html!{<p class="help">{line.suppliers[1..].join(", ")}</p>},
+                        false => html!{},
+                    }}
+                </td>
+                <td>
+                    <a href={href}>
+                        {match line.modification_name.is_empty() {
+                            true => line.modification_uuid.clone(),
+                            false => line.modification_name.clone(),
+                        }}
+                    </a>
+                </td>
+                <td style="width: 7rem;">
+                    <input
+                        class="input is-small"
+                        type="number"
+                        min="1"
+                        value={line.quantity.to_string()}
+                        oninput={oninput_quantity} />
+                </td>
+                <td>
+                    <input
+                        class="input is-small"
+                        type="text"
+                        value={line.note.clone()}
+                        oninput={oninput_note} />
+                </td>
+                <td>
+                    <button class="button is-small is-white" onclick={onclick_remove} title={get_value_field(&135)}>
+                        <span class="icon"><i class="fas fa-times" aria-hidden="true"></i></span>
+                    </button>
+                </td>
+            </tr>
+        }
+    }
+}
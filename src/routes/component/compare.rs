@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use yew::{agent::Bridged, html, Bridge, Component, ComponentLink, Html, ShouldRender};
+use yew_router::{agent::RouteRequest::ChangeRoute, prelude::RouteAgent, service::RouteService};
+use log::debug;
+use graphql_client::GraphQLQuery;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::error::Error;
+use crate::routes::AppRoute;
+use crate::fragments::{clipboard::ShareLinkBtn, list_errors::ListErrors};
+use crate::services::{
+    compare_from_query, compare_to_query, get_value_field, is_different, load_compare, param_rows,
+    resp_parsing, resp_parsing_two_level, save_compare, title_changer,
+};
+use crate::types::{ComponentInfo, ComponentParam, Pathname, UUID};
+use crate::gqls::make_query;
+use crate::gqls::component::{
+    GetComponentData, get_component_data,
+    GetComponentParams, get_component_params,
+};
+
+/// Components selected by the user (saved in the local storage) or opened from a shared link
+/// in aligned rows with the differences highlighted
+pub struct CompareComponents {
+    error: Option<Error>,
+    link: ComponentLink<Self>,
+    router_agent: Box<dyn Bridge<RouteAgent>>,
+    uuids: Vec<UUID>,
+    /// The components are opened from a link and are not saved until the user saves them as own comparison
+    shared: bool,
+    components: BTreeMap<UUID, ComponentInfo>,
+    params: BTreeMap<UUID, Vec<ComponentParam>>,
+    only_differences: bool,
+    /// Number of the requests for components and parameters without a response
+    pending_requests: usize,
+}
+
+pub enum Msg {
+    RequestComponents,
+    GetComponentResult(String),
+    GetParamsResult(UUID, String),
+    RemoveComponent(UUID),
+    ClearCompare,
+    SaveShared,
+    ToggleDifferences,
+    ResponseError(Error),
+    ClearError,
+    Ignore,
+}
+
+impl Component for CompareComponents {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let route_service: RouteService<()> = RouteService::new();
+        let shared_uuids = route_service.get_fragment()
+            .split_once('?')
+            .map(|(_, query)| compare_from_query(query))
+            .unwrap_or_default();
+        let shared = !shared_uuids.is_empty();
+        Self {
+            error: None,
+            router_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+            uuids: match shared {
+                true => shared_uuids,
+                false => load_compare(),
+            },
+            shared,
+            components: BTreeMap::new(),
+            params: BTreeMap::new(),
+            only_differences: false,
+            pending_requests: 0,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            title_changer::set_title(get_value_field(&619));
+            self.link.send_message(Msg::RequestComponents);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let link = self.link.clone();
+        match msg {
+            Msg::RequestComponents => {
+                self.pending_requests = self.uuids.len() * 2;
+                for component_uuid in self.uuids.iter().cloned() {
+                    let link_component = link.clone();
+                    let link_params = link.clone();
+                    let params_uuid = component_uuid.clone();
+                    spawn_local(async move {
+                        let res = make_query(GetComponentData::build_query(
+                            get_component_data::Variables { component_uuid }
+                        )).await.unwrap();
+                        link_component.send_message(Msg::GetComponentResult(res));
+                    });
+                    spawn_local(async move {
+                        let res = make_query(GetComponentParams::build_query(
+                            get_component_params::Variables { component_uuid: params_uuid.clone(), ipt_sort: None, ipt_paginate: None }
+                        )).await.unwrap();
+                        link_params.send_message(Msg::GetParamsResult(params_uuid, res));
+                    });
+                }
+            },
+            Msg::GetComponentResult(res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing::<ComponentInfo>(res, "component") {
+                    Ok(result) => {
+                        debug!("Component for comparison: {:?}", result.uuid);
+                        self.components.insert(result.uuid.clone(), result);
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetParamsResult(component_uuid, res) => {
+                self.pending_requests = self.pending_requests.saturating_sub(1);
+                match resp_parsing_two_level::<ComponentParam>(res, "component", "componentParams") {
+                    Ok(result) => {
+                        self.params.insert(component_uuid, result);
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::RemoveComponent(component_uuid) => {
+                self.uuids.retain(|uuid| *uuid != component_uuid);
+                self.save_uuids();
+            },
+            Msg::ClearCompare => {
+                self.uuids.clear();
+                self.save_uuids();
+            },
+            Msg::SaveShared => {
+                self.shared = false;
+                self.save_uuids();
+                self.router_agent.send(ChangeRoute(AppRoute::CompareComponents.into()));
+            },
+            Msg::ToggleDifferences => self.only_differences = !self.only_differences,
+            Msg::ResponseError(err) => self.error = Some(err),
+            Msg::ClearError => self.error = None,
+            Msg::Ignore => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let onclick_clear_error = self.link.callback(|_| Msg::ClearError);
+        html!{
+            <div class="settings-page">
+                <ListErrors error={self.error.clone()} clear_error={onclick_clear_error} />
+                <div class="container page">
+                    <div class="row">
+                        <h4 id="show-compare" class="title is-4">
+                            {get_value_field(&619)} // Comparison of components
+                            {match self.pending_requests > 0 {
+                                true => html!{<span class="icon ml-2"><i class="fas fa-spinner fa-pulse"></i></span>},
+                                false => html!{},
+                            }}
+                        </h4>
+                        {self.show_shared_notice()}
+                        {match self.uuids.len() < 2 {
+                            true => html!{
+                                <div class="card mb-3">
+                                    <div class="column">
+                                        <p class="help">{get_value_field(&622)}</p> // Select from 2 to 4 components...
+                                    </div>
+                                </div>
+                            },
+                            false => html!{},
+                        }}
+                        {match self.uuids.is_empty() {
+                            true => html!{},
+                            false => html!{<>
+                                {self.show_actions()}
+                                {self.show_table()}
+                            </>},
+                        }}
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl CompareComponents {
+    fn save_uuids(&self) {
+        if !self.shared {
+            save_compare(&self.uuids);
+        }
+    }
+
+    fn share_link(&self) -> String {
+        let href = web_sys::window()
+            .and_then(|window| window.location().href().ok())
+            .unwrap_or_default();
+        let base = href.split('?').next().unwrap_or_default();
+        format!("{}?{}", base, compare_to_query(&self.uuids))
+    }
+
+    /// Values of the components in the order of the comparison, empty for the components not received yet
+    fn values<F>(&self, value: F) -> Vec<String>
+    where
+        F: Fn(&ComponentInfo) -> String,
+    {
+        self.uuids.iter().map(|uuid| self.components.get(uuid).map(&value).unwrap_or_default()).collect()
+    }
+
+    fn show_shared_notice(&self) -> Html {
+        if !self.shared {
+            return html!{}
+        }
+        let onclick_save = self.link.callback(|_| Msg::SaveShared);
+        html!{
+            <div class="notification is-info is-light">
+                <p class="mb-2">{get_value_field(&629)}</p> // This comparison is opened from a link
+                <button id="compare-save-shared" class="button is-info" onclick={onclick_save}>
+                    {get_value_field(&630)} // Save as my comparison
+                </button>
+            </div>
+        }
+    }
+
+    fn show_actions(&self) -> Html {
+        let onclick_differences = self.link.callback(|_| Msg::ToggleDifferences);
+        let onclick_clear = self.link.callback(|_| Msg::ClearCompare);
+        let share_link = self.share_link();
+        html!{
+            <div class="columns">
+                <div class="column">
+                    <input
+                        id="compare-only-differences"
+                        type="checkbox"
+                        class="switch is-rtl is-small"
+                        checked={self.only_differences}
+                        onclick={onclick_differences} />
+                    <label for="compare-only-differences">{get_value_field(&621)}</label> // Only differences
+                </div>
+                <div class="column">
+                    <div class="buttons is-right">
+                        <ShareLinkBtn key={share_link.as_str()} share_link={share_link.clone()} />
+                        {match self.shared {
+                            true => html!{},
+                            false => html!{
+                                <button id="compare-clear" class="button is-danger is-outlined" onclick={onclick_clear}>
+                                    <span class="icon"><i class="fas fa-trash" aria-hidden="true"></i></span>
+                                    <span>{get_value_field(&88)}</span> // Clear
+                                </button>
+                            },
+                        }}
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn show_table(&self) -> Html {
+        let params: Vec<Vec<ComponentParam>> = self.uuids.iter()
+            .map(|uuid| self.params.get(uuid).cloned().unwrap_or_default())
+            .collect();
+        html!{
+            <div class="table-container">
+                <table class="table is-fullwidth is-bordered is-narrow" style="table-layout: fixed;">
+                    <thead>
+                        <tr>
+                            <th></th>
+                            {for self.uuids.iter().map(|uuid| self.show_head(uuid))}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {self.show_row(get_value_field(&96), self.values(|c| c.actual_status.name.clone()))} // Life cycle stage
+                        {self.show_row(get_value_field(&58), self.values(|c| c.type_access.name.clone()))} // Type access
+                        {self.show_row(get_value_field(&624), self.values(|c| // Licenses
+                            c.licenses.iter().map(|l| l.name.clone()).collect::<Vec<_>>().join(", ")
+                        ))}
+                        {self.show_row(get_value_field(&625), self.values(|c| // Specifications
+                            c.component_specs.iter().map(|s| s.spec.clone()).collect::<Vec<_>>().join(", ")
+                        ))}
+                        {self.show_row(get_value_field(&105), self.values(|c| // Keywords
+                            c.component_keywords.iter().map(|k| k.keyword.clone()).collect::<Vec<_>>().join(", ")
+                        ))}
+                        {self.show_row(get_value_field(&103), self.values(|c| // Standards
+                            c.component_standards.iter().map(|s| s.name.clone()).collect::<Vec<_>>().join(", ")
+                        ))}
+                        {self.show_row(get_value_field(&107), self.values(|c| // Suppliers
+                            c.component_suppliers.iter().map(|s| s.supplier.shortname.clone()).collect::<Vec<_>>().join(", ")
+                        ))}
+                    </tbody>
+                    <tbody>
+                        {self.show_section(get_value_field(&632))} // Counts
+                        {self.show_row(get_value_field(&626), self.values(|c| c.params_count.to_string()))} // Parameters
+                        {self.show_row(get_value_field(&100), self.values(|c| c.modifications_count.to_string()))} // Modifications
+                        {self.show_row(get_value_field(&627), self.values(|c| c.files_count.to_string()))} // Files
+                        {self.show_row(get_value_field(&107), self.values(|c| c.suppliers_count.to_string()))} // Suppliers
+                        {self.show_row(get_value_field(&103), self.values(|c| c.standards_count.to_string()))} // Standards
+                        {self.show_row(get_value_field(&628), self.values(|c| c.subscribers.to_string()))} // Subscribers
+                    </tbody>
+                    <tbody>
+                        {self.show_section(get_value_field(&626))} // Parameters
+                        {for param_rows(&params).into_iter().map(|(paramname, values)| self.show_row(&paramname, values))}
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+
+    fn show_head(&self, component_uuid: &UUID) -> Html {
+        let uuid = component_uuid.clone();
+        let onclick_remove = self.link.callback(move |_| Msg::RemoveComponent(uuid.clone()));
+        let pathname = Pathname::Component(component_uuid.clone()).get_pathname();
+        html!{
+            <th>
+                <div class="is-flex is-justify-content-flex-end">
+                    <button class="delete" title={get_value_field(&623)} onclick={onclick_remove} /> // Remove from the comparison
+                </div>
+                {match self.components.get(component_uuid) {
+                    Some(component) => html!{<>
+                        <figure class="image is-128x128 mb-2">
+                            <img src={component.image_file.download_url.clone()} alt="Image" />
+                        </figure>
+                        <a href={pathname}>{component.name.clone()}</a>
+                    </>},
+                    None => html!{<a href={pathname}>{component_uuid.clone()}</a>},
+                }}
+            </th>
+        }
+    }
+
+    fn show_section(&self, title: &str) -> Html {
+        html!{
+            <tr class="has-background-light">
+                <th colspan={(self.uuids.len() + 1).to_string()}>{title}</th>
+            </tr>
+        }
+    }
+
+    /// The differences are highlighted only when all components are received
+    fn show_row(&self, label: &str, values: Vec<String>) -> Html {
+        let different = self.uuids.iter().all(|uuid| self.components.contains_key(uuid)) && is_different(&values);
+        if self.only_differences && !different {
+            return html!{}
+        }
+        html!{
+            <tr class={match different {
+                true => "has-background-warning-light",
+                false => "",
+            }}>
+                <th>{label}</th>
+                {for values.into_iter().map(|value| html!{<td>{value}</td>})}
+            </tr>
+        }
+    }
+}
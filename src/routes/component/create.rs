@@ -13,11 +13,13 @@ use crate::routes::AppRoute;
 use crate::error::Error;
 use crate::fragments::list_errors::ListErrors;
 use crate::fragments::buttons::ft_create_btn;
+use crate::fragments::component::{find_uuid, ImportComponentBundle};
 use crate::services::{get_from_value, get_logged_user, get_value_field, get_value_response, resp_parsing, set_focus, set_history_back};
-use crate::types::{UUID, ComponentCreateData, TypeAccessInfo, ActualStatus};
+use crate::types::{UUID, ComponentCreateData, ComponentParentInfo, Pathname, TypeAccessInfo, ActualStatus};
 use crate::gqls::make_query;
 use crate::gqls::component::{
     GetComponentDataOpt, get_component_data_opt,
+    GetComponentParent, get_component_parent,
     RegisterComponent, register_component,
     SetCompanyOwnerSupplier, set_company_owner_supplier,
 };
@@ -38,6 +40,9 @@ pub struct CreateComponent {
     types_access: Vec<TypeAccessInfo>,
     disable_create_btn: bool,
     name_empty: bool,
+    parent_input: String,
+    parent: Option<ComponentParentInfo>,
+    parent_not_found: bool,
 }
 
 #[derive(Clone)]
@@ -49,6 +54,8 @@ pub enum Msg {
     GetListOpt(String),
     GetCreateComponentResult(String),
     GetUpdateSetSupplierResult(String, UUID),
+    GetParentResult(String),
+    UpdateParent(String),
     UpdateName(String),
     UpdateDescription(String),
     UpdateTypeAccessId(usize),
@@ -73,6 +80,9 @@ impl Component for CreateComponent {
             types_access: Vec::new(),
             disable_create_btn: false,
             name_empty: false,
+            parent_input: String::new(),
+            parent: None,
+            parent_not_found: false,
         }
     }
 
@@ -185,7 +195,38 @@ impl Component for CreateComponent {
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
+            Msg::GetParentResult(res) => {
+                match resp_parsing::<ComponentParentInfo>(res, "component") {
+                    Ok(parent) => {
+                        // the response for the previous input is skipped
+                        if find_uuid(&self.parent_input).as_ref() != Some(&parent.uuid) {
+                            return false
+                        }
+                        self.request_component.parent_component_uuid = Some(parent.uuid.clone());
+                        self.parent = Some(parent);
+                    },
+                    Err(err) => {
+                        debug!("Parent component not found: {:?}", err);
+                        self.parent_not_found = true;
+                    },
+                }
+            },
             // items request create main component data
+            Msg::UpdateParent(data) => {
+                self.parent = None;
+                self.parent_not_found = false;
+                self.request_component.parent_component_uuid = None;
+                match find_uuid(&data) {
+                    Some(component_uuid) => spawn_local(async move {
+                        let res = make_query(GetComponentParent::build_query(
+                            get_component_parent::Variables { component_uuid }
+                        )).await.unwrap();
+                        link.send_message(Msg::GetParentResult(res));
+                    }),
+                    None => self.parent_not_found = !data.trim().is_empty(),
+                }
+                self.parent_input = data;
+            },
             Msg::UpdateName(data) => {
                 self.request_component.name = data;
                 self.name_empty = false;
@@ -214,7 +255,10 @@ impl Component for CreateComponent {
                 <div class="container page">
                     <div class="row">
                         <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
-                        <h1 class="title">{get_value_field(&290)}</h1>
+                        <div class="is-flex is-justify-content-space-between is-align-items-center mb-4">
+                            <h1 class="title mb-0">{get_value_field(&290)}</h1>
+                            <ImportComponentBundle />
+                        </div>
                         {self.show_main_card()}
                         <br/>
                         {ft_create_btn(
@@ -260,6 +304,7 @@ impl CreateComponent {
                       value={self.request_component.name.clone()}
                       oninput={oninput_name} />
                 </div>
+                {self.show_parent()}
                 <MarkdownEditCard
                     id_tag={"create-component-description"}
                     title={get_value_field(&61)}
@@ -301,4 +346,37 @@ impl CreateComponent {
             </div>
         }
     }
+
+    fn show_parent(&self) -> Html {
+        let oninput_parent =
+            self.link.callback(|ev: InputData| Msg::UpdateParent(ev.value));
+        let class_parent = match self.parent_not_found {
+            true => "input is-danger",
+            false => "input",
+        };
+
+        html!{
+            <div class="column">
+                <label class="label" for="create-component-parent">{get_value_field(&582)}</label> // Parent component
+                <input
+                    id="create-component-parent"
+                    class={class_parent}
+                    type="text"
+                    placeholder={get_value_field(&582)}
+                    value={self.parent_input.clone()}
+                    oninput={oninput_parent} />
+                {match (&self.parent, self.parent_not_found) {
+                    (Some(parent), _) => html!{
+                        <p class="help">
+                            <span class="icon is-small"><i class="fas fa-code-branch" aria-hidden="true"></i></span>
+                            {" "}{get_value_field(&585)}{" "} // Fork of
+                            <a href={Pathname::Component(parent.uuid.clone()).get_pathname()}>{parent.name.clone()}</a>
+                        </p>
+                    },
+                    (None, true) => html!{<p class="help is-danger">{get_value_field(&584)}</p>}, // Component not found
+                    (None, false) => html!{<p class="help">{get_value_field(&583)}</p>},
+                }}
+            </div>
+        }
+    }
 }
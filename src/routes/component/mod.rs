@@ -1,7 +1,9 @@
+mod compare;
 mod create;
 mod settings;
 mod show;
 
+pub use compare::CompareComponents;
 pub use create::CreateComponent;
 pub use settings::ComponentSettings;
 pub use show::ShowComponent;
@@ -22,10 +22,14 @@ use crate::fragments::{
     notification::show_notification,
     component::{
         ComponentStandardsCard, ComponentSuppliersCard, ComponentParamsTags, UpdateComponentFaviconCard,
-        ModificationsTableEdit, ManageComponentFilesCard, SearchSpecsTags, AddKeywordsTags
+        GenerateThumbnailCard, ModificationsTableEdit, ManageComponentFilesCard, SearchSpecsTags, AddKeywordsTags,
+        status_options, status_note_field, send_status_change,
     },
 };
-use crate::services::{get_from_value, get_logged_user, get_value_field, get_value_response, resp_parsing, set_history_back};
+use crate::services::{
+    check_status_note, get_from_value, get_logged_user, get_value_field, get_value_response, is_allowed_transition,
+    resp_parsing, set_history_back, StatusChange,
+};
 use crate::types::{
     UUID, ComponentInfo, SlimUser, TypeAccessInfo, ActualStatus, ComponentUpdatePreData,
     ComponentUpdateData, ShowCompanyShort, ComponentModificationInfo,
@@ -58,6 +62,8 @@ pub struct ComponentSettings {
     link: ComponentLink<Self>,
     supplier_list: Vec<ShowCompanyShort>,
     actual_statuses: Vec<ActualStatus>,
+    /// Note required for the change of the status
+    status_note: String,
     types_access: Vec<TypeAccessInfo>,
     update_component: bool,
     update_component_access: bool,
@@ -91,6 +97,8 @@ pub enum Msg {
     GetDeleteComponentResult(String),
     UpdateTypeAccessId(usize),
     UpdateActualStatusId(String),
+    UpdateStatusNote(String),
+    GetStatusChangeResult(String),
     UpdateName(String),
     UpdateDescription(String),
     UpdateConfirmDelete(String),
@@ -121,6 +129,7 @@ impl Component for ComponentSettings {
             link,
             supplier_list: Vec::new(),
             actual_statuses: Vec::new(),
+            status_note: String::new(),
             types_access: Vec::new(),
             update_component: false,
             update_component_access: false,
@@ -203,6 +212,9 @@ impl Component for ComponentSettings {
                 ));
             },
             Msg::RequestManager => {
+                if self.status_change().is_some_and(|change| check_status_note(&change.note).is_err()) {
+                    return false
+                }
                 if self.update_component {
                     self.link.send_message(Msg::RequestUpdateComponentData)
                 }
@@ -289,7 +301,26 @@ impl Component for ComponentSettings {
             },
             Msg::GetUpdateComponentResult(res) => {
                 match resp_parsing(res, "putComponentUpdate") {
-                    Ok(result) => self.get_result_component_data = result,
+                    Ok(result) => {
+                        self.get_result_component_data = result;
+                        if let Some(change) = self.status_change() {
+                            // record the transition in the discussion and take the new status as the current one
+                            send_status_change(&change, link.callback(Msg::GetStatusChangeResult));
+                            if let (Some(component), Some(status)) = (
+                                self.current_component.as_mut(),
+                                self.actual_statuses.iter().find(|s| s.actual_status_id == change.to_status_id),
+                            ) {
+                                component.actual_status = status.clone();
+                            }
+                            self.status_note.clear();
+                        }
+                    },
+                    Err(err) => link.send_message(Msg::ResponseError(err)),
+                }
+            },
+            Msg::GetStatusChangeResult(res) => {
+                match resp_parsing::<UUID>(res, "registerDiscussionComment") {
+                    Ok(result) => debug!("Status change comment: {:?}", result),
                     Err(err) => link.send_message(Msg::ResponseError(err)),
                 }
             },
@@ -317,7 +348,16 @@ impl Component for ComponentSettings {
                 self.disable_save_changes_btn = false;
             },
             Msg::UpdateActualStatusId(data) => {
-                self.request_component.actual_status_id = data.parse::<usize>().unwrap_or_default();
+                let actual_status_id = data.parse::<usize>().unwrap_or_default();
+                if !is_allowed_transition(&self.actual_statuses, self.current_status_id(), actual_status_id) {
+                    return false
+                }
+                self.request_component.actual_status_id = actual_status_id;
+                self.update_component = true;
+                self.disable_save_changes_btn = false;
+            },
+            Msg::UpdateStatusNote(data) => {
+                self.status_note = data;
                 self.update_component = true;
                 self.disable_save_changes_btn = false;
             },
@@ -383,6 +423,7 @@ impl Component for ComponentSettings {
                                 <ModificationsTableEdit
                                     current_component_uuid={self.current_component_uuid.clone()}
                                     modifications_count={component_data.modifications_count}
+                                    component_specs={component_data.component_specs.clone()}
                                     />
                                 <br/>
                                 <div class="columns">
@@ -465,6 +506,11 @@ impl ComponentSettings {
                                 component_uuid={self.current_component_uuid.clone()}
                                 callback={callback_update_favicon.clone()}
                                 />
+                            <h4 class="title is-5">{get_value_field(&458)}</h4> // Generate preview image from 3D model
+                            <GenerateThumbnailCard
+                                component_uuid={self.current_component_uuid.clone()}
+                                callback={callback_update_favicon.clone()}
+                                />
                         </div>
                     },
                     ActiveTab::Characteristics => html!{
@@ -473,6 +519,7 @@ impl ComponentSettings {
                                 show_manage_btn={true}
                                 component_uuid={self.current_component_uuid.clone()}
                                 params_count={component_data.params_count}
+                                component_specs={component_data.component_specs.clone()}
                                 />
                         </div>
                     },
@@ -525,6 +572,7 @@ impl ComponentSettings {
                     onclick_save_changes,
                     true,
                     self.disable_save_changes_btn
+                        || self.status_change().is_some_and(|change| check_status_note(&change.note).is_err())
                 )}
             </footer>
         </>}
@@ -536,6 +584,7 @@ impl ComponentSettings {
               _ => "1".to_string(),
           }));
         let onchange_type_access = self.link.callback(|value| Msg::UpdateTypeAccessId(value));
+        let oninput_status_note = self.link.callback(|ev: InputData| Msg::UpdateStatusNote(ev.value));
 
         html!{
             <div class="columns">
@@ -547,16 +596,17 @@ impl ComponentSettings {
                             select={self.request_component.actual_status_id.to_string()}
                             onchange={onchange_actual_status_id}
                             >
-                          { for self.actual_statuses.iter().map(|x|
-                              html!{
-                                  <option value={x.actual_status_id.to_string()}
-                                        selected={x.actual_status_id == self.request_component.actual_status_id} >
-                                      {&x.name}
-                                  </option>
-                              }
+                          {status_options(
+                              &self.actual_statuses,
+                              self.current_status_id(),
+                              self.request_component.actual_status_id,
                           )}
                         </select>
                     </div>
+                    {match self.status_change().is_some() {
+                        true => status_note_field("setting-component-status-note", &self.status_note, oninput_status_note),
+                        false => html!{},
+                    }}
                 </div>
                 <div class="column">
                     <label class="label" for="type-access-block">{get_value_field(&58)}</label>
@@ -571,6 +621,29 @@ impl ComponentSettings {
         }
     }
 
+    fn current_status_id(&self) -> usize {
+        self.current_component.as_ref().map(|c| c.actual_status.actual_status_id).unwrap_or_default()
+    }
+
+    /// Change of the status made in the form, None if the status is not changed
+    fn status_change(&self) -> Option<StatusChange> {
+        let component = self.current_component.as_ref()?;
+        let to_status_id = self.request_component.actual_status_id;
+        if component.actual_status.actual_status_id == to_status_id {
+            return None
+        }
+        let to_status = self.actual_statuses.iter().find(|s| s.actual_status_id == to_status_id)?;
+        Some(StatusChange {
+            component_uuid: component.uuid.clone(),
+            modification_uuid: String::new(),
+            from_status_id: component.actual_status.actual_status_id,
+            to_status_id,
+            from_name: component.actual_status.name.clone(),
+            to_name: to_status.name.clone(),
+            note: self.status_note.trim().to_string(),
+        })
+    }
+
     fn show_top_btn(&self) -> Html {
         let onclick_open_component = self.link.callback(|_| Msg::OpenComponent);
         html!{
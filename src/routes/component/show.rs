@@ -20,13 +20,17 @@ use crate::fragments::{
         ComponentStandardItem, ComponentSupplierItem, ComponentLicenseTag, ComponentParamsTags,
         ModificationsTableCard, FilesOfFilesetCard, ModificationFilesetsCard,
         ComponentFilesBlock, SpecsTags, KeywordsTags,
+        ComponentAncestry, DerivedComponents, ForkComponent, DuplicateComponent, ExportComponentBundle, AddToCompare, StatusTimeline,
     },
     img_showcase::ImgShowcase,
     three_showcase::ThreeShowcase,
     clipboard::ShareLinkBtn,
 };
 use crate::services::content_adapter::{DateDisplay, Markdownable};
-use crate::services::{get_classes_table, get_logged_user, get_value_field, resp_parsing, set_focus, set_history_back, title_changer, Counter};
+use crate::services::{
+    get_classes_table, get_logged_user, get_value_field, resp_parsing, set_focus, set_history_back, title_changer,
+    ComponentViewLink, Counter,
+};
 use crate::types::{ComponentInfo, FilesetProgramInfo, DownloadFile, ObjectType, Pathname, SlimUser, ToObject, UUID};
 use crate::gqls::make_query;
 use crate::gqls::component::{
@@ -39,7 +43,8 @@ use crate::gqls::component::{
 pub enum ActiveTab {
     Description,
     Characteristics,
-    ComponentFiles
+    ComponentFiles,
+    StatusHistory,
 }
 
 /// Component with relate data
@@ -63,6 +68,8 @@ pub struct ShowComponent {
     show_related_standards: bool,
     file_arr: Vec<DownloadFile>,
     show_three_view: bool,
+    view_link: ComponentViewLink,
+    annotation: Option<ComponentViewLink>,
     active_tab: ActiveTab,
 }
 
@@ -94,6 +101,9 @@ pub enum Msg {
     OpenComponentSetting,
     GetDownloadFileResult(String),
     Show3D,
+    AddAnnotation(ComponentViewLink),
+    OpenAnnotation(ComponentViewLink),
+    ClearAnnotation,
     ChangeActiveTab(ActiveTab),
     Focuser,
     ClearError,
@@ -125,6 +135,8 @@ impl Component for ShowComponent {
             show_related_standards: false,
             file_arr: Vec::new(),
             show_three_view: false,
+            view_link: ComponentViewLink::default(),
+            annotation: None,
             active_tab: ActiveTab::Description,
         }
     }
@@ -136,11 +148,8 @@ impl Component for ShowComponent {
 
         // get component uuid for request component data
         let route_service: RouteService<()> = RouteService::new();
-        // get target user from route
-        let target_component_uuid = route_service
-            .get_fragment()
-            .trim_start_matches("#/component/")
-            .to_string();
+        // get target component and view link (modification, fileset, file, camera) from route
+        let (target_component_uuid, view_link) = ComponentViewLink::from_fragment(&route_service.get_fragment());
         // get flag changing current component in route
         let not_matches_component_uuid = target_component_uuid != self.current_component_uuid;
         debug!("self.current_component_uuid {:#?}", self.current_component_uuid);
@@ -155,6 +164,9 @@ impl Component for ShowComponent {
             self.error = None;
             self.component = None;
             self.current_component_uuid = target_component_uuid.to_string();
+            // open the viewer if the link sets a file or camera
+            self.show_three_view = view_link.has_view();
+            self.view_link = view_link;
 
             // update current_component_uuid for checking change component in route
             if not_matches_component_uuid {
@@ -198,8 +210,19 @@ impl Component for ShowComponent {
         let link = self.link.clone();
 
         match msg {
-            Msg::SelectFileset(fileset) => self.select_fileset = Some(fileset),
-            Msg::SelectModification(modification_uuid) => self.select_modification_uuid = modification_uuid,
+            Msg::SelectFileset(fileset) => {
+                // the view link no longer matches the selected fileset
+                if self.view_link.fileset_uuid.as_ref().is_some_and(|uuid| uuid != &fileset.uuid) {
+                    self.view_link = ComponentViewLink::default();
+                }
+                self.select_fileset = Some(fileset);
+            },
+            Msg::SelectModification(modification_uuid) => {
+                if self.view_link.modification_uuid.as_ref().is_some_and(|uuid| uuid != &modification_uuid) {
+                    self.view_link = ComponentViewLink::default();
+                }
+                self.select_modification_uuid = modification_uuid;
+            },
             Msg::Follow => {
                 let component_uuid = self.component.as_ref().unwrap().uuid.clone();
                 spawn_local(async move {
@@ -302,6 +325,18 @@ impl Component for ShowComponent {
                 }
             },
             Msg::Show3D => self.show_three_view = !self.show_three_view,
+            Msg::AddAnnotation(view_link) => {
+                self.annotation = Some(view_link);
+                self.open_discussion_card = true;
+                link.send_message(Msg::Focuser);
+            },
+            Msg::OpenAnnotation(view_link) => {
+                // select the modification and fileset of the annotation and open the viewer with the pin
+                self.view_link = view_link;
+                self.show_three_view = true;
+                set_focus("component-main-card");
+            },
+            Msg::ClearAnnotation => self.annotation = None,
             Msg::ChangeActiveTab(set_tab) => self.active_tab = set_tab,
             Msg::Focuser => set_focus("show-component-discussion"),
             Msg::ClearError => self.error = None,
@@ -328,7 +363,7 @@ impl Component for ShowComponent {
                     <ListErrors error={self.error.clone()} clear_error={onclick_clear_error.clone()}/>
                     <div class="container page">
                         <div class="row">
-                            <div class="card column">
+                            <div id="component-main-card" class="card column">
                               {self.show_main_card(component_data)}
                             </div>
                             {match &self.select_fileset {
@@ -365,6 +400,11 @@ impl Component for ShowComponent {
                                 keywords={component_data.component_keywords.clone()}
                             />
                             <br/>
+                            <DerivedComponents
+                                component_uuid={component_data.uuid.clone()}
+                                owner_uuid={component_data.owner_user.uuid.clone()}
+                            />
+                            <br/>
                         </div>
                     </div>
                 </div>
@@ -386,6 +426,9 @@ impl ShowComponent {
                     true => html!{
                         <ThreeShowcase
                             fileset_uuid={self.select_fileset.as_ref().map(|f| f.uuid.clone()).unwrap_or_default()}
+                            modification_uuid={self.select_modification_uuid.clone()}
+                            view_link={Some(self.view_link.clone()).filter(|vl| vl.has_view())}
+                            callback_annotation={self.link.callback(|value: ComponentViewLink| Msg::AddAnnotation(value))}
                         />
                     },
                     false => html!{
@@ -396,6 +439,11 @@ impl ShowComponent {
                     },
                 }}
               <div class="column">
+                <ComponentAncestry
+                    component_uuid={component_data.uuid.clone()}
+                    parent_component_uuid={component_data.parent_component_uuid.clone()}
+                    name={component_data.name.clone()}
+                    />
                 <div class="has-text-weight-bold is-size-4">{
                     component_data.name.clone()
                 }</div>
@@ -404,11 +452,14 @@ impl ShowComponent {
                     {self.show_three_btn()}
                     <ModificationFilesetsCard
                         modification_uuid={self.select_modification_uuid.clone()}
+                        select_fileset_uuid={self.view_link.fileset_uuid.clone()}
                         callback_select_fileset={callback_select_fileset}
                         callback_open_fileset={callback_open_fileset} />
                     {self.show_discussion_btn()}
                     {self.show_setting_btn()}
+                    {self.show_copy_btns(component_data)}
                     {self.show_followers_btn()}
+                    <AddToCompare component_uuid={component_data.uuid.clone()} />
                     <ShareLinkBtn />
                     {match component_data.licenses.is_empty() {
                         true => html!{},
@@ -461,6 +512,8 @@ impl ShowComponent {
                 modifications_count={modifications_count}
                 callback_select_modification={onclick_select_modification.clone()}
                 user_owner={self.current_user_owner}
+                select_modification_uuid={self.view_link.modification_uuid.clone()}
+                filter={self.view_link.filter.clone()}
               />
         }
     }
@@ -497,10 +550,12 @@ impl ShowComponent {
         let onclick_tab_description = self.link.callback(|_| Msg::ChangeActiveTab(ActiveTab::Description));
         let onclick_tab_characteristics = self.link.callback(|_| Msg::ChangeActiveTab(ActiveTab::Characteristics));
         let onclick_tab_component_files = self.link.callback(|_| Msg::ChangeActiveTab(ActiveTab::ComponentFiles));
+        let onclick_tab_status_history = self.link.callback(|_| Msg::ChangeActiveTab(ActiveTab::StatusHistory));
         let at = match self.active_tab {
-            ActiveTab::Description => ("is-active","",""),
-            ActiveTab::Characteristics => ("","is-active",""),
-            ActiveTab::ComponentFiles => ("","","is-active"),
+            ActiveTab::Description => ("is-active","","",""),
+            ActiveTab::Characteristics => ("","is-active","",""),
+            ActiveTab::ComponentFiles => ("","","is-active",""),
+            ActiveTab::StatusHistory => ("","","","is-active"),
         };
         let show_description_btn = self.link.callback(|_| Msg::ShowDescription);
         html!{<>
@@ -509,6 +564,7 @@ impl ShowComponent {
                     <li class={at.0} onclick={onclick_tab_description}><a>{get_value_field(&61)}</a></li>
                     <li class={at.1} onclick={onclick_tab_characteristics}><a>{get_value_field(&101)}</a></li>
                     <li class={at.2} onclick={onclick_tab_component_files}><a>{get_value_field(&102)}</a></li>
+                    <li class={at.3} onclick={onclick_tab_status_history}><a>{get_value_field(&633)}</a></li>
                 </ul>
             </div>
             <div class="card-content p-0">
@@ -543,6 +599,9 @@ impl ShowComponent {
                                 files_count={component_data.files_count}
                                 />
                         },
+                        ActiveTab::StatusHistory => html!{
+                            <StatusTimeline component_uuid={component_data.uuid.clone()} />
+                        },
                     }}
                 </div>
             </div>
@@ -646,6 +705,17 @@ impl ShowComponent {
         }
     }
 
+    fn show_copy_btns(&self, component_data: &ComponentInfo) -> Html {
+        match get_logged_user() {
+            Some(_) => html!{<>
+                <ForkComponent component={component_data.clone()} />
+                <DuplicateComponent component={component_data.clone()} />
+                <ExportComponentBundle component={component_data.clone()} />
+            </>},
+            None => html!{},
+        }
+    }
+
     fn show_followers_btn(&self) -> Html {
         let onclick_following = match self.is_followed {
             true => self.link.callback(|_| Msg::UnFollow),
@@ -712,6 +782,9 @@ impl ShowComponent {
                         <DiscussionCommentsBlock
                             discussion_uuid={None}
                             object_type={ObjectType::new(self.current_component_uuid.clone(), ToObject::COMPONENT)}
+                            annotation={self.annotation.clone()}
+                            callback_open_annotation={self.link.callback(|value: ComponentViewLink| Msg::OpenAnnotation(value))}
+                            callback_clear_annotation={self.link.callback(|_| Msg::ClearAnnotation)}
                         />
                     </div>
                 </div>
@@ -1,6 +1,9 @@
 use yew::{classes, html, Component, ComponentLink, Html, InputData, ShouldRender};
 use crate::fragments::search::{CatalogSpec, SearchArg, SearchBar};
-use crate::services::{get_history_search, get_value_field, set_history_search, wraps_text};
+use crate::services::{
+    get_history_search, get_logged_user, get_value_field, set_history_search, wraps_text,
+    SavedSearch, load_saved_searches, save_saved_searches, open_saved_search, take_opened_search,
+};
 
 #[derive(Clone)]
 pub enum Msg {
@@ -14,6 +17,11 @@ pub enum Msg {
     ForUser(String),
     ToggleCheckboxs,
     ToggleForObjects,
+    ToggleSavedSearches,
+    SavedSearchesChanged,
+    OpenSavedSearch(String),
+    PinSavedSearch(String),
+    DeleteSavedSearch(String),
     Ignore,
 }
 
@@ -22,6 +30,8 @@ pub struct SearchPage {
     search_arg: SearchArg,
     checkboxs_expanded: bool,
     for_objects_expanded: bool,
+    saved_searches: Vec<SavedSearch>,
+    saved_expanded: bool,
 }
 
 impl Component for SearchPage {
@@ -37,11 +47,17 @@ impl Component for SearchPage {
             // clear the already accepted search query
             set_history_search(None)
         }
+        // the saved search opened from the side menu
+        if let Some(saved) = take_opened_search() {
+            search_arg = (&saved.page_arg).into();
+        }
         SearchPage {
             link,
             search_arg,
             checkboxs_expanded: false,
             for_objects_expanded: false,
+            saved_searches: load_saved_searches(),
+            saved_expanded: true,
         }
     }
 
@@ -57,6 +73,25 @@ impl Component for SearchPage {
             Msg::ForUser(user_uuid) => self.search_arg.user_uuid = wraps_text(user_uuid),
             Msg::ToggleCheckboxs => self.checkboxs_expanded = !self.checkboxs_expanded,
             Msg::ToggleForObjects => self.for_objects_expanded = !self.for_objects_expanded,
+            Msg::ToggleSavedSearches => self.saved_expanded = !self.saved_expanded,
+            Msg::SavedSearchesChanged => self.saved_searches = load_saved_searches(),
+            Msg::OpenSavedSearch(name) => {
+                open_saved_search(&name);
+                if let Some(saved) = take_opened_search() {
+                    self.search_arg = (&saved.page_arg).into();
+                }
+                self.saved_searches = load_saved_searches();
+            },
+            Msg::PinSavedSearch(name) => {
+                if let Some(saved) = self.saved_searches.iter_mut().find(|s| s.name == name) {
+                    saved.pinned = !saved.pinned;
+                }
+                save_saved_searches(&self.saved_searches);
+            },
+            Msg::DeleteSavedSearch(name) => {
+                self.saved_searches.retain(|s| s.name != name);
+                save_saved_searches(&self.saved_searches);
+            },
             Msg::Ignore => {},
         }
         true
@@ -74,7 +109,10 @@ impl Component for SearchPage {
                     <div class={"columns"}>
                         <div class={"column is-one-quarter"}>{self.filters()}</div>
                         <div class={"column"}>
-                            <SearchBar search_arg={self.search_arg.clone()} />
+                            <SearchBar
+                                search_arg={self.search_arg.clone()}
+                                callback_saved={self.link.callback(|_| Msg::SavedSearchesChanged)}
+                                />
                         </div>
                     </div>
                 </div>
@@ -88,6 +126,7 @@ impl SearchPage {
         let callback_select_spec = self.link.callback(|spec_id| Msg::ChangeSpec(spec_id));
         html!{
             <div class={"block"}>
+                <div class={"column"}>{self.saved_searches()}</div>
                 <div class={"column"}>
                     <CatalogSpec callback_select_spec={callback_select_spec} />
                 </div>
@@ -98,6 +137,65 @@ impl SearchPage {
     }
 
 
+    fn saved_searches(&self) -> Html {
+        if get_logged_user().is_none() || self.saved_searches.is_empty() {
+            return html!{}
+        }
+        let onclick_toggle = self.link.callback(|_| Msg::ToggleSavedSearches);
+        html!{
+            <div class={"card"}>
+                <div class={"column is-flex is-justify-content-space-between is-align-items-center pointer"} onclick={onclick_toggle}>
+                    <p class={"title is-5 select-title"} style="margin-bottom: 0px;">{get_value_field(&699)}</p> // Saved searches
+                    <span class="icon is-clickable">
+                        <i class={classes!("fas", if self.saved_expanded { "fa-chevron-up" } else { "fa-chevron-down" })}></i>
+                    </span>
+                </div>
+                {if self.saved_expanded {
+                    html!{<>
+                        {for self.saved_searches.iter().map(|saved| self.saved_search_item(saved))}
+                    </>}
+                } else {
+                    html!{}
+                }}
+            </div>
+        }
+    }
+
+    fn saved_search_item(&self, saved: &SavedSearch) -> Html {
+        let name = saved.name.clone();
+        let onclick_open = self.link.callback(move |_| Msg::OpenSavedSearch(name.clone()));
+        let name = saved.name.clone();
+        let onclick_pin = self.link.callback(move |_| Msg::PinSavedSearch(name.clone()));
+        let name = saved.name.clone();
+        let onclick_delete = self.link.callback(move |_| Msg::DeleteSavedSearch(name.clone()));
+        html!{
+            <div class="column pt-0 mt-0 is-flex is-justify-content-space-between is-align-items-center">
+                <a onclick={onclick_open} title={saved.changes_notes().join(", ")}>
+                    {saved.name.clone()}
+                    {match saved.has_changes() {
+                        true => html!{
+                            <span class="tag is-info is-small ml-1">{saved.new_count + saved.updated_count}</span>
+                        },
+                        false => html!{},
+                    }}
+                </a>
+                <span>
+                    <a class="icon"
+                        title={match saved.pinned {
+                            true => get_value_field(&703), // Unpin from the side menu
+                            false => get_value_field(&702), // Pin to the side menu
+                        }}
+                        onclick={onclick_pin} >
+                        <i class={classes!("fas", "fa-thumbtack", (!saved.pinned).then_some("has-text-grey-light"))}></i>
+                    </a>
+                    <a class="icon" title={get_value_field(&135)} onclick={onclick_delete}>
+                        <i class="fas fa-trash"></i>
+                    </a>
+                </span>
+            </div>
+        }
+    }
+
     fn for_objects(&self) -> Html {
         let oninput_for_company = self.link.callback(|ev: InputData| Msg::ForCompany(ev.value));
         let oninput_for_standard = self.link.callback(|ev: InputData| Msg::ForStandard(ev.value));
@@ -6,6 +6,7 @@ pub mod component;
 pub mod company;
 pub mod standard;
 pub mod supplier_service;
+pub mod bom;
 
 pub use user::{
     login,
@@ -68,6 +69,10 @@ pub enum AppRoute {
     Profile(String),
     #[to = "#/search"]
     SearchPage,
+    #[to = "#/bom"]
+    Bom,
+    #[to = "#/compare"]
+    CompareComponents,
     #[to = "#/"]
     Home,
 }
@@ -24,7 +24,10 @@ use crate::fragments::{
     user::CatalogUsers,
     user::UserCertificatesCard,
 };
-use crate::services::{Counter, get_logged_user, get_value_field, resp_parsing, title_changer};
+use crate::services::{
+    Counter, get_logged_user, get_value_field, resp_parsing, title_changer,
+    load_saved_searches, open_saved_search,
+};
 use crate::types::{
     UserDataCard, CompaniesQueryArg, ComponentsQueryArg, ServicesQueryArg, SelfUserInfo, SlimUser,
     StandardsQueryArg, UserCertificate, UserInfo, UsersQueryArg, UUID, Region
@@ -78,6 +81,7 @@ pub enum Msg {
     GetUserProfileResult(String),
     ChangeTab(ProfileTab),
     ShowFullUserInfo,
+    OpenSavedSearch(String),
     ClearError,
     Ignore,
 }
@@ -269,6 +273,10 @@ impl Component for Profile {
                 }
             },
             Msg::ShowFullUserInfo => self.show_full_user_info = !self.show_full_user_info,
+            Msg::OpenSavedSearch(name) => {
+                open_saved_search(&name);
+                self.router_agent.send(ChangeRoute(AppRoute::SearchPage.into()));
+            },
             Msg::ClearError => self.error = None,
             Msg::Ignore => {},
         }
@@ -314,6 +322,7 @@ impl Profile {
                                         self_data.program.name.as_str(),
                                     ) }
                                 </div>
+                                {self.show_saved_search_notes()}
                             </div>
                             {self.self_user_relate_object(self_data)}
                         </div>
@@ -400,6 +409,27 @@ impl Profile {
         </div>}
     }
 
+    /// Notices about the new and updated components found by the pinned saved searches
+    fn show_saved_search_notes(&self) -> Html {
+        let notes: Vec<(String, String)> = load_saved_searches().into_iter()
+            .filter(|s| s.pinned)
+            .flat_map(|s| s.changes_notes().into_iter().map(move |note| (s.name.clone(), note)))
+            .collect();
+        html!{<>
+            {for notes.into_iter().map(|(name, note)| {
+                let onclick_open = self.link.callback(move |_| Msg::OpenSavedSearch(name.clone()));
+                html!{
+                    <div class="notification is-info is-light py-2 mb-2">
+                        <a onclick={onclick_open}>
+                            <span class="icon"><i class="fas fa-search"></i></span>
+                            <span>{note}</span>
+                        </a>
+                    </div>
+                }
+            })}
+        </>}
+    }
+
     fn view_card(&self) -> Html {
         let UserDataCard {
             image_file,
@@ -486,7 +516,7 @@ impl Profile {
     }
 
     fn show_profile_action(&self) -> Html {
-        let menu_arr: Vec<MenuItem> = vec![
+        let mut menu_arr: Vec<MenuItem> = vec![
             MenuItem {
                 title: get_value_field(&32).to_string(),
                 action: self.cb_generator(ProfileTab::Certificates),
@@ -565,6 +595,22 @@ impl Profile {
             },
         ];
 
+        // the pinned saved searches are shown only to the owner of the profile
+        if self.self_profile.is_some() {
+            for saved in load_saved_searches().into_iter().filter(|s| s.pinned) {
+                let name = saved.name.clone();
+                menu_arr.push(MenuItem {
+                    title: saved.name.clone(),
+                    action: self.link.callback(move |_| Msg::OpenSavedSearch(name.clone())),
+                    count: saved.new_count + saved.updated_count,
+                    item_class: classes!("has-background-white"),
+                    icon_classes: vec![classes!("fas", "fa-search")],
+                    is_active: false,
+                    is_extend: false,
+                });
+            }
+        }
+
         html! {
             <div style="margin-right: 18px;z-index: 1;" >
                 <SideMenu menu_arr={menu_arr} />
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use log::debug;
+use crate::types::UUID;
+use super::local_storage::{get_bom_lines, set_bom_lines};
+use super::util::{decode_value, encode_value};
+
+/// Line of the bill of materials: a modification of a component with the quantity
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BomLine {
+    pub component_uuid: UUID,
+    pub modification_uuid: UUID,
+    pub quantity: usize,
+    #[serde(default)]
+    pub note: String,
+    /// Names and suppliers are saved when the line is added and updated when the BOM is opened
+    #[serde(default)]
+    pub component_name: String,
+    #[serde(default)]
+    pub modification_name: String,
+    /// Short names of the suppliers of the component, the line is grouped by the first one
+    #[serde(default)]
+    pub suppliers: Vec<String>,
+}
+
+impl BomLine {
+    pub(crate) fn supplier(&self) -> &str {
+        self.suppliers.first().map(|s| s.as_str()).unwrap_or_default()
+    }
+}
+
+/// Lines of the supplier with the total quantity
+#[derive(Debug, PartialEq)]
+pub(crate) struct BomGroup<'a> {
+    /// Empty for the components without suppliers
+    pub supplier: &'a str,
+    pub lines: Vec<(usize, &'a BomLine)>,
+    pub quantity: usize,
+}
+
+pub(crate) fn load_bom() -> Vec<BomLine> {
+    get_bom_lines()
+        .and_then(|data| serde_json::from_str(&data).map_err(|err| debug!("Bad BOM: {:?}", err)).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_bom(lines: &[BomLine]) {
+    let data = match lines.is_empty() {
+        true => None,
+        false => serde_json::to_string(lines).ok(),
+    };
+    set_bom_lines(data);
+}
+
+/// Adds the quantity to the line of the same modification or adds a new line
+pub(crate) fn add_bom_line(lines: &mut Vec<BomLine>, line: BomLine) {
+    match lines.iter_mut().find(|l| l.modification_uuid == line.modification_uuid) {
+        Some(old) => {
+            old.quantity += line.quantity;
+            if !line.note.is_empty() {
+                old.note = line.note;
+            }
+        },
+        None => lines.push(line),
+    }
+}
+
+/// Groups the lines (with their indexes) by the supplier, the lines without suppliers go last
+pub(crate) fn group_by_supplier(lines: &[BomLine]) -> Vec<BomGroup<'_>> {
+    let mut groups: Vec<BomGroup> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        match groups.iter_mut().find(|g| g.supplier == line.supplier()) {
+            Some(group) => {
+                group.lines.push((index, line));
+                group.quantity += line.quantity;
+            },
+            None => groups.push(BomGroup { supplier: line.supplier(), lines: vec![(index, line)], quantity: line.quantity }),
+        }
+    }
+    groups.sort_by_key(|g| (g.supplier.is_empty(), g.supplier.to_lowercase()));
+    groups
+}
+
+/// Rows for the export in the order of the groups (without the header)
+pub(crate) fn bom_rows(lines: &[BomLine]) -> Vec<Vec<String>> {
+    group_by_supplier(lines).iter().flat_map(|group| group.lines.iter().map(|(_, line)| vec![
+        line.suppliers.join(", "),
+        line.component_name.clone(),
+        line.modification_name.clone(),
+        line.quantity.to_string(),
+        line.note.clone(),
+        line.component_uuid.clone(),
+        line.modification_uuid.clone(),
+    ])).collect()
+}
+
+/// Encodes the lines in the query of the BOM route (`#/bom?lines=component:modification:quantity:note,...`),
+/// the names are not included and are requested by the UUIDs
+pub(crate) fn bom_to_query(lines: &[BomLine]) -> String {
+    let lines: Vec<String> = lines.iter().map(|line| format!(
+        "{}:{}:{}:{}", line.component_uuid, line.modification_uuid, line.quantity, encode_value(&line.note)
+    )).collect();
+    format!("lines={}", lines.join(","))
+}
+
+/// Parses the lines from the query of the BOM route, invalid lines are skipped
+pub(crate) fn bom_from_query(query: &str) -> Vec<BomLine> {
+    let mut lines = Vec::new();
+    let value = query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "lines")
+        .map(|(_, value)| value)
+        .unwrap_or_default();
+    for item in value.split(',') {
+        let mut parts = item.split(':');
+        let (component_uuid, modification_uuid) = match (parts.next(), parts.next()) {
+            (Some(c), Some(m)) if c.len() == 36 && m.len() == 36 => (c.to_string(), m.to_string()),
+            _ => continue,
+        };
+        let quantity = match parts.next().and_then(|q| q.parse::<usize>().ok()) {
+            Some(quantity) if quantity > 0 => quantity,
+            _ => continue,
+        };
+        let note = parts.next().map(decode_value).unwrap_or_default();
+        add_bom_line(&mut lines, BomLine { component_uuid, modification_uuid, quantity, note, ..BomLine::default() });
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn line(n: u8, quantity: usize, suppliers: &[&str]) -> BomLine {
+        BomLine {
+            component_uuid: format!("{}0000000-0000-0000-0000-000000000000", n),
+            modification_uuid: format!("{}0000000-0000-0000-0000-00000000000{}", n, n),
+            quantity,
+            note: String::new(),
+            component_name: format!("Component {}", n),
+            modification_name: format!("M{}", n),
+            suppliers: suppliers.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn bom_groups_and_merge() {
+        let mut lines = vec![line(1, 2, &[]), line(2, 1, &["Bolts Ltd", "Acme"]), line(3, 4, &["acme"])];
+        add_bom_line(&mut lines, BomLine { note: "M8".to_string(), ..line(2, 3, &[]) });
+        assert_eq!(3, lines.len());
+        assert_eq!((4, "M8"), (lines[1].quantity, lines[1].note.as_str()));
+
+        let groups = group_by_supplier(&lines);
+        let suppliers: Vec<(&str, usize)> = groups.iter().map(|g| (g.supplier, g.quantity)).collect();
+        assert_eq!(vec![("acme", 4), ("Bolts Ltd", 4), ("", 2)], suppliers);
+        assert_eq!(0, groups[2].lines[0].0);
+
+        let rows = bom_rows(&lines);
+        assert_eq!(["Bolts Ltd, Acme", "Component 2", "M2", "4"], rows[1][..4]);
+    }
+
+    #[test]
+    fn bom_query_round_trip() {
+        let mut lines = vec![line(1, 2, &["Acme"]), line(2, 5, &[])];
+        lines[0].note = "left, side: 50%".to_string();
+        let query = bom_to_query(&lines);
+        let parsed = bom_from_query(&format!("{}&other=1,bad:line", query));
+        assert_eq!(2, parsed.len());
+        assert_eq!((2, "left, side: 50%"), (parsed[0].quantity, parsed[0].note.as_str()));
+        assert_eq!(lines[1].modification_uuid, parsed[1].modification_uuid);
+        assert!(parsed[1].component_name.is_empty());
+        assert!(bom_from_query("lines=").is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::types::{Keyword, UUID};
+use super::get_value_field;
+
+/// Kind of the objects of the catalog the bulk action is applied to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BulkTarget {
+    Component,
+    Standard,
+    Company,
+}
+
+/// Action for the selected objects, the data of the action is entered in the action bar
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BulkActionKind {
+    ChangeAccess,
+    AddKeywords,
+    RemoveKeywords,
+    AddSpec,
+    AttachStandard,
+    Follow,
+    Unfollow,
+    Delete,
+}
+
+impl BulkActionKind {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::ChangeAccess => get_value_field(&664),
+            Self::AddKeywords => get_value_field(&665),
+            Self::RemoveKeywords => get_value_field(&666),
+            Self::AddSpec => get_value_field(&667),
+            Self::AttachStandard => get_value_field(&668),
+            Self::Follow => get_value_field(&669),
+            Self::Unfollow => get_value_field(&670),
+            Self::Delete => get_value_field(&135),
+        }
+    }
+
+    pub(crate) fn value(&self) -> &'static str {
+        match self {
+            Self::ChangeAccess => "access",
+            Self::AddKeywords => "add-keywords",
+            Self::RemoveKeywords => "remove-keywords",
+            Self::AddSpec => "spec",
+            Self::AttachStandard => "standard",
+            Self::Follow => "follow",
+            Self::Unfollow => "unfollow",
+            Self::Delete => "delete",
+        }
+    }
+
+    pub(crate) fn from_value(value: &str) -> Option<Self> {
+        BulkTarget::Component.actions().into_iter().find(|kind| kind.value() == value)
+    }
+}
+
+impl BulkTarget {
+    /// Actions supported by the mutations of the objects (companies have no keywords,
+    /// standards are attached only to components)
+    pub(crate) fn actions(&self) -> Vec<BulkActionKind> {
+        use BulkActionKind::*;
+        match self {
+            BulkTarget::Component => vec![ChangeAccess, AddKeywords, RemoveKeywords, AddSpec, AttachStandard, Follow, Unfollow, Delete],
+            BulkTarget::Standard => vec![ChangeAccess, AddKeywords, RemoveKeywords, AddSpec, Follow, Unfollow, Delete],
+            BulkTarget::Company => vec![ChangeAccess, AddSpec, Follow, Unfollow, Delete],
+        }
+    }
+}
+
+/// Action with its data which is applied to each selected object
+#[derive(Clone, Debug, PartialEq)]
+pub enum BulkAction {
+    ChangeAccess(usize),
+    AddKeywords(Vec<String>),
+    RemoveKeywords(Vec<String>),
+    AddSpec(usize),
+    AttachStandard(UUID),
+    Follow,
+    Unfollow,
+    Delete,
+}
+
+/// Keywords separated by commas, semicolons or new lines, without repeats
+pub(crate) fn parse_keywords(text: &str) -> Vec<String> {
+    let mut keywords: Vec<String> = Vec::new();
+    for keyword in text.split([',', ';', '\n']).map(|k| k.trim()).filter(|k| !k.is_empty()) {
+        if keywords.iter().all(|k| k.to_lowercase() != keyword.to_lowercase()) {
+            keywords.push(keyword.to_string());
+        }
+    }
+    keywords
+}
+
+/// IDs of the keywords of the object with the names to remove
+pub(crate) fn keyword_ids(existing: &[Keyword], names: &[String]) -> Vec<usize> {
+    existing.iter()
+        .filter(|k| names.iter().any(|name| name.to_lowercase() == k.keyword.trim().to_lowercase()))
+        .map(|k| k.id)
+        .collect()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn bulk_keywords_and_actions() {
+        assert_eq!(parse_keywords(" bolt, Nut;\nbolt ,, washer "), vec!["bolt", "Nut", "washer"]);
+        let existing = vec![
+            Keyword { id: 1, keyword: "bolt".to_string() },
+            Keyword { id: 2, keyword: "nut".to_string() },
+            Keyword { id: 3, keyword: "m8".to_string() },
+        ];
+        assert_eq!(keyword_ids(&existing, &parse_keywords("NUT, Bolt, screw")), vec![1, 2]);
+
+        assert!(!BulkTarget::Company.actions().contains(&BulkActionKind::AddKeywords));
+        assert!(!BulkTarget::Standard.actions().contains(&BulkActionKind::AttachStandard));
+        assert_eq!(BulkActionKind::from_value("unfollow"), Some(BulkActionKind::Unfollow));
+        assert_eq!(BulkActionKind::from_value("other"), None);
+    }
+}
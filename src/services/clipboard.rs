@@ -16,4 +16,15 @@ pub(crate) fn set_clipboard(value: &str) {
     Clipboard::new(value);
     debug!("run click");
     // x.click();
-}
\ No newline at end of file
+}
+#[wasm_bindgen(module = "/assets/js/clipboard.js")]
+extern "C" {
+    #[wasm_bindgen(static_method_of = Clipboard, js_name = copy)]
+    fn copy_text(text: &str) -> String;
+}
+
+/// Copies the text to the clipboard, must be called by the click of the user
+pub(crate) fn copy_to_clipboard(text: &str) {
+    debug!("copy_to_clipboard: {} chars", text.len());
+    Clipboard::copy_text(text);
+}
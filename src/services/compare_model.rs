@@ -0,0 +1,83 @@
+use serde::{Serialize, Deserialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use wasm_bindgen::JsValue;
+use serde_wasm_bindgen::to_value;
+use log::debug;
+use crate::services::{get_value_field, ModelFormat};
+use crate::types::DownloadFile;
+
+/// How two models are shown for comparison
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CompareMode {
+    /// Two synchronised viewers next to each other
+    SideBySide,
+    /// The second model over the first one, coloured by its deviation
+    Overlay,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompareModel {
+    filename: String,
+    url: String,
+    model_format: ModelFormat,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompareLabels {
+    failed_to_load_model: String,
+    format_not_supported: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompareConfig {
+    left: CompareModel,
+    right: CompareModel,
+    mode: CompareMode,
+    labels: CompareLabels,
+}
+
+#[wasm_bindgen(module = "/assets/js/comparer.js")]
+extern "C" {
+    type ModelComparer;
+
+    #[wasm_bindgen(constructor)]
+    fn new(config: JsValue) -> ModelComparer;
+
+    #[wasm_bindgen(method)]
+    async fn starter(this: &ModelComparer);
+}
+
+impl CompareModel {
+    fn new(model_file: &DownloadFile) -> Self {
+        Self {
+            filename: model_file.filename.clone(),
+            url: model_file.download_url.clone(),
+            model_format: ModelFormat::from_filename(&model_file.filename),
+        }
+    }
+}
+
+/// Shows two models in the `compare-hull` containers (the previous comparison is closed)
+pub(crate) fn compare_models(left: &DownloadFile, right: &DownloadFile, mode: CompareMode) {
+    let config = CompareConfig {
+        left: CompareModel::new(left),
+        right: CompareModel::new(right),
+        mode,
+        labels: CompareLabels {
+            failed_to_load_model: get_value_field(&248).to_string(),
+            format_not_supported: get_value_field(&249).to_string(),
+        },
+    };
+    let config_js = match to_value(&config) {
+        Ok(config_js) => config_js,
+        Err(err) => {
+            debug!("Failed to create comparer config: {:?}", err);
+            return
+        },
+    };
+    spawn_local(async move {
+        let comparer = ModelComparer::new(config_js);
+        comparer.starter().await;
+    });
+}
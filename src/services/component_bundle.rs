@@ -0,0 +1,406 @@
+use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use crate::services::zip::{ZipArchive, ZipWriter};
+use crate::types::{ActualStatus, LicenseInfo, Param, Program, Spec, UUID};
+use super::get_value_field;
+
+/// Version of the manifest format, bundles of newer versions are not imported
+pub(crate) const BUNDLE_VERSION: u32 = 1;
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// Description of the exported component, saved as `manifest.json` in the ZIP bundle next to the files
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleManifest {
+    pub version: u32,
+    pub component: BundleComponent,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleComponent {
+    pub uuid: UUID,
+    pub name: String,
+    pub description: String,
+    pub type_access_id: usize,
+    /// Type of the component, the bundles exported without it have the base type
+    #[serde(default = "base_component_type_id")]
+    pub component_type_id: usize,
+    pub actual_status: Option<ActualStatus>,
+    pub specs: Vec<Spec>,
+    pub keywords: Vec<String>,
+    pub licenses: Vec<LicenseInfo>,
+    /// Standards and suppliers are saved for reference only, they are not imported
+    pub standards: Vec<BundleLink>,
+    pub suppliers: Vec<BundleLink>,
+    pub params: Vec<BundleParam>,
+    pub files: Vec<BundleFile>,
+    pub modifications: Vec<BundleModification>,
+}
+
+fn base_component_type_id() -> usize {
+    1
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleLink {
+    pub uuid: UUID,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleParam {
+    pub param: Param,
+    pub value: String,
+}
+
+/// File of the bundle with the path of its data in the archive
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleFile {
+    pub filename: String,
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleModification {
+    pub uuid: UUID,
+    pub modification_name: String,
+    pub description: String,
+    pub actual_status: Option<ActualStatus>,
+    pub params: Vec<BundleParam>,
+    pub files: Vec<BundleFile>,
+    pub filesets: Vec<BundleFileset>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleFileset {
+    pub program: Program,
+    pub files: Vec<BundleFile>,
+}
+
+/// Owner of the file in the bundle, defines the path of the file in the archive
+#[derive(Clone, Debug, PartialEq)]
+pub enum BundleFileOf {
+    Component,
+    /// UUID of the modification in the bundle
+    Modification(UUID),
+    /// UUID of the modification and ID of the program of the fileset
+    Fileset(UUID, usize),
+}
+
+impl BundleFile {
+    pub(crate) fn new(file_of: &BundleFileOf, filename: &str) -> Self {
+        let name = filename.replace(['/', '\\'], "_");
+        let path = match file_of {
+            BundleFileOf::Component => format!("files/{}", name),
+            BundleFileOf::Modification(uuid) => format!("modifications/{}/files/{}", uuid, name),
+            BundleFileOf::Fileset(uuid, program_id) => format!("modifications/{}/filesets/{}/{}", uuid, program_id, name),
+        };
+        Self { filename: filename.to_string(), path }
+    }
+}
+
+impl BundleManifest {
+    pub(crate) fn new(component: BundleComponent) -> Self {
+        Self { version: BUNDLE_VERSION, component }
+    }
+
+    /// Files of the component, its modifications and filesets
+    pub(crate) fn files(&self) -> Vec<&BundleFile> {
+        let component = &self.component;
+        component.files.iter()
+            .chain(component.modifications.iter().flat_map(|m| {
+                m.files.iter().chain(m.filesets.iter().flat_map(|f| f.files.iter()))
+            }))
+            .collect()
+    }
+
+    /// Parameters of the component and the modifications without repeats of the names
+    pub(crate) fn params(&self) -> Vec<Param> {
+        let component = &self.component;
+        let mut names = BTreeSet::new();
+        component.params.iter()
+            .chain(component.modifications.iter().flat_map(|m| m.params.iter()))
+            .filter(|p| names.insert(name_key(&p.param.paramname)))
+            .map(|p| p.param.clone())
+            .collect()
+    }
+}
+
+/// Packs the manifest and the data of the files (by the paths in the archive) to the ZIP bundle
+pub(crate) fn write_bundle(manifest: &BundleManifest, files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    let mut writer = ZipWriter::default();
+    let content = serde_json::to_string_pretty(manifest).unwrap_or_default();
+    writer.add(MANIFEST_NAME, content.as_bytes())?;
+    for (path, data) in files {
+        writer.add(path, data)?;
+    }
+    Ok(writer.finish())
+}
+
+pub(crate) fn read_bundle(data: &[u8]) -> Result<BundleManifest, String> {
+    let archive = ZipArchive::new(data)?;
+    let manifest: BundleManifest = serde_json::from_str(&archive.file_text(MANIFEST_NAME)?)
+        .map_err(|err| format!("Invalid {}: {}", MANIFEST_NAME, err))?;
+    if manifest.version > BUNDLE_VERSION {
+        return Err(format!("The bundle version {} is not supported", manifest.version))
+    }
+    Ok(manifest)
+}
+
+/// Unpacks the data of the files found in the bundle, the missing files are skipped
+pub(crate) fn read_bundle_files(data: &[u8], files: &[BundleFile]) -> Vec<(String, Vec<u8>)> {
+    let archive = match ZipArchive::new(data) {
+        Ok(archive) => archive,
+        Err(_) => return Vec::new(),
+    };
+    files.iter()
+        .filter_map(|file| archive.file(&file.path).map(|content| (file.filename.clone(), content)))
+        .collect()
+}
+
+/// Difference of the bundle with the data of the server which the component is imported to
+#[derive(Debug, Clone, PartialEq)]
+pub enum BundleConflict {
+    UnknownSpec(String),
+    UnknownLicense(String),
+    UnknownStatus(String),
+    /// The parameter is matched by the name and registered if there is no such name
+    ParamByName(String),
+    /// The fileset with its files is skipped
+    UnknownProgram(String),
+    MissingFile(String),
+    SkippedStandard(String),
+    SkippedSupplier(String),
+}
+
+impl BundleConflict {
+    pub(crate) fn message(&self) -> String {
+        let (label, name) = match self {
+            BundleConflict::UnknownSpec(name) => (get_value_field(&645), name),
+            BundleConflict::UnknownLicense(name) => (get_value_field(&646), name),
+            BundleConflict::UnknownStatus(name) => (get_value_field(&647), name),
+            BundleConflict::ParamByName(name) => (get_value_field(&648), name),
+            BundleConflict::UnknownProgram(name) => (get_value_field(&649), name),
+            BundleConflict::MissingFile(name) => (get_value_field(&650), name),
+            BundleConflict::SkippedStandard(name) => (get_value_field(&651), name),
+            BundleConflict::SkippedSupplier(name) => (get_value_field(&652), name),
+        };
+        format!("{}: {}", name, label)
+    }
+}
+
+/// Data of the server found by the IDs from the bundle
+#[derive(Clone, Debug, Default)]
+pub struct BundleKnown {
+    pub specs: Vec<Spec>,
+    pub params: Vec<Param>,
+    pub licenses: Vec<LicenseInfo>,
+    pub statuses: Vec<ActualStatus>,
+    pub programs: Vec<Program>,
+}
+
+/// What of the bundle is imported as is, what is replaced and what is skipped
+#[derive(Clone, Debug, Default)]
+pub struct BundlePlan {
+    pub conflicts: Vec<BundleConflict>,
+    pub spec_ids: Vec<usize>,
+    pub license_ids: Vec<usize>,
+    status_ids: BTreeSet<usize>,
+    /// The first stage of the life cycle, set instead of the unknown statuses
+    fallback_status_id: usize,
+    program_ids: BTreeSet<usize>,
+    missing_files: BTreeSet<String>,
+}
+
+impl BundlePlan {
+    pub(crate) fn status_id(&self, status: &Option<ActualStatus>) -> usize {
+        match status {
+            Some(status) if self.status_ids.contains(&status.actual_status_id) => status.actual_status_id,
+            _ => self.fallback_status_id,
+        }
+    }
+
+    pub(crate) fn has_program(&self, program_id: usize) -> bool {
+        self.program_ids.contains(&program_id)
+    }
+
+    /// Files of the list which are found in the bundle
+    pub(crate) fn present_files(&self, files: &[BundleFile]) -> Vec<BundleFile> {
+        files.iter().filter(|f| !self.missing_files.contains(&f.path)).cloned().collect()
+    }
+}
+
+/// Compares the bundle (the archive data and its manifest) with the data of the server
+pub(crate) fn plan_import(data: &[u8], manifest: &BundleManifest, known: &BundleKnown) -> BundlePlan {
+    let archive = ZipArchive::new(data).ok();
+    let has_file = |path: &str| archive.as_ref().map(|a| a.contains(path)).unwrap_or_default();
+    let component = &manifest.component;
+    let mut plan = BundlePlan {
+        status_ids: known.statuses.iter().map(|s| s.actual_status_id).collect(),
+        fallback_status_id: known.statuses.iter().map(|s| s.actual_status_id).min().unwrap_or(1),
+        ..BundlePlan::default()
+    };
+    for spec in &component.specs {
+        // the names of specs in other languages are not compared
+        match known.specs.iter().any(|s| s.spec_id == spec.spec_id
+            && (s.lang_id != spec.lang_id || name_key(&s.spec) == name_key(&spec.spec))) {
+            true => plan.spec_ids.push(spec.spec_id),
+            false => plan.conflicts.push(BundleConflict::UnknownSpec(spec.spec.clone())),
+        }
+    }
+    for license in &component.licenses {
+        match known.licenses.iter().any(|l| l.id == license.id
+            && (name_key(&l.name) == name_key(&license.name) || name_key(&l.keyword) == name_key(&license.keyword))) {
+            true => plan.license_ids.push(license.id),
+            false => plan.conflicts.push(BundleConflict::UnknownLicense(license.name.clone())),
+        }
+    }
+    let statuses = component.actual_status.iter()
+        .chain(component.modifications.iter().filter_map(|m| m.actual_status.as_ref()));
+    let mut reported_statuses = BTreeSet::new();
+    for status in statuses {
+        if !plan.status_ids.contains(&status.actual_status_id) && reported_statuses.insert(status.actual_status_id) {
+            plan.conflicts.push(BundleConflict::UnknownStatus(status.name.clone()));
+        }
+    }
+    for param in manifest.params() {
+        if !known.params.iter().any(|p| p.param_id == param.param_id && name_key(&p.paramname) == name_key(&param.paramname)) {
+            plan.conflicts.push(BundleConflict::ParamByName(param.paramname));
+        }
+    }
+    let mut reported_programs = BTreeSet::new();
+    for fileset in component.modifications.iter().flat_map(|m| m.filesets.iter()) {
+        let program = &fileset.program;
+        match known.programs.iter().any(|p| p.id == program.id && name_key(&p.name) == name_key(&program.name)) {
+            true => {
+                plan.program_ids.insert(program.id);
+            },
+            false => if reported_programs.insert(program.id) {
+                plan.conflicts.push(BundleConflict::UnknownProgram(program.name.clone()));
+            },
+        }
+    }
+    for file in manifest.files() {
+        if !has_file(&file.path) {
+            plan.missing_files.insert(file.path.clone());
+            plan.conflicts.push(BundleConflict::MissingFile(file.filename.clone()));
+        }
+    }
+    plan.conflicts.extend(component.standards.iter().map(|s| BundleConflict::SkippedStandard(s.name.clone())));
+    plan.conflicts.extend(component.suppliers.iter().map(|s| BundleConflict::SkippedSupplier(s.name.clone())));
+    plan
+}
+
+/// IDs of the parameters on the server with the values of the bundle,
+/// `registered` are the parameters returned by the registration of the names
+pub(crate) fn param_values(params: &[BundleParam], registered: &[Param]) -> Vec<(usize, String)> {
+    params.iter()
+        .filter_map(|p| registered.iter()
+            .find(|r| name_key(&r.paramname) == name_key(&p.param.paramname))
+            .map(|r| (r.param_id, p.value.clone())))
+        .collect()
+}
+
+fn name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn param(param_id: usize, paramname: &str, value: &str) -> BundleParam {
+        BundleParam { param: Param { param_id, lang_id: 1, paramname: paramname.to_string() }, value: value.to_string() }
+    }
+
+    fn status(actual_status_id: usize, name: &str) -> ActualStatus {
+        ActualStatus { actual_status_id, lang_id: 1, name: name.to_string() }
+    }
+
+    fn manifest() -> BundleManifest {
+        let modification_uuid = "20000000-0000-0000-0000-000000000000".to_string();
+        let fileset_of = BundleFileOf::Fileset(modification_uuid.clone(), 5);
+        BundleManifest::new(BundleComponent {
+            uuid: "10000000-0000-0000-0000-000000000000".to_string(),
+            name: "Bracket".to_string(),
+            component_type_id: 2,
+            actual_status: Some(status(2, "Review")),
+            specs: vec![
+                Spec { spec_id: 3, lang_id: 1, spec: "Fasteners".to_string() },
+                Spec { spec_id: 4, lang_id: 1, spec: "Gears".to_string() },
+            ],
+            params: vec![param(7, "Mass", "1 kg")],
+            files: vec![BundleFile::new(&BundleFileOf::Component, "a/b.step")],
+            standards: vec![BundleLink { uuid: String::new(), name: "ISO 4014".to_string() }],
+            modifications: vec![BundleModification {
+                uuid: modification_uuid,
+                actual_status: Some(status(9, "Archived")),
+                params: vec![param(7, "mass ", "2 kg"), param(8, "Length", "10 mm")],
+                filesets: vec![BundleFileset {
+                    program: Program { id: 5, name: "CAM".to_string() },
+                    files: vec![BundleFile::new(&fileset_of, "part.nc")],
+                }],
+                ..BundleModification::default()
+            }],
+            ..BundleComponent::default()
+        })
+    }
+
+    #[test]
+    fn bundle_roundtrip() {
+        let manifest = manifest();
+        let paths: Vec<String> = manifest.files().iter().map(|f| f.path.clone()).collect();
+        assert_eq!(vec![
+            "files/a_b.step".to_string(),
+            "modifications/20000000-0000-0000-0000-000000000000/filesets/5/part.nc".to_string(),
+        ], paths);
+        let data = write_bundle(&manifest, &[(paths[0].clone(), vec![1, 2, 3])]).unwrap();
+        let read = read_bundle(&data).unwrap();
+        assert_eq!("Bracket", read.component.name);
+        assert_eq!(2, read.component.modifications[0].params.len());
+        assert_eq!(2, read.component.component_type_id);
+        // the bundles exported before the type was saved are imported with the base type
+        let mut value = serde_json::to_value(&manifest).unwrap();
+        value["component"].as_object_mut().unwrap().remove("componentTypeId");
+        assert_eq!(1, serde_json::from_value::<BundleManifest>(value).unwrap().component.component_type_id);
+        let files = read_bundle_files(&data, &read.component.files);
+        assert_eq!(vec![("a/b.step".to_string(), vec![1, 2, 3])], files);
+        assert!(read_bundle(&[0, 1, 2]).is_err());
+    }
+
+    #[test]
+    fn bundle_conflicts() {
+        let manifest = manifest();
+        let known = BundleKnown {
+            specs: vec![Spec { spec_id: 3, lang_id: 1, spec: "fasteners".to_string() }],
+            params: vec![Param { param_id: 7, lang_id: 1, paramname: "Mass".to_string() }],
+            statuses: vec![status(2, "Review"), status(1, "Draft")],
+            programs: vec![Program { id: 5, name: "Other".to_string() }],
+            ..BundleKnown::default()
+        };
+        let data = write_bundle(&manifest, &[("files/a_b.step".to_string(), vec![1])]).unwrap();
+        let plan = plan_import(&data, &manifest, &known);
+        assert_eq!(vec![
+            BundleConflict::UnknownSpec("Gears".to_string()),
+            BundleConflict::UnknownStatus("Archived".to_string()),
+            BundleConflict::ParamByName("Length".to_string()),
+            BundleConflict::UnknownProgram("CAM".to_string()),
+            BundleConflict::MissingFile("part.nc".to_string()),
+            BundleConflict::SkippedStandard("ISO 4014".to_string()),
+        ], plan.conflicts);
+        assert_eq!(vec![3], plan.spec_ids);
+        assert_eq!(2, plan.status_id(&manifest.component.actual_status));
+        assert_eq!(1, plan.status_id(&manifest.component.modifications[0].actual_status));
+        assert!(!plan.has_program(5));
+        assert_eq!(1, plan.present_files(&manifest.component.files).len());
+
+        let registered = vec![Param { param_id: 11, lang_id: 1, paramname: "MASS".to_string() }];
+        assert_eq!(vec![(11, "2 kg".to_string())], param_values(&manifest.component.modifications[0].params, &registered));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::types::{ComponentParam, UUID};
+use super::local_storage::{get_compare_components, set_compare_components};
+
+/// Maximum number of the components in the comparison
+pub(crate) const MAX_COMPARE: usize = 4;
+
+pub(crate) fn load_compare() -> Vec<UUID> {
+    get_compare_components().map(|data| parse_uuids(&data)).unwrap_or_default()
+}
+
+pub(crate) fn save_compare(uuids: &[UUID]) {
+    let data = match uuids.is_empty() {
+        true => None,
+        false => Some(uuids.join(",")),
+    };
+    set_compare_components(data);
+}
+
+/// Removes the component from the comparison or adds it if there is room,
+/// returns true if the component is in the comparison after the call
+pub(crate) fn toggle_compare(uuids: &mut Vec<UUID>, uuid: &str) -> bool {
+    if let Some(index) = uuids.iter().position(|u| u == uuid) {
+        uuids.remove(index);
+        return false
+    }
+    if uuids.len() < MAX_COMPARE {
+        uuids.push(uuid.to_string());
+        return true
+    }
+    false
+}
+
+/// Encodes the components in the query of the comparison route (`#/compare?components=uuid,uuid`)
+pub(crate) fn compare_to_query(uuids: &[UUID]) -> String {
+    format!("components={}", uuids.join(","))
+}
+
+/// Parses the components from the query of the comparison route, invalid and repeated UUIDs are skipped
+pub(crate) fn compare_from_query(query: &str) -> Vec<UUID> {
+    query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "components")
+        .map(|(_, value)| parse_uuids(value))
+        .unwrap_or_default()
+}
+
+fn parse_uuids(value: &str) -> Vec<UUID> {
+    let mut uuids: Vec<UUID> = Vec::new();
+    for uuid in value.split(',').map(|u| u.trim()) {
+        if uuid.len() == 36 && !uuids.iter().any(|u| u == uuid) && uuids.len() < MAX_COMPARE {
+            uuids.push(uuid.to_string());
+        }
+    }
+    uuids
+}
+
+/// Values differ if at least one of them (after trimming, ignoring case) is not equal to the others,
+/// a missing value differs from a set one
+pub(crate) fn is_different(values: &[String]) -> bool {
+    let mut values = values.iter().map(|v| v.trim().to_lowercase());
+    match values.next() {
+        Some(first) => values.any(|v| v != first),
+        None => false,
+    }
+}
+
+/// Aligns the parameters of the components by the parameter: each row is the name of the parameter
+/// and the values in the order of the components (empty if the component does not have the parameter)
+pub(crate) fn param_rows(params: &[Vec<ComponentParam>]) -> Vec<(String, Vec<String>)> {
+    let mut rows: Vec<(usize, String, Vec<String>)> = Vec::new();
+    for (index, component_params) in params.iter().enumerate() {
+        for param in component_params {
+            let row = match rows.iter_mut().find(|(id, _, _)| *id == param.param.param_id) {
+                Some(row) => row,
+                None => {
+                    rows.push((param.param.param_id, param.param.paramname.clone(), vec![String::new(); params.len()]));
+                    rows.last_mut().unwrap()
+                },
+            };
+            row.2[index] = param.value.clone();
+        }
+    }
+    rows.into_iter().map(|(_, paramname, values)| (paramname, values)).collect()
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+    use crate::types::Param;
+
+    fn uuid(n: u8) -> UUID {
+        format!("{}0000000-0000-0000-0000-000000000000", n)
+    }
+
+    fn param(param_id: usize, paramname: &str, value: &str) -> ComponentParam {
+        ComponentParam {
+            component_uuid: String::new(),
+            param: Param { param_id, lang_id: 1, paramname: paramname.to_string() },
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn compare_selection_and_query() {
+        let mut uuids = Vec::new();
+        for n in 1..=5 {
+            toggle_compare(&mut uuids, &uuid(n));
+        }
+        assert_eq!(MAX_COMPARE, uuids.len());
+        assert!(!toggle_compare(&mut uuids, &uuid(2)));
+        assert!(toggle_compare(&mut uuids, &uuid(5)));
+        assert_eq!(vec![uuid(1), uuid(3), uuid(4), uuid(5)], uuids);
+
+        let query = format!("{}&other=1", compare_to_query(&uuids[..2]));
+        assert_eq!(vec![uuid(1), uuid(3)], compare_from_query(&query));
+        let repeated = format!("components={0},bad,{0},{1}", uuid(1), uuid(2));
+        assert_eq!(vec![uuid(1), uuid(2)], compare_from_query(&repeated));
+        assert!(compare_from_query("components=").is_empty());
+    }
+
+    #[test]
+    fn compare_params_and_differences() {
+        let params = vec![
+            vec![param(1, "Length", "10"), param(2, "Color", "Red")],
+            vec![param(2, "Color", "red "), param(3, "Weight", "5")],
+        ];
+        let rows = param_rows(&params);
+        assert_eq!(3, rows.len());
+        assert_eq!(("Length".to_string(), vec!["10".to_string(), String::new()]), rows[0]);
+        assert_eq!(vec![String::new(), "5".to_string()], rows[2].1);
+        assert!(is_different(&rows[0].1));
+        assert!(!is_different(&rows[1].1));
+        assert!(!is_different(&[]));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::services::get_value_field;
+
+/// Number of rows used to detect the separator
+const DETECT_ROWS: usize = 10;
+
+/// Separator of the values in a text table (copied from a spreadsheet or read from a CSV file)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ParsingSplit {
+    Tab,
+    Comma,
+    Semicolon,
+    Custom(char),
+}
+
+impl ParsingSplit {
+    /// Separators offered to the user, in the order of preference for the detection
+    pub(crate) const ALL: [ParsingSplit; 3] = [Self::Tab, Self::Semicolon, Self::Comma];
+
+    pub(crate) fn delimiter(&self) -> char {
+        match self {
+            Self::Tab => '\t',
+            Self::Comma => ',',
+            Self::Semicolon => ';',
+            Self::Custom(delimiter) => *delimiter,
+        }
+    }
+
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Self::Tab => get_value_field(&492).to_string(),
+            Self::Comma => get_value_field(&493).to_string(),
+            Self::Semicolon => get_value_field(&494).to_string(),
+            Self::Custom(delimiter) => format!("'{}'", delimiter),
+        }
+    }
+
+    /// Returns the value for the option of the select element
+    pub(crate) fn to_value(self) -> String {
+        match self {
+            Self::Tab => String::from("tab"),
+            Self::Comma => String::from("comma"),
+            Self::Semicolon => String::from("semicolon"),
+            Self::Custom(delimiter) => delimiter.to_string(),
+        }
+    }
+
+    /// Returns the separator from the value of the select element (None for the auto detection)
+    pub(crate) fn from_value(value: &str) -> Option<Self> {
+        let mut chars = value.chars();
+        match (value, chars.next(), chars.next()) {
+            ("tab", _, _) => Some(Self::Tab),
+            ("comma", _, _) => Some(Self::Comma),
+            ("semicolon", _, _) => Some(Self::Semicolon),
+            (_, Some(delimiter), None) => Some(Self::Custom(delimiter)),
+            _ => None,
+        }
+    }
+
+    /// Selects the separator that splits the first rows into the same number of columns,
+    /// the separator with more columns is preferred
+    pub(crate) fn detect(text: &str) -> Self {
+        let mut result = (Self::Tab, 0, false);
+        for split in Self::ALL {
+            let rows = parsing_rows(text, split, Some(DETECT_ROWS), true);
+            let columns = rows.first().map(|row| row.len()).unwrap_or_default();
+            let consistent = columns > 1 && rows.iter().all(|row| row.len() == columns);
+            let (_, best_columns, best_consistent) = result;
+            if (consistent && !best_consistent) || (consistent == best_consistent && columns > best_columns) {
+                result = (split, columns, consistent);
+            }
+        }
+        result.0
+    }
+}
+
+/// Encoding of a text file with a table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TextEncoding {
+    Utf8,
+    Utf16,
+    Windows1251,
+}
+
+impl TextEncoding {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Utf16 => "UTF-16",
+            Self::Windows1251 => "Windows-1251",
+        }
+    }
+}
+
+/// Returns the text of a file: UTF-8 (with or without BOM), UTF-16 LE with BOM (the "Unicode text" of Excel),
+/// otherwise the data is decoded as Windows-1251
+pub(crate) fn decode_text(data: &[u8]) -> (String, TextEncoding) {
+    if let Some(data) = data.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return (String::from_utf8_lossy(data).to_string(), TextEncoding::Utf8)
+    }
+    if let Some(data) = data.strip_prefix(&[0xFF, 0xFE]) {
+        let units = data.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect::<Vec<u16>>();
+        return (String::from_utf16_lossy(&units), TextEncoding::Utf16)
+    }
+    match std::str::from_utf8(data) {
+        Ok(text) => (text.to_string(), TextEncoding::Utf8),
+        Err(_) => (data.iter().map(|byte| windows_1251_char(*byte)).collect(), TextEncoding::Windows1251),
+    }
+}
+
+/// Splits the text into rows of values according to RFC 4180: a value in double quotes
+/// may contain separators, line breaks and escaped quotes (`""`). Empty rows are skipped.
+pub(crate) fn parsing_delimited(text: &str, split: ParsingSplit) -> Vec<Vec<String>> {
+    parsing_rows(text, split, None, true)
+}
+
+/// Splits a block of cells copied from a spreadsheet into rows of values.
+/// Unlike `parsing_delimited`, empty rows inside the block are kept.
+pub(crate) fn parsing_block(text: &str, split: ParsingSplit) -> Vec<Vec<String>> {
+    parsing_rows(text, split, None, false)
+}
+
+/// Joins the rows of values into a text table, values with separators, quotes
+/// or line breaks are written in double quotes (the text is read back by `parsing_delimited`)
+pub(crate) fn write_delimited(rows: &[Vec<String>], split: ParsingSplit) -> String {
+    let delimiter = split.delimiter();
+    let mut text = String::new();
+    for row in rows {
+        for (number, value) in row.iter().enumerate() {
+            if number > 0 {
+                text.push(delimiter);
+            }
+            if value.contains([delimiter, '"', '\n', '\r']) {
+                text.push('"');
+                text.push_str(&value.replace('"', "\"\""));
+                text.push('"');
+            } else {
+                text.push_str(value);
+            }
+        }
+        text.push_str("\r\n");
+    }
+    text
+}
+
+fn parsing_rows(text: &str, split: ParsingSplit, limit: Option<usize>, skip_empty: bool) -> Vec<Vec<String>> {
+    let delimiter = split.delimiter();
+    let mut rows = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut value = String::new();
+    let mut quoted = false;
+    // the value started with a quote, the text after the closing quote is added as is
+    let mut was_quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(ch) = chars.next() {
+        if quoted {
+            match (ch, chars.peek()) {
+                ('"', Some('"')) => {
+                    value.push('"');
+                    chars.next();
+                },
+                ('"', _) => quoted = false,
+                _ => value.push(ch),
+            }
+            continue
+        }
+        match ch {
+            '"' if value.is_empty() && !was_quoted => {
+                quoted = true;
+                was_quoted = true;
+            },
+            '\r' | '\n' => {
+                if ch == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                row.push(std::mem::take(&mut value));
+                was_quoted = false;
+                if !skip_empty || row.iter().any(|v| !v.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                    if limit.is_some_and(|limit| rows.len() >= limit) {
+                        return rows
+                    }
+                }
+                row.clear();
+            },
+            _ if ch == delimiter => {
+                row.push(std::mem::take(&mut value));
+                was_quoted = false;
+            },
+            _ => value.push(ch),
+        }
+    }
+    row.push(value);
+    // the text after the last line break is a row if it has values
+    if row.iter().any(|v| !v.is_empty()) || (!skip_empty && row.len() > 1) {
+        rows.push(row);
+    }
+    rows
+}
+
+fn windows_1251_char(byte: u8) -> char {
+    const UPPER_HALF: [u16; 64] = [
+        0x0402, 0x0403, 0x201A, 0x0453, 0x201E, 0x2026, 0x2020, 0x2021, 0x20AC, 0x2030, 0x0409, 0x2039, 0x040A, 0x040C, 0x040B, 0x040F,
+        0x0452, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014, 0xFFFD, 0x2122, 0x0459, 0x203A, 0x045A, 0x045C, 0x045B, 0x045F,
+        0x00A0, 0x040E, 0x045E, 0x0408, 0x00A4, 0x0490, 0x00A6, 0x00A7, 0x0401, 0x00A9, 0x0404, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x0407,
+        0x00B0, 0x00B1, 0x0406, 0x0456, 0x0491, 0x00B5, 0x00B6, 0x00B7, 0x0451, 0x2116, 0x0454, 0x00BB, 0x0458, 0x0405, 0x0455, 0x0457,
+    ];
+    match byte {
+        0x00..=0x7F => byte as char,
+        0x80..=0xBF => char::from_u32(UPPER_HALF[(byte - 0x80) as usize] as u32).unwrap_or('\u{fffd}'),
+        // А..я
+        _ => char::from_u32(0x0410 + (byte - 0xC0) as u32).unwrap_or('\u{fffd}'),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn parsing_csv_quoted() {
+        let input_test = "[ModificationName],name2,name3\r\npar1,\"22,cq,1\",\"say \"\"hi\"\"\"\r\n\r\npar2,\"line 1\nline 2\",\n";
+
+        let result = parsing_delimited(input_test, ParsingSplit::Comma);
+
+        assert_eq!(
+            vec![
+                vec!["[ModificationName]", "name2", "name3"],
+                vec!["par1", "22,cq,1", "say \"hi\""],
+                vec!["par2", "line 1\nline 2", ""],
+            ],
+            result
+        )
+    }
+
+    #[test]
+    fn write_delimited_round_trip() {
+        let rows = vec![
+            vec![String::from("[ModificationName]"), String::from("Length, mm")],
+            vec![String::from("M6\t\"20\""), String::from("line 1\nline 2")],
+        ];
+
+        let csv = write_delimited(&rows, ParsingSplit::Comma);
+        let tsv = write_delimited(&rows, ParsingSplit::Tab);
+
+        assert_eq!("[ModificationName],\"Length, mm\"\r\n\"M6\t\"\"20\"\"\",\"line 1\nline 2\"\r\n", csv);
+        assert_eq!(rows, parsing_delimited(&csv, ParsingSplit::Comma));
+        assert_eq!(rows, parsing_delimited(&tsv, ParsingSplit::Tab));
+    }
+
+    #[test]
+    fn parsing_block_with_empty_rows() {
+        let result = parsing_block("1\t2\r\n\t\r\n\r\n\"a\nb\"\t\r\n", ParsingSplit::Tab);
+
+        assert_eq!(
+            vec![vec!["1", "2"], vec!["", ""], vec![""], vec!["a\nb", ""]],
+            result
+        )
+    }
+
+    #[test]
+    fn detect_split() {
+        assert_eq!(ParsingSplit::Semicolon, ParsingSplit::detect("name;length\nbolt;1,5\nnut;2,25"));
+        assert_eq!(ParsingSplit::Comma, ParsingSplit::detect("name,length\nbolt,\"1;5\"\nnut,2"));
+        assert_eq!(ParsingSplit::Tab, ParsingSplit::detect("name\tlength, mm\nbolt\t1,5"));
+        assert_eq!(ParsingSplit::Tab, ParsingSplit::detect("name"));
+    }
+
+    #[test]
+    fn decode_text_encodings() {
+        assert_eq!((String::from("a;b"), TextEncoding::Utf8), decode_text(&[0xEF, 0xBB, 0xBF, b'a', b';', b'b']));
+        assert_eq!((String::from("Вес;ё"), TextEncoding::Windows1251), decode_text(&[0xC2, 0xE5, 0xF1, b';', 0xB8]));
+        assert_eq!((String::from("Ок"), TextEncoding::Utf16), decode_text(&[0xFF, 0xFE, 0x1E, 0x04, 0x3A, 0x04]));
+    }
+}
@@ -0,0 +1,97 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
+use log::debug;
+use crate::error::Error;
+
+/// Point of the drawing (in the units of the drawing)
+pub(crate) type Point = (f64, f64);
+
+/// Limits of the drawing zoom (pixels per unit of the drawing)
+const MIN_SCALE: f64 = 1e-6;
+const MAX_SCALE: f64 = 1e6;
+
+/// Position and zoom of a 2D drawing on the screen:
+/// `screen = offset + (x * scale, -y * scale)` (the Y axis of the drawing is directed up)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DrawingView {
+    pub(crate) scale: f64,
+    pub(crate) offset: Point,
+}
+
+impl Default for DrawingView {
+    fn default() -> Self {
+        Self { scale: 1.0, offset: (0.0, 0.0) }
+    }
+}
+
+impl DrawingView {
+    /// Places the bounds of the drawing in the center of the area with a small margin
+    pub(crate) fn fit(bounds: (Point, Point), width: f64, height: f64) -> Self {
+        let ((min_x, min_y), (max_x, max_y)) = bounds;
+        let size = ((max_x - min_x).max(f64::EPSILON), (max_y - min_y).max(f64::EPSILON));
+        let scale = (width * 0.9 / size.0).min(height * 0.9 / size.1).clamp(MIN_SCALE, MAX_SCALE);
+        let center = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+        Self {
+            scale,
+            offset: (width / 2.0 - center.0 * scale, height / 2.0 + center.1 * scale),
+        }
+    }
+
+    /// Changes the zoom keeping the point of the screen `at` in place
+    pub(crate) fn zoom(&mut self, factor: f64, at: Point) {
+        let scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+        let factor = scale / self.scale;
+        self.offset = (at.0 - (at.0 - self.offset.0) * factor, at.1 - (at.1 - self.offset.1) * factor);
+        self.scale = scale;
+    }
+
+    pub(crate) fn pan(&mut self, dx: f64, dy: f64) {
+        self.offset = (self.offset.0 + dx, self.offset.1 + dy);
+    }
+
+    pub(crate) fn to_drawing(self, screen: Point) -> Point {
+        ((screen.0 - self.offset.0) / self.scale, (self.offset.1 - screen.1) / self.scale)
+    }
+
+    pub(crate) fn to_screen(self, point: Point) -> Point {
+        (self.offset.0 + point.0 * self.scale, self.offset.1 - point.1 * self.scale)
+    }
+}
+
+/// Downloads the file of a drawing as text
+pub(crate) async fn load_drawing(url: &str) -> Result<String, Error> {
+    let window = yew::utils::window();
+    let resp_value = JsFuture::from(window.fetch_with_str(url)).await.map_err(|err| {
+        debug!("Failed to fetch drawing: {:?}", err);
+        Error::RequestError
+    })?;
+    let resp: Response = resp_value.dyn_into().map_err(|_| Error::RequestError)?;
+    if !resp.ok() {
+        debug!("Failed to fetch drawing, status: {:?}", resp.status());
+        return Err(Error::RequestError)
+    }
+    let text = resp.text().map_err(|_| Error::RequestError)?;
+    let text = JsFuture::from(text).await.map_err(|_| Error::RequestError)?;
+    text.as_string().ok_or(Error::DeserializeError)
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn drawing_view_fit_and_zoom() {
+        let mut view = DrawingView::fit(((0.0, 0.0), (100.0, 50.0)), 200.0, 200.0);
+
+        assert_eq!(1.8, view.scale);
+        assert_eq!((100.0, 100.0), view.to_screen((50.0, 25.0)));
+
+        let point = view.to_drawing((10.0, 20.0));
+        view.zoom(2.0, (10.0, 20.0));
+        let screen = view.to_screen(point);
+
+        assert_eq!(3.6, view.scale);
+        assert!((screen.0 - 10.0).abs() < 1e-9 && (screen.1 - 20.0).abs() < 1e-9);
+    }
+}
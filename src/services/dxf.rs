@@ -0,0 +1,543 @@
+use std::collections::{BTreeMap, BTreeSet};
+use regex::Regex;
+use web_sys::CanvasRenderingContext2d;
+use crate::services::{DrawingView, Point};
+
+/// Nesting limit of the block inserts (protects against recursive blocks)
+const MAX_INSERT_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DxfShape {
+    Line { start: Point, end: Point },
+    Circle { center: Point, radius: f64 },
+    /// Angles in degrees, counterclockwise from the start to the end
+    Arc { center: Point, radius: f64, start_angle: f64, end_angle: f64 },
+    /// Vertices with the bulge of the segment to the next vertex
+    Polyline { vertices: Vec<(Point, f64)>, closed: bool },
+    Text { position: Point, height: f64, rotation: f64, text: String },
+    /// Dimension is shown by its block, or by the line between the definition points if there is no block
+    Dimension { block: Option<String>, start: Point, end: Point, text_position: Point, text: String },
+    Insert { block: String, position: Point, scale: Point, rotation: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DxfEntity {
+    pub(crate) layer: String,
+    /// AutoCAD color index, `None` for the color of the layer
+    pub(crate) color: Option<i32>,
+    pub(crate) shape: DxfShape,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DxfLayer {
+    pub(crate) name: String,
+    pub(crate) color: i32,
+    /// Layers turned off in the file are hidden initially
+    pub(crate) visible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DxfBlock {
+    base: Point,
+    entities: Vec<DxfEntity>,
+}
+
+/// 2D geometry of a DXF file (the Z coordinates are ignored)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct DxfDrawing {
+    pub(crate) entities: Vec<DxfEntity>,
+    pub(crate) layers: Vec<DxfLayer>,
+    blocks: BTreeMap<String, DxfBlock>,
+}
+
+/// Group of the DXF file: an object type (group code 0) with its values
+struct Record<'a> {
+    kind: &'a str,
+    values: Vec<(i32, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    fn get(&self, code: i32) -> Option<&'a str> {
+        self.values.iter().find(|(c, _)| *c == code).map(|(_, value)| *value)
+    }
+
+    fn float(&self, code: i32) -> f64 {
+        self.get(code).and_then(|value| value.parse().ok()).unwrap_or_default()
+    }
+
+    fn float_or(&self, code: i32, default: f64) -> f64 {
+        self.get(code).and_then(|value| value.parse().ok()).unwrap_or(default)
+    }
+
+    /// Returns the point by the code of X (the code of Y is greater by 10)
+    fn point(&self, code: i32) -> Point {
+        (self.float(code), self.float(code + 10))
+    }
+
+    fn layer(&self) -> String {
+        self.get(8).unwrap_or("0").to_string()
+    }
+
+    fn color(&self) -> Option<i32> {
+        self.get(62).and_then(|value| value.parse().ok()).filter(|color| *color > 0 && *color < 256)
+    }
+}
+
+impl DxfDrawing {
+    /// Parses the text of an ASCII DXF file, unsupported entities are skipped
+    pub(crate) fn parse(content: &str) -> Result<Self, String> {
+        let records = read_records(content)?;
+        let mut drawing = DxfDrawing::default();
+        let mut section = "";
+        let mut block: Option<(String, DxfBlock)> = None;
+        let mut polyline: Option<DxfEntity> = None;
+
+        for record in records.iter() {
+            match (section, record.kind) {
+                (_, "SECTION") => section = record.get(2).unwrap_or_default(),
+                (_, "ENDSEC") => section = "",
+                ("TABLES", "LAYER") => {
+                    if let Some(name) = record.get(2) {
+                        let color = record.get(62).and_then(|value| value.parse::<i32>().ok()).unwrap_or(7);
+                        drawing.layers.push(DxfLayer { name: name.to_string(), color: color.abs(), visible: color >= 0 });
+                    }
+                },
+                ("BLOCKS", "BLOCK") => {
+                    let name = record.get(2).unwrap_or_default().to_string();
+                    block = Some((name, DxfBlock { base: record.point(10), entities: Vec::new() }));
+                },
+                ("BLOCKS", "ENDBLK") => {
+                    if let Some((name, dxf_block)) = block.take() {
+                        drawing.blocks.insert(name, dxf_block);
+                    }
+                },
+                ("BLOCKS", _) | ("ENTITIES", _) => {
+                    let entity = match record.kind {
+                        "VERTEX" => {
+                            if let Some(DxfShape::Polyline { vertices, .. }) = polyline.as_mut().map(|p| &mut p.shape) {
+                                vertices.push((record.point(10), record.float(42)));
+                            }
+                            continue
+                        },
+                        "SEQEND" => match polyline.take() {
+                            Some(entity) => entity,
+                            None => continue,
+                        },
+                        "POLYLINE" => {
+                            polyline = parse_entity(record);
+                            continue
+                        },
+                        _ => match parse_entity(record) {
+                            Some(entity) => entity,
+                            None => continue,
+                        },
+                    };
+                    match block.as_mut() {
+                        Some((_, dxf_block)) => dxf_block.entities.push(entity),
+                        None => drawing.entities.push(entity),
+                    }
+                },
+                _ => (),
+            }
+        }
+
+        // layers used by entities but missing in the table of layers
+        let mut known = drawing.layers.iter().map(|layer| layer.name.clone()).collect::<BTreeSet<String>>();
+        let used = drawing.entities
+            .iter()
+            .chain(drawing.blocks.values().flat_map(|dxf_block| dxf_block.entities.iter()))
+            .map(|entity| entity.layer.clone())
+            .collect::<Vec<String>>();
+        for name in used {
+            if known.insert(name.clone()) {
+                drawing.layers.push(DxfLayer { name, color: 7, visible: true });
+            }
+        }
+        Ok(drawing)
+    }
+
+    /// Returns the minimum and maximum points of the entities (None for an empty drawing)
+    pub(crate) fn bounds(&self) -> Option<(Point, Point)> {
+        let mut bounds = Bounds::default();
+        for entity in self.entities.iter() {
+            self.extend_bounds(&mut bounds, entity, &|point| point, 0);
+        }
+        bounds.0
+    }
+
+    fn extend_bounds(&self, bounds: &mut Bounds, entity: &DxfEntity, transform: &dyn Fn(Point) -> Point, depth: usize) {
+        match &entity.shape {
+            DxfShape::Line { start, end } => {
+                bounds.add(transform(*start));
+                bounds.add(transform(*end));
+            },
+            DxfShape::Circle { center, radius } | DxfShape::Arc { center, radius, .. } => {
+                bounds.add(transform((center.0 - radius, center.1 - radius)));
+                bounds.add(transform((center.0 + radius, center.1 + radius)));
+            },
+            DxfShape::Polyline { vertices, .. } => {
+                for (point, _) in vertices.iter() {
+                    bounds.add(transform(*point));
+                }
+            },
+            DxfShape::Text { position, .. } => bounds.add(transform(*position)),
+            DxfShape::Dimension { start, end, text_position, .. } => {
+                bounds.add(transform(*start));
+                bounds.add(transform(*end));
+                bounds.add(transform(*text_position));
+            },
+            DxfShape::Insert { block, position, scale, rotation } => {
+                let Some(dxf_block) = self.blocks.get(block).filter(|_| depth < MAX_INSERT_DEPTH) else {
+                    return bounds.add(transform(*position))
+                };
+                let (sin, cos) = rotation.to_radians().sin_cos();
+                let insert = |point: Point| {
+                    let (x, y) = ((point.0 - dxf_block.base.0) * scale.0, (point.1 - dxf_block.base.1) * scale.1);
+                    transform((position.0 + x * cos - y * sin, position.1 + x * sin + y * cos))
+                };
+                for block_entity in dxf_block.entities.iter() {
+                    self.extend_bounds(bounds, block_entity, &insert, depth + 1);
+                }
+            },
+        }
+    }
+
+    /// Draws the entities of the visible layers on the canvas
+    pub(crate) fn draw(&self, ctx: &CanvasRenderingContext2d, view: &DrawingView, hidden_layers: &BTreeSet<String>) {
+        let _ = ctx.set_transform(view.scale, 0.0, 0.0, -view.scale, view.offset.0, view.offset.1);
+        ctx.set_line_cap("round");
+        for entity in self.entities.iter().filter(|entity| !hidden_layers.contains(&entity.layer)) {
+            let color = self.entity_color(entity, None);
+            self.draw_entity(ctx, entity, color, 1.0 / view.scale, hidden_layers, 0);
+        }
+        let _ = ctx.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    }
+
+    /// Color of the entity, the entities of blocks with the color "by block" take the color of the insert
+    fn entity_color(&self, entity: &DxfEntity, block_color: Option<i32>) -> i32 {
+        entity.color.or(block_color).unwrap_or_else(|| {
+            self.layers
+                .iter()
+                .find(|layer| layer.name == entity.layer)
+                .map(|layer| layer.color)
+                .unwrap_or(7)
+        })
+    }
+
+    fn draw_entity(
+        &self,
+        ctx: &CanvasRenderingContext2d,
+        entity: &DxfEntity,
+        color: i32,
+        pixel: f64,
+        hidden_layers: &BTreeSet<String>,
+        depth: usize,
+    ) {
+        let css_color = aci_color(color);
+        ctx.set_stroke_style_str(css_color);
+        ctx.set_fill_style_str(css_color);
+        ctx.set_line_width(pixel);
+        match &entity.shape {
+            DxfShape::Line { start, end } => {
+                ctx.begin_path();
+                ctx.move_to(start.0, start.1);
+                ctx.line_to(end.0, end.1);
+                ctx.stroke();
+            },
+            DxfShape::Circle { center, radius } => {
+                ctx.begin_path();
+                let _ = ctx.arc(center.0, center.1, *radius, 0.0, std::f64::consts::TAU);
+                ctx.stroke();
+            },
+            DxfShape::Arc { center, radius, start_angle, end_angle } => {
+                ctx.begin_path();
+                let _ = ctx.arc(center.0, center.1, *radius, start_angle.to_radians(), end_angle.to_radians());
+                ctx.stroke();
+            },
+            DxfShape::Polyline { vertices, closed } => {
+                let Some((first, _)) = vertices.first() else {
+                    return
+                };
+                ctx.begin_path();
+                ctx.move_to(first.0, first.1);
+                let segments = match closed {
+                    true => vertices.len(),
+                    false => vertices.len() - 1,
+                };
+                for index in 0..segments {
+                    let (start, bulge) = vertices[index];
+                    let (end, _) = vertices[(index + 1) % vertices.len()];
+                    match bulge_arc(start, end, bulge) {
+                        Some((center, radius, start_angle, end_angle)) => {
+                            let _ = ctx.arc_with_anticlockwise(center.0, center.1, radius, start_angle, end_angle, bulge < 0.0);
+                        },
+                        None => ctx.line_to(end.0, end.1),
+                    }
+                }
+                ctx.stroke();
+            },
+            DxfShape::Text { position, height, rotation, text } => draw_text(ctx, *position, *height, *rotation, text),
+            DxfShape::Dimension { block, start, end, text_position, text } => {
+                if let Some(block) = block.as_ref().filter(|name| self.blocks.contains_key(*name)) {
+                    let insert = DxfEntity {
+                        layer: entity.layer.clone(),
+                        color: entity.color,
+                        shape: DxfShape::Insert { block: block.clone(), position: (0.0, 0.0), scale: (1.0, 1.0), rotation: 0.0 },
+                    };
+                    return self.draw_entity(ctx, &insert, color, pixel, hidden_layers, depth)
+                }
+                ctx.begin_path();
+                ctx.move_to(start.0, start.1);
+                ctx.line_to(end.0, end.1);
+                ctx.stroke();
+                let length = ((end.0 - start.0).powi(2) + (end.1 - start.1).powi(2)).sqrt();
+                let text = match text.is_empty() || text == "<>" {
+                    true => format!("{:.2}", length),
+                    false => text.replace("<>", &format!("{:.2}", length)),
+                };
+                draw_text(ctx, *text_position, length.max(pixel) / 20.0, 0.0, &text);
+            },
+            DxfShape::Insert { block, position, scale, rotation } => {
+                let Some(dxf_block) = self.blocks.get(block).filter(|_| depth < MAX_INSERT_DEPTH) else {
+                    return
+                };
+                ctx.save();
+                let _ = ctx.translate(position.0, position.1);
+                let _ = ctx.rotate(rotation.to_radians());
+                let _ = ctx.scale(scale.0, scale.1);
+                let _ = ctx.translate(-dxf_block.base.0, -dxf_block.base.1);
+                let pixel = pixel / scale.0.abs().max(scale.1.abs()).max(f64::EPSILON);
+                for block_entity in dxf_block.entities.iter() {
+                    // the entities on the layer "0" are shown on the layer of the insert
+                    if block_entity.layer != "0" && hidden_layers.contains(&block_entity.layer) {
+                        continue
+                    }
+                    let color = self.entity_color(block_entity, Some(color).filter(|_| block_entity.layer == "0"));
+                    self.draw_entity(ctx, block_entity, color, pixel, hidden_layers, depth + 1);
+                }
+                ctx.restore();
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct Bounds(Option<(Point, Point)>);
+
+impl Bounds {
+    fn add(&mut self, point: Point) {
+        if !point.0.is_finite() || !point.1.is_finite() {
+            return
+        }
+        self.0 = Some(match self.0 {
+            Some((min, max)) => ((min.0.min(point.0), min.1.min(point.1)), (max.0.max(point.0), max.1.max(point.1))),
+            None => (point, point),
+        });
+    }
+}
+
+/// Splits the content into groups by the group code 0
+fn read_records(content: &str) -> Result<Vec<Record<'_>>, String> {
+    let mut records: Vec<Record> = Vec::new();
+    let mut lines = content.lines();
+    while let Some(code) = lines.next() {
+        let code = code.trim();
+        if code.is_empty() {
+            continue
+        }
+        let code = code.parse::<i32>().map_err(|_| format!("Invalid group code: {}", code))?;
+        let value = lines.next().ok_or_else(|| format!("Missing value of the group code {}", code))?.trim();
+        match (code, records.last_mut()) {
+            (0, _) => records.push(Record { kind: value, values: Vec::new() }),
+            (_, Some(record)) => record.values.push((code, value)),
+            (_, None) => (),
+        }
+    }
+    match records.iter().any(|record| record.kind == "EOF" || record.kind == "SECTION") {
+        true => Ok(records),
+        false => Err(String::from("No DXF sections found")),
+    }
+}
+
+fn parse_entity(record: &Record) -> Option<DxfEntity> {
+    let shape = match record.kind {
+        "LINE" => DxfShape::Line { start: record.point(10), end: record.point(11) },
+        "CIRCLE" => DxfShape::Circle { center: record.point(10), radius: record.float(40) },
+        "ARC" => DxfShape::Arc {
+            center: record.point(10),
+            radius: record.float(40),
+            start_angle: record.float(50),
+            end_angle: record.float(51),
+        },
+        "LWPOLYLINE" => {
+            let mut vertices: Vec<(Point, f64)> = Vec::new();
+            for (code, value) in record.values.iter() {
+                let value = value.parse::<f64>().unwrap_or_default();
+                match (code, vertices.last_mut()) {
+                    (10, _) => vertices.push(((value, 0.0), 0.0)),
+                    (20, Some(vertex)) => vertex.0.1 = value,
+                    (42, Some(vertex)) => vertex.1 = value,
+                    _ => (),
+                }
+            }
+            DxfShape::Polyline { vertices, closed: (record.float(70) as i32 & 1) == 1 }
+        },
+        "POLYLINE" => DxfShape::Polyline { vertices: Vec::new(), closed: (record.float(70) as i32 & 1) == 1 },
+        "TEXT" => DxfShape::Text {
+            position: record.point(10),
+            height: record.float(40),
+            rotation: record.float(50),
+            text: record.get(1).unwrap_or_default().to_string(),
+        },
+        "MTEXT" => {
+            let mut text = record.values
+                .iter()
+                .filter(|(code, _)| *code == 3)
+                .map(|(_, value)| *value)
+                .collect::<String>();
+            text.push_str(record.get(1).unwrap_or_default());
+            let rotation = match record.get(11) {
+                Some(_) => record.float(21).atan2(record.float(11)).to_degrees(),
+                None => record.float(50),
+            };
+            DxfShape::Text { position: record.point(10), height: record.float(40), rotation, text: clean_mtext(&text) }
+        },
+        "DIMENSION" => DxfShape::Dimension {
+            block: record.get(2).map(|name| name.to_string()),
+            start: record.point(13),
+            end: record.point(14),
+            text_position: record.point(11),
+            text: record.get(1).unwrap_or_default().to_string(),
+        },
+        "INSERT" => DxfShape::Insert {
+            block: record.get(2)?.to_string(),
+            position: record.point(10),
+            scale: (record.float_or(41, 1.0), record.float_or(42, 1.0)),
+            rotation: record.float(50),
+        },
+        _ => return None,
+    };
+    Some(DxfEntity { layer: record.layer(), color: record.color(), shape })
+}
+
+/// Removes the formatting codes of the multiline text
+fn clean_mtext(text: &str) -> String {
+    let re = Regex::new(r"\\[ACcFfHQTW][^;]*;|\\[LlOoKk]|[{}]").unwrap();
+    re.replace_all(text, "").replace("\\P", "\n").replace("\\~", " ")
+}
+
+/// Returns the center, the radius and the angles (in radians) of the polyline segment with a bulge
+fn bulge_arc(start: Point, end: Point, bulge: f64) -> Option<(Point, f64, f64, f64)> {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let chord = (dx * dx + dy * dy).sqrt();
+    if bulge.abs() < 1e-9 || chord < f64::EPSILON {
+        return None
+    }
+    // distance from the middle of the chord to the center along the left normal
+    let offset = chord / 2.0 * (1.0 - bulge * bulge) / (2.0 * bulge);
+    let center = ((start.0 + end.0) / 2.0 - dy / chord * offset, (start.1 + end.1) / 2.0 + dx / chord * offset);
+    let radius = chord * (1.0 + bulge * bulge) / (4.0 * bulge.abs());
+    let start_angle = (start.1 - center.1).atan2(start.0 - center.0);
+    let end_angle = (end.1 - center.1).atan2(end.0 - center.0);
+    Some((center, radius, start_angle, end_angle))
+}
+
+fn draw_text(ctx: &CanvasRenderingContext2d, position: Point, height: f64, rotation: f64, text: &str) {
+    if height <= 0.0 {
+        return
+    }
+    ctx.save();
+    let _ = ctx.translate(position.0, position.1);
+    let _ = ctx.rotate(rotation.to_radians());
+    // the font is set in a large size and scaled down, small font sizes are rounded by browsers
+    let _ = ctx.scale(height / 100.0, -height / 100.0);
+    ctx.set_font("100px sans-serif");
+    for (index, line) in text.lines().enumerate() {
+        let _ = ctx.fill_text(line, 0.0, index as f64 * 150.0);
+    }
+    ctx.restore();
+}
+
+/// Returns the CSS color for the AutoCAD color index (white is shown as black on the light background)
+fn aci_color(color: i32) -> &'static str {
+    match color {
+        1 => "#ff0000",
+        2 => "#c8b400",
+        3 => "#00a000",
+        4 => "#00a0a0",
+        5 => "#0000ff",
+        6 => "#c000c0",
+        8 => "#808080",
+        9 => "#b0b0b0",
+        _ => "#000000",
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    const DXF_SAMPLE: &str = "0\nSECTION\n2\nTABLES\n0\nTABLE\n2\nLAYER\n0\nLAYER\n2\nOUTLINE\n62\n5\n0\nLAYER\n2\nHIDDEN\n62\n-3\n0\nENDTAB\n0\nENDSEC\n\
+0\nSECTION\n2\nBLOCKS\n0\nBLOCK\n2\nBOLT\n10\n0.0\n20\n0.0\n0\nCIRCLE\n8\n0\n10\n0.0\n20\n0.0\n40\n2.0\n0\nENDBLK\n0\nENDSEC\n\
+0\nSECTION\n2\nENTITIES\n\
+0\nLINE\n8\nOUTLINE\n10\n0.0\n20\n0.0\n11\n100.0\n21\n0.0\n\
+0\nARC\n8\nOUTLINE\n62\n1\n10\n50.0\n20\n0.0\n40\n10.0\n50\n0.0\n51\n180.0\n\
+0\nLWPOLYLINE\n8\nHIDDEN\n90\n3\n70\n1\n10\n0.0\n20\n0.0\n42\n1.0\n10\n10.0\n20\n0.0\n10\n10.0\n20\n-20.0\n\
+0\nPOLYLINE\n8\nOUTLINE\n70\n0\n0\nVERTEX\n10\n1.0\n20\n1.0\n0\nVERTEX\n10\n2.0\n20\n2.0\n0\nSEQEND\n\
+0\nMTEXT\n8\nNOTES\n10\n5.0\n20\n30.0\n40\n2.5\n1\n{\\fArial|b0;Part}\\PNo. 1\n\
+0\nINSERT\n8\nOUTLINE\n2\nBOLT\n10\n120.0\n20\n10.0\n41\n2.0\n42\n2.0\n\
+0\nDIMENSION\n8\nOUTLINE\n13\n0.0\n23\n0.0\n14\n100.0\n24\n0.0\n11\n50.0\n21\n-5.0\n1\n<>\n\
+0\nENDSEC\n0\nEOF\n";
+
+    #[test]
+    fn dxf_parse_entities() {
+        let drawing = DxfDrawing::parse(DXF_SAMPLE).unwrap();
+
+        assert_eq!(7, drawing.entities.len());
+        assert_eq!(
+            DxfShape::Arc { center: (50.0, 0.0), radius: 10.0, start_angle: 0.0, end_angle: 180.0 },
+            drawing.entities[1].shape
+        );
+        assert_eq!(Some(1), drawing.entities[1].color);
+        assert_eq!(
+            DxfShape::Polyline { vertices: vec![((0.0, 0.0), 1.0), ((10.0, 0.0), 0.0), ((10.0, -20.0), 0.0)], closed: true },
+            drawing.entities[2].shape
+        );
+        assert_eq!(
+            DxfShape::Polyline { vertices: vec![((1.0, 1.0), 0.0), ((2.0, 2.0), 0.0)], closed: false },
+            drawing.entities[3].shape
+        );
+        assert_eq!(
+            DxfShape::Text { position: (5.0, 30.0), height: 2.5, rotation: 0.0, text: String::from("Part\nNo. 1") },
+            drawing.entities[4].shape
+        );
+        // the layer "NOTES" is missing in the table, the layer "HIDDEN" is turned off
+        let layers = drawing.layers.iter().map(|l| (l.name.as_str(), l.color, l.visible)).collect::<Vec<_>>();
+        assert_eq!(vec![("OUTLINE", 5, true), ("HIDDEN", 3, false), ("NOTES", 7, true), ("0", 7, true)], layers);
+    }
+
+    #[test]
+    fn dxf_bounds_with_inserts() {
+        let drawing = DxfDrawing::parse(DXF_SAMPLE).unwrap();
+
+        // the circle of the block is scaled twice at the insertion point
+        assert_eq!(Some(((0.0, -20.0), (124.0, 30.0))), drawing.bounds());
+    }
+
+    #[test]
+    fn dxf_bulge_arc() {
+        let (center, radius, start_angle, end_angle) = bulge_arc((1.0, 0.0), (0.0, 1.0), (std::f64::consts::PI / 8.0).tan()).unwrap();
+
+        assert!(center.0.abs() < 1e-9 && center.1.abs() < 1e-9);
+        assert!((radius - 1.0).abs() < 1e-9);
+        assert!(start_angle.abs() < 1e-9);
+        assert!((end_angle - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+        assert_eq!(None, bulge_arc((0.0, 0.0), (1.0, 0.0), 0.0));
+    }
+
+    #[test]
+    fn dxf_invalid_content() {
+        assert!(DxfDrawing::parse("solid cube\nfacet normal 0 0 1").is_err());
+        assert!(DxfDrawing::parse("").is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Blob, BlobPropertyBag, HtmlElement, Url};
+use js_sys::{Array, Uint8Array};
+use log::debug;
+
+/// Returns a temporary local URL for the data (for example, to show a generated image)
+pub(crate) fn create_object_url(data: &[u8], mime_type: &str) -> Option<String> {
+    let parts = Array::new();
+    parts.push(&Uint8Array::from(data));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options).ok()?;
+    match Url::create_object_url_with_blob(&blob) {
+        Ok(url) => Some(url),
+        Err(err) => {
+            debug!("Failed to create object URL: {:?}", err);
+            None
+        },
+    }
+}
+
+/// Releases a URL created by `create_object_url`
+pub(crate) fn revoke_object_url(url: &str) {
+    if let Err(err) = Url::revoke_object_url(url) {
+        debug!("Failed to revoke object URL: {:?}", err);
+    }
+}
+
+/// Starts the download of the data as a file with the name.
+/// Returns the URL of the data, which should be released by `revoke_object_url` later.
+pub(crate) fn save_as_file(data: &[u8], mime_type: &str, filename: &str) -> Option<String> {
+    let url = create_object_url(data, mime_type)?;
+    let anchor = yew::utils::document().create_element("a").ok()?;
+    anchor.set_attribute("href", &url).ok()?;
+    anchor.set_attribute("download", filename).ok()?;
+    match anchor.dyn_into::<HtmlElement>() {
+        Ok(anchor) => anchor.click(),
+        Err(err) => debug!("Failed to save file: {:?}", err),
+    }
+    Some(url)
+}
@@ -14,6 +14,10 @@ const ACCEPT_LANGUAGE: &str = dotenv!("ACCEPT_LANGUAGE");
 const LIST_VIEW_TYPE: &str = dotenv!("LIST_VIEW_TYPE");
 const HISTORY_BACK: &str = dotenv!("HISTORY_BACK");
 const HISTORY_SEARCH: &str = dotenv!("HISTORY_SEARCH");
+const PARAM_TEMPLATES: &str = dotenv!("PARAM_TEMPLATES");
+const BOM_LINES: &str = dotenv!("BOM_LINES");
+const COMPARE_COMPONENTS: &str = dotenv!("COMPARE_COMPONENTS");
+const SAVED_SEARCHES: &str = dotenv!("SAVED_SEARCHES");
 
 lazy_static! {
     /// REST API server location setting
@@ -95,6 +99,36 @@ lazy_static! {
             RwLock::new(None)
         }
     };
+
+    /// Templates of the parameters created by the user
+    pub static ref PARAMTEMPLATES: RwLock<Option<String>> = {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled by the user");
+        if let Ok(param_templates) = storage.restore(PARAM_TEMPLATES) {
+            RwLock::new(Some(param_templates))
+        } else {
+            RwLock::new(None)
+        }
+    };
+
+    /// Lines of the bill of materials collected by the user
+    pub static ref BOMLINES: RwLock<Option<String>> = {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled by the user");
+        if let Ok(bom_lines) = storage.restore(BOM_LINES) {
+            RwLock::new(Some(bom_lines))
+        } else {
+            RwLock::new(None)
+        }
+    };
+
+    /// Components selected by the user for the comparison
+    pub static ref COMPARECOMPONENTS: RwLock<Option<String>> = {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled by the user");
+        if let Ok(compare_components) = storage.restore(COMPARE_COMPONENTS) {
+            RwLock::new(Some(compare_components))
+        } else {
+            RwLock::new(None)
+        }
+    };
 }
 
 // Generic storage helper functions, saves value to storage
@@ -185,6 +219,58 @@ pub fn get_history_search() -> Option<String> {
     get_storage(&HISTORYSEARCH)
 }
 
+/// Saves the templates of the parameters (JSON) to local storage
+pub fn set_param_templates(param_templates: Option<String>) {
+    set_storage(PARAM_TEMPLATES, param_templates, &PARAMTEMPLATES);
+}
+
+/// Retrieves the templates of the parameters (JSON)
+pub fn get_param_templates() -> Option<String> {
+    get_storage(&PARAMTEMPLATES)
+}
+
+/// Saves the lines of the bill of materials (JSON) to local storage
+pub fn set_bom_lines(bom_lines: Option<String>) {
+    set_storage(BOM_LINES, bom_lines, &BOMLINES);
+}
+
+/// Retrieves the lines of the bill of materials (JSON)
+pub fn get_bom_lines() -> Option<String> {
+    get_storage(&BOMLINES)
+}
+
+/// Saves the UUIDs of the components selected for the comparison (separated by commas) to local storage
+pub fn set_compare_components(compare_components: Option<String>) {
+    set_storage(COMPARE_COMPONENTS, compare_components, &COMPARECOMPONENTS);
+}
+
+/// Retrieves the UUIDs of the components selected for the comparison
+pub fn get_compare_components() -> Option<String> {
+    get_storage(&COMPARECOMPONENTS)
+}
+
+/// Key of the searches of the logged user, the searches are not kept without the login
+fn saved_searches_key() -> Option<String> {
+    get_logged_user().map(|user| format!("{}.{}", SAVED_SEARCHES, user.uuid))
+}
+
+/// Saves the searches of the logged user (JSON) to local storage
+pub fn set_saved_searches(saved_searches: Option<String>) {
+    if let Some(key) = saved_searches_key() {
+        let mut storage = StorageService::new(Area::Local).expect("storage was disabled by the user");
+        match saved_searches {
+            Some(v) => storage.store(&key, Ok(v)),
+            None => storage.remove(&key),
+        }
+    }
+}
+
+/// Retrieves the searches of the logged user (JSON), the key depends on the user so they are read from storage
+pub fn get_saved_searches() -> Option<String> {
+    let storage = StorageService::new(Area::Local).expect("storage was disabled by the user");
+    saved_searches_key().and_then(|key| storage.restore::<Result<String, _>>(&key).ok())
+}
+
 // Server location settings
 /// Sets the REST API server location in local storage
 pub fn set_server_location(server: Option<String>) {
@@ -462,5 +462,256 @@ lazy_static! {
             (455, " file(s) uploaded successfully"),
             (456, " file(s) failed to upload"),
             (457, "Upload URL not found"),
+            (458, "Generate preview image from 3D model"),
+            (459, "Select a modification, a file set and a model (STL or GLB) to render the preview image from the selected view."),
+            (460, "Generate"),
+            (461, "No STL or GLB models found in the selected file set"),
+            (462, "Use as preview image"),
+            (463, "Modification"),
+            (464, "Compare models"),
+            (465, "Side by side"),
+            (466, "Overlay"),
+            (467, "Translucent"),
+            (468, "matches"),
+            (469, "Small deviation"),
+            (470, "Large deviation"),
+            (471, "Compare with the active revision"),
+            (472, "Copy view link"),
+            (473, "The link opens the component with the selected modification, file and camera position"),
+            (474, "Pin an annotation on the model"),
+            (475, "Click on the model to place the pin"),
+            (476, "Comment on the pin"),
+            (477, "Place the pin on the model first"),
+            (478, "Annotation on the 3D model"),
+            (479, "Show on the model"),
+            (480, "Fit to view"),
+            (481, "Zoom in"),
+            (482, "Zoom out"),
+            (483, "Layers"),
+            (484, "Measure distance"),
+            (485, "Click two points on the drawing"),
+            (486, "Distance"),
+            (487, "Failed to read the drawing"),
+            (488, "Drag: pan | Wheel: zoom"),
+            (489, "File to view"),
+            (490, "Separator"),
+            (491, "Auto"),
+            (492, "Tab"),
+            (493, "Comma (,)"),
+            (494, "Semicolon (;)"),
+            (495, "Or load a CSV file"),
+            (496, "Encoding"),
+            (497, "Check the data before importing (only the first rows are shown)"),
+            (498, "Sheet"),
+            (499, "Header row"),
+            (500, "Select what each column contains"),
+            (501, "Skip"),
+            (502, "Actual status ID"),
+            (503, "New"),
+            (504, "Exists"),
+            (505, "Skip existing modifications"),
+            (506, "Current value:"),
+            (507, "Select the column with modification names"),
+            (508, "Or load a file (CSV, XLSX, ODS)"),
+            (509, "Failed to read the file"),
+            (510, "Next"),
+            (511, "Will be imported"),
+            (512, "Export modifications"),
+            (513, "Copy as TSV"),
+            (514, "Modifications loaded"),
+            (515, "Grid editing"),
+            (516, "Save all"),
+            (517, "Fill down"),
+            (518, "Discard changes"),
+            (519, "Changed cells"),
+            (520, "Arrows, Tab, Enter: move | Enter, F2 or typing: edit | Shift: select | Ctrl+V: paste from a spreadsheet | Ctrl+D: fill down | Delete: clear"),
+            (521, "Generate modifications"),
+            (522, "Key"),
+            (523, "Values"),
+            (524, "Values are separated by commas, ranges are written as 6..60 or 6..60 step 2"),
+            (525, "Exclusion rules"),
+            (526, "One rule per line, conditions are joined with &, for example: d=M3 & l>30. The value of other parameter is written in braces: l<{d}"),
+            (527, "Name template"),
+            (528, "Parameter keys in braces are replaced with values, for example: M{d}x{l}"),
+            (529, "Add parameter"),
+            (530, "Combinations"),
+            (531, "Excluded"),
+            (532, "Duplicate name"),
+            (533, "Create modifications"),
+            (534, "Lineage"),
+            (535, "Show all modifications"),
+            (536, "Filter the table by the modification and derived ones"),
+            (537, "Create derived modification"),
+            (538, "Parent modification"),
+            (539, "Copy parameters"),
+            (540, "Copy filesets"),
+            (541, "Filesets are created for the same programs, files are uploaded to them separately"),
+            (542, "Compare parameters"),
+            (543, "Hide identical"),
+            (544, "Missing in this modification"),
+            (545, "Fileset files"),
+            (546, "No differences"),
+            (547, "Check two or more modifications in the table to compare them"),
+            (548, "Filters"),
+            (549, "Sort by the column, click again to change the order"),
+            (550, "From"),
+            (551, "To"),
+            (552, "Any"),
+            (553, "Filters and sorting are saved in the page link, so the selection can be shared"),
+            (554, "Contains"),
+            (555, "The value must be a number, the unit can be written after it"),
+            (556, "Unknown unit"),
+            (557, "The unit does not match the unit of the parameter"),
+            (558, "Unit of the parameter"),
+            (559, "Saved as"),
+            (560, "To check the values, write the unit in square brackets at the end of the name, for example: Length [mm]"),
+            (561, "Metric"),
+            (562, "Imperial"),
+            (563, "Show values in metric or imperial units"),
+            (564, "Invalid values"),
+            (565, "Parameter templates"),
+            (566, "Apply"),
+            (567, "Template name"),
+            (568, "Without category"),
+            (569, "Template parameters"),
+            (570, "Save template"),
+            (571, "There are no templates yet, create one from the list of parameters"),
+            (572, "Move up"),
+            (573, "Category"),
+            (574, "Parameters already added are skipped when the template is applied"),
+            (575, "Fork"),
+            (576, "Fork this component"),
+            (577, "Copy modifications with their parameters"),
+            (578, "Derived from"),
+            (579, "Derived components"),
+            (580, "Find among the components of the owner and yours"),
+            (581, "No derived components found"),
+            (582, "Parent component"),
+            (583, "UUID or link of the component from which the new one is derived"),
+            (584, "Component not found"),
+            (585, "Fork of"),
+            (586, "The description, catalogs, keywords, licenses and parameters are copied to the new component"),
+            (587, "Open the new component"),
+            (588, "Duplicate"),
+            (589, "Duplicate component"),
+            (590, "Copy files (the files are downloaded and uploaded again)"),
+            (591, "Creating the component"),
+            (592, "Copying specs, keywords, licenses, standards, suppliers and parameters"),
+            (593, "Copying modifications and filesets"),
+            (594, "Copying files"),
+            (595, "Confirming the uploaded files"),
+            (596, "Deleting the partially created component"),
+            (597, "Copying failed, the created component has been deleted"),
+            (598, "requests completed"),
+            (599, "Everything is copied to a new component with the same access and status. If copying fails, the new component is deleted."),
+            (600, "Add to BOM"),
+            (601, "Add the modification to the bill of materials"),
+            (602, "Bill of materials"),
+            (603, "Quantity"),
+            (604, "Note"),
+            (605, "Added to the bill of materials."),
+            (606, "Open the bill of materials"),
+            (607, "Without supplier"),
+            (608, "Lines"),
+            (609, "Total quantity"),
+            (610, "The bill of materials is empty. Add modifications from the table of modifications of a component or from the search results."),
+            (611, "This bill of materials is opened from a link. Add it to yours to keep the changes."),
+            (612, "Replace my BOM"),
+            (613, "Add to my BOM"),
+            (614, "Component UUID"),
+            (615, "Modification UUID"),
+            (616, "Compare"),
+            (617, "Add to the comparison"),
+            (618, "In comparison"),
+            (619, "Comparison of components"),
+            (620, "Open the comparison"),
+            (621, "Only differences"),
+            (622, "Select from 2 to 4 components for the comparison in the search results, favourites or catalog of a company."),
+            (623, "Remove from the comparison"),
+            (624, "Licenses"),
+            (625, "Specifications"),
+            (626, "Parameters"),
+            (627, "Files"),
+            (628, "Subscribers"),
+            (629, "This comparison is opened from a link."),
+            (630, "Save as my comparison"),
+            (631, "The maximum number of components in the comparison:"),
+            (632, "Counts"),
+            (633, "Status history"),
+            (634, "Note to the status change"),
+            (635, "Summary of the change, then an empty line and details if needed"),
+            (636, "Write a note to the status change"),
+            (637, "The summary (the first line) is longer than"),
+            (638, "Separate the details from the summary with an empty line"),
+            (639, "No status changes yet"),
+            (640, "The status can be changed to the next or the previous stage of the life cycle or to the last stage. The change is recorded in the discussion."),
+            (641, "Export bundle"),
+            (642, "Export the component with its parameters, modifications, filesets and files as a ZIP bundle"),
+            (643, "Import bundle"),
+            (644, "Create a component from a ZIP bundle exported from this or another server"),
+            (645, "Spec not found on the server, it is skipped"),
+            (646, "License not found on the server, it is skipped"),
+            (647, "Status not found on the server, the first stage of the life cycle is set"),
+            (648, "Parameter not found by ID, it is matched by the name or created"),
+            (649, "Program of the fileset not found on the server, the fileset is skipped"),
+            (650, "File is missing in the bundle, it is skipped"),
+            (651, "Standard is not imported, add it after the import"),
+            (652, "Supplier is not imported, add it after the import"),
+            (653, "No conflicts found, the bundle can be imported as is"),
+            (654, "Conflicts of the bundle with the data of the server"),
+            (655, "Bundle file (.zip)"),
+            (656, "Collecting the data and files of the component"),
+            (657, "Importing specs, keywords, licenses and parameters"),
+            (658, "Importing modifications and filesets"),
+            (659, "Uploading files"),
+            (660, "Importing failed, the created component has been deleted"),
+            (661, "Checking the bundle"),
+            (662, "Select all"),
+            (663, "selected"),
+            (664, "Change access type"),
+            (665, "Add keywords"),
+            (666, "Remove keywords"),
+            (667, "Add a spec"),
+            (668, "Attach a standard"),
+            (669, "Follow"),
+            (670, "Unfollow"),
+            (671, "Keywords separated by commas"),
+            (672, "Search a spec by name"),
+            (673, "Choose a standard"),
+            (674, "processed"),
+            (675, "Errors"),
+            (676, "The selected items will be deleted, this cannot be undone. Number of items:"),
+            (677, "Done without errors"),
+            (678, "Action for the selected items"),
+            (679, "Catalog (spec) by name"),
+            (680, "Keyword"),
+            (681, "Owner username"),
+            (682, "Company name or UUID"),
+            (683, "Standard classifier or name"),
+            (684, "Only favorites: yes or no"),
+            (685, "Parameter value: name=value"),
+            (687, "The filter has no value"),
+            (688, "The closing quote is missing"),
+            (689, "Expected yes or no"),
+            (690, "Expected param:name=value"),
+            (691, "Nothing found for the filter"),
+            (692, "Tab to complete"),
+            (693, "Companies"),
+            (694, "Users"),
+            (695, "All"),
+            (696, "Show all"),
+            (697, "Save search"),
+            (698, "Name of the search"),
+            (699, "Saved searches"),
+            (700, "new parts in"),
+            (701, "updated parts in"),
+            (702, "Pin to the side menu"),
+            (703, "Unpin from the side menu"),
+            (704, "Skipped (exists)"),
+            (705, "Update"),
+            (706, "Modifications updated:"),
+            (707, "No upload link for the file"),
+            (708, "Number of the created modifications does not match the copied ones"),
+            (709, "Only the modifications of the current page are edited in the grid, switch the page to edit the others"),
         ]);
 }
@@ -462,5 +462,256 @@ lazy_static! {
             (455, " файл(ов) успешно загружено"),
             (456, " файл(ов) не удалось загрузить"),
             (457, "Ссылка для загрузки не найдена"),
+            (458, "Создать изображение для предпросмотра из 3D-модели"),
+            (459, "Выберите модификацию, набор файлов и модель (STL или GLB), чтобы получить изображение для предпросмотра с выбранного вида."),
+            (460, "Создать"),
+            (461, "В выбранном наборе файлов нет моделей STL или GLB"),
+            (462, "Использовать для предпросмотра"),
+            (463, "Модификация"),
+            (464, "Сравнить модели"),
+            (465, "Рядом"),
+            (466, "Наложение"),
+            (467, "Полупрозрачная"),
+            (468, "совпадает"),
+            (469, "Небольшое отклонение"),
+            (470, "Большое отклонение"),
+            (471, "Сравнить с активной ревизией"),
+            (472, "Копировать ссылку на вид"),
+            (473, "Ссылка откроет компонент с выбранной модификацией, файлом и положением камеры"),
+            (474, "Закрепить аннотацию на модели"),
+            (475, "Щёлкните по модели, чтобы поставить метку"),
+            (476, "Прокомментировать метку"),
+            (477, "Сначала поставьте метку на модели"),
+            (478, "Аннотация на 3D-модели"),
+            (479, "Показать на модели"),
+            (480, "Вписать в окно"),
+            (481, "Увеличить"),
+            (482, "Уменьшить"),
+            (483, "Слои"),
+            (484, "Измерить расстояние"),
+            (485, "Выберите две точки на чертеже"),
+            (486, "Расстояние"),
+            (487, "Не удалось прочитать чертёж"),
+            (488, "Перетаскивание: сдвиг | Колесо: масштаб"),
+            (489, "Файл для просмотра"),
+            (490, "Разделитель"),
+            (491, "Автоматически"),
+            (492, "Табуляция"),
+            (493, "Запятая (,)"),
+            (494, "Точка с запятой (;)"),
+            (495, "Или загрузите CSV-файл"),
+            (496, "Кодировка"),
+            (497, "Проверьте данные перед импортом (показаны только первые строки)"),
+            (498, "Лист"),
+            (499, "Строка заголовков"),
+            (500, "Укажите, что содержит каждый столбец"),
+            (501, "Пропустить"),
+            (502, "ID статуса"),
+            (503, "Новая"),
+            (504, "Существует"),
+            (505, "Пропустить существующие модификации"),
+            (506, "Текущее значение:"),
+            (507, "Выберите столбец с наименованиями модификаций"),
+            (508, "Или загрузите файл (CSV, XLSX, ODS)"),
+            (509, "Не удалось прочитать файл"),
+            (510, "Далее"),
+            (511, "Будет импортировано"),
+            (512, "Экспорт модификаций"),
+            (513, "Копировать как TSV"),
+            (514, "Загружено модификаций"),
+            (515, "Редактирование таблицей"),
+            (516, "Сохранить все"),
+            (517, "Заполнить вниз"),
+            (518, "Отменить изменения"),
+            (519, "Изменено ячеек"),
+            (520, "Стрелки, Tab, Enter: перемещение | Enter, F2 или ввод: редактирование | Shift: выделение | Ctrl+V: вставка из таблицы | Ctrl+D: заполнить вниз | Delete: очистить"),
+            (521, "Сгенерировать модификации"),
+            (522, "Ключ"),
+            (523, "Значения"),
+            (524, "Значения разделяются запятыми, диапазоны записываются как 6..60 или 6..60 step 2"),
+            (525, "Правила исключения"),
+            (526, "Одно правило в строке, условия объединяются через &, например: d=M3 & l>30. Значение другого параметра записывается в фигурных скобках: l<{d}"),
+            (527, "Шаблон имени"),
+            (528, "Ключи параметров в фигурных скобках заменяются значениями, например: M{d}x{l}"),
+            (529, "Добавить параметр"),
+            (530, "Комбинации"),
+            (531, "Исключено"),
+            (532, "Повтор имени"),
+            (533, "Создать модификации"),
+            (534, "Происхождение"),
+            (535, "Показать все модификации"),
+            (536, "Отфильтровать таблицу по модификации и производным от неё"),
+            (537, "Создать производную модификацию"),
+            (538, "Родительская модификация"),
+            (539, "Копировать параметры"),
+            (540, "Копировать наборы файлов"),
+            (541, "Наборы файлов создаются для тех же программ, файлы загружаются в них отдельно"),
+            (542, "Сравнить параметры"),
+            (543, "Скрыть одинаковые"),
+            (544, "Отсутствует в этой модификации"),
+            (545, "Файлы наборов"),
+            (546, "Различий нет"),
+            (547, "Отметьте в таблице две или более модификации, чтобы сравнить их"),
+            (548, "Фильтры"),
+            (549, "Сортировать по столбцу, повторное нажатие меняет порядок"),
+            (550, "От"),
+            (551, "До"),
+            (552, "Любая"),
+            (553, "Фильтры и сортировка сохраняются в ссылке на страницу, поэтому выборкой можно поделиться"),
+            (554, "Содержит"),
+            (555, "Значение должно быть числом, после него можно указать единицу измерения"),
+            (556, "Неизвестная единица измерения"),
+            (557, "Единица измерения не соответствует единице параметра"),
+            (558, "Единица измерения параметра"),
+            (559, "Будет сохранено как"),
+            (560, "Для проверки значений укажите единицу измерения в квадратных скобках в конце наименования, например: Длина [mm]"),
+            (561, "Метрические"),
+            (562, "Имперские"),
+            (563, "Показать значения в метрических или имперских единицах"),
+            (564, "Неверные значения"),
+            (565, "Шаблоны параметров"),
+            (566, "Применить"),
+            (567, "Название шаблона"),
+            (568, "Без категории"),
+            (569, "Параметры шаблона"),
+            (570, "Сохранить шаблон"),
+            (571, "Шаблонов пока нет, создайте шаблон из списка параметров"),
+            (572, "Выше"),
+            (573, "Категория"),
+            (574, "Уже добавленные параметры пропускаются при применении шаблона"),
+            (575, "Форк"),
+            (576, "Создать производный компонент"),
+            (577, "Копировать модификации с их параметрами"),
+            (578, "Производный от"),
+            (579, "Производные компоненты"),
+            (580, "Найти среди компонентов владельца и ваших"),
+            (581, "Производные компоненты не найдены"),
+            (582, "Родительский компонент"),
+            (583, "UUID или ссылка на компонент, от которого производится новый"),
+            (584, "Компонент не найден"),
+            (585, "Форк"),
+            (586, "Описание, каталоги, ключевые слова, лицензии и параметры копируются в новый компонент"),
+            (587, "Открыть новый компонент"),
+            (588, "Дублировать"),
+            (589, "Дублировать компонент"),
+            (590, "Копировать файлы (файлы скачиваются и загружаются заново)"),
+            (591, "Создание компонента"),
+            (592, "Копирование каталогов, ключевых слов, лицензий, стандартов, поставщиков и параметров"),
+            (593, "Копирование модификаций и наборов файлов"),
+            (594, "Копирование файлов"),
+            (595, "Подтверждение загруженных файлов"),
+            (596, "Удаление частично созданного компонента"),
+            (597, "Копирование не удалось, созданный компонент удалён"),
+            (598, "запросов выполнено"),
+            (599, "Всё копируется в новый компонент с тем же доступом и статусом. Если копирование не удастся, новый компонент будет удалён."),
+            (600, "В спецификацию"),
+            (601, "Добавить модификацию в спецификацию"),
+            (602, "Спецификация"),
+            (603, "Количество"),
+            (604, "Примечание"),
+            (605, "Добавлено в спецификацию."),
+            (606, "Открыть спецификацию"),
+            (607, "Без поставщика"),
+            (608, "Строки"),
+            (609, "Общее количество"),
+            (610, "Спецификация пуста. Добавьте модификации из таблицы модификаций компонента или из результатов поиска."),
+            (611, "Эта спецификация открыта по ссылке. Добавьте её к своей, чтобы сохранить изменения."),
+            (612, "Заменить мою спецификацию"),
+            (613, "Добавить в мою спецификацию"),
+            (614, "UUID компонента"),
+            (615, "UUID модификации"),
+            (616, "Сравнить"),
+            (617, "Добавить к сравнению"),
+            (618, "В сравнении"),
+            (619, "Сравнение компонентов"),
+            (620, "Открыть сравнение"),
+            (621, "Только различия"),
+            (622, "Выберите от 2 до 4 компонентов для сравнения в результатах поиска, избранном или каталоге компании."),
+            (623, "Убрать из сравнения"),
+            (624, "Лицензии"),
+            (625, "Спецификации"),
+            (626, "Параметры"),
+            (627, "Файлы"),
+            (628, "Подписчики"),
+            (629, "Это сравнение открыто по ссылке."),
+            (630, "Сохранить как моё сравнение"),
+            (631, "Максимальное количество компонентов в сравнении:"),
+            (632, "Количество"),
+            (633, "История статусов"),
+            (634, "Примечание к смене статуса"),
+            (635, "Кратко о смене, затем пустая строка и подробности при необходимости"),
+            (636, "Напишите примечание к смене статуса"),
+            (637, "Краткое описание (первая строка) длиннее, чем"),
+            (638, "Отделите подробности от краткого описания пустой строкой"),
+            (639, "Статус ещё не менялся"),
+            (640, "Статус можно сменить на следующий или предыдущий этап жизненного цикла или на последний этап. Смена записывается в обсуждение."),
+            (641, "Экспорт пакета"),
+            (642, "Экспортировать компонент с параметрами, модификациями, наборами файлов и файлами в ZIP-пакет"),
+            (643, "Импорт пакета"),
+            (644, "Создать компонент из ZIP-пакета, экспортированного с этого или другого сервера"),
+            (645, "Спецификация не найдена на сервере и пропускается"),
+            (646, "Лицензия не найдена на сервере и пропускается"),
+            (647, "Статус не найден на сервере, устанавливается первый этап жизненного цикла"),
+            (648, "Параметр не найден по ID, он сопоставляется по имени или создаётся"),
+            (649, "Программа набора файлов не найдена на сервере, набор пропускается"),
+            (650, "Файл отсутствует в пакете и пропускается"),
+            (651, "Стандарт не импортируется, добавьте его после импорта"),
+            (652, "Поставщик не импортируется, добавьте его после импорта"),
+            (653, "Конфликтов не найдено, пакет можно импортировать как есть"),
+            (654, "Конфликты пакета с данными сервера"),
+            (655, "Файл пакета (.zip)"),
+            (656, "Сбор данных и файлов компонента"),
+            (657, "Импорт спецификаций, ключевых слов, лицензий и параметров"),
+            (658, "Импорт модификаций и наборов файлов"),
+            (659, "Загрузка файлов"),
+            (660, "Импорт не удался, созданный компонент удалён"),
+            (661, "Проверка пакета"),
+            (662, "Выбрать все"),
+            (663, "выбрано"),
+            (664, "Изменить тип доступа"),
+            (665, "Добавить ключевые слова"),
+            (666, "Удалить ключевые слова"),
+            (667, "Добавить спецификацию"),
+            (668, "Привязать стандарт"),
+            (669, "Подписаться"),
+            (670, "Отписаться"),
+            (671, "Ключевые слова через запятую"),
+            (672, "Поиск спецификации по названию"),
+            (673, "Выберите стандарт"),
+            (674, "обработано"),
+            (675, "Ошибки"),
+            (676, "Выбранные элементы будут удалены, это нельзя отменить. Количество элементов:"),
+            (677, "Выполнено без ошибок"),
+            (678, "Действие для выбранных элементов"),
+            (679, "Каталог (спецификация) по названию"),
+            (680, "Ключевое слово"),
+            (681, "Имя пользователя владельца"),
+            (682, "Название или UUID компании"),
+            (683, "Классификатор или название стандарта"),
+            (684, "Только избранное: yes или no"),
+            (685, "Значение параметра: название=значение"),
+            (687, "У фильтра нет значения"),
+            (688, "Отсутствует закрывающая кавычка"),
+            (689, "Ожидается yes или no"),
+            (690, "Ожидается param:название=значение"),
+            (691, "Ничего не найдено по фильтру"),
+            (692, "Tab для автодополнения"),
+            (693, "Компании"),
+            (694, "Пользователи"),
+            (695, "Все"),
+            (696, "Показать все"),
+            (697, "Сохранить поиск"),
+            (698, "Название поиска"),
+            (699, "Сохранённые поиски"),
+            (700, "новых деталей в"),
+            (701, "обновлённых деталей в"),
+            (702, "Закрепить в боковом меню"),
+            (703, "Открепить от бокового меню"),
+            (704, "Пропущена (существует)"),
+            (705, "Обновление"),
+            (706, "Обновлено модификаций:"),
+            (707, "Нет ссылки для загрузки файла"),
+            (708, "Число созданных модификаций не совпадает с копируемыми"),
+            (709, "В таблице редактируются только модификации текущей страницы, для остальных переключите страницу"),
         ]);
 }
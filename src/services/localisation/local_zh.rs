@@ -462,5 +462,256 @@ lazy_static! {
         (455, " 文件上传成功"),
         (456, " 文件上传失败"),
         (457, "上传链接不存在"),
+        (458, "从三维模型生成预览图像"),
+        (459, "选择修改版本、文件集和模型（STL 或 GLB），以所选视图渲染预览图像。"),
+        (460, "生成"),
+        (461, "所选文件集中未找到 STL 或 GLB 模型"),
+        (462, "用作预览图像"),
+        (463, "修改版本"),
+        (464, "比较模型"),
+        (465, "并排"),
+        (466, "叠加"),
+        (467, "半透明"),
+        (468, "一致"),
+        (469, "小偏差"),
+        (470, "大偏差"),
+        (471, "与当前版本比较"),
+        (472, "复制视图链接"),
+        (473, "该链接将打开组件并恢复所选的修改、文件和相机位置"),
+        (474, "在模型上固定注释"),
+        (475, "点击模型放置标记"),
+        (476, "评论标记"),
+        (477, "请先在模型上放置标记"),
+        (478, "3D模型上的注释"),
+        (479, "在模型上显示"),
+        (480, "适应窗口"),
+        (481, "放大"),
+        (482, "缩小"),
+        (483, "图层"),
+        (484, "测量距离"),
+        (485, "在图纸上点击两个点"),
+        (486, "距离"),
+        (487, "无法读取图纸"),
+        (488, "拖动：平移 | 滚轮：缩放"),
+        (489, "要查看的文件"),
+        (490, "分隔符"),
+        (491, "自动"),
+        (492, "制表符"),
+        (493, "逗号 (,)"),
+        (494, "分号 (;)"),
+        (495, "或加载 CSV 文件"),
+        (496, "编码"),
+        (497, "导入前请检查数据（仅显示前几行）"),
+        (498, "工作表"),
+        (499, "标题行"),
+        (500, "请选择每列的内容"),
+        (501, "跳过"),
+        (502, "状态ID"),
+        (503, "新"),
+        (504, "已存在"),
+        (505, "跳过已存在的修改"),
+        (506, "当前值："),
+        (507, "请选择包含修改名称的列"),
+        (508, "或上传文件（CSV、XLSX、ODS）"),
+        (509, "无法读取文件"),
+        (510, "下一步"),
+        (511, "将被导入"),
+        (512, "导出修改"),
+        (513, "复制为TSV"),
+        (514, "已加载修改"),
+        (515, "表格编辑"),
+        (516, "全部保存"),
+        (517, "向下填充"),
+        (518, "放弃更改"),
+        (519, "已更改的单元格"),
+        (520, "方向键、Tab、Enter：移动 | Enter、F2或输入：编辑 | Shift：选择 | Ctrl+V：从表格粘贴 | Ctrl+D：向下填充 | Delete：清除"),
+        (521, "生成型号"),
+        (522, "键"),
+        (523, "值"),
+        (524, "值用逗号分隔，范围写作 6..60 或 6..60 step 2"),
+        (525, "排除规则"),
+        (526, "每行一条规则，条件用 & 连接，例如：d=M3 & l>30。其他参数的值写在花括号中：l<{d}"),
+        (527, "名称模板"),
+        (528, "花括号中的参数键将被替换为值，例如：M{d}x{l}"),
+        (529, "添加参数"),
+        (530, "组合"),
+        (531, "已排除"),
+        (532, "名称重复"),
+        (533, "创建型号"),
+        (534, "派生关系"),
+        (535, "显示所有型号"),
+        (536, "按此型号及其派生型号筛选表格"),
+        (537, "创建派生型号"),
+        (538, "父型号"),
+        (539, "复制参数"),
+        (540, "复制文件集"),
+        (541, "将为相同的程序创建文件集，文件需单独上传"),
+        (542, "比较参数"),
+        (543, "隐藏相同项"),
+        (544, "此型号中缺失"),
+        (545, "文件集中的文件"),
+        (546, "没有差异"),
+        (547, "在表格中勾选两个或更多型号以进行比较"),
+        (548, "筛选"),
+        (549, "按此列排序，再次点击可更改顺序"),
+        (550, "从"),
+        (551, "到"),
+        (552, "任意"),
+        (553, "筛选和排序保存在页面链接中，因此可以分享所选内容"),
+        (554, "包含"),
+        (555, "值必须是数字，可以在其后写上单位"),
+        (556, "未知单位"),
+        (557, "单位与参数的单位不匹配"),
+        (558, "参数的单位"),
+        (559, "保存为"),
+        (560, "要检查数值，请在名称末尾的方括号中写上单位，例如：长度 [mm]"),
+        (561, "公制"),
+        (562, "英制"),
+        (563, "以公制或英制单位显示数值"),
+        (564, "无效的值"),
+        (565, "参数模板"),
+        (566, "应用"),
+        (567, "模板名称"),
+        (568, "无类别"),
+        (569, "模板参数"),
+        (570, "保存模板"),
+        (571, "还没有模板，请从参数列表创建模板"),
+        (572, "上移"),
+        (573, "类别"),
+        (574, "应用模板时跳过已添加的参数"),
+        (575, "派生"),
+        (576, "派生此组件"),
+        (577, "复制修改及其参数"),
+        (578, "派生自"),
+        (579, "派生组件"),
+        (580, "在所有者和您的组件中查找"),
+        (581, "未找到派生组件"),
+        (582, "父组件"),
+        (583, "新组件派生自的组件的UUID或链接"),
+        (584, "未找到组件"),
+        (585, "派生自"),
+        (586, "描述、目录、关键词、许可证和参数将复制到新组件"),
+        (587, "打开新组件"),
+        (588, "复制"),
+        (589, "复制组件"),
+        (590, "复制文件（文件将被重新下载和上传）"),
+        (591, "正在创建组件"),
+        (592, "正在复制目录、关键词、许可证、标准、供应商和参数"),
+        (593, "正在复制修改和文件集"),
+        (594, "正在复制文件"),
+        (595, "正在确认已上传的文件"),
+        (596, "正在删除部分创建的组件"),
+        (597, "复制失败，已删除创建的组件"),
+        (598, "个请求已完成"),
+        (599, "所有内容都将复制到具有相同访问权限和状态的新组件。如果复制失败，新组件将被删除。"),
+        (600, "添加到BOM"),
+        (601, "将修改添加到物料清单"),
+        (602, "物料清单"),
+        (603, "数量"),
+        (604, "备注"),
+        (605, "已添加到物料清单。"),
+        (606, "打开物料清单"),
+        (607, "无供应商"),
+        (608, "行"),
+        (609, "总数量"),
+        (610, "物料清单为空。请从组件的修改表或搜索结果中添加修改。"),
+        (611, "此物料清单通过链接打开。将其添加到您的清单以保存更改。"),
+        (612, "替换我的BOM"),
+        (613, "添加到我的BOM"),
+        (614, "组件UUID"),
+        (615, "修改UUID"),
+        (616, "比较"),
+        (617, "添加到比较"),
+        (618, "在比较中"),
+        (619, "组件比较"),
+        (620, "打开比较"),
+        (621, "仅显示差异"),
+        (622, "在搜索结果、收藏夹或公司目录中选择2到4个组件进行比较。"),
+        (623, "从比较中移除"),
+        (624, "许可证"),
+        (625, "规格"),
+        (626, "参数"),
+        (627, "文件"),
+        (628, "订阅者"),
+        (629, "此比较是通过链接打开的。"),
+        (630, "保存为我的比较"),
+        (631, "比较中的最大组件数量："),
+        (632, "数量"),
+        (633, "状态历史"),
+        (634, "状态变更说明"),
+        (635, "变更摘要，如需要可空一行后填写详情"),
+        (636, "请填写状态变更说明"),
+        (637, "摘要（第一行）长度超过"),
+        (638, "请用空行将详情与摘要分开"),
+        (639, "暂无状态变更"),
+        (640, "状态可以变更为生命周期的下一个或上一个阶段，或最后阶段。变更会记录在讨论中。"),
+        (641, "导出包"),
+        (642, "将组件及其参数、修改、文件集和文件导出为 ZIP 包"),
+        (643, "导入包"),
+        (644, "从本服务器或其他服务器导出的 ZIP 包创建组件"),
+        (645, "服务器上未找到规格，已跳过"),
+        (646, "服务器上未找到许可证，已跳过"),
+        (647, "服务器上未找到状态，设置为生命周期的第一阶段"),
+        (648, "未按 ID 找到参数，将按名称匹配或创建"),
+        (649, "服务器上未找到文件集的程序，已跳过该文件集"),
+        (650, "包中缺少文件，已跳过"),
+        (651, "标准不会导入，请在导入后添加"),
+        (652, "供应商不会导入，请在导入后添加"),
+        (653, "未发现冲突，可以按原样导入该包"),
+        (654, "包与服务器数据的冲突"),
+        (655, "包文件 (.zip)"),
+        (656, "正在收集组件的数据和文件"),
+        (657, "正在导入规格、关键词、许可证和参数"),
+        (658, "正在导入修改和文件集"),
+        (659, "正在上传文件"),
+        (660, "导入失败，已删除创建的组件"),
+        (661, "正在检查包"),
+        (662, "全选"),
+        (663, "已选择"),
+        (664, "更改访问类型"),
+        (665, "添加关键词"),
+        (666, "删除关键词"),
+        (667, "添加规格"),
+        (668, "关联标准"),
+        (669, "关注"),
+        (670, "取消关注"),
+        (671, "用逗号分隔的关键词"),
+        (672, "按名称搜索规格"),
+        (673, "选择标准"),
+        (674, "已处理"),
+        (675, "错误"),
+        (676, "所选项目将被删除，此操作无法撤销。项目数量："),
+        (677, "已完成，没有错误"),
+        (678, "对所选项目的操作"),
+        (679, "按名称的目录（规格）"),
+        (680, "关键词"),
+        (681, "所有者用户名"),
+        (682, "公司名称或UUID"),
+        (683, "标准分类号或名称"),
+        (684, "仅收藏：yes 或 no"),
+        (685, "参数值：名称=值"),
+        (687, "过滤器没有值"),
+        (688, "缺少右引号"),
+        (689, "应为 yes 或 no"),
+        (690, "应为 param:名称=值"),
+        (691, "过滤器未找到结果"),
+        (692, "按 Tab 自动补全"),
+        (693, "公司"),
+        (694, "用户"),
+        (695, "全部"),
+        (696, "显示全部"),
+        (697, "保存搜索"),
+        (698, "搜索名称"),
+        (699, "已保存的搜索"),
+        (700, "个新零件，位于"),
+        (701, "个已更新零件，位于"),
+        (702, "固定到侧边菜单"),
+        (703, "从侧边菜单取消固定"),
+        (704, "已跳过（已存在）"),
+        (705, "更新"),
+        (706, "已更新的修改："),
+        (707, "文件没有上传链接"),
+        (708, "创建的修改数量与复制的数量不一致"),
+        (709, "表格中只编辑当前页的修改，切换页面以编辑其他修改"),
     ]);
 }
\ No newline at end of file
@@ -12,6 +12,24 @@ mod util;
 mod response_parsing;
 mod subscribers;
 mod clipboard;
+mod thumbnail;
+mod local_file;
+mod compare_model;
+mod view_link;
+mod modifications_filter;
+mod units;
+mod param_templates;
+mod bom;
+mod components_compare;
+mod status_workflow;
+mod component_bundle;
+mod bulk_actions;
+mod saved_searches;
+mod drawing;
+mod dxf;
+mod delimited_text;
+mod zip;
+mod spreadsheet;
 
 pub(crate) mod content_adapter;
 pub(crate) mod title_changer;
@@ -25,14 +43,37 @@ pub use local_storage::{
     set_token, set_list_view, get_list_view, set_history_back, get_history_back, set_history_search, get_history_search,
 };
 pub(crate) use focuser::set_focus;
-pub(crate) use preview_model::{ModelFormat, ResourceMapping, preview_model, is_gltf_resource};
+pub(crate) use preview_model::{ModelFormat, DrawingFormat, ResourceMapping, preview_model, get_viewer_state, set_pin_mode, is_gltf_resource};
 pub use requests::Requests;
 pub(crate) use server_location::{set_server_locations, get_server_locations, get_server_location_id};
 pub use set_classes::get_classes_table;
-pub(crate) use upload_file::{put_file, UploadData};
+pub(crate) use upload_file::{put_file, get_file, UploadData};
 pub(crate) use util::{ext_str, image_detector, prepare_username, compare_op_uuid, wraps_text};
 pub(crate) use response_parsing::{
     resp_parsing, resp_parsing_two_level, get_value_response, get_from_value
 };
 pub(crate) use subscribers::Counter;
-pub(crate) use clipboard::set_clipboard;
+pub(crate) use clipboard::{set_clipboard, copy_to_clipboard};
+pub(crate) use thumbnail::{ThumbnailView, render_thumbnail};
+pub(crate) use local_file::{create_object_url, revoke_object_url, save_as_file};
+pub(crate) use compare_model::{CompareMode, compare_models};
+pub(crate) use view_link::{ViewerState, ComponentViewLink, split_annotations};
+pub(crate) use modifications_filter::{ModificationsFilter, ColumnFilter, SortColumn, NAME_COLUMN, is_numeric_column};
+pub(crate) use bom::{BomLine, BomGroup, load_bom, save_bom, add_bom_line, group_by_supplier, bom_rows, bom_to_query, bom_from_query};
+pub(crate) use components_compare::{MAX_COMPARE, load_compare, save_compare, toggle_compare, compare_to_query, compare_from_query, is_different, param_rows};
+pub(crate) use status_workflow::{StatusChange, allowed_statuses, is_allowed_transition, check_status_note};
+pub(crate) use component_bundle::{
+    BundleManifest, BundleComponent, BundleLink, BundleParam, BundleFile, BundleFileOf, BundleModification,
+    BundleFileset, BundleKnown, BundlePlan, write_bundle, read_bundle, read_bundle_files, plan_import, param_values,
+};
+pub(crate) use bulk_actions::{BulkTarget, BulkActionKind, BulkAction, parse_keywords, keyword_ids};
+pub(crate) use saved_searches::{
+    SavedSearch, load_saved_searches, save_saved_searches, add_saved_search,
+    open_saved_search, take_opened_search, rerun_saved_searches,
+};
+pub(crate) use param_templates::{ParamTemplate, missing_params, load_templates, save_templates, sort_templates, upsert_template};
+pub(crate) use units::{UnitError, UnitSystem, param_unit, paramname_in_system, normalize_value, value_in_system};
+pub(crate) use drawing::{DrawingView, Point, load_drawing};
+pub(crate) use dxf::{DxfDrawing, DxfLayer};
+pub(crate) use delimited_text::{ParsingSplit, TextEncoding, parsing_delimited, parsing_block, write_delimited, decode_text};
+pub(crate) use spreadsheet::{Sheet, is_spreadsheet_file, read_spreadsheet, write_xlsx};
@@ -0,0 +1,262 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use crate::types::ComponentModificationInfo;
+use super::units::value_number;
+use super::util::{decode_value, encode_value};
+
+/// ID of the column with the names of the modifications (the IDs of parameters start from 1)
+pub(crate) const NAME_COLUMN: usize = 0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnFilter {
+    /// Bounds are inclusive, used for the columns with numeric values
+    Range { min: Option<f64>, max: Option<f64> },
+    /// Case-insensitive substring
+    Contains(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SortColumn {
+    pub param_id: usize,
+    pub desc: bool,
+}
+
+/// Filters and sorting of the modifications table, encoded in the query of the component link
+/// (`#/component/{uuid}?r12=10..20&c0=din&status=1&sort=-12`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModificationsFilter {
+    /// Filters by the ID of the parameter, `NAME_COLUMN` for the names of the modifications
+    pub columns: BTreeMap<usize, ColumnFilter>,
+    pub status_id: Option<usize>,
+    pub sort: Option<SortColumn>,
+}
+
+impl ModificationsFilter {
+    /// Sets the value of the pair from the query, unknown keys and values that cannot be parsed are skipped
+    pub(crate) fn parse_pair(&mut self, key: &str, value: &str) {
+        match key {
+            "status" => self.status_id = value.parse().ok(),
+            "sort" => {
+                let (desc, param_id) = match value.strip_prefix('-') {
+                    Some(param_id) => (true, param_id),
+                    None => (false, value),
+                };
+                self.sort = param_id.parse().ok().map(|param_id| SortColumn { param_id, desc });
+            },
+            _ => {
+                let column = match (key.get(..1), key.get(1..).and_then(|id| id.parse::<usize>().ok())) {
+                    (Some("r"), Some(param_id)) => parse_range(value).map(|range| (param_id, range)),
+                    (Some("c"), Some(param_id)) => {
+                        let text = decode_value(value);
+                        Some((param_id, ColumnFilter::Contains(text))).filter(|_| !value.is_empty())
+                    },
+                    _ => None,
+                };
+                if let Some((param_id, column_filter)) = column {
+                    self.columns.insert(param_id, column_filter);
+                }
+            },
+        }
+    }
+
+    pub(crate) fn to_params(&self) -> Vec<String> {
+        let mut params = self.columns.iter().map(|(param_id, column_filter)| match column_filter {
+            ColumnFilter::Range { min, max } => format!(
+                "r{}={}..{}",
+                param_id,
+                min.map(|min| min.to_string()).unwrap_or_default(),
+                max.map(|max| max.to_string()).unwrap_or_default(),
+            ),
+            ColumnFilter::Contains(text) => format!("c{}={}", param_id, encode_value(text)),
+        }).collect::<Vec<String>>();
+        if let Some(status_id) = self.status_id {
+            params.push(format!("status={}", status_id));
+        }
+        if let Some(sort) = self.sort {
+            params.push(format!("sort={}{}", if sort.desc { "-" } else { "" }, sort.param_id));
+        }
+        params
+    }
+
+    /// Returns true if at least one filter is set (sorting is not counted)
+    pub(crate) fn is_filtered(&self) -> bool {
+        !self.columns.is_empty() || self.status_id.is_some()
+    }
+
+    /// Returns true if the modifications are filtered or sorted on the client,
+    /// the server sorts the modifications by name only
+    pub(crate) fn is_client_side(&self) -> bool {
+        self.is_filtered() || self.sort.is_some_and(|sort| sort.param_id != NAME_COLUMN)
+    }
+
+    /// Returns true if the modifications are sorted by name in descending order on the server
+    pub(crate) fn server_desc(&self) -> bool {
+        self.sort.is_some_and(|sort| sort.param_id == NAME_COLUMN && sort.desc)
+    }
+
+    /// Sets or removes (for `None` and empty values) the filter of the column
+    pub(crate) fn set_column(&mut self, param_id: usize, column_filter: Option<ColumnFilter>) {
+        let column_filter = column_filter.filter(|column_filter| match column_filter {
+            ColumnFilter::Range { min, max } => min.is_some() || max.is_some(),
+            ColumnFilter::Contains(text) => !text.trim().is_empty(),
+        });
+        match column_filter {
+            Some(column_filter) => self.columns.insert(param_id, column_filter),
+            None => self.columns.remove(&param_id),
+        };
+    }
+
+    /// Switches the sorting of the column: ascending, descending, without sorting
+    pub(crate) fn switch_sort(&mut self, param_id: usize) {
+        self.sort = match self.sort {
+            Some(SortColumn { param_id: id, desc: false }) if id == param_id => Some(SortColumn { param_id, desc: true }),
+            Some(SortColumn { param_id: id, desc: true }) if id == param_id => None,
+            _ => Some(SortColumn { param_id, desc: false }),
+        };
+    }
+
+    pub(crate) fn matches(&self, modification: &ComponentModificationInfo) -> bool {
+        if self.status_id.is_some_and(|status_id| status_id != modification.actual_status.actual_status_id) {
+            return false
+        }
+        self.columns.iter().all(|(param_id, column_filter)| {
+            match column_filter {
+                ColumnFilter::Range { min, max } => match column_number(modification, *param_id) {
+                    Some(number) => min.map_or(true, |min| number >= min) && max.map_or(true, |max| number <= max),
+                    None => false,
+                },
+                ColumnFilter::Contains(text) => column_value(modification, *param_id)
+                    .is_some_and(|value| value.to_lowercase().contains(&text.trim().to_lowercase())),
+            }
+        })
+    }
+
+    /// Returns the modifications that match the filters in the order of the sorting
+    pub(crate) fn apply(&self, modifications: &[ComponentModificationInfo]) -> Vec<ComponentModificationInfo> {
+        let mut result = modifications
+            .iter()
+            .filter(|modification| self.matches(modification))
+            .cloned()
+            .collect::<Vec<ComponentModificationInfo>>();
+        if let Some(sort) = self.sort {
+            result.sort_by(|a, b| {
+                let ordering = match (column_number(a, sort.param_id), column_number(b, sort.param_id)) {
+                    (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                    _ => compare_values(column_value(a, sort.param_id), column_value(b, sort.param_id)),
+                };
+                match (column_value(a, sort.param_id), column_value(b, sort.param_id)) {
+                    (Some(_), Some(_)) if sort.desc => ordering.reverse(),
+                    (Some(_), Some(_)) => ordering,
+                    // modifications without the parameter are always at the end
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            });
+        }
+        result
+    }
+}
+
+/// Returns the name of the modification or the value of the parameter
+pub(crate) fn column_value(modification: &ComponentModificationInfo, param_id: usize) -> Option<&str> {
+    match param_id {
+        NAME_COLUMN => Some(modification.modification_name.as_str()),
+        _ => modification.modification_params
+            .iter()
+            .find(|mp| mp.param.param_id == param_id)
+            .map(|mp| mp.value.as_str()),
+    }
+}
+
+/// Returns the value of the column as a number in the unit of the parameter (if declared)
+pub(crate) fn column_number(modification: &ComponentModificationInfo, param_id: usize) -> Option<f64> {
+    match param_id {
+        NAME_COLUMN => value_number("", &modification.modification_name),
+        _ => modification.modification_params
+            .iter()
+            .find(|mp| mp.param.param_id == param_id)
+            .and_then(|mp| value_number(&mp.param.paramname, &mp.value)),
+    }
+}
+
+/// Returns true if all non-empty values of the column look like numbers
+pub(crate) fn is_numeric_column(modifications: &[ComponentModificationInfo], param_id: usize) -> bool {
+    let mut values = modifications
+        .iter()
+        .filter(|modification| column_value(modification, param_id).is_some_and(|value| !value.trim().is_empty()))
+        .peekable();
+    values.peek().is_some() && values.all(|modification| column_number(modification, param_id).is_some())
+}
+
+fn compare_values(a: Option<&str>, b: Option<&str>) -> Ordering {
+    a.unwrap_or_default().to_lowercase().cmp(&b.unwrap_or_default().to_lowercase())
+}
+
+fn parse_range(value: &str) -> Option<ColumnFilter> {
+    let (min, max) = value.split_once("..")?;
+    let parse_bound = |bound: &str| match bound.is_empty() {
+        true => Some(None),
+        false => bound.parse::<f64>().ok().filter(|bound| bound.is_finite()).map(Some),
+    };
+    match (parse_bound(min)?, parse_bound(max)?) {
+        (None, None) => None,
+        (min, max) => Some(ColumnFilter::Range { min, max }),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn modification(name: &str, status_id: usize, params: &[(usize, &str, &str)]) -> ComponentModificationInfo {
+        let mut modification = ComponentModificationInfo::default().with_params(params);
+        modification.modification_name = name.to_string();
+        modification.actual_status.actual_status_id = status_id;
+        modification
+    }
+
+    #[test]
+    fn filter_query_round_trip() {
+        let mut filter = ModificationsFilter::default();
+        filter.set_column(12, Some(ColumnFilter::Range { min: Some(10.0), max: None }));
+        filter.set_column(0, Some(ColumnFilter::Contains("din 912 ä".to_string())));
+        filter.set_column(5, Some(ColumnFilter::Contains("  ".to_string())));
+        filter.status_id = Some(1);
+        filter.switch_sort(12);
+        filter.switch_sort(12);
+
+        let query = filter.to_params().join("&");
+
+        assert_eq!("c0=din%20912%20%C3%A4&r12=10..&status=1&sort=-12", query);
+        let mut parsed = ModificationsFilter::default();
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            parsed.parse_pair(key, value);
+        }
+        assert_eq!(filter, parsed)
+    }
+
+    #[test]
+    fn apply_filters_and_sort() {
+        let modifications = vec![
+            modification("M8x30", 1, &[(12, "Length [mm]", "30 mm"), (13, "Material", "steel")]),
+            modification("M8x60", 1, &[(12, "Length [mm]", "2.36 in"), (13, "Material", "Stainless steel")]),
+            modification("M8x16", 2, &[(12, "Length [mm]", "16 mm"), (13, "Material", "steel")]),
+            modification("M8x45", 1, &[(13, "Material", "brass")]),
+            modification("M8x80", 1, &[(12, "Length [mm]", "80"), (13, "Material", "steel")]),
+        ];
+        let mut filter = ModificationsFilter::default();
+        filter.set_column(13, Some(ColumnFilter::Contains("STEEL".to_string())));
+        filter.set_column(12, Some(ColumnFilter::Range { min: Some(20.0), max: Some(100.0) }));
+        filter.status_id = Some(1);
+        filter.switch_sort(12);
+        filter.switch_sort(12);
+
+        let names = filter.apply(&modifications).into_iter().map(|m| m.modification_name).collect::<Vec<String>>();
+
+        assert_eq!(vec!["M8x80", "M8x60", "M8x30"], names);
+        assert!(is_numeric_column(&modifications, 12));
+        assert!(!is_numeric_column(&modifications, 13));
+        assert!(filter.is_client_side())
+    }
+}
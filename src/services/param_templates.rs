@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use log::debug;
+use crate::types::{Param, Spec};
+use super::local_storage::{get_param_templates, set_param_templates};
+
+/// Ordered list of the parameters which are created together, optionally for the components of the spec
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParamTemplate {
+    pub name: String,
+    pub spec_id: Option<usize>,
+    /// Name of the spec at the moment of saving the template
+    #[serde(default)]
+    pub spec: String,
+    pub params: Vec<Param>,
+}
+
+impl ParamTemplate {
+    /// The template is suitable for a component with the specs
+    pub(crate) fn for_specs(&self, specs: &[Spec]) -> bool {
+        self.spec_id.map_or(true, |spec_id| specs.iter().any(|s| s.spec_id == spec_id))
+    }
+}
+
+/// Parameters of the template which are not in the set, in the order of the template
+pub(crate) fn missing_params(params: &[Param], param_ids: &BTreeSet<usize>) -> Vec<Param> {
+    params.iter().filter(|p| !param_ids.contains(&p.param_id)).cloned().collect()
+}
+
+/// Templates saved in the local storage, the templates of the spec go first
+pub(crate) fn load_templates(specs: &[Spec]) -> Vec<ParamTemplate> {
+    let mut templates: Vec<ParamTemplate> = get_param_templates()
+        .and_then(|data| serde_json::from_str(&data).map_err(|err| debug!("Bad templates: {:?}", err)).ok())
+        .unwrap_or_default();
+    sort_templates(&mut templates, specs);
+    templates
+}
+
+pub(crate) fn save_templates(templates: &[ParamTemplate]) {
+    let data = match templates.is_empty() {
+        true => None,
+        false => serde_json::to_string(templates).ok(),
+    };
+    set_param_templates(data);
+}
+
+/// Templates of the specs of the component, then the templates without a spec, then others
+pub(crate) fn sort_templates(templates: &mut [ParamTemplate], specs: &[Spec]) {
+    templates.sort_by_key(|t| (
+        match (t.spec_id, t.for_specs(specs)) {
+            (Some(_), true) => 0,
+            (None, _) => 1,
+            (Some(_), false) => 2,
+        },
+        t.name.to_lowercase(),
+    ));
+}
+
+/// Replaces the template with the same name or adds a new one
+pub(crate) fn upsert_template(templates: &mut Vec<ParamTemplate>, template: ParamTemplate) {
+    let name = template.name.trim().to_lowercase();
+    match templates.iter_mut().find(|t| t.name.trim().to_lowercase() == name) {
+        Some(old) => *old = template,
+        None => templates.push(template),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn param(param_id: usize, paramname: &str) -> Param {
+        Param { param_id, lang_id: 1, paramname: paramname.to_string() }
+    }
+
+    fn spec(spec_id: usize) -> Spec {
+        Spec { spec_id, lang_id: 1, spec: format!("Spec {}", spec_id) }
+    }
+
+    fn template(name: &str, spec_id: Option<usize>) -> ParamTemplate {
+        ParamTemplate {
+            name: name.to_string(),
+            spec_id,
+            spec: String::new(),
+            params: vec![param(3, "Thread"), param(1, "Length [mm]"), param(2, "Material")],
+        }
+    }
+
+    #[test]
+    fn templates_order_and_missing_params() {
+        let mut templates = vec![template("b", Some(7)), template("Bolts", None), template("a", Some(5))];
+        sort_templates(&mut templates, &[spec(5)]);
+        let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(vec!["a", "Bolts", "b"], names);
+        assert!(templates[1].for_specs(&[]));
+        assert!(!templates[2].for_specs(&[spec(5)]));
+
+        let missing = missing_params(&templates[0].params, &BTreeSet::from([1]));
+        let ids: Vec<usize> = missing.iter().map(|p| p.param_id).collect();
+        assert_eq!(vec![3, 2], ids);
+
+        upsert_template(&mut templates, template(" bolts ", Some(5)));
+        assert_eq!(3, templates.len());
+        assert_eq!(Some(5), templates[1].spec_id);
+        upsert_template(&mut templates, template("Nuts", None));
+        assert_eq!(4, templates.len());
+    }
+
+    #[test]
+    fn template_json_round_trip() {
+        let data = serde_json::to_string(&vec![template("Bolts", Some(5))]).unwrap();
+        assert!(data.contains("\"specId\":5"));
+        let templates: Vec<ParamTemplate> = serde_json::from_str(&data).unwrap();
+        assert_eq!(vec![template("Bolts", Some(5))], templates);
+    }
+}
@@ -4,9 +4,9 @@ use wasm_bindgen::prelude::*;
 // use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen::JsValue;
-use serde_wasm_bindgen::to_value;
+use serde_wasm_bindgen::{to_value, from_value};
 use log::debug;
-use crate::services::{Size, get_value_field, ext_str};
+use crate::services::{Size, get_value_field, ext_str, ViewerState};
 use crate::types::DownloadFile;
 
 /// Array of file extensions used as resources in GLTF
@@ -47,6 +47,33 @@ impl ModelFormat {
             _ => true,
         }
     }
+
+    /// Checks whether the model of this format is stored in a single file (without resources)
+    pub(crate) fn is_self_contained(&self) -> bool {
+        matches!(self, Self::STL | Self::GLB)
+    }
+}
+
+/// Formats of 2D drawings shown in the drawing viewer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DrawingFormat {
+    Dxf,
+    Svg,
+    Unknown,
+}
+
+impl DrawingFormat {
+    pub(crate) fn from_filename(filename: &str) -> Self {
+        match ext_str(filename).to_lowercase().as_str() {
+            ".dxf" => Self::Dxf,
+            ".svg" => Self::Svg,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub(crate) fn is_drawing_format(&self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +82,7 @@ pub(crate) struct ViewerConfig {
     pub(crate) model_format: ModelFormat,
     pub(crate) resource_mapping: Vec<ResourceMapping>,
     pub(crate) size_flag: bool,
+    pub(crate) initial_state: Option<ViewerState>,
     pub(crate) labels: ViewerLabels,
 }
 
@@ -127,6 +155,12 @@ extern "C" {
 
     #[wasm_bindgen(method)]
     async fn starter(this: &GreatViewer);
+
+    #[wasm_bindgen(js_name = getViewerState)]
+    fn get_viewer_state_js() -> JsValue;
+
+    #[wasm_bindgen(js_name = setPinMode)]
+    fn set_pin_mode_js(enabled: bool);
 }
 
 #[wasm_bindgen(module = "/assets/js/greatviewer-ifc.js")]
@@ -168,10 +202,11 @@ pub(crate) fn preview_model(
     model_format: ModelFormat,
     resource_mapping: Vec<ResourceMapping>,
     // suitable_files: Vec<(DownloadFile, ModelFormat)>,
-    size_flag: bool
+    size_flag: bool,
+    initial_state: Option<ViewerState>,
 ) {
     debug!("viewer");
-    let Some(config_js) = get_js_value(model_file, model_format, resource_mapping, size_flag) else {
+    let Some(config_js) = get_js_value(model_file, model_format, resource_mapping, size_flag, initial_state) else {
         debug!("Failed to create viewer config");
         return
     };
@@ -189,11 +224,22 @@ pub(crate) fn preview_model(
     });
 }
 
+/// Returns the camera and display settings of the running viewer (None if no model is shown)
+pub(crate) fn get_viewer_state() -> Option<ViewerState> {
+    from_value::<Option<ViewerState>>(get_viewer_state_js()).unwrap_or_default()
+}
+
+/// Enables placing the annotation pin by clicking on the model in the running viewer
+pub(crate) fn set_pin_mode(enabled: bool) {
+    set_pin_mode_js(enabled);
+}
+
 fn get_js_value(
     model_file: &DownloadFile,
     model_format: ModelFormat,
     resource_mapping: Vec<ResourceMapping>,
-    size_flag: bool
+    size_flag: bool,
+    initial_state: Option<ViewerState>,
 ) -> Option<JsValue> {
     let config = ViewerConfig {
         model: ShowModel {
@@ -205,6 +251,7 @@ fn get_js_value(
         model_format,
         resource_mapping,
         size_flag,
+        initial_state,
         labels: ViewerLabels {
             controls: get_value_field(&252).to_string(),
             material_folder: get_value_field(&253).to_string(),
@@ -0,0 +1,251 @@
+use chrono::NaiveDateTime;
+use graphql_client::GraphQLQuery;
+use lazy_static::lazy_static;
+use log::debug;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use crate::fragments::search::SearchArg;
+use crate::gqls::make_query;
+use crate::gqls::component::{SearchByComponents, search_by_components};
+use crate::types::{ShowComponentShort, UUID};
+use super::local_storage::{get_saved_searches, set_saved_searches};
+use super::{get_value_field, resp_parsing};
+
+lazy_static! {
+    /// Saved search opened from the side menu, taken by the search page
+    static ref OPENED_SEARCH: RwLock<Option<SavedSearch>> = RwLock::new(None);
+}
+
+/// Arguments of the search in the form for local storage
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchArg {
+    pub search: String,
+    pub by_params: bool,
+    pub by_specs: bool,
+    pub by_keywords: bool,
+    pub company_uuid: Option<UUID>,
+    pub user_uuid: Option<UUID>,
+    pub standard_uuid: Option<UUID>,
+    pub service_uuid: Option<UUID>,
+    pub favorite: bool,
+    pub spec_id: Option<i64>,
+}
+
+impl From<&SearchArg> for SavedSearchArg {
+    fn from(arg: &SearchArg) -> Self {
+        Self {
+            search: arg.search.clone(),
+            by_params: arg.by_params,
+            by_specs: arg.by_specs,
+            by_keywords: arg.by_keywords,
+            company_uuid: arg.company_uuid.clone(),
+            user_uuid: arg.user_uuid.clone(),
+            standard_uuid: arg.standard_uuid.clone(),
+            service_uuid: arg.service_uuid.clone(),
+            favorite: arg.favorite,
+            spec_id: arg.spec_id,
+        }
+    }
+}
+
+impl From<&SavedSearchArg> for SearchArg {
+    fn from(arg: &SavedSearchArg) -> Self {
+        Self {
+            search: arg.search.clone(),
+            by_params: arg.by_params,
+            by_specs: arg.by_specs,
+            by_keywords: arg.by_keywords,
+            company_uuid: arg.company_uuid.clone(),
+            user_uuid: arg.user_uuid.clone(),
+            standard_uuid: arg.standard_uuid.clone(),
+            service_uuid: arg.service_uuid.clone(),
+            favorite: arg.favorite,
+            spec_id: arg.spec_id,
+        }
+    }
+}
+
+/// Component found by the saved search
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedResult {
+    pub uuid: UUID,
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<&ShowComponentShort> for SavedResult {
+    fn from(component: &ShowComponentShort) -> Self {
+        Self {
+            uuid: component.uuid.clone(),
+            updated_at: component.updated_at,
+        }
+    }
+}
+
+/// Named search of the user, re-run on login to flag the new and updated components
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+    pub name: String,
+    /// Arguments of the search page: the text of the search bar (with filters) and the spec of the catalog
+    pub page_arg: SavedSearchArg,
+    /// Arguments with the filters of the query resolved, used to re-run the search
+    pub run_arg: SavedSearchArg,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub last_results: Vec<SavedResult>,
+    /// Changes found by the runs since the search was opened last time
+    #[serde(default)]
+    pub new_count: usize,
+    #[serde(default)]
+    pub updated_count: usize,
+}
+
+impl SavedSearch {
+    pub(crate) fn new(name: String, page_arg: &SearchArg, run_arg: &SearchArg, results: &[ShowComponentShort]) -> Self {
+        Self {
+            name,
+            page_arg: page_arg.into(),
+            run_arg: run_arg.into(),
+            pinned: true,
+            last_results: results.iter().map(|c| c.into()).collect(),
+            new_count: 0,
+            updated_count: 0,
+        }
+    }
+
+    pub(crate) fn has_changes(&self) -> bool {
+        self.new_count + self.updated_count > 0
+    }
+
+    /// Compares the results with the last run by UUIDs and the update time,
+    /// the changes are added to the counts until the search is opened
+    pub(crate) fn check_results(&mut self, results: Vec<SavedResult>) {
+        for result in &results {
+            match self.last_results.iter().find(|last| last.uuid == result.uuid) {
+                Some(last) if last.updated_at < result.updated_at => self.updated_count += 1,
+                Some(_) => {},
+                None => self.new_count += 1,
+            }
+        }
+        self.last_results = results;
+    }
+
+    /// Notices about the changes, e.g. "3 new parts in 'M8 stainless bolts'"
+    pub(crate) fn changes_notes(&self) -> Vec<String> {
+        let mut notes = Vec::new();
+        if self.new_count > 0 {
+            notes.push(format!("{} {} '{}'", self.new_count, get_value_field(&700), self.name));
+        }
+        if self.updated_count > 0 {
+            notes.push(format!("{} {} '{}'", self.updated_count, get_value_field(&701), self.name));
+        }
+        notes
+    }
+
+    pub(crate) fn mark_seen(&mut self) {
+        self.new_count = 0;
+        self.updated_count = 0;
+    }
+}
+
+pub(crate) fn load_saved_searches() -> Vec<SavedSearch> {
+    get_saved_searches()
+        .and_then(|data| serde_json::from_str(&data).map_err(|err| debug!("Bad saved searches: {:?}", err)).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_saved_searches(searches: &[SavedSearch]) {
+    let data = match searches.is_empty() {
+        true => None,
+        false => serde_json::to_string(searches).ok(),
+    };
+    set_saved_searches(data);
+}
+
+/// Adds the search or replaces the search with the same name
+pub(crate) fn add_saved_search(searches: &mut Vec<SavedSearch>, search: SavedSearch) {
+    match searches.iter_mut().find(|s| s.name == search.name) {
+        Some(old) => *old = search,
+        None => searches.push(search),
+    }
+}
+
+/// Marks the saved search as seen and passes it to the search page
+pub(crate) fn open_saved_search(name: &str) {
+    let mut searches = load_saved_searches();
+    if let Some(search) = searches.iter_mut().find(|s| s.name == name) {
+        search.mark_seen();
+        *OPENED_SEARCH.write() = Some(search.clone());
+    }
+    save_saved_searches(&searches);
+}
+
+/// Saved search opened by the user, if any (it is taken only once)
+pub(crate) fn take_opened_search() -> Option<SavedSearch> {
+    OPENED_SEARCH.write().take()
+}
+
+/// Re-runs the saved searches and saves the changes of the results
+pub(crate) async fn rerun_saved_searches() {
+    let mut found: Vec<(String, Vec<SavedResult>)> = Vec::new();
+    for search in load_saved_searches() {
+        let ipt_search_arg = search_by_components::IptSearchArg::get_ipt(&(&search.run_arg).into());
+        let res = make_query(SearchByComponents::build_query(search_by_components::Variables {
+            ipt_search_arg
+        })).await.unwrap();
+        match resp_parsing::<Vec<ShowComponentShort>>(res, "searchByComponents") {
+            Ok(components) => found.push((search.name, components.iter().map(|c| c.into()).collect())),
+            Err(err) => debug!("Saved search {} failed: {:?}", search.name, err),
+        }
+    }
+    // the searches are loaded again in case they have been changed during the requests
+    let mut searches = load_saved_searches();
+    for (name, results) in found {
+        if let Some(search) = searches.iter_mut().find(|s| s.name == name) {
+            search.check_results(results);
+        }
+    }
+    save_saved_searches(&searches);
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn result(uuid: &str, minute: u32) -> SavedResult {
+        SavedResult {
+            uuid: uuid.to_string(),
+            updated_at: chrono::NaiveDate::from_ymd(2024, 5, 1).and_hms(10, minute, 0),
+        }
+    }
+
+    #[test]
+    fn saved_search_changes() {
+        let mut search = SavedSearch {
+            name: "M8 stainless bolts".to_string(),
+            last_results: vec![result("a", 0), result("b", 0), result("c", 0)],
+            ..Default::default()
+        };
+        search.check_results(vec![result("a", 0), result("b", 5), result("d", 0), result("e", 0)]);
+        assert_eq!((2, 1), (search.new_count, search.updated_count));
+        assert!(search.has_changes());
+
+        // the changes are added until the search is opened
+        search.check_results(vec![result("a", 0), result("b", 5), result("d", 0), result("e", 0), result("f", 0)]);
+        assert_eq!((3, 1), (search.new_count, search.updated_count));
+        search.mark_seen();
+        assert!(!search.has_changes());
+
+        let mut searches = vec![search.clone()];
+        add_saved_search(&mut searches, SavedSearch { name: search.name.clone(), pinned: true, ..Default::default() });
+        add_saved_search(&mut searches, SavedSearch { name: "Nuts".to_string(), ..Default::default() });
+        assert_eq!(2, searches.len());
+        assert!(searches[0].pinned && searches[0].last_results.is_empty());
+
+        let arg = SearchArg { search: "bolt".to_string(), by_keywords: true, spec_id: Some(7), ..Default::default() };
+        assert_eq!(arg, SearchArg::from(&SavedSearchArg::from(&arg)));
+    }
+}
@@ -0,0 +1,667 @@
+use std::collections::BTreeMap;
+use crate::services::zip::{ZipArchive, ZipWriter};
+
+/// Limits of the table read from a file (the repeated rows and columns of ODS may be very long)
+const MAX_ROWS: usize = 10_000;
+const MAX_COLUMNS: usize = 1_000;
+
+/// Sheet of a spreadsheet file with the values of the cells as text
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Sheet {
+    pub(crate) name: String,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+/// Returns true if the file is read as a spreadsheet (XLSX or ODS), other files are read as text
+pub(crate) fn is_spreadsheet_file(filename: &str) -> bool {
+    let filename = filename.to_lowercase();
+    filename.ends_with(".xlsx") || filename.ends_with(".ods")
+}
+
+/// Reads the sheets of an XLSX or ODS file.
+/// The values of merged cells are copied to all cells of the merged area.
+pub(crate) fn read_spreadsheet(filename: &str, data: &[u8]) -> Result<Vec<Sheet>, String> {
+    let archive = ZipArchive::new(data)?;
+    let mut sheets = match filename.to_lowercase() {
+        name if name.ends_with(".xlsx") => read_xlsx(&archive)?,
+        name if name.ends_with(".ods") => {
+            let content = archive.file_text("content.xml")?;
+            read_ods_content(&content)
+        },
+        _ => return Err(format!("Unsupported file type: {}", filename)),
+    };
+    for sheet in sheets.iter_mut() {
+        trim_rows(&mut sheet.rows);
+    }
+    Ok(sheets)
+}
+
+/// Tag or text of an XML document, the names are given without the namespace prefix
+#[derive(Debug, PartialEq)]
+enum XmlEvent<'a> {
+    Start { name: &'a str, attrs: Vec<(&'a str, String)>, empty: bool },
+    End(&'a str),
+    Text(String),
+}
+
+impl<'a> XmlEvent<'a> {
+    fn is_start(&self, tag: &str) -> bool {
+        matches!(self, XmlEvent::Start { name, .. } if *name == tag)
+    }
+
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            XmlEvent::Start { attrs, .. } => attrs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Value of the numeric attribute, 1 if the attribute is absent
+    fn attr_count(&self, key: &str) -> usize {
+        self.attr(key).and_then(|v| v.parse().ok()).unwrap_or(1)
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Splits an XML document into tags and texts (enough for the documents of spreadsheets)
+fn xml_events(xml: &str) -> Vec<XmlEvent<'_>> {
+    let mut events = Vec::new();
+    let mut rest = xml;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut events, rest);
+            break
+        };
+        push_text(&mut events, &rest[..start]);
+        rest = &rest[start..];
+        if let Some(data) = rest.strip_prefix("<![CDATA[") {
+            let end = data.find("]]>").unwrap_or(data.len());
+            events.push(XmlEvent::Text(data[..end].to_string()));
+            rest = data.get(end + 3..).unwrap_or_default();
+            continue
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").and_then(|end| comment.get(end + 3..)).unwrap_or_default();
+            continue
+        }
+        // the end of the tag, skipping '>' inside quoted values of attributes
+        let mut quote = None;
+        let end = rest.char_indices().find(|&(_, ch)| match (quote, ch) {
+            (None, '"') | (None, '\'') => { quote = Some(ch); false },
+            (Some(q), _) if q == ch => { quote = None; false },
+            (None, '>') => true,
+            _ => false,
+        }).map(|(end, _)| end).unwrap_or(rest.len());
+        let tag = &rest[1..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            events.push(XmlEvent::End(local_name(name.trim())));
+            continue
+        }
+        let empty = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(|ch: char| ch.is_whitespace()).unwrap_or(tag.len());
+        events.push(XmlEvent::Start {
+            name: local_name(&tag[..name_end]),
+            attrs: parse_attrs(&tag[name_end..]),
+            empty,
+        });
+    }
+    events
+}
+
+fn push_text(events: &mut Vec<XmlEvent>, text: &str) {
+    if !text.is_empty() {
+        events.push(XmlEvent::Text(unescape_xml(text)));
+    }
+}
+
+fn parse_attrs(mut text: &str) -> Vec<(&str, String)> {
+    let mut attrs = Vec::new();
+    while let Some(eq) = text.find('=') {
+        let key = local_name(text[..eq].trim());
+        let value = text[eq + 1..].trim_start();
+        let Some(quote) = value.chars().next().filter(|ch| *ch == '"' || *ch == '\'') else {
+            break
+        };
+        let value = &value[1..];
+        let end = value.find(quote).unwrap_or(value.len());
+        attrs.push((key, unescape_xml(&value[..end])));
+        text = value.get(end + 1..).unwrap_or_default();
+    }
+    attrs
+}
+
+fn unescape_xml(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string()
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let ch = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => name.strip_prefix("#x").or_else(|| name.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| name.strip_prefix('#').map(|dec| dec.parse::<u32>()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        });
+        match (ch, entity) {
+            (Some(ch), Some((_, end))) => {
+                result.push(ch);
+                rest = &rest[end + 1..];
+            },
+            _ => {
+                result.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Cells of a sheet by (row, column) with the merged areas
+#[derive(Default)]
+struct SheetCells {
+    cells: BTreeMap<(usize, usize), String>,
+    /// The first cell and the last cell of a merged area
+    merged: Vec<((usize, usize), (usize, usize))>,
+}
+
+impl SheetCells {
+    fn set(&mut self, row: usize, column: usize, value: String) {
+        if row < MAX_ROWS && column < MAX_COLUMNS && !value.is_empty() {
+            self.cells.insert((row, column), value);
+        }
+    }
+
+    fn into_rows(mut self) -> Vec<Vec<String>> {
+        for ((first_row, first_column), (last_row, last_column)) in std::mem::take(&mut self.merged) {
+            let Some(value) = self.cells.get(&(first_row, first_column)).cloned() else {
+                continue
+            };
+            for row in first_row..=last_row.min(MAX_ROWS - 1) {
+                for column in first_column..=last_column.min(MAX_COLUMNS - 1) {
+                    self.cells.entry((row, column)).or_insert_with(|| value.clone());
+                }
+            }
+        }
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for ((row, column), value) in self.cells {
+            if rows.len() <= row {
+                rows.resize(row + 1, Vec::new());
+            }
+            if rows[row].len() <= column {
+                rows[row].resize(column + 1, String::new());
+            }
+            rows[row][column] = value;
+        }
+        rows
+    }
+}
+
+/// Removes empty cells at the end of the rows and empty rows at the end of the sheet
+fn trim_rows(rows: &mut Vec<Vec<String>>) {
+    for row in rows.iter_mut() {
+        while row.last().is_some_and(|value| value.trim().is_empty()) {
+            row.pop();
+        }
+    }
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+}
+
+fn read_xlsx(archive: &ZipArchive) -> Result<Vec<Sheet>, String> {
+    let workbook = archive.file_text("xl/workbook.xml")?;
+    let relations = archive.file_text("xl/_rels/workbook.xml.rels")?;
+    let targets = xml_events(&relations).into_iter()
+        .filter(|event| event.is_start("Relationship"))
+        .filter_map(|event| Some((event.attr("Id")?.to_string(), event.attr("Target")?.to_string())))
+        .collect::<BTreeMap<String, String>>();
+    let shared_strings = archive.file_text("xl/sharedStrings.xml")
+        .map(|xml| read_xlsx_shared_strings(&xml))
+        .unwrap_or_default();
+    let mut sheets = Vec::new();
+    for event in xml_events(&workbook).iter().filter(|event| event.is_start("sheet")) {
+        let (Some(name), Some(target)) = (event.attr("name"), event.attr("id").and_then(|id| targets.get(id))) else {
+            continue
+        };
+        let path = match target.strip_prefix('/') {
+            Some(path) => path.to_string(),
+            None => format!("xl/{}", target),
+        };
+        let xml = archive.file_text(&path)?;
+        sheets.push(Sheet { name: name.to_string(), rows: read_xlsx_sheet(&xml, &shared_strings) });
+    }
+    Ok(sheets)
+}
+
+fn read_xlsx_shared_strings(xml: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut value = String::new();
+    let (mut in_text, mut in_phonetic) = (false, false);
+    for event in xml_events(xml) {
+        match event {
+            XmlEvent::Start { name: "si", .. } => value.clear(),
+            XmlEvent::Start { name: "rPh", empty: false, .. } => in_phonetic = true,
+            XmlEvent::End("rPh") => in_phonetic = false,
+            XmlEvent::Start { name: "t", empty: false, .. } => in_text = !in_phonetic,
+            XmlEvent::End("t") => in_text = false,
+            XmlEvent::Text(text) if in_text => value.push_str(&text),
+            XmlEvent::End("si") => strings.push(std::mem::take(&mut value)),
+            _ => (),
+        }
+    }
+    strings
+}
+
+/// Returns (row, column) counted from 0 for the reference of a cell like "AB12"
+fn parse_cell_ref(reference: &str) -> Option<(usize, usize)> {
+    let digits = reference.find(|ch: char| ch.is_ascii_digit())?;
+    let (letters, number) = reference.split_at(digits);
+    if letters.is_empty() {
+        return None
+    }
+    let column = letters.chars().try_fold(0usize, |acc, ch| match ch.is_ascii_alphabetic() {
+        true => Some(acc * 26 + (ch.to_ascii_uppercase() as usize - 'A' as usize + 1)),
+        false => None,
+    })?;
+    let row = number.parse::<usize>().ok()?;
+    Some((row.checked_sub(1)?, column - 1))
+}
+
+fn read_xlsx_sheet(xml: &str, shared_strings: &[String]) -> Vec<Vec<String>> {
+    let mut sheet = SheetCells::default();
+    let (mut row, mut column) = (0, 0);
+    let mut next_row = 0;
+    let mut cell_type = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    for event in xml_events(xml) {
+        match &event {
+            XmlEvent::Start { name: "row", .. } => {
+                row = event.attr("r").and_then(|r| r.parse::<usize>().ok()).map(|r| r.saturating_sub(1)).unwrap_or(next_row);
+                next_row = row + 1;
+                column = 0;
+            },
+            XmlEvent::Start { name: "c", empty, .. } => {
+                if let Some((_, c)) = event.attr("r").and_then(parse_cell_ref) {
+                    column = c;
+                }
+                cell_type = event.attr("t").unwrap_or_default().to_string();
+                value.clear();
+                if *empty {
+                    column += 1;
+                }
+            },
+            XmlEvent::Start { name: "v", empty: false, .. } | XmlEvent::Start { name: "t", empty: false, .. } => in_value = true,
+            XmlEvent::End("v") | XmlEvent::End("t") => in_value = false,
+            XmlEvent::Text(text) if in_value => value.push_str(text),
+            XmlEvent::End("c") => {
+                let text = std::mem::take(&mut value);
+                let text = match cell_type.as_str() {
+                    "s" => text.trim().parse::<usize>().ok()
+                        .and_then(|index| shared_strings.get(index).cloned())
+                        .unwrap_or_default(),
+                    "b" => match text.trim() {
+                        "1" => String::from("TRUE"),
+                        _ => String::from("FALSE"),
+                    },
+                    _ => text,
+                };
+                sheet.set(row, column, text);
+                column += 1;
+            },
+            XmlEvent::Start { name: "mergeCell", .. } => {
+                let area = event.attr("ref").and_then(|area| area.split_once(':'))
+                    .and_then(|(first, last)| Some((parse_cell_ref(first)?, parse_cell_ref(last)?)));
+                if let Some(area) = area {
+                    sheet.merged.push(area);
+                }
+            },
+            _ => (),
+        }
+    }
+    sheet.into_rows()
+}
+
+fn read_ods_content(xml: &str) -> Vec<Sheet> {
+    let mut sheets = Vec::new();
+    let mut sheet = SheetCells::default();
+    let mut sheet_name = String::new();
+    let (mut row, mut column) = (0, 0);
+    // the repeat of the current row and cell, the span of the cell
+    let (mut rows_repeated, mut columns_repeated) = (1, 1);
+    let mut span = (1, 1);
+    let mut value: Option<String> = None;
+    let mut paragraphs = 0;
+    let mut annotation_depth = 0;
+    for event in xml_events(xml) {
+        if annotation_depth > 0 {
+            match event {
+                XmlEvent::Start { name: "annotation", empty: false, .. } => annotation_depth += 1,
+                XmlEvent::End("annotation") => annotation_depth -= 1,
+                _ => (),
+            }
+            continue
+        }
+        match &event {
+            XmlEvent::Start { name: "table", .. } => {
+                sheet = SheetCells::default();
+                sheet_name = event.attr("name").unwrap_or_default().to_string();
+                row = 0;
+            },
+            XmlEvent::End("table") => sheets.push(Sheet {
+                name: std::mem::take(&mut sheet_name),
+                rows: std::mem::take(&mut sheet).into_rows(),
+            }),
+            XmlEvent::Start { name: "table-row", empty, .. } => {
+                rows_repeated = event.attr_count("number-rows-repeated");
+                column = 0;
+                if *empty {
+                    row += rows_repeated;
+                }
+            },
+            XmlEvent::End("table-row") => {
+                // the repeated row is copied only if it has values, otherwise it just moves the next row
+                for copy in 1..rows_repeated.min(MAX_ROWS.saturating_sub(row)) {
+                    let values = sheet.cells.range((row, 0)..(row + 1, 0))
+                        .map(|((_, c), v)| (*c, v.clone()))
+                        .collect::<Vec<_>>();
+                    for (c, v) in values {
+                        sheet.set(row + copy, c, v);
+                    }
+                }
+                row += rows_repeated;
+            },
+            XmlEvent::Start { name: "table-cell", empty, .. } | XmlEvent::Start { name: "covered-table-cell", empty, .. } => {
+                columns_repeated = event.attr_count("number-columns-repeated");
+                span = (event.attr_count("number-rows-spanned"), event.attr_count("number-columns-spanned"));
+                paragraphs = 0;
+                value = Some(match event.attr("value-type") {
+                    // the text is preferred, the value is used if the cell has no text
+                    Some("float") | Some("percentage") | Some("currency") => event.attr("value"),
+                    Some("date") => event.attr("date-value"),
+                    Some("boolean") => event.attr("boolean-value"),
+                    _ => None,
+                }.unwrap_or_default().to_string());
+                if *empty {
+                    finish_ods_cell(&mut sheet, row, &mut column, columns_repeated, span, value.take());
+                }
+            },
+            XmlEvent::End("table-cell") | XmlEvent::End("covered-table-cell") => {
+                finish_ods_cell(&mut sheet, row, &mut column, columns_repeated, span, value.take());
+            },
+            XmlEvent::Start { name: "annotation", empty: false, .. } => annotation_depth = 1,
+            XmlEvent::Start { name: "p", .. } if value.is_some() => {
+                let text = value.get_or_insert_with(String::new);
+                if paragraphs == 0 {
+                    // the text of the cell replaces the value
+                    text.clear();
+                } else {
+                    text.push('\n');
+                }
+                paragraphs += 1;
+            },
+            XmlEvent::Start { name: "s", .. } => if let Some(text) = value.as_mut() {
+                text.push_str(&" ".repeat(event.attr_count("c").min(MAX_COLUMNS)));
+            },
+            XmlEvent::Start { name: "tab", .. } => if let Some(text) = value.as_mut() {
+                text.push('\t');
+            },
+            XmlEvent::Start { name: "line-break", .. } => if let Some(text) = value.as_mut() {
+                text.push('\n');
+            },
+            XmlEvent::Text(t) => if let Some(text) = value.as_mut().filter(|_| paragraphs > 0) {
+                text.push_str(t);
+            },
+            _ => (),
+        }
+    }
+    sheets
+}
+
+fn finish_ods_cell(
+    sheet: &mut SheetCells,
+    row: usize,
+    column: &mut usize,
+    repeated: usize,
+    span: (usize, usize),
+    value: Option<String>,
+) {
+    let value = value.unwrap_or_default();
+    if value.is_empty() {
+        *column += repeated;
+        return
+    }
+    if span != (1, 1) {
+        sheet.merged.push(((row, *column), (row + span.0 - 1, *column + span.1 - 1)));
+    }
+    for _ in 0..repeated.min(MAX_COLUMNS.saturating_sub(*column)) {
+        sheet.set(row, *column, value.clone());
+        *column += 1;
+    }
+}
+
+/// Writes the sheet to an XLSX file, the values are saved as text
+pub(crate) fn write_xlsx(sheet: &Sheet) -> Result<Vec<u8>, String> {
+    // the name of a sheet is limited to 31 characters without []:*?/\
+    let sheet_name = sheet.name.chars()
+        .filter(|ch| !"[]:*?/\\".contains(*ch))
+        .take(31)
+        .collect::<String>();
+    let mut sheet_data = String::new();
+    for (row, values) in sheet.rows.iter().enumerate() {
+        sheet_data.push_str(&format!("<row r=\"{}\">", row + 1));
+        for (column, value) in values.iter().enumerate().filter(|(_, value)| !value.is_empty()) {
+            sheet_data.push_str(&format!(
+                "<c r=\"{}{}\" t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+                column_letters(column), row + 1, escape_xml(value)
+            ));
+        }
+        sheet_data.push_str("</row>");
+    }
+    let mut writer = ZipWriter::default();
+    writer.add("[Content_Types].xml", concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+        r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+        r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+        r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+        r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+        r#"</Types>"#,
+    ).as_bytes())?;
+    writer.add("_rels/.rels", concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+        r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+        r#"</Relationships>"#,
+    ).as_bytes())?;
+    writer.add("xl/workbook.xml", format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" "#,
+            r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">"#,
+            r#"<sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        ),
+        escape_xml(&sheet_name)
+    ).as_bytes())?;
+    writer.add("xl/_rels/workbook.xml.rels", concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+        r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+        r#"</Relationships>"#,
+    ).as_bytes())?;
+    writer.add("xl/worksheets/sheet1.xml", format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+            r#"<sheetData>{}</sheetData></worksheet>"#,
+        ),
+        sheet_data
+    ).as_bytes())?;
+    Ok(writer.finish())
+}
+
+/// Returns the letters of the column counted from 0 (0 is "A", 27 is "AB")
+fn column_letters(column: usize) -> String {
+    let mut letters = Vec::new();
+    let mut number = column + 1;
+    while number > 0 {
+        letters.push((b'A' + ((number - 1) % 26) as u8) as char);
+        number = (number - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Escapes the special characters, the control characters not allowed in XML are removed
+fn escape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '&' => result.push_str("&amp;"),
+            '"' => result.push_str("&quot;"),
+            '\t' | '\n' | '\r' => result.push(ch),
+            _ if ch.is_control() => (),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn zip_archive(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = ZipWriter::default();
+        for (name, content) in files {
+            writer.add(name, content.as_bytes()).unwrap();
+        }
+        writer.finish()
+    }
+
+    #[test]
+    fn read_xlsx_file() {
+        let workbook = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <workbook xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+            <sheets><sheet name="Bolts &amp; nuts" sheetId="1" r:id="rId1"/></sheets></workbook>"#;
+        let relations = r#"<Relationships><Relationship Id="rId1" Type="worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+        let shared = r#"<sst><si><t>[ModificationName]</t></si><si><t>Length</t></si>
+            <si><r><t>M</t></r><r><t xml:space="preserve">6 x 20</t></r><rPh><t>x</t></rPh></si></sst>"#;
+        let sheet = r#"<worksheet><sheetData>
+            <row r="1"><c r="A1" t="s"><v>0</v></c><c r="B1" t="s"><v>1</v></c><c r="C1" t="inlineStr"><is><t>Note</t></is></c></row>
+            <row r="2"><c r="A2" t="s"><v>2</v></c><c r="B2"><v>20</v></c><c r="C2" t="str"><v>line 1
+line 2</v></c></row>
+            <row r="4"><c r="A4" t="b"><v>1</v></c><c r="B4"/></row>
+            </sheetData><mergeCells count="1"><mergeCell ref="B2:B3"/></mergeCells></worksheet>"#;
+        let data = zip_archive(&[
+            ("xl/workbook.xml", workbook),
+            ("xl/_rels/workbook.xml.rels", relations),
+            ("xl/sharedStrings.xml", shared),
+            ("xl/worksheets/sheet1.xml", sheet),
+        ]);
+
+        let result = read_spreadsheet("modifications.XLSX", &data).unwrap();
+
+        assert_eq!(
+            vec![Sheet {
+                name: String::from("Bolts & nuts"),
+                rows: vec![
+                    vec![String::from("[ModificationName]"), String::from("Length"), String::from("Note")],
+                    vec![String::from("M6 x 20"), String::from("20"), String::from("line 1\nline 2")],
+                    vec![String::new(), String::from("20")],
+                    vec![String::from("TRUE")],
+                ],
+            }],
+            result
+        )
+    }
+
+    #[test]
+    fn read_ods_content_repeated() {
+        let content = r#"<office:document-content><office:body><office:spreadsheet>
+            <table:table table:name="Sheet1">
+            <table:table-column table:number-columns-repeated="3"/>
+            <table:table-row><table:table-cell office:value-type="string"><text:p>name</text:p></table:table-cell>
+            <table:table-cell table:number-columns-spanned="2" office:value-type="string"><text:p>a<text:s text:c="2"/>&lt;b&gt;</text:p><text:p>c</text:p></table:table-cell>
+            <table:covered-table-cell/></table:table-row>
+            <table:table-row table:number-rows-repeated="2"><table:table-cell office:value-type="float" office:value="1.5"><office:annotation><text:p>note</text:p></office:annotation></table:table-cell>
+            <table:table-cell table:number-columns-repeated="1000000"/></table:table-row>
+            <table:table-row table:number-rows-repeated="1048570"><table:table-cell table:number-columns-repeated="1024"/></table:table-row>
+            </table:table>
+            <table:table table:name="Empty"><table:table-row><table:table-cell/></table:table-row></table:table>
+            </office:spreadsheet></office:body></office:document-content>"#;
+
+        let mut result = read_ods_content(content);
+        for sheet in result.iter_mut() {
+            trim_rows(&mut sheet.rows);
+        }
+
+        assert_eq!(
+            vec![
+                Sheet {
+                    name: String::from("Sheet1"),
+                    rows: vec![
+                        vec![String::from("name"), String::from("a  <b>\nc"), String::from("a  <b>\nc")],
+                        vec![String::from("1.5")],
+                        vec![String::from("1.5")],
+                    ],
+                },
+                Sheet { name: String::from("Empty"), rows: Vec::new() },
+            ],
+            result
+        )
+    }
+
+    #[test]
+    fn write_xlsx_file() {
+        let sheet = Sheet {
+            name: String::from("Modifications: <M6>"),
+            rows: vec![
+                vec![String::from("[ModificationName]"), String::from("Length & width")],
+                vec![String::from(" M6\n20 "), String::new(), String::from("\u{1}x")],
+            ],
+        };
+
+        let result = read_spreadsheet("export.xlsx", &write_xlsx(&sheet).unwrap()).unwrap();
+
+        assert_eq!(
+            vec![Sheet {
+                name: String::from("Modifications <M6>"),
+                rows: vec![
+                    vec![String::from("[ModificationName]"), String::from("Length & width")],
+                    vec![String::from(" M6\n20 "), String::new(), String::from("x")],
+                ],
+            }],
+            result
+        );
+        assert_eq!("AB", column_letters(27));
+    }
+
+    #[test]
+    fn read_not_spreadsheet() {
+        assert!(read_spreadsheet("table.xlsx", b"name;value").is_err());
+        assert!(is_spreadsheet_file("Table.ODS"));
+        assert!(!is_spreadsheet_file("table.csv"));
+        assert_eq!(Some((11, 27)), parse_cell_ref("AB12"));
+    }
+}
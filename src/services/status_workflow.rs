@@ -0,0 +1,178 @@
+use regex::Regex;
+use crate::types::{ActualStatus, UUID};
+use super::get_value_field;
+
+/// Maximum length of the summary (the first line) of the note of a status change
+pub(crate) const NOTE_SUMMARY_MAX: usize = 72;
+
+/// Statuses the component or the modification can be moved to from the current one.
+/// The life cycle follows the order of the statuses by ID (e.g. Draft → Review → Released → Obsolete):
+/// the next stage, the previous stage (returning for rework) and the last stage (withdrawal) from any stage.
+/// The current status goes first. If the current status is unknown (e.g. not loaded yet),
+/// all statuses are returned so that the select is not empty.
+pub(crate) fn allowed_statuses(statuses: &[ActualStatus], current_id: usize) -> Vec<&ActualStatus> {
+    let mut lifecycle: Vec<&ActualStatus> = statuses.iter().collect();
+    lifecycle.sort_by_key(|s| s.actual_status_id);
+    let index = match lifecycle.iter().position(|s| s.actual_status_id == current_id) {
+        Some(index) => index,
+        None => return lifecycle,
+    };
+    let mut allowed = vec![lifecycle[index]];
+    let mut targets = vec![index + 1, lifecycle.len().saturating_sub(1)];
+    if index > 0 {
+        targets.insert(0, index - 1);
+    }
+    for target in targets {
+        if let Some(status) = lifecycle.get(target) {
+            if allowed.iter().all(|s| s.actual_status_id != status.actual_status_id) {
+                allowed.push(status);
+            }
+        }
+    }
+    allowed
+}
+
+pub(crate) fn is_allowed_transition(statuses: &[ActualStatus], from_id: usize, to_id: usize) -> bool {
+    allowed_statuses(statuses, from_id).iter().any(|s| s.actual_status_id == to_id)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatusNoteError {
+    Empty,
+    LongSummary,
+    /// The details must be separated from the summary by an empty line
+    NoEmptyLine,
+}
+
+impl StatusNoteError {
+    pub(crate) fn message(&self) -> String {
+        match self {
+            StatusNoteError::Empty => get_value_field(&636).to_string(),
+            StatusNoteError::LongSummary => format!("{} {}", get_value_field(&637), NOTE_SUMMARY_MAX),
+            StatusNoteError::NoEmptyLine => get_value_field(&638).to_string(),
+        }
+    }
+}
+
+/// Checks the note of a status change written like a commit message:
+/// the summary line, then optionally an empty line and the details
+pub(crate) fn check_status_note(note: &str) -> Result<(), StatusNoteError> {
+    let mut lines = note.trim().lines();
+    let summary = lines.next().unwrap_or_default().trim();
+    if summary.is_empty() {
+        return Err(StatusNoteError::Empty)
+    }
+    if summary.chars().count() > NOTE_SUMMARY_MAX {
+        return Err(StatusNoteError::LongSummary)
+    }
+    match lines.next() {
+        Some(line) if !line.trim().is_empty() => Err(StatusNoteError::NoEmptyLine),
+        _ => Ok(()),
+    }
+}
+
+/// Change of the status of the component or its modification,
+/// saved as a comment in the discussion of the component
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusChange {
+    pub component_uuid: UUID,
+    /// Empty for the status of the component
+    pub modification_uuid: UUID,
+    pub from_status_id: usize,
+    pub to_status_id: usize,
+    /// Names of the statuses at the time of the change
+    pub from_name: String,
+    pub to_name: String,
+    pub note: String,
+}
+
+impl StatusChange {
+    /// Markdown of the comment: the link with the transition (readable in the discussion) and the note
+    pub(crate) fn to_message(&self) -> String {
+        let clean = |name: &str| name.replace(['[', ']'], "");
+        let modification = match self.modification_uuid.is_empty() {
+            true => String::new(),
+            false => format!("&mod={}", self.modification_uuid),
+        };
+        format!(
+            "[🔖 {} → {}](#/component/{}?status={}-{}{})\n\n{}",
+            clean(&self.from_name), clean(&self.to_name),
+            self.component_uuid, self.from_status_id, self.to_status_id, modification,
+            self.note.trim(),
+        )
+    }
+
+    /// Parses the status change from the comment, other comments are skipped
+    pub(crate) fn from_message(message: &str) -> Option<Self> {
+        let re = Regex::new(
+            r"^\[🔖 ([^\]]*)\]\(#/component/([0-9a-fA-F-]{36})\?status=(\d+)-(\d+)(?:&mod=([0-9a-fA-F-]{36}))?\)"
+        ).unwrap();
+        let cap = re.captures(message.trim_start())?;
+        let (from_name, to_name) = cap[1].split_once(" → ").unwrap_or((&cap[1], ""));
+        Some(Self {
+            component_uuid: cap[2].to_string(),
+            modification_uuid: cap.get(5).map(|m| m.as_str().to_string()).unwrap_or_default(),
+            from_status_id: cap[3].parse().ok()?,
+            to_status_id: cap[4].parse().ok()?,
+            from_name: from_name.to_string(),
+            to_name: to_name.to_string(),
+            note: message.trim_start()[cap[0].len()..].trim().to_string(),
+        })
+    }
+
+    /// The summary line of the note and the details
+    pub(crate) fn split_note(&self) -> (&str, &str) {
+        match self.note.split_once('\n') {
+            Some((summary, details)) => (summary.trim(), details.trim()),
+            None => (self.note.trim(), ""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    fn statuses() -> Vec<ActualStatus> {
+        ["Obsolete", "Draft", "Released", "Review"].iter().zip([4, 1, 3, 2])
+            .map(|(name, actual_status_id)| ActualStatus { actual_status_id, lang_id: 1, name: name.to_string() })
+            .collect()
+    }
+
+    #[test]
+    fn status_transitions() {
+        let statuses = statuses();
+        let ids = |current| allowed_statuses(&statuses, current).iter().map(|s| s.actual_status_id).collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 4], ids(1));
+        assert_eq!(vec![2, 1, 3, 4], ids(2));
+        assert_eq!(vec![4, 3], ids(4));
+        assert_eq!(vec![1, 2, 3, 4], ids(0));
+        assert!(is_allowed_transition(&statuses, 3, 2));
+        assert!(!is_allowed_transition(&statuses, 1, 3));
+    }
+
+    #[test]
+    fn status_notes_and_messages() {
+        assert_eq!(check_status_note(" \n"), Err(StatusNoteError::Empty));
+        assert_eq!(check_status_note(&"a".repeat(NOTE_SUMMARY_MAX + 1)), Err(StatusNoteError::LongSummary));
+        assert_eq!(check_status_note("Passed review\ndetails"), Err(StatusNoteError::NoEmptyLine));
+        assert_eq!(check_status_note("Passed review\n\nChecked by QA"), Ok(()));
+
+        let change = StatusChange {
+            component_uuid: "10000000-0000-0000-0000-000000000000".to_string(),
+            modification_uuid: "20000000-0000-0000-0000-000000000000".to_string(),
+            from_status_id: 2,
+            to_status_id: 3,
+            from_name: "Review".to_string(),
+            to_name: "Released [v1]".to_string(),
+            note: "Passed review\n\nChecked by QA".to_string(),
+        };
+        let parsed = StatusChange::from_message(&change.to_message()).unwrap();
+        assert_eq!(parsed, StatusChange { to_name: "Released v1".to_string(), ..change.clone() });
+        assert_eq!(parsed.split_note(), ("Passed review", "Checked by QA"));
+
+        let component_change = StatusChange { modification_uuid: String::new(), ..change };
+        assert_eq!(StatusChange::from_message(&component_change.to_message()).unwrap().modification_uuid, "");
+        assert!(StatusChange::from_message("Just a comment").is_none());
+    }
+}
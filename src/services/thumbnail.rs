@@ -0,0 +1,106 @@
+use serde::{Serialize, Deserialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsValue;
+use serde_wasm_bindgen::to_value;
+use js_sys::Uint8Array;
+use log::debug;
+use crate::error::Error;
+use crate::services::{get_value_field, ModelFormat};
+use crate::types::DownloadFile;
+
+/// Size of the generated preview image in pixels
+const THUMBNAIL_SIZE: u32 = 512;
+
+/// Camera view used to render the preview image
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) enum ThumbnailView {
+    Isometric,
+    Front,
+    Back,
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl ThumbnailView {
+    /// All available views in the order they are offered to the user
+    pub(crate) const ALL: [ThumbnailView; 7] = [
+        Self::Isometric, Self::Front, Self::Back, Self::Top, Self::Bottom, Self::Left, Self::Right,
+    ];
+
+    /// Returns the translated name of the view (the same as in the viewer)
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::Isometric => get_value_field(&448),
+            Self::Front => get_value_field(&444),
+            Self::Back => get_value_field(&445),
+            Self::Top => get_value_field(&442),
+            Self::Bottom => get_value_field(&443),
+            Self::Left => get_value_field(&446),
+            Self::Right => get_value_field(&447),
+        }
+    }
+
+    /// Returns the view by its index in `ThumbnailView::ALL`
+    pub(crate) fn from_index(index: usize) -> Self {
+        Self::ALL.get(index).copied().unwrap_or(Self::Isometric)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ThumbnailModel {
+    filename: String,
+    url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ThumbnailConfig {
+    model: ThumbnailModel,
+    model_format: ModelFormat,
+    view: ThumbnailView,
+    width: u32,
+    height: u32,
+    background: String,
+    model_color: String,
+}
+
+#[wasm_bindgen(module = "/assets/js/thumbnail.js")]
+extern "C" {
+    type ThumbnailRenderer;
+
+    #[wasm_bindgen(constructor)]
+    fn new(config: JsValue) -> ThumbnailRenderer;
+
+    #[wasm_bindgen(method, catch)]
+    async fn render(this: &ThumbnailRenderer) -> Result<JsValue, JsValue>;
+}
+
+/// Renders the model file offscreen and returns the PNG image data
+pub(crate) async fn render_thumbnail(
+    model_file: &DownloadFile,
+    model_format: ModelFormat,
+    view: ThumbnailView,
+) -> Result<Vec<u8>, Error> {
+    let config = ThumbnailConfig {
+        model: ThumbnailModel {
+            filename: model_file.filename.clone(),
+            url: model_file.download_url.clone(),
+        },
+        model_format,
+        view,
+        width: THUMBNAIL_SIZE,
+        height: THUMBNAIL_SIZE,
+        background: String::from("#ffffff"),
+        model_color: String::from("#1872f0"),
+    };
+    let config_js = to_value(&config).map_err(|_| Error::DeserializeError)?;
+    let renderer = ThumbnailRenderer::new(config_js);
+    match renderer.render().await {
+        Ok(data) => Ok(Uint8Array::new(&data).to_vec()),
+        Err(err) => {
+            debug!("Failed to render thumbnail: {:?}", err);
+            Err(Error::BadRequest(format!("{}: {}", get_value_field(&248), model_file.filename)))
+        },
+    }
+}
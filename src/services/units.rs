@@ -0,0 +1,271 @@
+use super::get_value_field;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dimension {
+    Length,
+    Area,
+    Volume,
+    Mass,
+    Force,
+    Pressure,
+    Torque,
+    Temperature,
+    Angle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+/// Unit of measure, the value in the base unit of the dimension is `value * factor + offset`
+#[derive(Debug, PartialEq)]
+pub struct Unit {
+    pub symbol: &'static str,
+    pub aliases: &'static [&'static str],
+    pub dimension: Dimension,
+    /// `None` for the units used in both systems (e.g. degrees)
+    pub system: Option<UnitSystem>,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+const fn unit(symbol: &'static str, aliases: &'static [&'static str], dimension: Dimension, system: Option<UnitSystem>, factor: f64) -> Unit {
+    Unit { symbol, aliases, dimension, system, factor, offset: 0.0 }
+}
+
+use Dimension::*;
+use UnitSystem::*;
+
+/// Known units, the first unit of the dimension in the system is used to display values in this system
+const UNITS: &[Unit] = &[
+    unit("mm", &[], Length, Some(Metric), 1e-3),
+    unit("cm", &[], Length, Some(Metric), 1e-2),
+    unit("m", &[], Length, Some(Metric), 1.0),
+    unit("km", &[], Length, Some(Metric), 1e3),
+    unit("µm", &["um", "μm"], Length, Some(Metric), 1e-6),
+    unit("in", &["inch", "\""], Length, Some(Imperial), 0.0254),
+    unit("ft", &["feet", "'"], Length, Some(Imperial), 0.3048),
+    unit("mm²", &["mm2", "sq mm"], Area, Some(Metric), 1e-6),
+    unit("cm²", &["cm2"], Area, Some(Metric), 1e-4),
+    unit("m²", &["m2"], Area, Some(Metric), 1.0),
+    unit("in²", &["in2", "sq in"], Area, Some(Imperial), 6.4516e-4),
+    unit("ft²", &["ft2", "sq ft"], Area, Some(Imperial), 0.09290304),
+    unit("cm³", &["cm3", "ml"], Volume, Some(Metric), 1e-6),
+    unit("mm³", &["mm3"], Volume, Some(Metric), 1e-9),
+    unit("l", &["L"], Volume, Some(Metric), 1e-3),
+    unit("m³", &["m3"], Volume, Some(Metric), 1.0),
+    unit("in³", &["in3", "cu in"], Volume, Some(Imperial), 1.6387064e-5),
+    unit("ft³", &["ft3", "cu ft"], Volume, Some(Imperial), 0.028316846592),
+    unit("gal", &[], Volume, Some(Imperial), 3.785411784e-3),
+    unit("kg", &[], Mass, Some(Metric), 1.0),
+    unit("g", &[], Mass, Some(Metric), 1e-3),
+    unit("t", &[], Mass, Some(Metric), 1e3),
+    unit("lb", &["lbs"], Mass, Some(Imperial), 0.45359237),
+    unit("oz", &[], Mass, Some(Imperial), 0.028349523125),
+    unit("N", &[], Force, Some(Metric), 1.0),
+    unit("kN", &[], Force, Some(Metric), 1e3),
+    unit("lbf", &[], Force, Some(Imperial), 4.4482216152605),
+    unit("MPa", &["N/mm²", "N/mm2"], Pressure, Some(Metric), 1e6),
+    unit("kPa", &[], Pressure, Some(Metric), 1e3),
+    unit("Pa", &[], Pressure, Some(Metric), 1.0),
+    unit("bar", &[], Pressure, Some(Metric), 1e5),
+    unit("psi", &[], Pressure, Some(Imperial), 6894.757293168),
+    unit("ksi", &[], Pressure, Some(Imperial), 6894757.293168),
+    unit("N·m", &["Nm", "N*m", "N.m", "N m"], Torque, Some(Metric), 1.0),
+    unit("lbf·ft", &["lbf*ft", "lbf-ft", "ft-lb", "ft·lbf", "lb-ft"], Torque, Some(Imperial), 1.3558179483314),
+    unit("lbf·in", &["lbf*in", "lbf-in", "in-lb", "in·lbf", "lb-in"], Torque, Some(Imperial), 0.1129848290276),
+    Unit { symbol: "°C", aliases: &["C", "degC"], dimension: Temperature, system: Some(Metric), factor: 1.0, offset: 273.15 },
+    unit("K", &[], Temperature, Some(Metric), 1.0),
+    Unit { symbol: "°F", aliases: &["F", "degF"], dimension: Temperature, system: Some(Imperial), factor: 5.0 / 9.0, offset: 255.372_222_222_222_2 },
+    unit("°", &["deg"], Angle, None, 1.0),
+    unit("rad", &[], Angle, None, 57.295_779_513_082_32),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnitError {
+    NotNumber,
+    UnknownUnit(String),
+    /// The unit of the value is not the unit of the dimension of the parameter
+    OtherDimension(String),
+}
+
+impl UnitError {
+    pub(crate) fn message(&self) -> String {
+        match self {
+            UnitError::NotNumber => get_value_field(&555).to_string(),
+            UnitError::UnknownUnit(symbol) => format!("{}: {}", get_value_field(&556), symbol),
+            UnitError::OtherDimension(symbol) => format!("{}: {}", get_value_field(&557), symbol),
+        }
+    }
+}
+
+impl Unit {
+    /// Converts the value in this unit to the value in the other unit of the same dimension
+    pub(crate) fn convert(&self, value: f64, to: &Unit) -> f64 {
+        (value * self.factor + self.offset - to.offset) / to.factor
+    }
+
+    /// Unit of the same dimension to display values in the system
+    pub(crate) fn in_system(&'static self, system: UnitSystem) -> &'static Unit {
+        match self.system {
+            Some(unit_system) if unit_system != system => UNITS
+                .iter()
+                .find(|unit| unit.dimension == self.dimension && unit.system == Some(system))
+                .unwrap_or(self),
+            _ => self,
+        }
+    }
+}
+
+/// Finds the unit by the symbol, the letter case matters unless the symbol is found only in other case
+pub(crate) fn find_unit(symbol: &str) -> Option<&'static Unit> {
+    let symbol = symbol
+        .trim()
+        .replace("<sup>2</sup>", "²")
+        .replace("<sup>3</sup>", "³");
+    let matches = |unit: &&Unit, eq: &dyn Fn(&str) -> bool| eq(unit.symbol) || unit.aliases.iter().any(|alias| eq(alias));
+    UNITS.iter().find(|unit| matches(unit, &|s: &str| s == symbol)).or_else(|| {
+        let mut found = UNITS.iter().filter(|unit| matches(unit, &|s: &str| s.eq_ignore_ascii_case(&symbol)));
+        match (found.next(), found.next()) {
+            (Some(unit), None) => Some(unit),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the unit declared in square brackets at the end of the parameter name, e.g. `Length [mm]`
+pub(crate) fn param_unit(paramname: &str) -> Option<&'static Unit> {
+    let paramname = paramname.trim_end().strip_suffix(']')?;
+    let start = paramname.rfind('[')?;
+    find_unit(&paramname[start + 1..])
+}
+
+/// Returns the name of the parameter with the unit of the system instead of the declared unit
+pub(crate) fn paramname_in_system(paramname: &str, system: Option<UnitSystem>) -> String {
+    let (unit, system) = match (param_unit(paramname), system) {
+        (Some(unit), Some(system)) => (unit, system),
+        _ => return paramname.to_string(),
+    };
+    let display_unit = unit.in_system(system);
+    match (display_unit == unit, paramname.rfind('[')) {
+        (false, Some(start)) => format!("{}[{}]", &paramname[..start], display_unit.symbol),
+        _ => paramname.to_string(),
+    }
+}
+
+/// Splits the value into the number at the beginning and the rest (`12,5 mm` is 12.5 and `mm`)
+pub(crate) fn split_number(value: &str) -> Option<(f64, &str)> {
+    let value = value.trim();
+    let end = value
+        .char_indices()
+        .find(|(index, ch)| !(ch.is_ascii_digit() || *ch == '.' || *ch == ',' || (*index == 0 && (*ch == '-' || *ch == '+'))))
+        .map(|(index, _)| index)
+        .unwrap_or(value.len());
+    let (number, rest) = value.split_at(end);
+    let number = number.replace(',', ".").parse::<f64>().ok().filter(|number| number.is_finite())?;
+    Some((number, rest.trim()))
+}
+
+/// Parses the value with an optional unit and returns the number in the unit of the parameter
+pub(crate) fn parse_value(value: &str, param_unit: &'static Unit) -> Result<f64, UnitError> {
+    let (number, symbol) = split_number(value).ok_or(UnitError::NotNumber)?;
+    if symbol.is_empty() {
+        return Ok(number)
+    }
+    let unit = find_unit(symbol).ok_or_else(|| UnitError::UnknownUnit(symbol.to_string()))?;
+    match unit.dimension == param_unit.dimension {
+        true => Ok(unit.convert(number, param_unit)),
+        false => Err(UnitError::OtherDimension(symbol.to_string())),
+    }
+}
+
+/// Validates the entered value of the parameter and returns the value to save:
+/// the number in the unit of the parameter for parameters with a unit, otherwise the value as is
+pub(crate) fn normalize_value(paramname: &str, value: &str) -> Result<String, UnitError> {
+    match param_unit(paramname) {
+        Some(unit) if !value.trim().is_empty() => parse_value(value, unit).map(show_number),
+        _ => Ok(value.trim().to_string()),
+    }
+}
+
+/// Returns the value of the parameter as a number in the unit of the parameter
+pub(crate) fn value_number(paramname: &str, value: &str) -> Option<f64> {
+    match param_unit(paramname) {
+        Some(unit) => parse_value(value, unit).ok(),
+        None => split_number(value)
+            .filter(|(_, rest)| !rest.chars().any(|ch| ch.is_ascii_digit()))
+            .map(|(number, _)| number),
+    }
+}
+
+/// Returns the value converted to the unit of the system, values that cannot be converted are returned as is
+pub(crate) fn value_in_system(paramname: &str, value: &str, system: Option<UnitSystem>) -> String {
+    let (unit, system) = match (param_unit(paramname), system) {
+        (Some(unit), Some(system)) => (unit, system),
+        _ => return value.to_string(),
+    };
+    let display_unit = unit.in_system(system);
+    match (display_unit == unit, parse_value(value, unit)) {
+        (false, Ok(number)) => show_number(unit.convert(number, display_unit)),
+        _ => value.to_string(),
+    }
+}
+
+/// Rounds the number to 6 decimal places without trailing zeros
+pub(crate) fn show_number(number: f64) -> String {
+    let text = format!("{:.6}", number);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".to_string(),
+        text => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn units_of_params() {
+        assert_eq!(Some("mm"), param_unit("Length [mm]").map(|unit| unit.symbol));
+        assert_eq!(Some("m²"), param_unit("Area [m<sup>2</sup>]").map(|unit| unit.symbol));
+        assert_eq!(Some("N·m"), param_unit("Torque [Nm] ").map(|unit| unit.symbol));
+        assert_eq!(None, param_unit("Thread [metric]"));
+        assert_eq!(None, param_unit("Length, mm"));
+        assert_eq!("Length [in]", paramname_in_system("Length [mm]", Some(Imperial)));
+        assert_eq!("Length [mm]", paramname_in_system("Length [mm]", Some(Metric)));
+        assert_eq!("Angle [°]", paramname_in_system("Angle [°]", Some(Imperial)))
+    }
+
+    #[test]
+    fn normalize_values() {
+        let values = ["25", "25mm", "25 mm", "2,5 cm", "0.98in"]
+            .iter()
+            .map(|value| normalize_value("Length [mm]", value))
+            .collect::<Vec<Result<String, UnitError>>>();
+
+        assert_eq!(
+            vec![Ok("25".to_string()), Ok("25".to_string()), Ok("25".to_string()), Ok("25".to_string()), Ok("24.892".to_string())],
+            values
+        );
+        assert_eq!(Err(UnitError::NotNumber), normalize_value("Length [mm]", "M8"));
+        assert_eq!(Err(UnitError::UnknownUnit("mmm".to_string())), normalize_value("Length [mm]", "5 mmm"));
+        assert_eq!(Err(UnitError::OtherDimension("kg".to_string())), normalize_value("Length [mm]", "5 kg"));
+        assert_eq!(Ok("M8".to_string()), normalize_value("Thread", " M8 "));
+        assert_eq!(Ok(String::new()), normalize_value("Length [mm]", " "))
+    }
+
+    #[test]
+    fn values_in_systems() {
+        assert_eq!("1", value_in_system("Length [mm]", "25.4", Some(Imperial)));
+        assert_eq!("212", value_in_system("Temperature [°C]", "100", Some(Imperial)));
+        assert_eq!("100", value_in_system("Temperature [°F]", "212", Some(Metric)));
+        assert_eq!("M8", value_in_system("Length [mm]", "M8", Some(Imperial)));
+        assert_eq!("25.4", value_in_system("Length [mm]", "25.4", None));
+        assert_eq!(Some(25.4), value_number("Length [mm]", "1 in"));
+        assert_eq!(Some(12.0), value_number("Length", "12 mm"));
+        assert_eq!(None, value_number("Size", "8x30"))
+    }
+}
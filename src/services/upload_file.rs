@@ -1,7 +1,8 @@
 use yew::callback::Callback;
-use web_sys::XmlHttpRequest;
+use web_sys::{Response, XmlHttpRequest};
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
 use crate::error::Error;
 use crate::types::ErrorInfo;
 // use log::debug;
@@ -67,3 +68,22 @@ pub(crate) fn put_file(
     let body = js_sys::Uint8Array::from(&upload_data.file_data[..]);
     xhr.send_with_opt_buffer_source(Some(&body)).unwrap();
 }
+
+/// Get file data from storage by the presigned url
+pub(crate) async fn get_file(download_url: &str) -> Result<Vec<u8>, Error> {
+    let window = yew::utils::window();
+    let resp_value = JsFuture::from(window.fetch_with_str(download_url)).await
+        .map_err(|_| Error::RequestError)?;
+    let resp: Response = resp_value.dyn_into().map_err(|_| Error::RequestError)?;
+    match resp.status() {
+        200..=299 => {},
+        401 => return Err(Error::Unauthorized),
+        403 => return Err(Error::Forbidden),
+        404 => return Err(Error::NotFound),
+        500 => return Err(Error::InternalServerError),
+        _ => return Err(Error::RequestError),
+    }
+    let data = JsFuture::from(resp.array_buffer().map_err(|_| Error::RequestError)?).await
+        .map_err(|_| Error::RequestError)?;
+    Ok(js_sys::Uint8Array::new(&data).to_vec())
+}
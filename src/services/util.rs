@@ -78,6 +78,39 @@ pub(crate) fn wraps_text(text: String) -> Option<String> {
     }
 }
 
+/// Percent-encodes everything except the unreserved characters
+pub(super) fn encode_value(value: &str) -> String {
+    value.bytes().map(|byte| match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte),
+    }).collect()
+}
+
+/// Decodes the value encoded by `encode_value`, `+` is decoded as a space
+pub(super) fn decode_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = value.get(index + 1..index + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            (b'+', _) => {
+                decoded.push(b' ');
+                index += 1;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
 #[cfg(test)]
 mod test_utils {
     use super::*;
@@ -101,4 +134,12 @@ mod test_utils {
 
         assert_eq!(output_test, result)
     }
+
+    #[test]
+    fn encode_decode_value() {
+        let value = "M8 x 30; 50% (A2-70)";
+        assert_eq!(encode_value(value), "M8%20x%2030%3B%2050%25%20%28A2-70%29");
+        assert_eq!(decode_value(&encode_value(value)), value);
+        assert_eq!(decode_value("M8+x+30%2"), "M8 x 30%2");
+    }
 }
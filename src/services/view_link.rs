@@ -0,0 +1,235 @@
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+use crate::types::UUID;
+use super::modifications_filter::ModificationsFilter;
+
+/// Camera and display settings of the 3D viewer that can be restored from a link
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewerState {
+    pub camera: Option<[f64; 3]>,
+    pub target: Option<[f64; 3]>,
+    pub wireframe: bool,
+    /// Color in hex without `#`, e.g. `1872f0`
+    pub model_color: Option<String>,
+    pub background: Option<String>,
+    /// Active G-code layer
+    pub layer: Option<usize>,
+    /// Point of the annotation pin (in the coordinates of the scene)
+    pub pin: Option<[f64; 3]>,
+}
+
+/// Modification, fileset and file of a component with the state of the viewer,
+/// encoded in the query of the component route (`#/component/{uuid}?mod=...&file=...`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentViewLink {
+    pub modification_uuid: Option<UUID>,
+    pub fileset_uuid: Option<UUID>,
+    pub file_uuid: Option<UUID>,
+    pub viewer: ViewerState,
+    /// Filters and sorting of the modifications table
+    pub filter: ModificationsFilter,
+}
+
+impl ComponentViewLink {
+    /// Parses the query of the route, values that cannot be parsed are skipped
+    pub(crate) fn from_query(query: &str) -> Self {
+        let mut view_link = Self::default();
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key {
+                "mod" => view_link.modification_uuid = parse_uuid(value),
+                "fileset" => view_link.fileset_uuid = parse_uuid(value),
+                "file" => view_link.file_uuid = parse_uuid(value),
+                "cam" => view_link.viewer.camera = parse_vector(value),
+                "target" => view_link.viewer.target = parse_vector(value),
+                "wire" => view_link.viewer.wireframe = value == "1",
+                "color" => view_link.viewer.model_color = parse_color(value),
+                "bg" => view_link.viewer.background = parse_color(value),
+                "layer" => view_link.viewer.layer = value.parse().ok(),
+                "pin" => view_link.viewer.pin = parse_vector(value),
+                _ => view_link.filter.parse_pair(key, value),
+            }
+        }
+        view_link
+    }
+
+    /// Splits the route fragment into the component UUID and the view link
+    pub(crate) fn from_fragment(fragment: &str) -> (UUID, Self) {
+        let fragment = fragment.trim_start_matches("#/component/");
+        match fragment.split_once('?') {
+            Some((component_uuid, query)) => (component_uuid.to_string(), Self::from_query(query)),
+            None => (fragment.to_string(), Self::default()),
+        }
+    }
+
+    pub(crate) fn to_query(&self) -> String {
+        let mut params = Vec::new();
+        let uuids = [("mod", &self.modification_uuid), ("fileset", &self.fileset_uuid), ("file", &self.file_uuid)];
+        for (key, value) in uuids {
+            if let Some(uuid) = value {
+                params.push(format!("{}={}", key, uuid));
+            }
+        }
+        if let Some(camera) = &self.viewer.camera {
+            params.push(format!("cam={}", show_vector(camera)));
+        }
+        if let Some(target) = &self.viewer.target {
+            params.push(format!("target={}", show_vector(target)));
+        }
+        if self.viewer.wireframe {
+            params.push("wire=1".to_string());
+        }
+        if let Some(color) = &self.viewer.model_color {
+            params.push(format!("color={}", color));
+        }
+        if let Some(color) = &self.viewer.background {
+            params.push(format!("bg={}", color));
+        }
+        if let Some(layer) = self.viewer.layer {
+            params.push(format!("layer={}", layer));
+        }
+        if let Some(pin) = &self.viewer.pin {
+            params.push(format!("pin={}", show_vector(pin)));
+        }
+        params.extend(self.filter.to_params());
+        params.join("&")
+    }
+
+    /// Replaces the query of the component link (`href`) with the view link
+    pub(crate) fn to_href(&self, href: &str) -> String {
+        let base = href.split('?').next().unwrap_or_default();
+        match self.to_query().as_str() {
+            "" => base.to_string(),
+            query => format!("{}?{}", base, query),
+        }
+    }
+
+    /// Returns true if the link sets the file or the state of the viewer
+    pub(crate) fn has_view(&self) -> bool {
+        self.file_uuid.is_some() || self.viewer != ViewerState::default()
+    }
+
+    /// Returns the annotation as a markdown link to the component view, e.g.
+    /// `[📍 Annotation](#/component/{uuid}?mod=...&pin=1,2,3)`
+    pub(crate) fn to_annotation(&self, component_uuid: &str, label: &str) -> String {
+        format!("[📍 {}](#/component/{}?{})", label, component_uuid, self.to_query())
+    }
+}
+
+/// Separates the annotations (links to the component view with a pin) from the text of a comment
+pub(crate) fn split_annotations(text: &str) -> (String, Vec<ComponentViewLink>) {
+    let re = Regex::new(r"\[📍[^\]]*\]\(#/component/[0-9a-fA-F-]{36}\?([^)\s]+)\)").unwrap();
+    let annotations = re
+        .captures_iter(text)
+        .map(|cap| ComponentViewLink::from_query(&cap[1]))
+        .filter(|view_link| view_link.viewer.pin.is_some())
+        .collect::<Vec<ComponentViewLink>>();
+    match annotations.is_empty() {
+        true => (text.to_string(), annotations),
+        false => (re.replace_all(text, "").trim_end().to_string(), annotations),
+    }
+}
+
+fn parse_uuid(value: &str) -> Option<UUID> {
+    match value.len() == 36 && value.chars().all(|ch| ch.is_ascii_hexdigit() || ch == '-') {
+        true => Some(value.to_string()),
+        false => None,
+    }
+}
+
+fn parse_vector(value: &str) -> Option<[f64; 3]> {
+    let coords = value
+        .split(',')
+        .map(|coord| coord.parse::<f64>().ok().filter(|c| c.is_finite()))
+        .collect::<Option<Vec<f64>>>()?;
+    match coords.as_slice() {
+        [x, y, z] => Some([*x, *y, *z]),
+        _ => None,
+    }
+}
+
+fn parse_color(value: &str) -> Option<String> {
+    match value.len() == 6 && value.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        true => Some(value.to_ascii_lowercase()),
+        false => None,
+    }
+}
+
+/// Coordinates are rounded to hundredths to keep the link short
+fn show_vector(vector: &[f64; 3]) -> String {
+    vector
+        .iter()
+        .map(|coord| {
+            let coord = (coord * 100.0).round() / 100.0;
+            // avoid "-0" in the link
+            if coord == 0.0 { "0".to_string() } else { coord.to_string() }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn view_link_round_trip() {
+        let view_link = ComponentViewLink {
+            modification_uuid: Some("a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d".to_string()),
+            fileset_uuid: None,
+            file_uuid: Some("00000000-1111-2222-3333-444444444444".to_string()),
+            viewer: ViewerState {
+                camera: Some([12.5, -3.0, 40.0]),
+                target: Some([0.0, 0.0, 0.0]),
+                wireframe: true,
+                model_color: Some("1872f0".to_string()),
+                background: None,
+                layer: Some(7),
+                pin: None,
+            },
+            filter: ModificationsFilter::default(),
+        };
+
+        let query = view_link.to_query();
+
+        assert_eq!(
+            "mod=a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d&file=00000000-1111-2222-3333-444444444444&cam=12.5,-3,40&target=0,0,0&wire=1&color=1872f0&layer=7",
+            query
+        );
+        assert_eq!(view_link, ComponentViewLink::from_query(&query))
+    }
+
+    #[test]
+    fn view_link_from_fragment() {
+        let fragment = "#/component/a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d?cam=1,2&color=red&layer=x&bg=FFFFFF";
+
+        let (component_uuid, view_link) = ComponentViewLink::from_fragment(fragment);
+
+        assert_eq!("a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d", component_uuid);
+        assert_eq!(None, view_link.viewer.camera);
+        assert_eq!(None, view_link.viewer.model_color);
+        assert_eq!(None, view_link.viewer.layer);
+        assert_eq!(Some("ffffff".to_string()), view_link.viewer.background);
+        assert!(view_link.has_view())
+    }
+
+    #[test]
+    fn annotations_in_comment() {
+        let view_link = ComponentViewLink {
+            fileset_uuid: Some("a1b2c3d4-e5f6-4a5b-8c9d-0e1f2a3b4c5d".to_string()),
+            viewer: ViewerState {
+                camera: Some([10.0, 10.0, 10.0]),
+                target: Some([0.0, 0.0, 0.0]),
+                pin: Some([1.25, 0.5, -2.0]),
+                ..ViewerState::default()
+            },
+            ..ComponentViewLink::default()
+        };
+        let annotation = view_link.to_annotation("00000000-1111-2222-3333-444444444444", "Annotation");
+        let comment = format!("The hole is too close to the edge\n\n{}", annotation);
+
+        let (text, annotations) = split_annotations(&comment);
+
+        assert_eq!("The hole is too close to the edge", text);
+        assert_eq!(vec![view_link], annotations)
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use miniz_oxide::inflate::decompress_to_vec;
+use miniz_oxide::deflate::compress_to_vec;
+
+/// File of the ZIP archive
+struct ZipEntry {
+    method: u16,
+    compressed_size: usize,
+    offset: usize,
+}
+
+/// Reader of the files of a ZIP archive (without encryption and ZIP64)
+pub(crate) struct ZipArchive<'a> {
+    data: &'a [u8],
+    entries: BTreeMap<String, ZipEntry>,
+}
+
+impl<'a> ZipArchive<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Result<Self, String> {
+        let corrupted = || String::from("The file is not a ZIP archive or is corrupted");
+        // the end of central directory record is placed before the comment of the archive (up to 64 KiB)
+        let last = data.len().checked_sub(22).ok_or_else(corrupted)?;
+        let eocd = (last.saturating_sub(0xFFFF)..=last).rev()
+            .find(|&pos| read_u32(data, pos) == Some(0x0605_4b50))
+            .ok_or_else(corrupted)?;
+        let count = read_u16(data, eocd + 10).ok_or_else(corrupted)? as usize;
+        let mut pos = read_u32(data, eocd + 16).ok_or_else(corrupted)? as usize;
+        let mut entries = BTreeMap::new();
+        for _ in 0..count {
+            if read_u32(data, pos) != Some(0x0201_4b50) {
+                return Err(corrupted())
+            }
+            let field = |shift: usize| read_u16(data, pos + shift).map(|v| v as usize).ok_or_else(corrupted);
+            let (name_len, extra_len, comment_len) = (field(28)?, field(30)?, field(32)?);
+            let name = data.get(pos + 46..pos + 46 + name_len).ok_or_else(corrupted)?;
+            entries.insert(String::from_utf8_lossy(name).to_string(), ZipEntry {
+                method: field(10)? as u16,
+                compressed_size: read_u32(data, pos + 20).ok_or_else(corrupted)? as usize,
+                offset: read_u32(data, pos + 42).ok_or_else(corrupted)? as usize,
+            });
+            pos += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(Self { data, entries })
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Returns the unpacked data of the file by the path in the archive
+    pub(crate) fn file(&self, name: &str) -> Option<Vec<u8>> {
+        let entry = self.entries.get(name)?;
+        if read_u32(self.data, entry.offset) != Some(0x0403_4b50) {
+            return None
+        }
+        let name_len = read_u16(self.data, entry.offset + 26)? as usize;
+        let extra_len = read_u16(self.data, entry.offset + 28)? as usize;
+        let start = entry.offset + 30 + name_len + extra_len;
+        let raw = self.data.get(start..start + entry.compressed_size)?;
+        match entry.method {
+            0 => Some(raw.to_vec()),
+            8 => decompress_to_vec(raw).ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn file_text(&self, name: &str) -> Result<String, String> {
+        self.file(name)
+            .map(|data| String::from_utf8_lossy(&data).to_string())
+            .ok_or_else(|| format!("Failed to read {} from the file", name))
+    }
+}
+
+fn read_u16(data: &[u8], pos: usize) -> Option<u16> {
+    data.get(pos..pos + 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    data.get(pos..pos + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Writer of a ZIP archive with compressed files
+#[derive(Default)]
+pub(crate) struct ZipWriter {
+    data: Vec<u8>,
+    directory: Vec<u8>,
+    count: u16,
+}
+
+impl ZipWriter {
+    /// Adds the compressed file, the count of the files is limited by 65535 (without ZIP64)
+    pub(crate) fn add(&mut self, name: &str, content: &[u8]) -> Result<(), String> {
+        if self.count == u16::MAX {
+            return Err(format!("The ZIP archive cannot contain more than {} files", u16::MAX))
+        }
+        let compressed = compress_to_vec(content, 6);
+        let offset = self.data.len() as u32;
+        // version, flags (UTF-8 names), method, time and date (1980-01-01), CRC-32, sizes, the length of the name
+        let mut fields = Vec::with_capacity(26);
+        fields.extend(20u16.to_le_bytes());
+        fields.extend(0x0800u16.to_le_bytes());
+        fields.extend(8u16.to_le_bytes());
+        fields.extend(0u16.to_le_bytes());
+        fields.extend(0x0021u16.to_le_bytes());
+        fields.extend(crc32(content).to_le_bytes());
+        fields.extend((compressed.len() as u32).to_le_bytes());
+        fields.extend((content.len() as u32).to_le_bytes());
+        fields.extend((name.len() as u16).to_le_bytes());
+        // local header
+        self.data.extend(0x0403_4b50u32.to_le_bytes());
+        self.data.extend(&fields);
+        self.data.extend(0u16.to_le_bytes());
+        self.data.extend(name.as_bytes());
+        self.data.extend(&compressed);
+        // central directory entry
+        self.directory.extend(0x0201_4b50u32.to_le_bytes());
+        self.directory.extend(20u16.to_le_bytes());
+        self.directory.extend(&fields);
+        // the lengths of the extra field and the comment, the disk, attributes
+        self.directory.extend([0u8; 12]);
+        self.directory.extend(offset.to_le_bytes());
+        self.directory.extend(name.as_bytes());
+        self.count += 1;
+        Ok(())
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        let directory_offset = self.data.len() as u32;
+        let directory_size = self.directory.len() as u32;
+        self.data.append(&mut self.directory);
+        self.data.extend(0x0605_4b50u32.to_le_bytes());
+        self.data.extend([0u8; 4]);
+        self.data.extend(self.count.to_le_bytes());
+        self.data.extend(self.count.to_le_bytes());
+        self.data.extend(directory_size.to_le_bytes());
+        self.data.extend(directory_offset.to_le_bytes());
+        self.data.extend(0u16.to_le_bytes());
+        self.data
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    #[test]
+    fn zip_write_and_read() {
+        let mut writer = ZipWriter::default();
+        writer.add("xl/workbook.xml", b"<workbook/>").unwrap();
+        writer.add("\u{0434}\u{0430}\u{043d}\u{043d}\u{044b}\u{0435}.txt", &[7u8; 1000]).unwrap();
+        let data = writer.finish();
+
+        let archive = ZipArchive::new(&data).unwrap();
+
+        assert_eq!(Ok(String::from("<workbook/>")), archive.file_text("xl/workbook.xml"));
+        assert_eq!(Some(vec![7u8; 1000]), archive.file("данные.txt"));
+        assert_eq!(None, archive.file("other.txt"));
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+    }
+
+    #[test]
+    fn zip_files_limit() {
+        let mut writer = ZipWriter { count: u16::MAX - 1, ..Default::default() };
+        assert_eq!(Ok(()), writer.add("65535.txt", b"last"));
+        assert!(writer.add("65536.txt", b"over").is_err());
+        assert_eq!(u16::MAX, writer.count);
+    }
+}
@@ -17,6 +17,7 @@ pub struct ComponentInfo {
   pub image_file: DownloadFile,
   pub owner_user: ShowUserShort,
   pub type_access: TypeAccessInfo,
+  pub component_type: ComponentType,
   pub actual_status: ActualStatus,
   pub is_base: bool,
   pub subscribers: usize,
@@ -36,6 +37,15 @@ pub struct ComponentInfo {
   pub standards_count: i64,
 }
 
+/// Component with the UUID of the component it is derived from
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentParentInfo {
+  pub uuid: UUID,
+  pub parent_component_uuid: UUID,
+  pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ComponentCreateData {
@@ -133,6 +143,14 @@ pub struct Supplier{
   pub description: String
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentType{
+  pub component_type_id: usize,
+  pub lang_id: usize,
+  pub component_type: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ActualStatus{
@@ -157,7 +175,7 @@ pub struct Param{
   pub paramname: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ParamValue{
   pub param_id: usize,
@@ -178,6 +196,36 @@ pub struct ComponentModificationInfo{
   pub files_count: i64,
 }
 
+#[cfg(test)]
+impl Default for ComponentModificationInfo {
+  fn default() -> Self {
+    Self {
+      uuid: String::new(),
+      component_uuid: String::new(),
+      parent_modification_uuid: String::new(),
+      modification_name: String::new(),
+      description: String::new(),
+      actual_status: ActualStatus { actual_status_id: 1, lang_id: 1, name: String::new() },
+      updated_at: NaiveDateTime::from_timestamp(0, 0),
+      modification_params: Vec::new(),
+      files_count: 0,
+    }
+  }
+}
+
+#[cfg(test)]
+impl ComponentModificationInfo {
+  /// Sets the parameters of the modification by the id, the name and the value
+  pub(crate) fn with_params(mut self, params: &[(usize, &str, &str)]) -> Self {
+    self.modification_params = params.iter().map(|(param_id, paramname, value)| ModificationParam {
+      modification_uuid: self.uuid.clone(),
+      param: Param { param_id: *param_id, lang_id: 1, paramname: paramname.to_string() },
+      value: value.to_string(),
+    }).collect();
+    self
+  }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct NewModificationsPreData{
     pub modification_name: String,
@@ -30,6 +30,17 @@ pub struct DownloadFile {
     pub download_url: String,
 }
 
+impl From<&ShowFileInfo> for DownloadFile {
+    fn from(file_info: &ShowFileInfo) -> Self {
+        Self {
+            uuid: file_info.uuid.clone(),
+            filename: file_info.filename.clone(),
+            filesize: file_info.filesize,
+            download_url: file_info.download_url.clone(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UploadFile {